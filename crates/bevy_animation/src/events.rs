@@ -0,0 +1,260 @@
+//! Events attached to [`AnimationClip`](crate::AnimationClip)s.
+
+use std::{fmt, sync::Arc};
+
+use bevy_ecs::prelude::*;
+use bevy_reflect::{
+    serde::{ReflectSerializer, UntypedReflectDeserializer},
+    FromReflect, FromType, Reflect, TypeRegistry,
+};
+use serde::{
+    de::{DeserializeSeed, Error, MapAccess, SeqAccess, Visitor},
+    ser::{SerializeSeq, SerializeStruct},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+/// An event attached to an [`AnimationClip`](crate::AnimationClip) at a specific time.
+///
+/// See [`AnimationClip::add_event`](crate::AnimationClip::add_event).
+#[derive(Clone, Debug)]
+pub struct TimedAnimationEvent {
+    /// The time, in seconds from the start of the clip, at which the event is
+    /// triggered.
+    pub time: f32,
+    /// The event to trigger.
+    pub event: AnimationEvent,
+}
+
+/// The event triggered when playback crosses a [`TimedAnimationEvent`].
+#[derive(Debug)]
+pub enum AnimationEvent {
+    /// A reflected [`Event`], triggered on the [`AnimationPlayer`](crate::AnimationPlayer) entity.
+    ///
+    /// The type of the event must be registered with [`ReflectAnimationEvent`] type data, usually
+    /// with `#[reflect(AnimationEvent)]`. Reflected events can be serialized with an
+    /// [`AnimationEventsSerializer`] and loaded from glTF files.
+    Reflected(Box<dyn Reflect>),
+    /// A function called when the event is triggered. Functions can't be serialized.
+    Fn(AnimationEventFn),
+}
+
+impl Clone for AnimationEvent {
+    fn clone(&self) -> Self {
+        match self {
+            AnimationEvent::Reflected(event) => AnimationEvent::Reflected(event.clone_value()),
+            AnimationEvent::Fn(func) => AnimationEvent::Fn(func.clone()),
+        }
+    }
+}
+
+/// A type-erased function that triggers an animation event.
+///
+/// It's called with the [`AnimationPlayer`](crate::AnimationPlayer) entity, the time of the event
+/// in the clip, and the weight of the animation in the [`AnimationGraph`](crate::prelude::AnimationGraph).
+#[derive(Clone)]
+pub struct AnimationEventFn(Arc<dyn Fn(&mut Commands, Entity, f32, f32) + Send + Sync>);
+
+impl AnimationEventFn {
+    /// Creates a new [`AnimationEventFn`] from the given function.
+    pub fn new(func: impl Fn(&mut Commands, Entity, f32, f32) + Send + Sync + 'static) -> Self {
+        Self(Arc::new(func))
+    }
+
+    /// Triggers the event for the given [`AnimationPlayer`](crate::AnimationPlayer) entity.
+    pub fn trigger(&self, commands: &mut Commands, entity: Entity, time: f32, weight: f32) {
+        (self.0)(commands, entity, time, weight);
+    }
+}
+
+impl fmt::Debug for AnimationEventFn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("AnimationEventFn").finish_non_exhaustive()
+    }
+}
+
+/// Type data that allows a reflected [`Event`] to be triggered as an [`AnimationEvent::Reflected`].
+///
+/// ```
+/// # use bevy_animation::ReflectAnimationEvent;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_reflect::Reflect;
+/// #[derive(Event, Reflect)]
+/// #[reflect(AnimationEvent)]
+/// struct Footstep {
+///     volume: f32,
+/// }
+/// ```
+#[derive(Clone)]
+pub struct ReflectAnimationEvent {
+    trigger: fn(&dyn Reflect, &mut Commands, Entity) -> bool,
+}
+
+impl ReflectAnimationEvent {
+    /// Triggers `event` on the given [`AnimationPlayer`](crate::AnimationPlayer) entity.
+    ///
+    /// Returns `false` if `event` couldn't be converted to the type of this type data.
+    pub fn trigger(&self, event: &dyn Reflect, commands: &mut Commands, entity: Entity) -> bool {
+        (self.trigger)(event, commands, entity)
+    }
+}
+
+impl<E: Event + FromReflect> FromType<E> for ReflectAnimationEvent {
+    fn from_type() -> Self {
+        ReflectAnimationEvent {
+            trigger: |event, commands, entity| {
+                let Some(event) = E::from_reflect(event) else {
+                    return false;
+                };
+                commands.trigger_targets(event, entity);
+                true
+            },
+        }
+    }
+}
+
+/// Serializes the reflected events of an [`AnimationClip`](crate::AnimationClip).
+///
+/// Each event is serialized as a `time` and an `event`, in the format of a [`ReflectSerializer`].
+/// [`AnimationEvent::Fn`] events can't be serialized and are skipped.
+pub struct AnimationEventsSerializer<'a> {
+    events: &'a [TimedAnimationEvent],
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> AnimationEventsSerializer<'a> {
+    /// Creates a serializer for the given events.
+    pub fn new(events: &'a [TimedAnimationEvent], registry: &'a TypeRegistry) -> Self {
+        Self { events, registry }
+    }
+}
+
+impl<'a> Serialize for AnimationEventsSerializer<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let reflected = self.events.iter().filter_map(|event| match &event.event {
+            AnimationEvent::Reflected(value) => Some((event.time, value)),
+            AnimationEvent::Fn(_) => None,
+        });
+        let mut seq = serializer.serialize_seq(Some(reflected.clone().count()))?;
+        for (time, value) in reflected {
+            seq.serialize_element(&TimedEventSerializer {
+                time,
+                event: ReflectSerializer::new(value.as_ref(), self.registry),
+            })?;
+        }
+        seq.end()
+    }
+}
+
+struct TimedEventSerializer<'a> {
+    time: f32,
+    event: ReflectSerializer<'a>,
+}
+
+impl<'a> Serialize for TimedEventSerializer<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("TimedAnimationEvent", 2)?;
+        state.serialize_field("time", &self.time)?;
+        state.serialize_field("event", &self.event)?;
+        state.end()
+    }
+}
+
+/// Deserializes the events written by an [`AnimationEventsSerializer`] as
+/// [`AnimationEvent::Reflected`] events.
+///
+/// The returned events can be added to a clip with
+/// [`AnimationClip::add_reflected_event`](crate::AnimationClip::add_reflected_event).
+pub struct AnimationEventsDeserializer<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> AnimationEventsDeserializer<'a> {
+    /// Creates a deserializer that looks up the types of the events in `registry`.
+    pub fn new(registry: &'a TypeRegistry) -> Self {
+        Self { registry }
+    }
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for AnimationEventsDeserializer<'a> {
+    type Value = Vec<TimedAnimationEvent>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for AnimationEventsDeserializer<'a> {
+    type Value = Vec<TimedAnimationEvent>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a list of animation events")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut events = Vec::new();
+        while let Some(event) = seq.next_element_seed(TimedEventDeserializer {
+            registry: self.registry,
+        })? {
+            events.push(event);
+        }
+        Ok(events)
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(field_identifier, rename_all = "lowercase")]
+enum TimedEventField {
+    Time,
+    Event,
+}
+
+struct TimedEventDeserializer<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for TimedEventDeserializer<'a> {
+    type Value = TimedAnimationEvent;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_struct("TimedAnimationEvent", &["time", "event"], self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for TimedEventDeserializer<'a> {
+    type Value = TimedAnimationEvent;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("an animation event with a `time` and an `event`")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let time = seq
+            .next_element::<f32>()?
+            .ok_or_else(|| Error::invalid_length(0, &self))?;
+        let event = seq
+            .next_element_seed(UntypedReflectDeserializer::new(self.registry))?
+            .ok_or_else(|| Error::invalid_length(1, &self))?;
+        Ok(TimedAnimationEvent {
+            time,
+            event: AnimationEvent::Reflected(event),
+        })
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut time = None;
+        let mut event = None;
+        while let Some(key) = map.next_key::<TimedEventField>()? {
+            match key {
+                TimedEventField::Time => time = Some(map.next_value::<f32>()?),
+                TimedEventField::Event => {
+                    event =
+                        Some(map.next_value_seed(UntypedReflectDeserializer::new(self.registry))?);
+                }
+            }
+        }
+        Ok(TimedAnimationEvent {
+            time: time.ok_or_else(|| Error::missing_field("time"))?,
+            event: AnimationEvent::Reflected(event.ok_or_else(|| Error::missing_field("event"))?),
+        })
+    }
+}
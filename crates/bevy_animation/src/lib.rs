@@ -1,22 +1,28 @@
 //! Animation for the game engine Bevy
 
 mod animatable;
+mod events;
 mod graph;
 mod transition;
 mod util;
 
+pub use events::*;
+
 use std::cell::RefCell;
 use std::collections::BTreeMap;
+use std::fmt;
 use std::hash::{Hash, Hasher};
 use std::iter;
 use std::ops::{Add, Mul};
+use std::sync::Arc;
 
 use bevy_app::{App, Plugin, PostUpdate};
 use bevy_asset::{Asset, AssetApp, Assets, Handle};
 use bevy_core::Name;
 use bevy_ecs::entity::MapEntities;
 use bevy_ecs::prelude::*;
-use bevy_ecs::reflect::ReflectMapEntities;
+use bevy_ecs::reflect::{AppTypeRegistry, ReflectMapEntities};
+use bevy_math::curve::{Curve, Interval};
 use bevy_math::{FloatExt, Quat, Vec3};
 use bevy_reflect::Reflect;
 use bevy_render::mesh::morph::MorphWeights;
@@ -25,7 +31,7 @@ use bevy_transform::{prelude::Transform, TransformSystem};
 use bevy_utils::hashbrown::HashMap;
 use bevy_utils::{
     tracing::{error, trace},
-    NoOpHash,
+    warn_once, NoOpHash,
 };
 use fixedbitset::FixedBitSet;
 use graph::{AnimationGraph, AnimationNodeIndex};
@@ -40,8 +46,8 @@ use uuid::Uuid;
 pub mod prelude {
     #[doc(hidden)]
     pub use crate::{
-        animatable::*, graph::*, transition::*, AnimationClip, AnimationPlayer, AnimationPlugin,
-        Interpolation, Keyframes, VariableCurve,
+        animatable::*, graph::*, transition::*, AnimatedCurve, AnimationClip, AnimationPlayer,
+        AnimationPlugin, Interpolation, Keyframes, VariableCurve,
     };
 }
 
@@ -70,15 +76,26 @@ pub enum Keyframes {
     ///
     /// [glTF design]: https://registry.khronos.org/glTF/specs/2.0/glTF-2.0.html#animations
     Weights(Vec<f32>),
+    /// A [`Curve`] that is sampled at the seek time instead of interpolating
+    /// between keyframes.
+    ///
+    /// This variant has no keyframes: the [`VariableCurve`] holding it has no
+    /// `keyframe_timestamps`, its `interpolation` is unused, and its time span
+    /// is the domain of the curve. See [`VariableCurve::from_curve`] and
+    /// [`VariableCurve::domain`].
+    Curve(AnimatedCurve),
 }
 
 impl Keyframes {
     /// Returns the number of keyframes.
+    ///
+    /// A [`Keyframes::Curve`] has no keyframes.
     pub fn len(&self) -> usize {
         match self {
             Keyframes::Weights(vec) => vec.len(),
             Keyframes::Translation(vec) | Keyframes::Scale(vec) => vec.len(),
             Keyframes::Rotation(vec) => vec.len(),
+            Keyframes::Curve(_) => 0,
         }
     }
 
@@ -88,9 +105,81 @@ impl Keyframes {
     }
 }
 
+/// A [`Curve`] driving an attribute of a [`Transform`] or [`MorphWeights`].
+///
+/// This allows animations to be driven procedurally, by any function of time,
+/// instead of by baked keyframes. See [`VariableCurve::from_curve`].
+#[derive(Reflect, Clone)]
+#[reflect_value]
+pub enum AnimatedCurve {
+    /// A curve for the translation.
+    Translation(Arc<dyn Curve<Vec3> + Send + Sync>),
+    /// A curve for the rotation.
+    Rotation(Arc<dyn Curve<Quat> + Send + Sync>),
+    /// A curve for the scale.
+    Scale(Arc<dyn Curve<Vec3> + Send + Sync>),
+    /// A curve for the morph target weights, with one value per target.
+    Weights(Arc<dyn Curve<Vec<f32>> + Send + Sync>),
+}
+
+impl AnimatedCurve {
+    /// Creates an [`AnimatedCurve`] for the translation.
+    pub fn translation(curve: impl Curve<Vec3> + Send + Sync + 'static) -> Self {
+        Self::Translation(Arc::new(curve))
+    }
+
+    /// Creates an [`AnimatedCurve`] for the rotation.
+    pub fn rotation(curve: impl Curve<Quat> + Send + Sync + 'static) -> Self {
+        Self::Rotation(Arc::new(curve))
+    }
+
+    /// Creates an [`AnimatedCurve`] for the scale.
+    pub fn scale(curve: impl Curve<Vec3> + Send + Sync + 'static) -> Self {
+        Self::Scale(Arc::new(curve))
+    }
+
+    /// Creates an [`AnimatedCurve`] for the morph target weights.
+    pub fn weights(curve: impl Curve<Vec<f32>> + Send + Sync + 'static) -> Self {
+        Self::Weights(Arc::new(curve))
+    }
+
+    /// The domain of the underlying [`Curve`].
+    pub fn domain(&self) -> Interval {
+        match self {
+            AnimatedCurve::Translation(curve) | AnimatedCurve::Scale(curve) => curve.domain(),
+            AnimatedCurve::Rotation(curve) => curve.domain(),
+            AnimatedCurve::Weights(curve) => curve.domain(),
+        }
+    }
+
+    /// The time at which the curve is sampled for the given `seek_time`: the
+    /// seek time clamped to the [domain](AnimatedCurve::domain) of the curve,
+    /// so that its first and last values are held before and after it.
+    pub fn sample_time(&self, seek_time: f32) -> f32 {
+        self.domain().clamp(seek_time)
+    }
+}
+
+impl fmt::Debug for AnimatedCurve {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            AnimatedCurve::Translation(_) => "Translation",
+            AnimatedCurve::Rotation(_) => "Rotation",
+            AnimatedCurve::Scale(_) => "Scale",
+            AnimatedCurve::Weights(_) => "Weights",
+        };
+        f.debug_struct("AnimatedCurve")
+            .field("property", &name)
+            .field("domain", &self.domain())
+            .finish()
+    }
+}
+
 /// Describes how an attribute of a [`Transform`] or [`MorphWeights`] should be animated.
 ///
-/// `keyframe_timestamps` and `keyframes` should have the same length.
+/// `keyframe_timestamps` and `keyframes` should have the same length. A
+/// [`Keyframes::Curve`] has no keyframes, so its `keyframe_timestamps` are
+/// empty. Use [`VariableCurve::domain`] for the time span of any curve.
 #[derive(Reflect, Clone, Debug)]
 pub struct VariableCurve {
     /// Timestamp for each of the keyframes.
@@ -108,12 +197,80 @@ pub struct VariableCurve {
 }
 
 impl VariableCurve {
+    /// Creates a [`VariableCurve`] that samples the given [`AnimatedCurve`]
+    /// instead of interpolating between keyframes.
+    ///
+    /// The curve is sampled at the seek time of the animation, clamped to the
+    /// domain of the curve, so the first and last values of the curve are held
+    /// before and after its domain.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_animation::{AnimatedCurve, VariableCurve};
+    /// # use bevy_math::{curve::{interval, FunctionCurve}, Vec3};
+    /// // Bob up and down for two seconds.
+    /// let bobbing = FunctionCurve::new(interval(0.0, 2.0).unwrap(), |t| {
+    ///     Vec3::new(0.0, (t * std::f32::consts::PI).sin(), 0.0)
+    /// });
+    /// let curve = VariableCurve::from_curve(AnimatedCurve::translation(bobbing));
+    /// assert_eq!(curve.domain(), Some(interval(0.0, 2.0).unwrap()));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the domain of the curve is unbounded.
+    pub fn from_curve(curve: AnimatedCurve) -> Self {
+        let domain = curve.domain();
+        assert!(
+            domain.is_bounded(),
+            "animation curves must have a bounded domain"
+        );
+        Self {
+            keyframe_timestamps: Vec::new(),
+            keyframes: Keyframes::Curve(curve),
+            interpolation: Interpolation::Linear,
+        }
+    }
+
+    /// The span of time covered by this curve.
+    ///
+    /// This is the domain of a [`Keyframes::Curve`], or the span from the first
+    /// to the last keyframe timestamp. Returns [`None`] for keyframes with fewer
+    /// than two distinct timestamps.
+    pub fn domain(&self) -> Option<Interval> {
+        match &self.keyframes {
+            Keyframes::Curve(curve) => Some(curve.domain()),
+            _ => Interval::new(
+                *self.keyframe_timestamps.first()?,
+                *self.keyframe_timestamps.last()?,
+            )
+            .ok(),
+        }
+    }
+
+    /// The time of the end of this curve: the end of the domain of a
+    /// [`Keyframes::Curve`], or the last keyframe timestamp.
+    fn end_time(&self) -> Option<f32> {
+        match &self.keyframes {
+            Keyframes::Curve(curve) => Some(curve.domain().end()),
+            _ => self.keyframe_timestamps.last().copied(),
+        }
+    }
+
     /// Find the index of the keyframe at or before the current time.
     ///
     /// Returns [`None`] if the curve is finished or not yet started.
     /// To be more precise, this returns [`None`] if the frame is at or past the last keyframe:
     /// we cannot get the *next* keyframe to interpolate to in that case.
+    ///
+    /// A [`Keyframes::Curve`] has no keyframes, so this always returns [`None`]
+    /// for it.
     pub fn find_current_keyframe(&self, seek_time: f32) -> Option<usize> {
+        if let Keyframes::Curve(_) = self.keyframes {
+            return None;
+        }
+
         // An Ok(keyframe_index) result means an exact result was found by binary search
         // An Err result means the keyframe was not found, and the index is the keyframe
         // PERF: finding the current keyframe can be optimised
@@ -168,9 +325,14 @@ pub enum Interpolation {
 ///
 /// Because animation clips refer to targets by UUID, they can target any
 /// [`AnimationTarget`] with that ID.
+///
+/// Clips can also hold events, which are triggered when playback crosses their
+/// time. See [`AnimationClip::add_event`].
 #[derive(Asset, Reflect, Clone, Debug, Default)]
 pub struct AnimationClip {
     curves: AnimationCurves,
+    #[reflect(ignore)]
+    events: Vec<TimedAnimationEvent>,
     duration: f32,
 }
 
@@ -274,13 +436,81 @@ impl AnimationClip {
     /// curve covers.
     pub fn add_curve_to_target(&mut self, target_id: AnimationTargetId, curve: VariableCurve) {
         // Update the duration of the animation by this curve duration if it's longer
-        self.duration = self
-            .duration
-            .max(*curve.keyframe_timestamps.last().unwrap_or(&0.0));
+        self.duration = self.duration.max(curve.end_time().unwrap_or(0.0));
         self.curves.entry(target_id).or_default().push(curve);
     }
+
+    /// The events of this clip, sorted by time.
+    #[inline]
+    pub fn events(&self) -> &[TimedAnimationEvent] {
+        &self.events
+    }
+
+    /// Adds an event that is triggered on the [`AnimationPlayer`] entity each
+    /// time playback crosses `time`.
+    ///
+    /// The event can be observed with an [`Observer`] on the player entity.
+    /// Playback crosses events in both directions, so events are triggered
+    /// when playing in reverse as well. Seeking doesn't trigger the events
+    /// that were skipped over.
+    ///
+    /// The event is stored as an [`AnimationEvent::Reflected`], so its type
+    /// must be registered in the [`AppTypeRegistry`] with
+    /// [`ReflectAnimationEvent`] type data, usually with
+    /// `#[reflect(AnimationEvent)]`. This allows clips with events to be
+    /// serialized with an [`AnimationEventsSerializer`].
+    ///
+    /// If `time` is beyond the current duration of this clip, this method
+    /// lengthens this clip to include it.
+    pub fn add_event(&mut self, time: f32, event: impl Event + Reflect) {
+        self.add_reflected_event(time, Box::new(event));
+    }
+
+    /// Adds a reflected event, e.g. one read by an
+    /// [`AnimationEventsDeserializer`], that is triggered each time playback
+    /// crosses `time`.
+    ///
+    /// See [`AnimationClip::add_event`] for more details.
+    pub fn add_reflected_event(&mut self, time: f32, event: Box<dyn Reflect>) {
+        self.insert_event(TimedAnimationEvent {
+            time,
+            event: AnimationEvent::Reflected(event),
+        });
+    }
+
+    /// Adds a function that is called each time playback crosses `time`.
+    ///
+    /// The function receives the [`AnimationPlayer`] entity, the time of the
+    /// event, and the weight of the animation in the [`AnimationGraph`], which
+    /// can be used to ignore events from animations that are being blended out.
+    ///
+    /// See [`AnimationClip::add_event`] for more details.
+    pub fn add_event_fn(
+        &mut self,
+        time: f32,
+        func: impl Fn(&mut Commands, Entity, f32, f32) + Send + Sync + 'static,
+    ) {
+        self.insert_event(TimedAnimationEvent {
+            time,
+            event: AnimationEvent::Fn(AnimationEventFn::new(func)),
+        });
+    }
+
+    fn insert_event(&mut self, event: TimedAnimationEvent) {
+        self.duration = self.duration.max(event.time);
+        // Keep the events sorted by time, in insertion order for equal times.
+        let index = self
+            .events
+            .partition_point(|other| other.time <= event.time);
+        self.events.insert(index, event);
+    }
 }
 
+/// The maximum number of times the events of an [`AnimationClip`] are
+/// triggered for the full loops covered by a single tick, for example after a
+/// long hitch or with a high playback speed.
+pub const MAX_EVENT_LOOPS_PER_TICK: u32 = 8;
+
 /// Repetition behavior of an animation.
 #[derive(Reflect, Debug, PartialEq, Eq, Copy, Clone, Default)]
 pub enum RepeatAnimation {
@@ -314,6 +544,17 @@ pub struct ActiveAnimation {
     ///
     /// Note: This will always be in the range [0.0, animation clip duration]
     seek_time: f32,
+    /// The seek time at the start of the last tick.
+    last_seek_time: f32,
+    /// How far playback moved in the clip during the last tick, including
+    /// loops.
+    ///
+    /// This is negative if the animation is playing in reverse, and zero if it
+    /// wasn't ticked.
+    last_seek_delta: f32,
+    /// Whether the last tick started playback, in which case events at
+    /// `last_seek_time` are triggered too.
+    last_tick_started: bool,
     /// Number of times the animation has completed.
     /// If the animation is playing in reverse, this increments when the animation passes the start.
     completions: u32,
@@ -329,6 +570,9 @@ impl Default for ActiveAnimation {
             speed: 1.0,
             elapsed: 0.0,
             seek_time: 0.0,
+            last_seek_time: 0.0,
+            last_seek_delta: 0.0,
+            last_tick_started: false,
             completions: 0,
             paused: false,
         }
@@ -351,12 +595,17 @@ impl ActiveAnimation {
     /// Update the animation given the delta time and the duration of the clip being played.
     #[inline]
     fn update(&mut self, delta: f32, clip_duration: f32) {
+        self.last_seek_time = self.seek_time;
+        self.last_seek_delta = 0.0;
+        self.last_tick_started = self.elapsed == 0.0;
+
         if self.is_finished() {
             return;
         }
 
         self.elapsed += delta;
         self.seek_time += delta * self.speed;
+        self.last_seek_delta = delta * self.speed;
 
         let over_time = self.speed > 0.0 && self.seek_time >= clip_duration;
         let under_time = self.speed < 0.0 && self.seek_time < 0.0;
@@ -377,6 +626,72 @@ impl ActiveAnimation {
         }
     }
 
+    /// Calls `f` with each event of `clip` that playback crossed during the
+    /// last tick, in the order in which they were crossed.
+    ///
+    /// If the tick looped over the whole clip several times, the events are
+    /// triggered once per loop, up to the remaining repetitions of the
+    /// animation and at most [`MAX_EVENT_LOOPS_PER_TICK`] times.
+    fn for_each_triggered_event<'a>(
+        &self,
+        clip: &'a AnimationClip,
+        mut f: impl FnMut(&'a TimedAnimationEvent),
+    ) {
+        let duration = clip.duration;
+        if clip.events.is_empty() || self.last_seek_delta == 0.0 || duration <= 0.0 {
+            return;
+        }
+
+        // Positions are measured from the start of the clip in the direction
+        // of playback, so that playing in reverse is handled like playing
+        // forward.
+        let forward = self.last_seek_delta > 0.0;
+        let position = |time: f32| if forward { time } else { duration - time };
+        let mut trigger = |from: f32, to: f32, include_from: bool| {
+            let mut visit = |event: &'a TimedAnimationEvent| {
+                let event_position = position(event.time);
+                if (event_position > from || include_from && event_position == from)
+                    && event_position <= to
+                {
+                    f(event);
+                }
+            };
+            if forward {
+                clip.events.iter().for_each(&mut visit);
+            } else {
+                clip.events.iter().rev().for_each(&mut visit);
+            }
+        };
+
+        let from = position(self.last_seek_time.clamp(0.0, duration));
+        let include_from = self.last_tick_started;
+        // Playback stops at the end of the clip once the animation finishes.
+        if self.is_finished() {
+            trigger(from, duration, include_from);
+            return;
+        }
+
+        // Otherwise, it loops as many times as the tick covered.
+        let end = f64::from(from) + f64::from(self.last_seek_delta.abs());
+        let loops = (end / f64::from(duration)).floor();
+        if loops < 1.0 {
+            trigger(from, end as f32, include_from);
+            return;
+        }
+        trigger(from, duration, include_from);
+        let remaining_repetitions = match self.repeat {
+            RepeatAnimation::Count(n) => n.saturating_sub(self.completions),
+            RepeatAnimation::Never | RepeatAnimation::Forever => u32::MAX,
+        };
+        let full_loops = ((loops - 1.0) as u32)
+            .min(remaining_repetitions)
+            .min(MAX_EVENT_LOOPS_PER_TICK);
+        for _ in 0..full_loops {
+            trigger(0.0, duration, true);
+        }
+        trigger(0.0, (end - loops * f64::from(duration)) as f32, true);
+    }
+
     /// Reset back to the initial state as if no time has elapsed.
     pub fn replay(&mut self) {
         self.completions = 0;
@@ -703,6 +1018,9 @@ pub fn advance_animations(
                 evaluator.weights[node_index.index()] = weight;
 
                 if let Some(active_animation) = active_animations.get_mut(&node_index) {
+                    // Only animations ticked this frame trigger events.
+                    active_animation.last_seek_delta = 0.0;
+
                     // Tick the animation if necessary.
                     if !active_animation.paused {
                         if let Some(ref clip_handle) = node.clip {
@@ -732,6 +1050,63 @@ pub fn advance_animations(
         });
 }
 
+/// A system that triggers the events of the animation clips that playback
+/// crossed this frame.
+///
+/// Events are triggered for the [`AnimationPlayer`] entity in the order in
+/// which they were crossed, including across loops and in reverse. Animations
+/// whose computed weight in the [`AnimationGraph`] is zero don't trigger any
+/// events.
+pub fn trigger_animation_events(
+    mut commands: Commands,
+    type_registry: Res<AppTypeRegistry>,
+    clips: Res<Assets<AnimationClip>>,
+    graphs: Res<Assets<AnimationGraph>>,
+    players: Query<(Entity, &AnimationPlayer, &Handle<AnimationGraph>)>,
+) {
+    for (entity, player, graph_handle) in &players {
+        let Some(graph) = graphs.get(graph_handle) else {
+            continue;
+        };
+
+        for (&node_index, active_animation) in &player.active_animations {
+            let weight = active_animation.computed_weight;
+            if weight == 0.0 {
+                continue;
+            }
+
+            let Some(clip) = graph
+                .get(node_index)
+                .and_then(|node| node.clip.as_ref())
+                .and_then(|clip_handle| clips.get(clip_handle))
+            else {
+                continue;
+            };
+
+            active_animation.for_each_triggered_event(clip, |event| match &event.event {
+                AnimationEvent::Reflected(value) => {
+                    let type_registry = type_registry.read();
+                    let type_path = value.reflect_type_path();
+                    let triggered = value
+                        .get_represented_type_info()
+                        .and_then(|info| {
+                            type_registry.get_type_data::<ReflectAnimationEvent>(info.type_id())
+                        })
+                        .is_some_and(|reflect_event| {
+                            reflect_event.trigger(value.as_ref(), &mut commands, entity)
+                        });
+                    if !triggered {
+                        warn_once!(
+                            "Animation event `{type_path}` couldn't be triggered: its type must be registered with `#[reflect(AnimationEvent)]`"
+                        );
+                    }
+                }
+                AnimationEvent::Fn(func) => func.trigger(&mut commands, entity, event.time, weight),
+            });
+        }
+    }
+}
+
 /// A system that modifies animation targets (e.g. bones in a skinned mesh)
 /// according to the currently-playing animation.
 pub fn animate_targets(
@@ -827,6 +1202,16 @@ impl AnimationTargetContext<'_> {
     /// [`AnimationTargetContext`].
     fn apply(&mut self, curves: &[VariableCurve], weight: f32, seek_time: f32) {
         for curve in curves {
+            // Curves are sampled directly instead of interpolating keyframes.
+            if let Keyframes::Curve(ref animated_curve) = curve.keyframes {
+                self.apply_curve(
+                    animated_curve,
+                    weight,
+                    animated_curve.sample_time(seek_time),
+                );
+                continue;
+            }
+
             // Some curves have only one keyframe used to set a transform
             if curve.keyframe_timestamps.len() == 1 {
                 self.apply_single_keyframe(curve, weight);
@@ -853,6 +1238,45 @@ impl AnimationTargetContext<'_> {
         }
     }
 
+    /// Applies an [`AnimatedCurve`] sampled at `sample_time`, which must be in its domain.
+    fn apply_curve(&mut self, curve: &AnimatedCurve, weight: f32, sample_time: f32) {
+        match curve {
+            AnimatedCurve::Translation(curve) => {
+                if let Some(ref mut transform) = self.transform {
+                    let translation = curve.sample_unchecked(sample_time);
+                    transform.translation = transform.translation.lerp(translation, weight);
+                }
+            }
+
+            AnimatedCurve::Rotation(curve) => {
+                if let Some(ref mut transform) = self.transform {
+                    let rotation = curve.sample_unchecked(sample_time).normalize();
+                    transform.rotation = transform.rotation.slerp(rotation, weight);
+                }
+            }
+
+            AnimatedCurve::Scale(curve) => {
+                if let Some(ref mut transform) = self.transform {
+                    let scale = curve.sample_unchecked(sample_time);
+                    transform.scale = transform.scale.lerp(scale, weight);
+                }
+            }
+
+            AnimatedCurve::Weights(curve) => {
+                let Some(ref mut morphs) = self.morph_weights else {
+                    error!(
+                        "Tried to animate morphs on {:?} ({:?}), but no `MorphWeights` was found",
+                        self.entity, self.name,
+                    );
+                    return;
+                };
+
+                let weights = curve.sample_unchecked(sample_time);
+                lerp_morph_weights(morphs.weights_mut(), weights.into_iter(), weight);
+            }
+        }
+    }
+
     fn apply_single_keyframe(&mut self, curve: &VariableCurve, weight: f32) {
         match &curve.keyframes {
             Keyframes::Rotation(keyframes) => {
@@ -889,6 +1313,10 @@ impl AnimationTargetContext<'_> {
                     weight,
                 );
             }
+
+            Keyframes::Curve(_) => {
+                unreachable!("curves have no keyframes and are sampled in `apply_curve` instead")
+            }
         }
     }
 
@@ -1072,6 +1500,10 @@ impl AnimationTargetContext<'_> {
                     );
                 lerp_morph_weights(morphs.weights_mut(), result, weight);
             }
+
+            (_, Keyframes::Curve(_)) => {
+                unreachable!("curves have no keyframes and are sampled in `apply_curve` instead")
+            }
         }
     }
 }
@@ -1139,6 +1571,7 @@ impl Plugin for AnimationPlugin {
                     advance_transitions,
                     advance_animations,
                     animate_targets,
+                    trigger_animation_events,
                     expire_completed_transitions,
                 )
                     .chain()
@@ -1194,8 +1627,24 @@ impl AnimationGraphEvaluator {
 
 #[cfg(test)]
 mod tests {
-    use crate::VariableCurve;
+    use crate::{
+        animate_targets, trigger_animation_events, ActiveAnimation, AnimatedCurve, AnimationClip,
+        AnimationEvent, AnimationEventsDeserializer, AnimationEventsSerializer, AnimationPlayer,
+        AnimationTarget, AnimationTargetId, ReflectAnimationEvent, RepeatAnimation,
+        TimedAnimationEvent, VariableCurve, MAX_EVENT_LOOPS_PER_TICK,
+    };
+    use bevy_asset::Assets;
+    use bevy_core::Name;
+    use bevy_ecs::prelude::*;
+    use bevy_ecs::reflect::AppTypeRegistry;
+    use bevy_ecs::system::RunSystemOnce;
+    use bevy_math::curve::{interval, FunctionCurve};
     use bevy_math::Vec3;
+    use bevy_reflect::{FromReflect, Reflect, TypeRegistry};
+    use bevy_transform::prelude::Transform;
+    use serde::de::DeserializeSeed;
+
+    use crate::graph::AnimationGraph;
 
     fn test_variable_curve() -> VariableCurve {
         let keyframe_timestamps = vec![1.0, 2.0, 3.0, 4.0];
@@ -1339,4 +1788,334 @@ mod tests {
             assert!(exact_keyframe == inexact_keyframe);
         }
     }
+
+    fn test_event_clip() -> AnimationClip {
+        let mut clip = AnimationClip::default();
+        for time in [0.0, 0.25, 0.5, 1.0] {
+            clip.add_event_fn(time, |_, _, _, _| {});
+        }
+        clip
+    }
+
+    fn triggered_event_times(animation: &ActiveAnimation, clip: &AnimationClip) -> Vec<f32> {
+        let mut times = vec![];
+        animation.for_each_triggered_event(clip, |event| times.push(event.time));
+        times
+    }
+
+    #[test]
+    fn events_are_sorted_and_extend_the_duration() {
+        let mut clip = AnimationClip::default();
+        clip.add_event_fn(2.0, |_, _, _, _| {});
+        clip.add_event_fn(1.0, |_, _, _, _| {});
+        clip.add_event_fn(1.5, |_, _, _, _| {});
+
+        let times: Vec<f32> = clip.events().iter().map(|event| event.time).collect();
+        assert_eq!(times, vec![1.0, 1.5, 2.0]);
+        assert_eq!(clip.duration(), 2.0);
+    }
+
+    #[test]
+    fn events_are_triggered_when_crossed() {
+        let clip = test_event_clip();
+        let mut animation = ActiveAnimation::default();
+
+        // The first tick includes the starting time.
+        animation.update(0.3, clip.duration());
+        assert_eq!(triggered_event_times(&animation, &clip), vec![0.0, 0.25]);
+
+        animation.update(0.1, clip.duration());
+        assert!(triggered_event_times(&animation, &clip).is_empty());
+
+        // A long frame still triggers every event in between.
+        animation.update(0.65, clip.duration());
+        assert_eq!(triggered_event_times(&animation, &clip), vec![0.5, 1.0]);
+
+        // The animation has finished, so nothing is triggered anymore.
+        animation.update(0.5, clip.duration());
+        assert!(triggered_event_times(&animation, &clip).is_empty());
+    }
+
+    #[test]
+    fn events_are_triggered_across_loops() {
+        let clip = test_event_clip();
+        let mut animation = ActiveAnimation::default();
+        animation.set_repeat(RepeatAnimation::Forever);
+
+        animation.update(0.75, clip.duration());
+        animation.update(0.5, clip.duration());
+        assert_eq!(
+            triggered_event_times(&animation, &clip),
+            vec![1.0, 0.0, 0.25]
+        );
+
+        // A frame longer than the clip triggers every event of the loop.
+        animation.update(1.25, clip.duration());
+        assert_eq!(
+            triggered_event_times(&animation, &clip),
+            vec![0.5, 1.0, 0.0, 0.25, 0.5]
+        );
+    }
+
+    #[test]
+    fn events_are_triggered_a_bounded_number_of_times() {
+        let clip = test_event_clip();
+        let mut animation = ActiveAnimation::default();
+        animation.set_repeat(RepeatAnimation::Forever);
+
+        // The first and last partial loops, and the capped full loops.
+        animation.update(1e9, clip.duration());
+        let times = triggered_event_times(&animation, &clip);
+        assert_eq!(times.len(), 4 + 4 * MAX_EVENT_LOOPS_PER_TICK as usize + 1);
+
+        // Full loops are limited to the remaining repetitions.
+        let mut animation = ActiveAnimation::default();
+        animation.set_repeat(RepeatAnimation::Count(3));
+        animation.update(5.5, clip.duration());
+        let times = triggered_event_times(&animation, &clip);
+        assert_eq!(times.len(), 4 + 4 * 2 + 3);
+    }
+
+    #[test]
+    fn events_are_triggered_in_reverse() {
+        let clip = test_event_clip();
+        let mut animation = ActiveAnimation::default();
+        animation.set_repeat(RepeatAnimation::Forever);
+        animation.seek_to(0.6).set_speed(-1.0);
+
+        animation.update(0.4, clip.duration());
+        assert_eq!(triggered_event_times(&animation, &clip), vec![0.5, 0.25]);
+
+        animation.update(0.3, clip.duration());
+        assert_eq!(triggered_event_times(&animation, &clip), vec![0.0, 1.0]);
+    }
+
+    #[test]
+    fn seeking_skips_events() {
+        let clip = test_event_clip();
+        let mut animation = ActiveAnimation::default();
+
+        animation.update(0.1, clip.duration());
+        animation.seek_to(0.6);
+        animation.update(0.1, clip.duration());
+        assert!(triggered_event_times(&animation, &clip).is_empty());
+
+        animation.seek_to(0.2);
+        animation.update(0.1, clip.duration());
+        assert_eq!(triggered_event_times(&animation, &clip), vec![0.25]);
+    }
+
+    #[derive(Event, Reflect, Clone, Debug, PartialEq)]
+    #[reflect(AnimationEvent)]
+    struct Footstep;
+
+    #[derive(Resource, Default)]
+    struct Footsteps(Vec<Entity>);
+
+    #[test]
+    fn events_are_triggered_on_the_player() {
+        let mut world = World::new();
+        world.init_resource::<Footsteps>();
+        world.init_resource::<AppTypeRegistry>();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<Footstep>();
+
+        let mut clip = AnimationClip::default();
+        clip.add_event(0.5, Footstep);
+        clip.add_event_fn(0.5, |commands, entity, _time, weight| {
+            // Blended out animations aren't expected to trigger events.
+            assert!(weight > 0.0);
+            commands.trigger_targets(Footstep, entity);
+        });
+        world.init_resource::<Assets<AnimationClip>>();
+        let clip = world.resource_mut::<Assets<AnimationClip>>().add(clip);
+        let (graph, node_index) = AnimationGraph::from_clip(clip);
+        world.init_resource::<Assets<AnimationGraph>>();
+        let graph = world.resource_mut::<Assets<AnimationGraph>>().add(graph);
+
+        let mut player = AnimationPlayer::default();
+        player.play(node_index).update(0.6, 1.0);
+        let player = world.spawn((player, graph)).id();
+        world.entity_mut(player).observe(
+            |trigger: Trigger<Footstep>, mut footsteps: ResMut<Footsteps>| {
+                footsteps.0.push(trigger.entity());
+            },
+        );
+
+        world.run_system_once(trigger_animation_events);
+        assert_eq!(world.resource::<Footsteps>().0, vec![player, player]);
+
+        // Animations with a zero weight don't trigger events.
+        world.resource_mut::<Footsteps>().0.clear();
+        let mut entity = world.entity_mut(player);
+        let mut player_component = entity.get_mut::<AnimationPlayer>().unwrap();
+        let animation = player_component.animation_mut(node_index).unwrap();
+        animation.rewind().update(0.6, 1.0);
+        animation.computed_weight = 0.0;
+        world.run_system_once(trigger_animation_events);
+        assert!(world.resource::<Footsteps>().0.is_empty());
+    }
+
+    #[derive(Event, Reflect, Clone, Debug, PartialEq)]
+    #[reflect(AnimationEvent)]
+    struct Sound {
+        name: String,
+        volume: f32,
+    }
+
+    fn serializable_events() -> (TypeRegistry, AnimationClip) {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Sound>();
+        registry.register::<Footstep>();
+
+        let mut clip = AnimationClip::default();
+        clip.add_event(
+            0.5,
+            Sound {
+                name: "step".into(),
+                volume: 0.25,
+            },
+        );
+        // Functions can't be serialized and are skipped.
+        clip.add_event_fn(0.75, |_, _, _, _| {});
+        clip.add_event(1.0, Footstep);
+        (registry, clip)
+    }
+
+    fn assert_deserialized_events(events: Vec<TimedAnimationEvent>) {
+        let mut loaded_clip = AnimationClip::default();
+        for event in events {
+            let AnimationEvent::Reflected(value) = event.event else {
+                panic!("deserialized events are reflected");
+            };
+            loaded_clip.add_reflected_event(event.time, value);
+        }
+        assert_eq!(loaded_clip.duration(), 1.0);
+        let events = loaded_clip.events();
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].time, 0.5);
+        let AnimationEvent::Reflected(value) = &events[0].event else {
+            unreachable!();
+        };
+        assert_eq!(
+            Sound::from_reflect(value.as_ref()),
+            Some(Sound {
+                name: "step".into(),
+                volume: 0.25,
+            })
+        );
+        let AnimationEvent::Reflected(value) = &events[1].event else {
+            unreachable!();
+        };
+        assert_eq!(Footstep::from_reflect(value.as_ref()), Some(Footstep));
+    }
+
+    #[test]
+    fn reflected_events_round_trip() {
+        let (registry, clip) = serializable_events();
+
+        let serialized =
+            ron::to_string(&AnimationEventsSerializer::new(clip.events(), &registry)).unwrap();
+        let mut deserializer = ron::Deserializer::from_str(&serialized).unwrap();
+        let events = AnimationEventsDeserializer::new(&registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        assert_deserialized_events(events);
+    }
+
+    #[test]
+    fn reflected_events_round_trip_postcard() {
+        let (registry, clip) = serializable_events();
+
+        // Postcard doesn't store field names, so events are read as sequences.
+        let serialized =
+            postcard::to_allocvec(&AnimationEventsSerializer::new(clip.events(), &registry))
+                .unwrap();
+        let mut deserializer = postcard::Deserializer::from_bytes(&serialized);
+        let events = AnimationEventsDeserializer::new(&registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        assert_deserialized_events(events);
+    }
+
+    #[test]
+    fn variable_curve_domain() {
+        let keyframes = test_variable_curve();
+        assert_eq!(keyframes.domain(), Some(interval(1.0, 4.0).unwrap()));
+        assert!(keyframes.keyframes.len() == 4);
+
+        let curve = VariableCurve::from_curve(AnimatedCurve::translation(FunctionCurve::new(
+            interval(1.0, 3.0).unwrap(),
+            |t| Vec3::X * t,
+        )));
+        assert_eq!(curve.domain(), Some(interval(1.0, 3.0).unwrap()));
+        assert!(curve.keyframe_timestamps.is_empty());
+        assert!(curve.keyframes.is_empty());
+        assert_eq!(curve.find_current_keyframe(2.0), None);
+    }
+
+    #[test]
+    fn animated_curves_are_sampled_in_their_domain() {
+        let curve =
+            AnimatedCurve::translation(FunctionCurve::new(interval(1.0, 3.0).unwrap(), |t| {
+                Vec3::X * t
+            }));
+        assert_eq!(curve.sample_time(0.0), 1.0);
+        assert_eq!(curve.sample_time(2.5), 2.5);
+        assert_eq!(curve.sample_time(f32::INFINITY), 3.0);
+    }
+
+    #[test]
+    fn curves_drive_animation_targets() {
+        let mut world = World::new();
+
+        let target_id = AnimationTargetId::from_name(&Name::new("target"));
+        let mut clip = AnimationClip::default();
+        clip.add_curve_to_target(
+            target_id,
+            VariableCurve::from_curve(AnimatedCurve::translation(FunctionCurve::new(
+                interval(1.0, 3.0).unwrap(),
+                |t| Vec3::X * t,
+            ))),
+        );
+        assert_eq!(clip.duration(), 3.0);
+
+        world.init_resource::<Assets<AnimationClip>>();
+        let clip = world.resource_mut::<Assets<AnimationClip>>().add(clip);
+        let (graph, node_index) = AnimationGraph::from_clip(clip);
+        world.init_resource::<Assets<AnimationGraph>>();
+        let graph = world.resource_mut::<Assets<AnimationGraph>>().add(graph);
+
+        let mut player = AnimationPlayer::default();
+        player.play(node_index).seek_to(1.5);
+        let player = world.spawn((player, graph)).id();
+        let target = world
+            .spawn((
+                AnimationTarget {
+                    id: target_id,
+                    player,
+                },
+                Transform::default(),
+            ))
+            .id();
+
+        let translation_at = |world: &mut World, seek_time: f32| {
+            world
+                .get_mut::<AnimationPlayer>(player)
+                .unwrap()
+                .animation_mut(node_index)
+                .unwrap()
+                .seek_to(seek_time);
+            world.run_system_once(animate_targets);
+            world.get::<Transform>(target).unwrap().translation
+        };
+
+        assert_eq!(translation_at(&mut world, 1.5), Vec3::X * 1.5);
+        // The curve holds its first value before the start of its domain...
+        assert_eq!(translation_at(&mut world, 0.5), Vec3::X * 1.0);
+        // ...and its last value after the end of its domain.
+        assert_eq!(translation_at(&mut world, 4.0), Vec3::X * 3.0);
+    }
 }
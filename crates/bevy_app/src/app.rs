@@ -1,16 +1,18 @@
-use crate::{First, Main, MainSchedulePlugin, Plugin, Plugins, StateTransition};
+use crate::{First, Main, MainSchedulePlugin, Plugin, Plugins};
 pub use bevy_derive::AppLabel;
 use bevy_ecs::{
+    error::{DefaultErrorHandler, ErrorHandler},
+    event::ManualEventReader,
     prelude::*,
-    schedule::{
-        common_conditions::run_once as run_once_condition, run_enter_schedule,
-        InternedScheduleLabel, ScheduleBuildSettings, ScheduleLabel,
-    },
+    schedule::{InternedScheduleLabel, ScheduleBuildSettings, ScheduleLabel},
+    system::IntoObserverSystem,
 };
 use bevy_utils::{intern::Interned, tracing::debug, HashMap, HashSet};
 use std::{
     fmt::Debug,
+    num::NonZeroU8,
     panic::{catch_unwind, resume_unwind, AssertUnwindSafe},
+    process::{ExitCode, Termination},
 };
 use thiserror::Error;
 
@@ -70,7 +72,7 @@ pub struct App {
     /// the application's event loop and advancing the [`Schedule`].
     /// Typically, it is not configured manually, but set by one of Bevy's built-in plugins.
     /// See `bevy::winit::WinitPlugin` and [`ScheduleRunnerPlugin`](crate::schedule_runner::ScheduleRunnerPlugin).
-    pub runner: Box<dyn FnOnce(App) + Send>, // Send bound is required to make App Send
+    pub runner: Box<dyn FnOnce(App) -> AppExit + Send>, // Send bound is required to make App Send
     /// The schedule that systems are added to by default.
     ///
     /// The schedule that runs the main loop of schedule execution.
@@ -185,6 +187,9 @@ impl Default for App {
         #[cfg(feature = "bevy_reflect")]
         app.init_resource::<AppTypeRegistry>();
 
+        #[cfg(feature = "reflect_functions")]
+        app.init_resource::<AppFunctionRegistry>();
+
         app.add_plugins(MainSchedulePlugin);
 
         app.add_event::<AppExit>();
@@ -287,10 +292,16 @@ impl App {
     ///
     /// By default, *Bevy* uses the `winit` crate for window creation.
     ///
+    /// # Exit code
+    ///
+    /// The returned [`AppExit`] is the one produced by the runner, usually derived from the
+    /// [`AppExit`] events sent during the last update. It implements [`Termination`], so it
+    /// can be returned from `main` to forward the exit code to the process.
+    ///
     /// # Panics
     ///
     /// Panics if called from `Plugin::build()`, because it would prevent other plugins to properly build.
-    pub fn run(&mut self) {
+    pub fn run(&mut self) -> AppExit {
         #[cfg(feature = "trace")]
         let _bevy_app_run_span = info_span!("bevy_app").entered();
 
@@ -300,7 +311,7 @@ impl App {
         }
 
         let runner = std::mem::replace(&mut app.runner, Box::new(run_once));
-        runner(app);
+        runner(app)
     }
 
     /// Check the state of all plugins already added to this app. This is usually called by the
@@ -344,79 +355,6 @@ impl App {
         self.plugins_state = PluginsState::Cleaned;
     }
 
-    /// Initializes a [`State`] with standard starting values.
-    ///
-    /// If the [`State`] already exists, nothing happens.
-    ///
-    /// Adds [`State<S>`] and [`NextState<S>`] resources, [`OnEnter`] and [`OnExit`] schedules
-    /// for each state variant (if they don't already exist), an instance of [`apply_state_transition::<S>`] in
-    /// [`StateTransition`] so that transitions happen before [`Update`](crate::Update) and
-    /// a instance of [`run_enter_schedule::<S>`] in [`StateTransition`] with a
-    /// [`run_once`](`run_once_condition`) condition to run the on enter schedule of the
-    /// initial state.
-    ///
-    /// If you would like to control how other systems run based on the current state,
-    /// you can emulate this behavior using the [`in_state`] [`Condition`].
-    ///
-    /// Note that you can also apply state transitions at other points in the schedule
-    /// by adding the [`apply_state_transition`] system manually.
-    pub fn init_state<S: States + FromWorld>(&mut self) -> &mut Self {
-        if !self.world.contains_resource::<State<S>>() {
-            self.init_resource::<State<S>>()
-                .init_resource::<NextState<S>>()
-                .add_event::<StateTransitionEvent<S>>()
-                .add_systems(
-                    StateTransition,
-                    (
-                        run_enter_schedule::<S>.run_if(run_once_condition()),
-                        apply_state_transition::<S>,
-                    )
-                        .chain(),
-                );
-        }
-
-        // The OnEnter, OnExit, and OnTransition schedules are lazily initialized
-        // (i.e. when the first system is added to them), and World::try_run_schedule is used to fail
-        // gracefully if they aren't present.
-
-        self
-    }
-
-    /// Inserts a specific [`State`] to the current [`App`] and
-    /// overrides any [`State`] previously added of the same type.
-    ///
-    /// Adds [`State<S>`] and [`NextState<S>`] resources, [`OnEnter`] and [`OnExit`] schedules
-    /// for each state variant (if they don't already exist), an instance of [`apply_state_transition::<S>`] in
-    /// [`StateTransition`] so that transitions happen before [`Update`](crate::Update) and
-    /// a instance of [`run_enter_schedule::<S>`] in [`StateTransition`] with a
-    /// [`run_once`](`run_once_condition`) condition to run the on enter schedule of the
-    /// initial state.
-    ///
-    /// If you would like to control how other systems run based on the current state,
-    /// you can emulate this behavior using the [`in_state`] [`Condition`].
-    ///
-    /// Note that you can also apply state transitions at other points in the schedule
-    /// by adding the [`apply_state_transition`] system manually.
-    pub fn insert_state<S: States>(&mut self, state: S) -> &mut Self {
-        self.insert_resource(State::new(state))
-            .init_resource::<NextState<S>>()
-            .add_event::<StateTransitionEvent<S>>()
-            .add_systems(
-                StateTransition,
-                (
-                    run_enter_schedule::<S>.run_if(run_once_condition()),
-                    apply_state_transition::<S>,
-                )
-                    .chain(),
-            );
-
-        // The OnEnter, OnExit, and OnTransition schedules are lazily initialized
-        // (i.e. when the first system is added to them), and World::try_run_schedule is used to fail
-        // gracefully if they aren't present.
-
-        self
-    }
-
     /// Adds a system to the given schedule in this app's [`Schedules`].
     ///
     /// # Examples
@@ -476,6 +414,7 @@ impl App {
     ///
     /// This is done by adding a [`Resource`] of type [`Events::<T>`],
     /// and inserting an [`event_update_system`] into [`First`].
+    /// Use [`Events::set_auto_update`] to update the events manually instead.
     ///
     /// See [`Events`] for defining events.
     ///
@@ -616,21 +555,58 @@ impl App {
     /// ```
     /// # use bevy_app::prelude::*;
     /// #
-    /// fn my_runner(mut app: App) {
+    /// fn my_runner(mut app: App) -> AppExit {
     ///     loop {
     ///         println!("In main loop");
     ///         app.update();
+    ///         if let Some(exit) = app.should_exit() {
+    ///             return exit;
+    ///         }
     ///     }
     /// }
     ///
     /// App::new()
     ///     .set_runner(my_runner);
     /// ```
-    pub fn set_runner(&mut self, run_fn: impl FnOnce(App) + 'static + Send) -> &mut Self {
+    pub fn set_runner(
+        &mut self,
+        run_fn: impl FnOnce(App) -> AppExit + 'static + Send,
+    ) -> &mut Self {
         self.runner = Box::new(run_fn);
         self
     }
 
+    /// Sets the [`ErrorHandler`] used for errors returned by fallible systems and commands,
+    /// by inserting it as the [`DefaultErrorHandler`] of the [`App`] and all of its sub apps.
+    ///
+    /// Sub apps that are inserted afterwards get the same handler, unless they already have a
+    /// [`DefaultErrorHandler`] of their own.
+    /// By default, errors are handled with [`panic`](bevy_ecs::error::panic).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_ecs::prelude::*;
+    /// use bevy_ecs::error::{self, Result};
+    ///
+    /// fn fallible_system() -> Result {
+    ///     Err("something went wrong".into())
+    /// }
+    ///
+    /// App::new()
+    ///     .set_error_handler(error::warn)
+    ///     .add_systems(Update, fallible_system);
+    /// ```
+    pub fn set_error_handler(&mut self, error_handler: ErrorHandler) -> &mut Self {
+        self.world
+            .insert_resource(DefaultErrorHandler(error_handler));
+        for sub_app in self.sub_apps.values_mut() {
+            sub_app.app.set_error_handler(error_handler);
+        }
+        self
+    }
+
     /// Boxed variant of [`add_plugins`](App::add_plugins) that can be used from a
     /// [`PluginGroup`](super::PluginGroup)
     pub(crate) fn add_boxed_plugin(
@@ -796,6 +772,88 @@ impl App {
         self
     }
 
+    /// Registers the given function into the [`AppFunctionRegistry`] resource.
+    ///
+    /// The given function will internally be stored as a [`DynamicFunction`]
+    /// and mapped according to its [name].
+    ///
+    /// Because the function must have a name,
+    /// anonymous functions (e.g. `|a: i32, b: i32| { a + b }`) must instead
+    /// be registered using [`register_function_with_name`] or converted to a [`DynamicFunction`]
+    /// and named using [`DynamicFunction::with_name`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if a function has already been registered with the given name
+    /// or if the function is missing a name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bevy_app::App;
+    ///
+    /// fn add(a: i32, b: i32) -> i32 {
+    ///     a + b
+    /// }
+    ///
+    /// App::new().register_function(add);
+    /// ```
+    ///
+    /// See [`FunctionRegistry::register`] for more information.
+    ///
+    /// [`DynamicFunction`]: bevy_reflect::func::DynamicFunction
+    /// [name]: bevy_reflect::func::FunctionInfo::name
+    /// [`register_function_with_name`]: Self::register_function_with_name
+    /// [`DynamicFunction::with_name`]: bevy_reflect::func::DynamicFunction::with_name
+    /// [`FunctionRegistry::register`]: bevy_reflect::func::FunctionRegistry::register
+    #[cfg(feature = "reflect_functions")]
+    pub fn register_function<F, Marker>(&mut self, function: F) -> &mut Self
+    where
+        F: bevy_reflect::func::IntoFunction<'static, Marker> + 'static,
+    {
+        let registry = self.world.resource_mut::<AppFunctionRegistry>();
+        if let Err(error) = registry.write().register(function) {
+            panic!("failed to register function: {error}");
+        }
+        self
+    }
+
+    /// Registers the given function into the [`AppFunctionRegistry`] resource using the given name.
+    ///
+    /// This is useful for closures, which are unnamed,
+    /// or to register a function under a name other than its full type name.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a function has already been registered with the given name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bevy_app::App;
+    ///
+    /// App::new().register_function_with_name("my_crate::mul", |a: i32, b: i32| a * b);
+    /// ```
+    ///
+    /// See [`FunctionRegistry::register_with_name`] for more information.
+    ///
+    /// [`FunctionRegistry::register_with_name`]: bevy_reflect::func::FunctionRegistry::register_with_name
+    #[cfg(feature = "reflect_functions")]
+    pub fn register_function_with_name<F, Marker>(
+        &mut self,
+        name: impl Into<std::borrow::Cow<'static, str>>,
+        function: F,
+    ) -> &mut Self
+    where
+        F: bevy_reflect::func::IntoFunction<'static, Marker> + 'static,
+    {
+        let registry = self.world.resource_mut::<AppFunctionRegistry>();
+        if let Err(error) = registry.write().register_with_name(name, function) {
+            panic!("failed to register function: {error}");
+        }
+        self
+    }
+
     /// Retrieves a `SubApp` stored inside this [`App`].
     ///
     /// # Panics
@@ -830,7 +888,15 @@ impl App {
     }
 
     /// Inserts an existing sub app into the app
-    pub fn insert_sub_app(&mut self, label: impl AppLabel, sub_app: SubApp) {
+    ///
+    /// If an error handler was set with [`set_error_handler`](Self::set_error_handler), it is
+    /// also used by the sub app, unless the sub app already has a [`DefaultErrorHandler`].
+    pub fn insert_sub_app(&mut self, label: impl AppLabel, mut sub_app: SubApp) {
+        if let Some(&DefaultErrorHandler(error_handler)) = self.world.get_resource() {
+            if !sub_app.app.world.contains_resource::<DefaultErrorHandler>() {
+                sub_app.app.set_error_handler(error_handler);
+            }
+        }
         self.sub_apps.insert(label.intern(), sub_app);
     }
 
@@ -1027,9 +1093,71 @@ impl App {
 
         self
     }
+
+    /// Spawns an [`Observer`] entity, which will watch for and respond to the given event.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_ecs::prelude::*;
+    /// #
+    /// # let mut app = App::new();
+    /// #
+    /// # #[derive(Event)]
+    /// # struct Party {
+    /// #   friends_allowed: bool,
+    /// # };
+    /// #
+    /// # #[derive(Event)]
+    /// # struct Invite;
+    /// #
+    /// # #[derive(Component)]
+    /// # struct Friend;
+    /// #
+    /// // An observer system can be any system where the first parameter is a trigger
+    /// app.observe(|trigger: Trigger<Party>, friends: Query<Entity, With<Friend>>, mut commands: Commands| {
+    ///     if trigger.event().friends_allowed {
+    ///         for friend in friends.iter() {
+    ///             commands.trigger_targets(Invite, friend);
+    ///         }
+    ///     }
+    /// });
+    /// ```
+    pub fn observe<E: Event, B: Bundle, M>(
+        &mut self,
+        observer: impl IntoObserverSystem<E, B, M>,
+    ) -> &mut Self {
+        self.world.observe(observer);
+        self
+    }
+
+    /// Checks whether an [`AppExit`] event was sent during the last two updates.
+    ///
+    /// If several [`AppExit`] events were sent, the first [`AppExit::Error`] wins over any
+    /// [`AppExit::Success`], so that a failure is never hidden by a clean shutdown request.
+    ///
+    /// This is meant to be called by [runner functions](Self::set_runner) after each update
+    /// to decide whether to stop the main loop.
+    pub fn should_exit(&self) -> Option<AppExit> {
+        let mut reader = ManualEventReader::default();
+        let events = self.world.get_resource::<Events<AppExit>>()?;
+        let mut events = reader.read(events);
+
+        if events.len() != 0 {
+            return Some(
+                events
+                    .find(|exit| exit.is_error())
+                    .cloned()
+                    .unwrap_or(AppExit::Success),
+            );
+        }
+
+        None
+    }
 }
 
-fn run_once(mut app: App) {
+fn run_once(mut app: App) -> AppExit {
     while app.plugins_state() == PluginsState::Adding {
         #[cfg(not(target_arch = "wasm32"))]
         bevy_tasks::tick_global_task_pools_on_main_thread();
@@ -1038,10 +1166,17 @@ fn run_once(mut app: App) {
     app.cleanup();
 
     app.update();
+
+    app.should_exit().unwrap_or(AppExit::Success)
 }
 
-/// An event that indicates the [`App`] should exit. This will fully exit the app process at the
-/// start of the next tick of the schedule.
+/// An event that indicates the [`App`] should exit. If one or more of these are present at the
+/// end of an update, the [runner](App::set_runner) will end and ([maybe](App::run)) return
+/// control to the caller.
+///
+/// The [`AppExit`] returned by [`App::run`] carries the exit code of the app: it is
+/// [`AppExit::Success`] unless at least one [`AppExit::Error`] was sent, in which case the
+/// first error is returned.
 ///
 /// You can also use this event to detect that an exit was requested. In order to receive it, systems
 /// subscribing to this event should run after it was emitted and before the schedule of the same
@@ -1050,19 +1185,78 @@ fn run_once(mut app: App) {
 /// If you don't require access to other components or resources, consider implementing the [`Drop`]
 /// trait on components/resources for code that runs on exit. That saves you from worrying about
 /// system schedule ordering, and is idiomatic Rust.
-#[derive(Event, Debug, Clone, Default)]
-pub struct AppExit;
+#[derive(Event, Debug, Clone, Default, PartialEq, Eq)]
+pub enum AppExit {
+    /// [`App`] exited without any problems.
+    #[default]
+    Success,
+    /// The [`App`] experienced an unhandleable error.
+    /// Holds the exit code we expect our app to return.
+    Error(NonZeroU8),
+}
+
+impl AppExit {
+    /// Creates a [`AppExit::Error`] with a error code of 1.
+    #[must_use]
+    pub const fn error() -> Self {
+        Self::Error(NonZeroU8::MIN)
+    }
+
+    /// Returns `true` if `self` is a [`AppExit::Success`].
+    #[must_use]
+    pub const fn is_success(&self) -> bool {
+        matches!(self, AppExit::Success)
+    }
+
+    /// Returns `true` if `self` is a [`AppExit::Error`].
+    #[must_use]
+    pub const fn is_error(&self) -> bool {
+        matches!(self, AppExit::Error(_))
+    }
+
+    /// Creates a [`AppExit`] from a code.
+    ///
+    /// When `code` is 0 a [`AppExit::Success`] is constructed otherwise a
+    /// [`AppExit::Error`] is constructed.
+    #[must_use]
+    pub const fn from_code(code: u8) -> Self {
+        match NonZeroU8::new(code) {
+            Some(code) => Self::Error(code),
+            None => Self::Success,
+        }
+    }
+}
+
+impl From<u8> for AppExit {
+    fn from(value: u8) -> Self {
+        Self::from_code(value)
+    }
+}
+
+impl Termination for AppExit {
+    fn report(self) -> ExitCode {
+        match self {
+            AppExit::Success => ExitCode::SUCCESS,
+            // We leave logging an error to our users
+            AppExit::Error(value) => ExitCode::from(value.get()),
+        }
+    }
+}
 
 #[cfg(test)]
 mod tests {
-    use std::marker::PhantomData;
+    use std::{
+        marker::PhantomData,
+        sync::atomic::{AtomicBool, Ordering},
+    };
 
     use bevy_ecs::{
-        schedule::{OnEnter, States},
-        system::Commands,
+        error::{self, BoxedError, ErrorContext, Result},
+        event::EventWriter,
+        schedule::ScheduleLabel,
     };
 
-    use crate::{App, Plugin};
+    use crate::{self as bevy_app, App, AppExit, AppLabel, Plugin, SubApp, Update};
 
     struct PluginA;
     impl Plugin for PluginA {
@@ -1121,39 +1315,6 @@ mod tests {
         App::new().add_plugins(PluginRun);
     }
 
-    #[derive(States, PartialEq, Eq, Debug, Default, Hash, Clone)]
-    enum AppState {
-        #[default]
-        MainMenu,
-    }
-    fn bar(mut commands: Commands) {
-        commands.spawn_empty();
-    }
-
-    fn foo(mut commands: Commands) {
-        commands.spawn_empty();
-    }
-
-    #[test]
-    fn add_systems_should_create_schedule_if_it_does_not_exist() {
-        let mut app = App::new();
-        app.init_state::<AppState>()
-            .add_systems(OnEnter(AppState::MainMenu), (foo, bar));
-
-        app.world.run_schedule(OnEnter(AppState::MainMenu));
-        assert_eq!(app.world.entities().len(), 2);
-    }
-
-    #[test]
-    fn add_systems_should_create_schedule_if_it_does_not_exist2() {
-        let mut app = App::new();
-        app.add_systems(OnEnter(AppState::MainMenu), (foo, bar))
-            .init_state::<AppState>();
-
-        app.world.run_schedule(OnEnter(AppState::MainMenu));
-        assert_eq!(app.world.entities().len(), 2);
-    }
-
     #[test]
     fn test_derive_app_label() {
         use super::AppLabel;
@@ -1269,13 +1430,15 @@ mod tests {
         #[derive(Resource)]
         struct MyState {}
 
-        fn my_runner(mut app: App) {
+        fn my_runner(mut app: App) -> AppExit {
             let my_state = MyState {};
             app.world.insert_resource(my_state);
 
             for _ in 0..5 {
                 app.update();
             }
+
+            AppExit::Success
         }
 
         fn my_system(_: Res<MyState>) {
@@ -1288,4 +1451,67 @@ mod tests {
             .add_systems(PreUpdate, my_system)
             .run();
     }
+
+    #[test]
+    fn app_exit_size() {
+        // There wont be many of them so the size isn't a issue but
+        // it's nice they're so small let's keep it that way.
+        assert_eq!(std::mem::size_of::<AppExit>(), std::mem::size_of::<u8>());
+    }
+
+    #[test]
+    fn run_returns_success_without_exit_events() {
+        assert_eq!(App::new().run(), AppExit::Success);
+    }
+
+    #[test]
+    fn run_returns_first_error_over_success() {
+        fn exit(mut exits: EventWriter<AppExit>) {
+            exits.send(AppExit::Success);
+            exits.send(AppExit::from_code(4));
+            exits.send(AppExit::Success);
+            exits.send(AppExit::from_code(73));
+        }
+
+        let exit = App::new().add_systems(Update, exit).run();
+        assert_eq!(exit, AppExit::from_code(4));
+    }
+
+    #[test]
+    fn error_handler_is_used_by_sub_apps() {
+        #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, AppLabel)]
+        enum Sub {
+            Before,
+            After,
+            Own,
+        }
+
+        fn fallible_system() -> Result {
+            Err("something went wrong".into())
+        }
+
+        static OWN_HANDLER_RAN: AtomicBool = AtomicBool::new(false);
+        fn record_error(_: BoxedError, _: ErrorContext) {
+            OWN_HANDLER_RAN.store(true, Ordering::Relaxed);
+        }
+
+        fn sub_app() -> SubApp {
+            let mut app = App::empty();
+            app.main_schedule_label = Update.intern();
+            app.add_systems(Update, fallible_system);
+            SubApp::new(app, |_, _| {})
+        }
+
+        let mut app = App::new();
+        app.insert_sub_app(Sub::Before, sub_app());
+        app.set_error_handler(error::ignore);
+        app.insert_sub_app(Sub::After, sub_app());
+        let mut own = sub_app();
+        own.app.set_error_handler(record_error);
+        app.insert_sub_app(Sub::Own, own);
+
+        // Would panic if a sub app fell back to the default handler.
+        app.update();
+        assert!(OWN_HANDLER_RAN.load(Ordering::Relaxed));
+    }
 }
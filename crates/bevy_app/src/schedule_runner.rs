@@ -3,7 +3,6 @@ use crate::{
     plugin::Plugin,
     PluginsState,
 };
-use bevy_ecs::event::{Events, ManualEventReader};
 use bevy_utils::{Duration, Instant};
 
 #[cfg(target_arch = "wasm32")]
@@ -13,6 +12,9 @@ use wasm_bindgen::{prelude::*, JsCast};
 
 /// Determines the method used to run an [`App`]'s [`Schedule`](bevy_ecs::schedule::Schedule).
 ///
+/// In both modes the runner returns the [`AppExit`] aggregated by [`App::should_exit`],
+/// or [`AppExit::Success`] if no exit was requested.
+///
 /// It is used in the [`ScheduleRunnerPlugin`].
 #[derive(Copy, Clone, Debug)]
 pub enum RunMode {
@@ -82,24 +84,22 @@ impl Plugin for ScheduleRunnerPlugin {
                 app.cleanup();
             }
 
-            let mut app_exit_event_reader = ManualEventReader::<AppExit>::default();
             match run_mode {
-                RunMode::Once => app.update(),
+                RunMode::Once => {
+                    app.update();
+
+                    app.should_exit().unwrap_or(AppExit::Success)
+                }
                 RunMode::Loop { wait } => {
-                    let mut tick = move |app: &mut App,
-                                         wait: Option<Duration>|
+                    let tick = move |app: &mut App,
+                                     wait: Option<Duration>|
                           -> Result<Option<Duration>, AppExit> {
                         let start_time = Instant::now();
 
                         app.update();
 
-                        if let Some(app_exit_events) =
-                            app.world.get_resource_mut::<Events<AppExit>>()
-                        {
-                            if let Some(exit) = app_exit_event_reader.read(&app_exit_events).last()
-                            {
-                                return Err(exit.clone());
-                            }
+                        if let Some(exit) = app.should_exit() {
+                            return Err(exit);
                         }
 
                         let end_time = Instant::now();
@@ -116,9 +116,11 @@ impl Plugin for ScheduleRunnerPlugin {
 
                     #[cfg(not(target_arch = "wasm32"))]
                     {
-                        while let Ok(delay) = tick(&mut app, wait) {
-                            if let Some(delay) = delay {
-                                std::thread::sleep(delay);
+                        loop {
+                            match tick(&mut app, wait) {
+                                Ok(Some(delay)) => std::thread::sleep(delay),
+                                Ok(None) => continue,
+                                Err(exit) => return exit,
                             }
                         }
                     }
@@ -152,7 +154,11 @@ impl Plugin for ScheduleRunnerPlugin {
                         };
                         *g.borrow_mut() = Some(Closure::wrap(Box::new(c) as Box<dyn FnMut()>));
                         set_timeout(g.borrow().as_ref().unwrap(), asap);
-                    };
+
+                        // The loop keeps going in the browser after this returns, so there is
+                        // no exit code to report yet.
+                        AppExit::Success
+                    }
                 }
             }
         });
@@ -11,6 +11,7 @@ pub(crate) fn bevy_asset_path() -> Path {
 }
 
 const DEPENDENCY_ATTRIBUTE: &str = "dependency";
+const ASSET_ATTRIBUTE: &str = "asset";
 
 #[proc_macro_derive(Asset, attributes(dependency))]
 pub fn derive_asset(input: TokenStream) -> TokenStream {
@@ -19,10 +20,11 @@ pub fn derive_asset(input: TokenStream) -> TokenStream {
 
     let struct_name = &ast.ident;
     let (impl_generics, type_generics, where_clause) = &ast.generics.split_for_impl();
-    let dependency_visitor = match derive_dependency_visitor_internal(&ast, &bevy_asset_path) {
-        Ok(dependency_visitor) => dependency_visitor,
-        Err(err) => return err.into_compile_error().into(),
-    };
+    let dependency_visitor =
+        match derive_dependency_visitor_internal(&ast, &bevy_asset_path, DEPENDENCY_ATTRIBUTE) {
+            Ok(dependency_visitor) => dependency_visitor,
+            Err(err) => return err.into_compile_error().into(),
+        };
 
     TokenStream::from(quote! {
         impl #impl_generics #bevy_asset_path::Asset for #struct_name #type_generics #where_clause { }
@@ -34,21 +36,94 @@ pub fn derive_asset(input: TokenStream) -> TokenStream {
 pub fn derive_asset_dependency_visitor(input: TokenStream) -> TokenStream {
     let ast = parse_macro_input!(input as DeriveInput);
     let bevy_asset_path: Path = bevy_asset_path();
-    match derive_dependency_visitor_internal(&ast, &bevy_asset_path) {
+    match derive_dependency_visitor_internal(&ast, &bevy_asset_path, DEPENDENCY_ATTRIBUTE) {
         Ok(dependency_visitor) => TokenStream::from(dependency_visitor),
         Err(err) => err.into_compile_error().into(),
     }
 }
 
+#[proc_macro_derive(AssetCollection, attributes(asset))]
+pub fn derive_asset_collection(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+    let bevy_asset_path: Path = bevy_asset_path();
+    match derive_asset_collection_internal(&ast, &bevy_asset_path) {
+        Ok(asset_collection) => TokenStream::from(asset_collection),
+        Err(err) => err.into_compile_error().into(),
+    }
+}
+
+fn derive_asset_collection_internal(
+    ast: &DeriveInput,
+    bevy_asset_path: &Path,
+) -> Result<proc_macro2::TokenStream, syn::Error> {
+    let struct_name = &ast.ident;
+    let (impl_generics, type_generics, where_clause) = &ast.generics.split_for_impl();
+
+    let Data::Struct(syn::DataStruct {
+        fields: syn::Fields::Named(fields),
+        ..
+    }) = &ast.data
+    else {
+        return Err(syn::Error::new(
+            Span::call_site().into(),
+            "AssetCollection derive only works on structs with named fields",
+        ));
+    };
+
+    let mut field_initializers = Vec::new();
+    for field in &fields.named {
+        let ident = &field.ident;
+        let mut path = None;
+        for attr in &field.attrs {
+            if !attr.path().is_ident(ASSET_ATTRIBUTE) {
+                continue;
+            }
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("path") {
+                    path = Some(meta.value()?.parse::<syn::LitStr>()?);
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported asset attribute, expected `path`"))
+                }
+            })?;
+            if path.is_none() {
+                return Err(syn::Error::new_spanned(
+                    attr,
+                    "asset attribute requires a path: #[asset(path = \"...\")]",
+                ));
+            }
+        }
+        field_initializers.push(match path {
+            Some(path) => quote!(#ident: asset_server.load(#path)),
+            None => quote!(#ident: ::core::default::Default::default()),
+        });
+    }
+
+    let dependency_visitor =
+        derive_dependency_visitor_internal(ast, bevy_asset_path, ASSET_ATTRIBUTE)?;
+
+    Ok(quote! {
+        impl #impl_generics #bevy_asset_path::AssetCollection for #struct_name #type_generics #where_clause {
+            fn load(asset_server: &#bevy_asset_path::AssetServer) -> Self {
+                Self {
+                    #(#field_initializers,)*
+                }
+            }
+        }
+        #dependency_visitor
+    })
+}
+
 fn derive_dependency_visitor_internal(
     ast: &DeriveInput,
     bevy_asset_path: &Path,
+    attribute: &str,
 ) -> Result<proc_macro2::TokenStream, syn::Error> {
     let struct_name = &ast.ident;
     let (impl_generics, type_generics, where_clause) = &ast.generics.split_for_impl();
 
     let visit_dep = |to_read| quote!(#bevy_asset_path::VisitAssetDependencies::visit_dependencies(#to_read, visit););
-    let is_dep_attribute = |a: &syn::Attribute| a.path().is_ident(DEPENDENCY_ATTRIBUTE);
+    let is_dep_attribute = |a: &syn::Attribute| a.path().is_ident(attribute);
     let field_has_dep = |f: &syn::Field| f.attrs.iter().any(is_dep_attribute);
 
     let body = match &ast.data {
@@ -0,0 +1,185 @@
+use crate::{
+    AssetServer, LoadState, RecursiveDependencyLoadState, UntypedAssetId, VisitAssetDependencies,
+};
+#[cfg(feature = "bevy_state")]
+use bevy_ecs::system::{In, ResMut};
+use bevy_ecs::{
+    event::{Event, EventWriter},
+    system::{Commands, Res, Resource},
+    world::World,
+};
+#[cfg(feature = "bevy_state")]
+use bevy_state::state::{FreelyMutableState, NextState};
+use bevy_utils::tracing::error;
+use std::{any::type_name, marker::PhantomData};
+
+/// A [`Resource`] holding the handles of a set of assets that are loaded together.
+///
+/// This is typically derived, with a `#[asset(path = "...")]` attribute on each [`Handle`] field
+/// to load. Fields without this attribute are initialized with [`Default`].
+///
+/// ```
+/// # use bevy_asset::{AssetCollection, Handle, LoadedUntypedAsset};
+/// # use bevy_ecs::system::Resource;
+/// #[derive(Resource, AssetCollection)]
+/// struct LevelAssets {
+///     #[asset(path = "levels/forest.level")]
+///     forest: Handle<LoadedUntypedAsset>,
+///     #[asset(path = "music/forest.ogg")]
+///     music: Handle<LoadedUntypedAsset>,
+///     visits: u32,
+/// }
+/// ```
+///
+/// Use [`AssetApp::load_asset_collection`](crate::AssetApp::load_asset_collection) to load the
+/// collection and insert it as a resource once all of its assets and their dependencies are loaded.
+/// Systems can then wait for it with the `resource_added` or `resource_exists` run conditions:
+///
+/// ```
+/// # use bevy_app::{App, Update};
+/// # use bevy_asset::{AssetApp, AssetCollection, Handle, LoadedUntypedAsset};
+/// # use bevy_ecs::prelude::*;
+/// # #[derive(Resource, AssetCollection)]
+/// # struct LevelAssets {
+/// #     #[asset(path = "levels/forest.level")]
+/// #     forest: Handle<LoadedUntypedAsset>,
+/// # }
+/// fn start_level(level_assets: Res<LevelAssets>) {
+///     // All of the level assets are loaded here.
+/// }
+///
+/// fn build(app: &mut App) {
+///     app.load_asset_collection::<LevelAssets>()
+///         .add_systems(Update, start_level.run_if(resource_added::<LevelAssets>));
+/// }
+/// ```
+///
+/// To load the collection in a loading state, use `AssetApp::load_asset_collection_in_state`
+/// instead, which requires the `bevy_state` feature.
+///
+/// [`Handle`]: crate::Handle
+pub trait AssetCollection: Resource + VisitAssetDependencies + Sized {
+    /// Starts loading every asset of the collection with the given [`AssetServer`].
+    fn load(asset_server: &AssetServer) -> Self;
+
+    /// Returns the combined [`RecursiveDependencyLoadState`] of the assets of this collection.
+    ///
+    /// This is [`RecursiveDependencyLoadState::Failed`] as soon as one asset or one of its dependencies
+    /// failed to load, and [`RecursiveDependencyLoadState::Loaded`] once all of them are loaded.
+    fn recursive_dependency_load_state(
+        &self,
+        asset_server: &AssetServer,
+    ) -> RecursiveDependencyLoadState {
+        let mut state = RecursiveDependencyLoadState::Loaded;
+        self.visit_dependencies(&mut |id: UntypedAssetId| {
+            let asset_state = match asset_server.get_load_states(id) {
+                Some((LoadState::Failed, _, _)) => RecursiveDependencyLoadState::Failed,
+                Some((_, _, rec_dep_load_state)) => rec_dep_load_state,
+                None => RecursiveDependencyLoadState::NotLoaded,
+            };
+            state = match (state, asset_state) {
+                (RecursiveDependencyLoadState::Failed, _)
+                | (_, RecursiveDependencyLoadState::Failed) => RecursiveDependencyLoadState::Failed,
+                (RecursiveDependencyLoadState::Loaded, other)
+                | (other, RecursiveDependencyLoadState::Loaded) => other,
+                (RecursiveDependencyLoadState::Loading, _)
+                | (_, RecursiveDependencyLoadState::Loading) => {
+                    RecursiveDependencyLoadState::Loading
+                }
+                _ => RecursiveDependencyLoadState::NotLoaded,
+            };
+        });
+        state
+    }
+}
+
+/// A [`Resource`] holding an [`AssetCollection`] that is still loading.
+///
+/// It is replaced by the collection itself once all of its assets are loaded, or removed if one of
+/// them failed to load. See [`AssetApp::load_asset_collection`](crate::AssetApp::load_asset_collection).
+#[derive(Resource)]
+pub struct LoadingAssetCollection<C: AssetCollection>(pub C);
+
+/// An [`Event`] sent when an [`AssetCollection`] could not be loaded because one of its assets or
+/// their dependencies failed to load.
+#[derive(Event)]
+pub struct AssetCollectionLoadFailedEvent<C: AssetCollection> {
+    /// The ids of the assets of the collection that failed to load, or whose dependencies failed to load.
+    pub failed: Vec<UntypedAssetId>,
+    marker: PhantomData<C>,
+}
+
+/// Starts loading `C` and inserts it as a [`LoadingAssetCollection`].
+pub(crate) fn start_loading_asset_collection<C: AssetCollection>(
+    mut commands: Commands,
+    asset_server: Option<Res<AssetServer>>,
+) {
+    let Some(asset_server) = asset_server else {
+        error!(
+            "Cannot load asset collection {}: the `AssetPlugin` was not added",
+            type_name::<C>()
+        );
+        return;
+    };
+    commands.insert_resource(LoadingAssetCollection(C::load(&asset_server)));
+}
+
+/// Inserts the [`LoadingAssetCollection`] of `C` as a resource once all of its assets are loaded,
+/// or sends an [`AssetCollectionLoadFailedEvent`] if one of them failed to load.
+///
+/// Returns the [`RecursiveDependencyLoadState`] of the collection.
+pub(crate) fn track_asset_collection<C: AssetCollection>(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    loading: Res<LoadingAssetCollection<C>>,
+    mut failures: EventWriter<AssetCollectionLoadFailedEvent<C>>,
+) -> RecursiveDependencyLoadState {
+    let state = loading.0.recursive_dependency_load_state(&asset_server);
+    match state {
+        RecursiveDependencyLoadState::Loaded => commands.add(|world: &mut World| {
+            if let Some(LoadingAssetCollection(collection)) =
+                world.remove_resource::<LoadingAssetCollection<C>>()
+            {
+                world.insert_resource(collection);
+            }
+        }),
+        RecursiveDependencyLoadState::Failed => {
+            error!(
+                "Failed to load asset collection {}: one of its assets or their dependencies failed to load",
+                type_name::<C>()
+            );
+            let mut failed = Vec::new();
+            loading.0.visit_dependencies(&mut |id: UntypedAssetId| {
+                if let Some((load_state, _, rec_dep_load_state)) = asset_server.get_load_states(id)
+                {
+                    if load_state == LoadState::Failed
+                        || rec_dep_load_state == RecursiveDependencyLoadState::Failed
+                    {
+                        failed.push(id);
+                    }
+                }
+            });
+            failures.send(AssetCollectionLoadFailedEvent {
+                failed,
+                marker: PhantomData,
+            });
+            commands.remove_resource::<LoadingAssetCollection<C>>();
+        }
+        RecursiveDependencyLoadState::NotLoaded | RecursiveDependencyLoadState::Loading => {}
+    }
+    state
+}
+
+/// Returns a system that switches to the `next` state once the collection piped into it is loaded,
+/// or to the `failure` state if it failed to load.
+#[cfg(feature = "bevy_state")]
+pub(crate) fn leave_loading_state<S: FreelyMutableState>(
+    next: S,
+    failure: S,
+) -> impl FnMut(In<RecursiveDependencyLoadState>, ResMut<NextState<S>>) {
+    move |In(state), mut next_state| match state {
+        RecursiveDependencyLoadState::Loaded => next_state.set(next.clone()),
+        RecursiveDependencyLoadState::Failed => next_state.set(failure.clone()),
+        RecursiveDependencyLoadState::NotLoaded | RecursiveDependencyLoadState::Loading => {}
+    }
+}
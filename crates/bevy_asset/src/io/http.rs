@@ -0,0 +1,383 @@
+//! An [`AssetReader`] that fetches assets over HTTP, with an optional on-disk cache.
+//!
+//! [`AssetPath`](crate::AssetPath) parses `http://` and `https://` prefixes as asset sources, so
+//! registering [`HttpAssetReader`]s for the `http` and `https` sources makes
+//! `asset_server.load("https://example.com/sprite.png")` work:
+//!
+//! ```no_run
+//! # use bevy_app::App;
+//! # use bevy_asset::{io::http::HttpAssetReader, AssetApp};
+//! # use bevy_utils::Duration;
+//! let mut app = App::new();
+//! app.register_asset_source(
+//!     "https",
+//!     HttpAssetReader::new("https://")
+//!         .with_timeout(Duration::from_secs(10))
+//!         .with_cache("http_cache")
+//!         .into_source_builder(),
+//! );
+//! ```
+//!
+//! A source can also point to a fixed location, for example to load `cdn://levels/1.scn.ron`
+//! from a content delivery network, by passing `https://cdn.example.com/content/` as the prefix.
+
+use crate::io::{
+    get_meta_path, AssetReader, AssetReaderError, AssetSource, AssetSourceBuilder, EmptyPathStream,
+    PathStream, Reader, VecReader,
+};
+use bevy_utils::tracing::{error, warn};
+use bevy_utils::Duration;
+use std::{
+    io::{ErrorKind, Read},
+    path::{Path, PathBuf},
+};
+
+/// Reader implementation for loading assets over HTTP on native platforms.
+///
+/// The URL of an asset is built by appending its path to the `url_prefix` given to
+/// [`HttpAssetReader::new`]. Status code 404 is reported as [`AssetReaderError::NotFound`], other
+/// non-successful status codes as [`AssetReaderError::HttpError`]. Failures that happen before a
+/// status code is received, such as timeouts, are reported as [`AssetReaderError::Io`].
+///
+/// HTTPS URLs require the `https_source` feature.
+#[derive(Clone)]
+pub struct HttpAssetReader {
+    url_prefix: String,
+    connect_timeout: Option<Duration>,
+    read_timeout: Option<Duration>,
+    timeout: Option<Duration>,
+    cache: Option<HttpCache>,
+    agent: ureq::Agent,
+}
+
+impl HttpAssetReader {
+    /// Creates a new [`HttpAssetReader`] requesting URLs starting with `url_prefix`.
+    pub fn new(url_prefix: impl Into<String>) -> Self {
+        Self {
+            url_prefix: url_prefix.into(),
+            connect_timeout: None,
+            read_timeout: None,
+            timeout: None,
+            cache: None,
+            agent: ureq::Agent::new(),
+        }
+    }
+
+    /// Sets the maximum time spent establishing a connection.
+    pub fn with_connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self.rebuild_agent()
+    }
+
+    /// Sets the maximum time spent waiting for each read of the response.
+    pub fn with_read_timeout(mut self, timeout: Duration) -> Self {
+        self.read_timeout = Some(timeout);
+        self.rebuild_agent()
+    }
+
+    /// Sets the maximum time spent on a whole request, from connecting to reading the last byte.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self.rebuild_agent()
+    }
+
+    /// Caches responses in the `path` directory.
+    ///
+    /// Responses are only cached if they have an `ETag` header. Cached responses are revalidated
+    /// with an `If-None-Match` request, and reused when the server answers `304 Not Modified`.
+    pub fn with_cache(mut self, path: impl Into<PathBuf>) -> Self {
+        self.cache = Some(HttpCache { root: path.into() });
+        self
+    }
+
+    /// Returns the prefix prepended to asset paths to build their URL.
+    pub fn url_prefix(&self) -> &str {
+        &self.url_prefix
+    }
+
+    /// Creates an [`AssetSourceBuilder`] using this reader, to be registered with
+    /// [`AssetApp::register_asset_source`](crate::AssetApp::register_asset_source) or
+    /// [`AssetSourceBuilders::insert`](crate::io::AssetSourceBuilders::insert).
+    pub fn into_source_builder(self) -> AssetSourceBuilder {
+        AssetSource::build().with_reader(move || Box::new(self.clone()))
+    }
+
+    fn rebuild_agent(mut self) -> Self {
+        let mut builder = ureq::AgentBuilder::new();
+        if let Some(timeout) = self.connect_timeout {
+            builder = builder.timeout_connect(timeout);
+        }
+        if let Some(timeout) = self.read_timeout {
+            builder = builder.timeout_read(timeout);
+        }
+        if let Some(timeout) = self.timeout {
+            builder = builder.timeout(timeout);
+        }
+        self.agent = builder.build();
+        self
+    }
+
+    fn url(&self, path: &Path) -> String {
+        let path = path.to_string_lossy().replace('\\', "/");
+        format!("{}{}", self.url_prefix, path)
+    }
+
+    async fn fetch_bytes<'a>(&self, path: PathBuf) -> Result<Box<Reader<'a>>, AssetReaderError> {
+        let url = self.url(&path);
+        let agent = self.agent.clone();
+        let cache = self.cache.clone();
+        let bytes = blocking::unblock(move || fetch(&agent, &url, cache.as_ref(), path)).await?;
+        let reader: Box<Reader> = Box::new(VecReader::new(bytes));
+        Ok(reader)
+    }
+}
+
+fn fetch(
+    agent: &ureq::Agent,
+    url: &str,
+    cache: Option<&HttpCache>,
+    path: PathBuf,
+) -> Result<Vec<u8>, AssetReaderError> {
+    let cached_etag = cache.and_then(|cache| cache.etag(url));
+    let mut request = agent.get(url);
+    if let Some(etag) = &cached_etag {
+        request = request.set("If-None-Match", etag);
+    }
+
+    let response = match request.call() {
+        Ok(response) => response,
+        Err(ureq::Error::Status(404, _)) => return Err(AssetReaderError::NotFound(path)),
+        Err(ureq::Error::Status(status, _)) => return Err(AssetReaderError::HttpError(status)),
+        Err(ureq::Error::Transport(transport)) => {
+            return Err(std::io::Error::other(transport).into())
+        }
+    };
+
+    match response.status() {
+        304 => {
+            if let Some(bytes) = cache.and_then(|cache| cache.body(url)) {
+                return Ok(bytes);
+            }
+            Err(AssetReaderError::HttpError(304))
+        }
+        200..=299 => {
+            let etag = response.header("ETag").map(ToString::to_string);
+            let mut bytes = Vec::new();
+            response.into_reader().read_to_end(&mut bytes)?;
+            if let Some(cache) = cache {
+                if let Err(err) = cache.store(url, etag.as_deref(), &bytes) {
+                    warn!("Failed to cache the response for {url}: {err}");
+                }
+            }
+            Ok(bytes)
+        }
+        status => Err(AssetReaderError::HttpError(status)),
+    }
+}
+
+/// An on-disk cache of HTTP responses, keyed by URL and validated with their `ETag`.
+#[derive(Clone)]
+struct HttpCache {
+    root: PathBuf,
+}
+
+impl HttpCache {
+    fn entry_path(&self, url: &str, extension: &str) -> PathBuf {
+        let hash = blake3::hash(url.as_bytes());
+        self.root
+            .join(hash.to_hex().as_str())
+            .with_extension(extension)
+    }
+
+    fn etag(&self, url: &str) -> Option<String> {
+        if !self.entry_path(url, "body").is_file() {
+            return None;
+        }
+        std::fs::read_to_string(self.entry_path(url, "etag")).ok()
+    }
+
+    fn body(&self, url: &str) -> Option<Vec<u8>> {
+        std::fs::read(self.entry_path(url, "body")).ok()
+    }
+
+    fn store(&self, url: &str, etag: Option<&str>, bytes: &[u8]) -> std::io::Result<()> {
+        let etag_path = self.entry_path(url, "etag");
+        let body_path = self.entry_path(url, "body");
+        // The ETag is removed first so that an interrupted write never pairs a new body with an old ETag.
+        match std::fs::remove_file(&etag_path) {
+            Err(err) if err.kind() != ErrorKind::NotFound => return Err(err),
+            _ => {}
+        }
+        let Some(etag) = etag else {
+            return match std::fs::remove_file(&body_path) {
+                Err(err) if err.kind() != ErrorKind::NotFound => Err(err),
+                _ => Ok(()),
+            };
+        };
+        std::fs::create_dir_all(&self.root)?;
+        let temp_path = self.entry_path(url, "tmp");
+        std::fs::write(&temp_path, bytes)?;
+        std::fs::rename(&temp_path, &body_path)?;
+        std::fs::write(&etag_path, etag)
+    }
+}
+
+impl AssetReader for HttpAssetReader {
+    async fn read<'a>(&'a self, path: &'a Path) -> Result<Box<Reader<'a>>, AssetReaderError> {
+        self.fetch_bytes(path.to_owned()).await
+    }
+
+    async fn read_meta<'a>(&'a self, path: &'a Path) -> Result<Box<Reader<'a>>, AssetReaderError> {
+        self.fetch_bytes(get_meta_path(path)).await
+    }
+
+    async fn read_directory<'a>(
+        &'a self,
+        _path: &'a Path,
+    ) -> Result<Box<PathStream>, AssetReaderError> {
+        let stream: Box<PathStream> = Box::new(EmptyPathStream);
+        error!("Reading directories is not supported with the HttpAssetReader");
+        Ok(stream)
+    }
+
+    async fn is_directory<'a>(&'a self, _path: &'a Path) -> Result<bool, AssetReaderError> {
+        error!("Reading directories is not supported with the HttpAssetReader");
+        Ok(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HttpAssetReader;
+    use crate::io::{AssetReader, AssetReaderError};
+    use bevy_utils::Duration;
+    use futures_lite::{future::block_on, AsyncReadExt};
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        path::Path,
+        sync::{Arc, Mutex},
+    };
+
+    /// A request received by the [`serve`] loopback server: its path and `If-None-Match` header.
+    type Requests = Arc<Mutex<Vec<(String, Option<String>)>>>;
+
+    /// Serves every request with `respond` on a loopback port, returning the URL prefix to use.
+    fn serve(
+        respond: impl Fn(&str, Option<&str>) -> Option<String> + Send + 'static,
+    ) -> (String, Requests) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let prefix = format!("http://{}/", listener.local_addr().unwrap());
+        let requests = Requests::default();
+        let received = requests.clone();
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                let path = line.split(' ').nth(1).unwrap().to_string();
+                let mut etag = None;
+                loop {
+                    let mut header = String::new();
+                    reader.read_line(&mut header).unwrap();
+                    let header = header.trim_end();
+                    if header.is_empty() {
+                        break;
+                    }
+                    if let Some((name, value)) = header.split_once(": ") {
+                        if name.eq_ignore_ascii_case("if-none-match") {
+                            etag = Some(value.to_string());
+                        }
+                    }
+                }
+                received.lock().unwrap().push((path.clone(), etag.clone()));
+                match respond(&path, etag.as_deref()) {
+                    Some(response) => stream.write_all(response.as_bytes()).unwrap(),
+                    // Never answer, so that the client times out.
+                    None => std::thread::sleep(Duration::from_secs(5)),
+                }
+            }
+        });
+        (prefix, requests)
+    }
+
+    fn response(status: &str, headers: &str, body: &str) -> String {
+        format!(
+            "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n{headers}\r\n{body}",
+            body.len()
+        )
+    }
+
+    fn read_string(reader: &HttpAssetReader, path: &str) -> Result<String, AssetReaderError> {
+        block_on(async {
+            let mut reader = reader.read(Path::new(path)).await?;
+            let mut text = String::new();
+            reader.read_to_string(&mut text).await?;
+            Ok(text)
+        })
+    }
+
+    #[test]
+    fn status_codes() {
+        let (prefix, requests) = serve(|path, _| {
+            Some(match path {
+                "/dir/asset.txt" => response("200 OK", "", "hello"),
+                "/dir/asset.txt.meta" => response("404 Not Found", "", ""),
+                _ => response("503 Service Unavailable", "", ""),
+            })
+        });
+        let reader = HttpAssetReader::new(prefix);
+
+        assert_eq!(read_string(&reader, "dir/asset.txt").unwrap(), "hello");
+        let meta = block_on(reader.read_meta(Path::new("dir/asset.txt")));
+        assert!(
+            matches!(meta, Err(AssetReaderError::NotFound(path)) if path == Path::new("dir/asset.txt.meta"))
+        );
+        assert!(matches!(
+            read_string(&reader, "other.txt"),
+            Err(AssetReaderError::HttpError(503))
+        ));
+        assert_eq!(requests.lock().unwrap().len(), 3);
+    }
+
+    #[test]
+    fn timeout() {
+        let (prefix, _) = serve(|_, _| None);
+        let reader = HttpAssetReader::new(prefix).with_timeout(Duration::from_millis(100));
+        assert!(matches!(
+            read_string(&reader, "slow.txt"),
+            Err(AssetReaderError::Io(_))
+        ));
+    }
+
+    #[test]
+    fn cache() {
+        let cache_dir =
+            std::env::temp_dir().join(format!("bevy_asset_http_cache_test_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&cache_dir);
+
+        let (prefix, requests) = serve(|path, etag| {
+            Some(match (path, etag) {
+                ("/cached.txt", Some("\"v1\"")) => response("304 Not Modified", "", ""),
+                ("/cached.txt", _) => response("200 OK", "ETag: \"v1\"\r\n", "first"),
+                _ => response("200 OK", "", "no etag"),
+            })
+        });
+        let reader = HttpAssetReader::new(prefix).with_cache(&cache_dir);
+
+        assert_eq!(read_string(&reader, "cached.txt").unwrap(), "first");
+        assert_eq!(read_string(&reader, "cached.txt").unwrap(), "first");
+        assert_eq!(read_string(&reader, "uncached.txt").unwrap(), "no etag");
+        assert_eq!(read_string(&reader, "uncached.txt").unwrap(), "no etag");
+
+        let requests = requests.lock().unwrap();
+        let etags = requests
+            .iter()
+            .map(|(_, etag)| etag.as_deref())
+            .collect::<Vec<_>>();
+        assert_eq!(etags, [None, Some("\"v1\""), None, None]);
+
+        std::fs::remove_dir_all(&cache_dir).unwrap();
+    }
+}
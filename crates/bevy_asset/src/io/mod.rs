@@ -11,7 +11,11 @@ pub mod embedded;
 #[cfg(not(target_arch = "wasm32"))]
 pub mod file;
 pub mod gated;
+#[cfg(all(feature = "http_source", not(target_arch = "wasm32")))]
+pub mod http;
 pub mod memory;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod pack;
 pub mod processor_gated;
 #[cfg(target_arch = "wasm32")]
 pub mod wasm;
@@ -0,0 +1,575 @@
+//! Asset packs: single files bundling many assets and their `.meta` files, along with an index.
+//!
+//! Packs are built with a [`PackBuilder`], usually from the output of the
+//! [`AssetProcessor`](crate::processor::AssetProcessor) in `imported_assets/Default`, and are read
+//! by a [`PackAssetReader`]. A reader can mount several packs with different priorities, so that
+//! patches and mods override the assets of the base game:
+//!
+//! ```no_run
+//! # use bevy_app::App;
+//! # use bevy_asset::{io::pack::{PackAssetReader, PackBuilder}, AssetApp};
+//! // At build time.
+//! PackBuilder::new()
+//!     .add_directory("imported_assets/Default")?
+//!     .write_to_file("base.pack")?;
+//!
+//! // At runtime.
+//! let reader = PackAssetReader::new()
+//!     .with_pack("base.pack", 0)?
+//!     .with_pack("patch_1.pack", 1)?;
+//! let mut app = App::new();
+//! app.register_asset_source("packs", reader.into_source_builder());
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! A pack starts with the [`PACK_MAGIC`] bytes, followed by the format version and the offset of
+//! the index as little-endian `u32` and `u64`. The asset bytes come next, and the index ends the
+//! file: a `u32` entry count, then the length and UTF-8 bytes of each `/`-separated path, followed
+//! by the offset and length of its bytes.
+
+use crate::io::{
+    get_meta_path, AssetReader, AssetReaderError, AssetSource, AssetSourceBuilder,
+    ErasedAssetReader, PathStream, Reader, VecReader,
+};
+use async_fs::File;
+use bevy_utils::{HashMap, HashSet};
+use futures_lite::{AsyncReadExt, AsyncSeekExt, StreamExt};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    io::{Read, Seek, SeekFrom, Write},
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+use thiserror::Error;
+
+/// The bytes every asset pack starts with.
+pub const PACK_MAGIC: [u8; 8] = *b"BEVYPACK";
+
+/// The version of the asset pack format written by [`PackBuilder`].
+pub const PACK_VERSION: u32 = 1;
+
+const HEADER_LEN: u64 = 8 + 4 + 8;
+
+/// The length of an index entry with an empty path.
+const MIN_ENTRY_LEN: usize = 4 + 8 + 8;
+
+/// Errors that occur while opening an asset pack.
+#[derive(Error, Debug)]
+pub enum PackError {
+    /// Encountered an I/O error while reading the pack.
+    #[error("encountered an io error while reading asset pack: {0}")]
+    Io(#[from] std::io::Error),
+    /// The file does not start with [`PACK_MAGIC`].
+    #[error("the file is not an asset pack")]
+    InvalidMagic,
+    /// The pack was written with an unknown version of the format.
+    #[error("unsupported asset pack version {0}")]
+    UnsupportedVersion(u32),
+    /// The index of the pack is malformed.
+    #[error("the index of the asset pack is corrupted")]
+    InvalidIndex,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct PackEntry {
+    offset: u64,
+    len: u64,
+}
+
+/// An asset pack mounted by a [`PackAssetReader`].
+#[derive(Debug)]
+struct Pack {
+    path: PathBuf,
+    priority: i32,
+    entries: HashMap<PathBuf, PackEntry>,
+    directories: HashSet<PathBuf>,
+}
+
+impl Pack {
+    fn open(path: PathBuf, priority: i32) -> Result<Self, PackError> {
+        let mut file = std::fs::File::open(&path)?;
+        let file_len = file.metadata()?.len();
+        if file_len < HEADER_LEN {
+            return Err(PackError::InvalidMagic);
+        }
+
+        let mut header = [0; HEADER_LEN as usize];
+        file.read_exact(&mut header)?;
+        if header[..8] != PACK_MAGIC {
+            return Err(PackError::InvalidMagic);
+        }
+        let version = u32::from_le_bytes(header[8..12].try_into().unwrap());
+        if version != PACK_VERSION {
+            return Err(PackError::UnsupportedVersion(version));
+        }
+        let index_offset = u64::from_le_bytes(header[12..20].try_into().unwrap());
+        if !(HEADER_LEN..=file_len).contains(&index_offset) {
+            return Err(PackError::InvalidIndex);
+        }
+
+        file.seek(SeekFrom::Start(index_offset))?;
+        let mut index = Vec::new();
+        file.read_to_end(&mut index)?;
+        let entries = parse_index(&index, index_offset).ok_or(PackError::InvalidIndex)?;
+
+        let directories = entries
+            .keys()
+            .flat_map(|path| path.ancestors().skip(1))
+            .map(Path::to_path_buf)
+            .collect();
+        Ok(Self {
+            path,
+            priority,
+            entries,
+            directories,
+        })
+    }
+
+    async fn read_entry<'a>(&self, path: &Path) -> Result<Box<Reader<'a>>, AssetReaderError> {
+        let entry = *self
+            .entries
+            .get(path)
+            .ok_or_else(|| AssetReaderError::NotFound(path.to_path_buf()))?;
+        let mut file = File::open(&self.path).await?;
+        file.seek(SeekFrom::Start(entry.offset)).await?;
+        let mut bytes = vec![0; entry.len as usize];
+        file.read_exact(&mut bytes).await?;
+        let reader: Box<Reader> = Box::new(VecReader::new(bytes));
+        Ok(reader)
+    }
+}
+
+fn parse_index(mut index: &[u8], data_end: u64) -> Option<HashMap<PathBuf, PackEntry>> {
+    fn take<'a>(index: &mut &'a [u8], len: usize) -> Option<&'a [u8]> {
+        if index.len() < len {
+            return None;
+        }
+        let (bytes, rest) = index.split_at(len);
+        *index = rest;
+        Some(bytes)
+    }
+    let read_u32 = |index: &mut &[u8]| Some(u32::from_le_bytes(take(index, 4)?.try_into().ok()?));
+    let read_u64 = |index: &mut &[u8]| Some(u64::from_le_bytes(take(index, 8)?.try_into().ok()?));
+
+    let count = read_u32(&mut index)?;
+    // The count comes from the file, so don't trust it further than the index can hold.
+    let mut entries = HashMap::with_capacity((count as usize).min(index.len() / MIN_ENTRY_LEN));
+    for _ in 0..count {
+        let path_len = read_u32(&mut index)?;
+        let path = std::str::from_utf8(take(&mut index, path_len as usize)?).ok()?;
+        let entry = PackEntry {
+            offset: read_u64(&mut index)?,
+            len: read_u64(&mut index)?,
+        };
+        if entry.offset < HEADER_LEN || entry.offset.checked_add(entry.len)? > data_end {
+            return None;
+        }
+        entries.insert(path.split('/').collect(), entry);
+    }
+    index.is_empty().then_some(entries)
+}
+
+/// Reader implementation serving assets out of one or more asset packs built by a [`PackBuilder`].
+///
+/// When several packs contain the same path, the pack with the highest priority wins. Among packs
+/// with the same priority, the last one mounted wins. The `.meta` file of an asset is only read
+/// from the pack its asset is read from. Directories list the entries of all packs.
+#[derive(Clone, Default)]
+pub struct PackAssetReader {
+    /// The mounted packs, from the highest priority to the lowest.
+    packs: Vec<Arc<Pack>>,
+}
+
+impl PackAssetReader {
+    /// Creates a new [`PackAssetReader`] without any pack mounted.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Mounts the pack at `path` with the given `priority`.
+    ///
+    /// The index of the pack is read immediately; asset bytes are read on demand.
+    pub fn mount(&mut self, path: impl Into<PathBuf>, priority: i32) -> Result<(), PackError> {
+        let pack = Pack::open(path.into(), priority)?;
+        let index = self.packs.partition_point(|pack| pack.priority > priority);
+        self.packs.insert(index, Arc::new(pack));
+        Ok(())
+    }
+
+    /// Mounts the pack at `path` with the given `priority`. See [`PackAssetReader::mount`].
+    pub fn with_pack(mut self, path: impl Into<PathBuf>, priority: i32) -> Result<Self, PackError> {
+        self.mount(path, priority)?;
+        Ok(self)
+    }
+
+    /// Returns the paths of the mounted packs, from the highest priority to the lowest.
+    pub fn packs(&self) -> impl Iterator<Item = &Path> {
+        self.packs.iter().map(|pack| pack.path.as_path())
+    }
+
+    /// Creates an [`AssetSourceBuilder`] using this reader, to be registered with
+    /// [`AssetApp::register_asset_source`](crate::AssetApp::register_asset_source) or
+    /// [`AssetSourceBuilders::insert`](crate::io::AssetSourceBuilders::insert).
+    pub fn into_source_builder(self) -> AssetSourceBuilder {
+        AssetSource::build().with_reader(move || Box::new(self.clone()))
+    }
+
+    fn has_directory(&self, path: &Path) -> bool {
+        self.packs
+            .iter()
+            .any(|pack| pack.directories.contains(path))
+    }
+
+    /// Returns the pack with the highest priority containing `path`.
+    fn find_pack(&self, path: &Path) -> Result<&Pack, AssetReaderError> {
+        self.packs
+            .iter()
+            .find(|pack| pack.entries.contains_key(path))
+            .map(AsRef::as_ref)
+            .ok_or_else(|| AssetReaderError::NotFound(path.to_path_buf()))
+    }
+}
+
+impl AssetReader for PackAssetReader {
+    async fn read<'a>(&'a self, path: &'a Path) -> Result<Box<Reader<'a>>, AssetReaderError> {
+        self.find_pack(path)?.read_entry(path).await
+    }
+
+    async fn read_meta<'a>(&'a self, path: &'a Path) -> Result<Box<Reader<'a>>, AssetReaderError> {
+        // Only read the `.meta` file from the pack serving the asset, so that a patch overriding an
+        // asset without its `.meta` file doesn't get the `.meta` file of the asset it replaces.
+        self.find_pack(path)?.read_entry(&get_meta_path(path)).await
+    }
+
+    async fn read_directory<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> Result<Box<PathStream>, AssetReaderError> {
+        if !self.has_directory(path) {
+            return Err(AssetReaderError::NotFound(path.to_path_buf()));
+        }
+        let mut children = BTreeSet::new();
+        for pack in &self.packs {
+            let paths = pack.entries.keys().chain(pack.directories.iter());
+            children.extend(paths.filter(|child| {
+                child.parent() == Some(path)
+                    && !child
+                        .extension()
+                        .is_some_and(|ext| ext.eq_ignore_ascii_case("meta"))
+            }));
+        }
+        let children = children.into_iter().cloned().collect::<Vec<_>>();
+        let stream: Box<PathStream> = Box::new(futures_lite::stream::iter(children));
+        Ok(stream)
+    }
+
+    async fn is_directory<'a>(&'a self, path: &'a Path) -> Result<bool, AssetReaderError> {
+        Ok(self.has_directory(path))
+    }
+}
+
+/// Builds an asset pack, to be read by a [`PackAssetReader`].
+#[derive(Default)]
+pub struct PackBuilder {
+    entries: BTreeMap<String, Vec<u8>>,
+}
+
+impl PackBuilder {
+    /// Creates an empty [`PackBuilder`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the file `bytes` at `path`, replacing any previous file at the same path.
+    ///
+    /// The `.meta` file of an asset is added with the `.meta` extension appended to its path.
+    pub fn add(&mut self, path: impl AsRef<Path>, bytes: impl Into<Vec<u8>>) -> &mut Self {
+        let path = path
+            .as_ref()
+            .components()
+            .map(|component| component.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+        self.entries.insert(path, bytes.into());
+        self
+    }
+
+    /// Adds every file in the `root` directory and its subdirectories, with paths relative to
+    /// `root`. This includes `.meta` files, so it can pack the `imported_assets/Default` directory
+    /// written by the [`AssetProcessor`](crate::processor::AssetProcessor).
+    pub fn add_directory(&mut self, root: impl AsRef<Path>) -> std::io::Result<&mut Self> {
+        let root = root.as_ref();
+        let mut directories = vec![root.to_path_buf()];
+        while let Some(directory) = directories.pop() {
+            for entry in std::fs::read_dir(directory)? {
+                let path = entry?.path();
+                if path.is_dir() {
+                    directories.push(path);
+                } else {
+                    let bytes = std::fs::read(&path)?;
+                    self.add(path.strip_prefix(root).unwrap(), bytes);
+                }
+            }
+        }
+        Ok(self)
+    }
+
+    /// Adds every asset in the `path` directory of `reader` and its subdirectories, along with
+    /// their `.meta` files. This can pack the processed assets of an [`AssetSource`].
+    pub async fn add_from_reader(
+        &mut self,
+        reader: &dyn ErasedAssetReader,
+        path: &Path,
+    ) -> Result<&mut Self, AssetReaderError> {
+        let mut directories = vec![path.to_path_buf()];
+        while let Some(directory) = directories.pop() {
+            let mut children = reader.read_directory(&directory).await?;
+            while let Some(child) = children.next().await {
+                if reader.is_directory(&child).await? {
+                    directories.push(child);
+                    continue;
+                }
+                let mut bytes = Vec::new();
+                reader.read(&child).await?.read_to_end(&mut bytes).await?;
+                match reader.read_meta_bytes(&child).await {
+                    Ok(meta) => {
+                        self.add(get_meta_path(&child), meta);
+                    }
+                    Err(AssetReaderError::NotFound(_)) => {}
+                    Err(err) => return Err(err),
+                }
+                self.add(child, bytes);
+            }
+        }
+        Ok(self)
+    }
+
+    /// Writes the pack to `writer`.
+    pub fn write(&self, mut writer: impl Write) -> std::io::Result<()> {
+        let data_len: u64 = self.entries.values().map(|bytes| bytes.len() as u64).sum();
+        writer.write_all(&PACK_MAGIC)?;
+        writer.write_all(&PACK_VERSION.to_le_bytes())?;
+        writer.write_all(&(HEADER_LEN + data_len).to_le_bytes())?;
+        for bytes in self.entries.values() {
+            writer.write_all(bytes)?;
+        }
+
+        writer.write_all(&(self.entries.len() as u32).to_le_bytes())?;
+        let mut offset = HEADER_LEN;
+        for (path, bytes) in &self.entries {
+            writer.write_all(&(path.len() as u32).to_le_bytes())?;
+            writer.write_all(path.as_bytes())?;
+            writer.write_all(&offset.to_le_bytes())?;
+            writer.write_all(&(bytes.len() as u64).to_le_bytes())?;
+            offset += bytes.len() as u64;
+        }
+        writer.flush()
+    }
+
+    /// Writes the pack to the file at `path`, replacing it if it exists.
+    pub fn write_to_file(&self, path: impl AsRef<Path>) -> std::io::Result<()> {
+        let file = std::fs::File::create(path)?;
+        self.write(std::io::BufWriter::new(file))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_index, PackAssetReader, PackBuilder, PackError};
+    use crate::io::{memory::Dir, memory::MemoryAssetReader, AssetReader, AssetReaderError};
+    use futures_lite::{future::block_on, AsyncReadExt, StreamExt};
+    use std::path::{Path, PathBuf};
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "bevy_asset_pack_test_{name}_{}",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn read_string(reader: &PackAssetReader, path: &str) -> Result<String, AssetReaderError> {
+        block_on(async {
+            let mut reader = reader.read(Path::new(path)).await?;
+            let mut text = String::new();
+            reader.read_to_string(&mut text).await?;
+            Ok(text)
+        })
+    }
+
+    #[test]
+    fn overlay_packs() {
+        let dir = temp_dir("overlay");
+        PackBuilder::new()
+            .add("a.txt", "base a")
+            .add("a.txt.meta", "base meta")
+            .add("textures/b.txt", "base b")
+            .add("textures/c.txt", "base c")
+            .write_to_file(dir.join("base.pack"))
+            .unwrap();
+        PackBuilder::new()
+            .add("textures/b.txt", "patch b")
+            .add("sounds/d.txt", "patch d")
+            .write_to_file(dir.join("patch.pack"))
+            .unwrap();
+        PackBuilder::new()
+            .add("textures/b.txt", "mod b")
+            .write_to_file(dir.join("mod.pack"))
+            .unwrap();
+
+        let reader = PackAssetReader::new()
+            .with_pack(dir.join("mod.pack"), 1)
+            .unwrap()
+            .with_pack(dir.join("base.pack"), 0)
+            .unwrap()
+            .with_pack(dir.join("patch.pack"), 1)
+            .unwrap();
+        let packs = reader.packs().map(Path::to_path_buf).collect::<Vec<_>>();
+        assert_eq!(
+            packs,
+            [
+                dir.join("patch.pack"),
+                dir.join("mod.pack"),
+                dir.join("base.pack")
+            ]
+        );
+
+        assert_eq!(read_string(&reader, "a.txt").unwrap(), "base a");
+        assert_eq!(read_string(&reader, "textures/b.txt").unwrap(), "patch b");
+        assert_eq!(read_string(&reader, "textures/c.txt").unwrap(), "base c");
+        assert_eq!(read_string(&reader, "sounds/d.txt").unwrap(), "patch d");
+        assert!(matches!(
+            read_string(&reader, "missing.txt"),
+            Err(AssetReaderError::NotFound(_))
+        ));
+
+        let meta = block_on(reader.read_meta_bytes(Path::new("a.txt"))).unwrap();
+        assert_eq!(meta, b"base meta");
+        assert!(matches!(
+            block_on(reader.read_meta_bytes(Path::new("textures/b.txt"))),
+            Err(AssetReaderError::NotFound(_))
+        ));
+
+        let root = block_on(async {
+            let stream = reader.read_directory(Path::new("")).await.unwrap();
+            stream.collect::<Vec<_>>().await
+        });
+        assert_eq!(
+            root,
+            [
+                Path::new("a.txt"),
+                Path::new("sounds"),
+                Path::new("textures")
+            ]
+        );
+        assert!(block_on(reader.is_directory(Path::new("sounds"))).unwrap());
+        assert!(!block_on(reader.is_directory(Path::new("a.txt"))).unwrap());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn overlay_meta_comes_from_asset_pack() {
+        let dir = temp_dir("overlay_meta");
+        PackBuilder::new()
+            .add("foo.png", "base foo")
+            .add("foo.png.meta", "base foo meta")
+            .add("bar.png", "base bar")
+            .add("bar.png.meta", "base bar meta")
+            .write_to_file(dir.join("base.pack"))
+            .unwrap();
+        PackBuilder::new()
+            .add("foo.png", "patch foo")
+            .add("bar.png", "patch bar")
+            .add("bar.png.meta", "patch bar meta")
+            .write_to_file(dir.join("patch.pack"))
+            .unwrap();
+
+        let reader = PackAssetReader::new()
+            .with_pack(dir.join("base.pack"), 0)
+            .unwrap()
+            .with_pack(dir.join("patch.pack"), 1)
+            .unwrap();
+
+        assert_eq!(read_string(&reader, "foo.png").unwrap(), "patch foo");
+        assert!(matches!(
+            block_on(reader.read_meta_bytes(Path::new("foo.png"))),
+            Err(AssetReaderError::NotFound(_))
+        ));
+        assert_eq!(read_string(&reader, "bar.png").unwrap(), "patch bar");
+        let meta = block_on(reader.read_meta_bytes(Path::new("bar.png"))).unwrap();
+        assert_eq!(meta, b"patch bar meta");
+        assert!(matches!(
+            block_on(reader.read_meta_bytes(Path::new("missing.png"))),
+            Err(AssetReaderError::NotFound(_))
+        ));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn build_from_directory() {
+        let dir = temp_dir("directory");
+        let imported = dir.join("imported_assets");
+        std::fs::create_dir_all(imported.join("models")).unwrap();
+        std::fs::write(imported.join("models/cube.gltf"), "cube").unwrap();
+        std::fs::write(imported.join("models/cube.gltf.meta"), "meta").unwrap();
+
+        PackBuilder::new()
+            .add_directory(&imported)
+            .unwrap()
+            .write_to_file(dir.join("assets.pack"))
+            .unwrap();
+        let reader = PackAssetReader::new()
+            .with_pack(dir.join("assets.pack"), 0)
+            .unwrap();
+
+        assert_eq!(read_string(&reader, "models/cube.gltf").unwrap(), "cube");
+        let meta = block_on(reader.read_meta_bytes(Path::new("models/cube.gltf"))).unwrap();
+        assert_eq!(meta, b"meta");
+
+        std::fs::write(dir.join("invalid.pack"), "not a pack at all").unwrap();
+        assert!(matches!(
+            PackAssetReader::new().mount(dir.join("invalid.pack"), 0),
+            Err(PackError::InvalidMagic)
+        ));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn corrupted_index() {
+        // An entry count larger than the index can hold must not be used to pre-allocate.
+        let mut index = u32::MAX.to_le_bytes().to_vec();
+        index.extend_from_slice(&[0; 20]);
+        assert!(parse_index(&index, u64::MAX).is_none());
+    }
+
+    #[test]
+    fn build_from_reader() {
+        let dir = Dir::default();
+        dir.insert_asset_text(Path::new("a.txt"), "a");
+        dir.insert_meta_text(Path::new("a.txt"), "a meta");
+        dir.insert_asset_text(Path::new("nested/b.txt"), "b");
+        let source = MemoryAssetReader { root: dir };
+
+        let mut bytes = Vec::new();
+        let mut builder = PackBuilder::new();
+        block_on(builder.add_from_reader(&source, Path::new(""))).unwrap();
+        builder.write(&mut bytes).unwrap();
+
+        let pack_dir = temp_dir("reader");
+        std::fs::write(pack_dir.join("assets.pack"), bytes).unwrap();
+        let reader = PackAssetReader::new()
+            .with_pack(pack_dir.join("assets.pack"), 0)
+            .unwrap();
+        assert_eq!(read_string(&reader, "a.txt").unwrap(), "a");
+        assert_eq!(read_string(&reader, "nested/b.txt").unwrap(), "b");
+        let meta = block_on(reader.read_meta_bytes(Path::new("a.txt"))).unwrap();
+        assert_eq!(meta, b"a meta");
+
+        std::fs::remove_dir_all(&pack_dir).unwrap();
+    }
+}
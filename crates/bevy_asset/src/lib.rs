@@ -17,6 +17,7 @@ pub mod prelude {
 }
 
 mod assets;
+mod collection;
 mod direct_access_ext;
 mod event;
 mod folder;
@@ -28,7 +29,8 @@ mod reflect;
 mod server;
 
 pub use assets::*;
-pub use bevy_asset_macros::Asset;
+pub use bevy_asset_macros::{Asset, AssetCollection};
+pub use collection::*;
 pub use direct_access_ext::DirectAssetAccessExt;
 pub use event::*;
 pub use folder::*;
@@ -47,14 +49,23 @@ use crate::{
     io::{embedded::EmbeddedAssetRegistry, AssetSourceBuilder, AssetSourceBuilders, AssetSourceId},
     processor::{AssetProcessor, Process},
 };
-use bevy_app::{App, Last, Plugin, PreUpdate};
+use bevy_app::{App, Last, Plugin, PreStartup, PreUpdate};
+#[cfg(feature = "bevy_state")]
+use bevy_ecs::schedule::Condition;
 use bevy_ecs::{
     reflect::AppTypeRegistry,
-    schedule::{IntoSystemConfigs, IntoSystemSetConfigs, SystemSet},
-    system::Resource,
+    schedule::{
+        common_conditions::resource_exists, IntoSystemConfigs, IntoSystemSetConfigs, SystemSet,
+    },
+    system::{IntoSystem, Resource},
     world::FromWorld,
 };
 use bevy_reflect::{FromReflect, GetTypeRegistration, Reflect, TypePath};
+#[cfg(feature = "bevy_state")]
+use bevy_state::{
+    condition::in_state,
+    state::{FreelyMutableState, OnEnter},
+};
 use bevy_utils::{tracing::error, HashSet};
 use std::{any::TypeId, sync::Arc};
 
@@ -310,6 +321,27 @@ pub trait AssetApp {
     /// Preregisters a loader for the given extensions, that will block asset loads until a real loader
     /// is registered.
     fn preregister_asset_loader<L: AssetLoader>(&mut self, extensions: &[&str]) -> &mut Self;
+    /// Starts loading the given [`AssetCollection`] at startup and inserts it as a resource once all of
+    /// its assets and their dependencies are loaded.
+    ///
+    /// The collection is held in a [`LoadingAssetCollection`] resource while it loads. If one of its
+    /// assets fails to load, an [`AssetCollectionLoadFailedEvent`] is sent and the collection is never
+    /// inserted.
+    fn load_asset_collection<C: AssetCollection>(&mut self) -> &mut Self;
+    /// Starts loading the given [`AssetCollection`] each time the `loading` state is entered, like
+    /// [`AssetApp::load_asset_collection`].
+    ///
+    /// Once all of its assets and their dependencies are loaded, the collection is inserted as a
+    /// resource and the state is set to `next`. If one of its assets fails to load, an
+    /// [`AssetCollectionLoadFailedEvent`] is sent and the state is set to `failure` instead.
+    /// The collection is only tracked while in the `loading` state.
+    #[cfg(feature = "bevy_state")]
+    fn load_asset_collection_in_state<C: AssetCollection, S: FreelyMutableState>(
+        &mut self,
+        loading: S,
+        next: S,
+        failure: S,
+    ) -> &mut Self;
 }
 
 impl AssetApp for App {
@@ -409,6 +441,41 @@ impl AssetApp for App {
             .preregister_loader::<L>(extensions);
         self
     }
+
+    fn load_asset_collection<C: AssetCollection>(&mut self) -> &mut Self {
+        self.add_event::<AssetCollectionLoadFailedEvent<C>>()
+            .add_systems(PreStartup, collection::start_loading_asset_collection::<C>)
+            .add_systems(
+                PreUpdate,
+                collection::track_asset_collection::<C>
+                    .map(drop)
+                    .after(handle_internal_asset_events)
+                    .run_if(resource_exists::<LoadingAssetCollection<C>>),
+            )
+    }
+
+    #[cfg(feature = "bevy_state")]
+    fn load_asset_collection_in_state<C: AssetCollection, S: FreelyMutableState>(
+        &mut self,
+        loading: S,
+        next: S,
+        failure: S,
+    ) -> &mut Self {
+        self.add_event::<AssetCollectionLoadFailedEvent<C>>()
+            .add_systems(
+                OnEnter(loading.clone()),
+                collection::start_loading_asset_collection::<C>,
+            )
+            .add_systems(
+                PreUpdate,
+                collection::track_asset_collection::<C>
+                    .pipe(collection::leave_loading_state(next, failure))
+                    .after(handle_internal_asset_events)
+                    .run_if(
+                        in_state(loading).and_then(resource_exists::<LoadingAssetCollection<C>>),
+                    ),
+            )
+    }
 }
 
 /// A system set that holds all "track asset" operations.
@@ -433,9 +500,10 @@ mod tests {
             AssetReader, AssetReaderError, AssetSource, AssetSourceId, Reader,
         },
         loader::{AssetLoader, LoadContext},
-        Asset, AssetApp, AssetEvent, AssetId, AssetLoadError, AssetLoadFailedEvent, AssetPath,
-        AssetPlugin, AssetServer, Assets, DependencyLoadState, LoadState,
-        RecursiveDependencyLoadState,
+        Asset, AssetApp, AssetCollection, AssetCollectionLoadFailedEvent, AssetDependencyGraph,
+        AssetEvent, AssetId, AssetLoadError, AssetLoadFailedEvent, AssetPath, AssetPlugin,
+        AssetServer, Assets, DependencyLoadState, LoadState, LoadingAssetCollection,
+        RecursiveDependencyLoadState, UntypedAssetId, UntypedAssetLoadFailedEvent,
     };
     use bevy_app::{App, Update};
     use bevy_core::TaskPoolPlugin;
@@ -446,10 +514,18 @@ mod tests {
     };
     use bevy_log::LogPlugin;
     use bevy_reflect::TypePath;
+    #[cfg(feature = "bevy_state")]
+    use bevy_state::prelude::*;
     use bevy_utils::{Duration, HashMap};
     use futures_lite::AsyncReadExt;
     use serde::{Deserialize, Serialize};
-    use std::{path::Path, sync::Arc};
+    use std::{
+        path::Path,
+        sync::{
+            atomic::{AtomicBool, Ordering},
+            Arc,
+        },
+    };
     use thiserror::Error;
 
     #[derive(Asset, TypePath, Debug, Default)]
@@ -1134,6 +1210,453 @@ mod tests {
         );
     }
 
+    #[test]
+    fn load_priority_and_cancellation() {
+        #[derive(Resource, Default)]
+        struct CancelledLoads(Vec<UntypedAssetId>);
+
+        fn store_cancelled_loads(
+            mut failures: EventReader<UntypedAssetLoadFailedEvent>,
+            mut cancelled: ResMut<CancelledLoads>,
+        ) {
+            for failure in failures.read() {
+                if let AssetLoadError::Cancelled { .. } = failure.error {
+                    cancelled.0.push(failure.id);
+                }
+            }
+        }
+
+        let dir = Dir::default();
+        for name in ["a", "b", "c", "d"] {
+            dir.insert_asset_text(
+                Path::new(&format!("{name}.cool.ron")),
+                &SIMPLE_TEXT.replace("\"dep\"", &format!("\"{name}\"")),
+            );
+        }
+
+        let (mut app, gate_opener) = test_app(dir);
+        app.init_asset::<CoolText>()
+            .init_asset::<SubText>()
+            .init_resource::<StoredEvents>()
+            .init_resource::<CancelledLoads>()
+            .register_asset_loader(CoolTextLoader)
+            .add_systems(Update, (store_asset_events, store_cancelled_loads));
+        for name in ["a", "b", "c"] {
+            gate_opener.open(format!("{name}.cool.ron"));
+        }
+
+        let asset_server = app.world.resource::<AssetServer>().clone();
+        // Pause the loads so that they all wait in the queue.
+        asset_server.set_max_concurrent_loads(AssetSourceId::Default, Some(0));
+        let a: Handle<CoolText> = asset_server.load("a.cool.ron");
+        let b: Handle<CoolText> = asset_server.load_with_priority("b.cool.ron", 5);
+        let c: Handle<CoolText> = asset_server.load("c.cool.ron");
+        let d: Handle<CoolText> = asset_server.load("d.cool.ron");
+        // Requesting `c` again with a higher priority moves it ahead of `b`.
+        let _c = asset_server.load_with_priority::<CoolText>("c.cool.ron", 10);
+        // Dropping all handles to `d` cancels its load.
+        let d_id = d.id();
+        drop(d);
+
+        app.update();
+        assert_eq!(asset_server.pending_loads(AssetSourceId::Default), 4);
+        assert_eq!(asset_server.load_state(a.id()), LoadState::Loading);
+
+        asset_server.set_max_concurrent_loads(AssetSourceId::Default, Some(1));
+        run_app_until(&mut app, |world| {
+            get::<CoolText>(world, a.id())?;
+            Some(())
+        });
+        app.update();
+
+        let loaded = app
+            .world
+            .resource::<StoredEvents>()
+            .0
+            .iter()
+            .filter_map(|event| match event {
+                AssetEvent::Added { id } => Some(*id),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(loaded, [c.id(), b.id(), a.id()]);
+        assert_eq!(asset_server.pending_loads(AssetSourceId::Default), 0);
+        // The asset of `d` is removed once its last handle, held by the cancelled load, is dropped.
+        run_app_until(&mut app, |_| {
+            asset_server.get_load_state(d_id).is_none().then_some(())
+        });
+        assert!(get::<CoolText>(&app.world, d_id).is_none());
+        assert_eq!(app.world.resource::<CancelledLoads>().0, [d_id.untyped()]);
+    }
+
+    #[test]
+    fn load_cancelled_asset_again() {
+        let dir = Dir::default();
+        dir.insert_asset_text(Path::new("a.cool.ron"), SIMPLE_TEXT);
+
+        let (mut app, gate_opener) = test_app(dir);
+        app.init_asset::<CoolText>()
+            .init_asset::<SubText>()
+            .register_asset_loader(CoolTextLoader);
+
+        let asset_server = app.world.resource::<AssetServer>().clone();
+        asset_server.set_max_concurrent_loads(AssetSourceId::Default, Some(0));
+        let a: Handle<CoolText> = asset_server.load("a.cool.ron");
+        let a_id = a.id();
+        drop(a);
+
+        // Let the queued load start, and wait for it to be cancelled without processing its failure and handle drop.
+        asset_server.set_max_concurrent_loads(AssetSourceId::Default, Some(1));
+        for _ in 0..LARGE_ITERATION_COUNT {
+            bevy_tasks::tick_global_task_pools_on_main_thread();
+            if asset_server.load_state(a_id) == LoadState::Failed {
+                break;
+            }
+            std::thread::yield_now();
+        }
+        assert_eq!(asset_server.load_state(a_id), LoadState::Failed);
+
+        // Requesting the asset again reuses its id and starts a new load.
+        gate_opener.open("a.cool.ron");
+        let a: Handle<CoolText> = asset_server.load("a.cool.ron");
+        assert_eq!(a.id(), a_id);
+        assert_eq!(asset_server.load_state(a_id), LoadState::Loading);
+
+        run_app_until(&mut app, |world| {
+            get::<CoolText>(world, a_id)?;
+            Some(())
+        });
+        // The failure of the cancelled load and the drop of its handle leave the new load untouched.
+        app.update();
+        assert_eq!(asset_server.load_state(a_id), LoadState::Loaded);
+        assert_eq!(get::<CoolText>(&app.world, a_id).unwrap().text, "dep");
+    }
+
+    #[test]
+    fn load_base_of_cancelled_labeled_asset() {
+        /// Loads a [`CoolText`] with a `sub` labeled asset, waiting for `gate` to be unlocked first.
+        struct GatedSubTextLoader {
+            started: Arc<AtomicBool>,
+            gate: Arc<async_lock::Mutex<()>>,
+        }
+
+        impl AssetLoader for GatedSubTextLoader {
+            type Asset = CoolText;
+            type Settings = ();
+            type Error = std::io::Error;
+
+            async fn load<'a>(
+                &'a self,
+                _reader: &'a mut Reader<'_>,
+                _settings: &'a Self::Settings,
+                load_context: &'a mut LoadContext<'_>,
+            ) -> Result<Self::Asset, Self::Error> {
+                self.started.store(true, Ordering::Release);
+                drop(self.gate.lock().await);
+                let sub_text = SubText {
+                    text: "sub".to_owned(),
+                };
+                Ok(CoolText {
+                    text: "base".to_owned(),
+                    embedded: String::new(),
+                    dependencies: Vec::new(),
+                    sub_texts: vec![load_context.add_labeled_asset("sub".to_owned(), sub_text)],
+                })
+            }
+
+            fn extensions(&self) -> &[&str] {
+                &["gated.ron"]
+            }
+        }
+
+        let dir = Dir::default();
+        dir.insert_asset_text(Path::new("a.gated.ron"), "");
+
+        let (mut app, gate_opener) = test_app(dir);
+        let started = Arc::new(AtomicBool::new(false));
+        let gate = Arc::new(async_lock::Mutex::new(()));
+        app.init_asset::<CoolText>()
+            .init_asset::<SubText>()
+            .register_asset_loader(GatedSubTextLoader {
+                started: started.clone(),
+                gate: gate.clone(),
+            });
+        gate_opener.open("a.gated.ron");
+        gate_opener.open("a.gated.ron");
+
+        let asset_server = app.world.resource::<AssetServer>().clone();
+        let guard = gate.try_lock().unwrap();
+        let sub: Handle<SubText> = asset_server.load("a.gated.ron#sub");
+        let sub_id = sub.id();
+        for _ in 0..LARGE_ITERATION_COUNT {
+            if started.load(Ordering::Acquire) {
+                break;
+            }
+            std::thread::yield_now();
+        }
+        assert!(started.load(Ordering::Acquire));
+
+        // Dropping the labeled handle while its loader runs cancels the load once it resumes.
+        drop(sub);
+        drop(guard);
+        // The labeled asset is marked as failed, then freed along with its last handle.
+        run_app_until(&mut app, |_| {
+            (asset_server.load_state(sub_id) != LoadState::Loading).then_some(())
+        });
+
+        // The base asset isn't left loading, so requesting it starts a new load.
+        let base: Handle<CoolText> = asset_server.load("a.gated.ron");
+        run_app_until(&mut app, |_| {
+            (asset_server.load_state(&base) == LoadState::Loaded).then_some(())
+        });
+        assert_eq!(get::<CoolText>(&app.world, base.id()).unwrap().text, "base");
+    }
+
+    #[derive(Resource, AssetCollection)]
+    struct CoolTexts {
+        #[asset(path = "a.cool.ron")]
+        a: Handle<CoolText>,
+        #[asset(path = "c.cool.ron")]
+        c: Handle<CoolText>,
+        count: usize,
+    }
+
+    #[derive(Resource, AssetCollection)]
+    struct MissingCoolTexts {
+        #[asset(path = "a.cool.ron")]
+        a: Handle<CoolText>,
+        #[asset(path = "missing.cool.ron")]
+        missing: Handle<CoolText>,
+    }
+
+    fn asset_collection_dir() -> Dir {
+        let dir = Dir::default();
+        let a_ron = r#"
+(
+    text: "a",
+    dependencies: ["b.cool.ron"],
+    embedded_dependencies: [],
+    sub_texts: [],
+)"#;
+        dir.insert_asset_text(Path::new("a.cool.ron"), a_ron);
+        for name in ["b", "c"] {
+            dir.insert_asset_text(
+                Path::new(&format!("{name}.cool.ron")),
+                &SIMPLE_TEXT.replace("\"dep\"", &format!("\"{name}\"")),
+            );
+        }
+        dir
+    }
+
+    #[test]
+    fn load_asset_collection() {
+        #[derive(Resource, Default)]
+        struct FailedAssetCounts(Vec<usize>);
+
+        fn store_failed_asset_counts(
+            mut failures: EventReader<AssetCollectionLoadFailedEvent<MissingCoolTexts>>,
+            mut counts: ResMut<FailedAssetCounts>,
+        ) {
+            counts
+                .0
+                .extend(failures.read().map(|failure| failure.failed.len()));
+        }
+
+        let (mut app, gate_opener) = test_app(asset_collection_dir());
+        for name in ["a", "b", "c", "missing"] {
+            gate_opener.open(format!("{name}.cool.ron"));
+        }
+        app.init_asset::<CoolText>()
+            .init_asset::<SubText>()
+            .register_asset_loader(CoolTextLoader)
+            .load_asset_collection::<CoolTexts>()
+            .load_asset_collection::<MissingCoolTexts>()
+            .init_resource::<FailedAssetCounts>()
+            .add_systems(Update, store_failed_asset_counts);
+
+        run_app_until(&mut app, |world| {
+            world.get_resource::<CoolTexts>()?;
+            Some(())
+        });
+        assert!(!app
+            .world
+            .contains_resource::<LoadingAssetCollection<CoolTexts>>());
+        let collection = app.world.resource::<CoolTexts>();
+        assert_eq!(collection.count, 0);
+        let asset_server = app.world.resource::<AssetServer>();
+        assert!(asset_server.is_loaded_with_dependencies(&collection.a));
+        assert_eq!(
+            get::<CoolText>(&app.world, collection.a.id()).unwrap().text,
+            "a"
+        );
+        assert_eq!(
+            get::<CoolText>(&app.world, collection.c.id()).unwrap().text,
+            "c"
+        );
+
+        // A collection with an asset that fails to load is never inserted.
+        run_app_until(&mut app, |world| {
+            (!world.contains_resource::<LoadingAssetCollection<MissingCoolTexts>>()).then_some(())
+        });
+        assert!(!app.world.contains_resource::<MissingCoolTexts>());
+        // The failure may have been sent while waiting for the other collection, so it's recorded
+        // by a system running every update rather than read at the end.
+        assert_eq!(app.world.resource::<FailedAssetCounts>().0, [1]);
+    }
+
+    #[cfg(feature = "bevy_state")]
+    #[test]
+    fn load_asset_collection_in_state() {
+        #[derive(States, Default, Debug, Clone, PartialEq, Eq, Hash)]
+        enum LoadingState {
+            #[default]
+            Loading,
+            Ready,
+            Failed,
+        }
+
+        fn loading_app<C: AssetCollection>() -> App {
+            let (mut app, gate_opener) = test_app(asset_collection_dir());
+            for name in ["a", "b", "c", "missing"] {
+                gate_opener.open(format!("{name}.cool.ron"));
+            }
+            app.add_plugins(StatesPlugin)
+                .init_state::<LoadingState>()
+                .init_asset::<CoolText>()
+                .init_asset::<SubText>()
+                .register_asset_loader(CoolTextLoader)
+                .load_asset_collection_in_state::<C, _>(
+                    LoadingState::Loading,
+                    LoadingState::Ready,
+                    LoadingState::Failed,
+                );
+            app
+        }
+
+        fn run_until_state(app: &mut App, expected: LoadingState) {
+            run_app_until(app, |world| {
+                (*world.resource::<State<LoadingState>>().get() == expected).then_some(())
+            });
+        }
+
+        let mut app = loading_app::<CoolTexts>();
+        run_until_state(&mut app, LoadingState::Ready);
+        let collection = app.world.resource::<CoolTexts>();
+        assert!(app
+            .world
+            .resource::<AssetServer>()
+            .is_loaded_with_dependencies(&collection.a));
+
+        // Entering the loading state again loads the collection again.
+        app.world
+            .resource_mut::<NextState<LoadingState>>()
+            .set(LoadingState::Loading);
+        app.update();
+        assert!(app
+            .world
+            .contains_resource::<LoadingAssetCollection<CoolTexts>>());
+        run_until_state(&mut app, LoadingState::Ready);
+        assert!(app.world.contains_resource::<CoolTexts>());
+
+        let mut app = loading_app::<MissingCoolTexts>();
+        run_until_state(&mut app, LoadingState::Failed);
+        assert!(!app.world.contains_resource::<MissingCoolTexts>());
+        assert!(!app
+            .world
+            .contains_resource::<LoadingAssetCollection<MissingCoolTexts>>());
+    }
+
+    #[test]
+    fn dependency_graph() {
+        let dir = Dir::default();
+        for (name, dependencies) in [
+            ("a", r#""b.cool.ron", "c.cool.ron""#),
+            ("b", ""),
+            ("c", r#""d.cool.ron""#),
+            ("d", ""),
+        ] {
+            let ron = format!(
+                "(text: \"{name}\", dependencies: [{dependencies}], embedded_dependencies: [], sub_texts: [])"
+            );
+            dir.insert_asset_text(Path::new(&format!("{name}.cool.ron")), &ron);
+        }
+
+        let (mut app, gate_opener) = test_app(dir);
+        for name in ["a", "b", "c", "d"] {
+            gate_opener.open(format!("{name}.cool.ron"));
+        }
+        app.init_asset::<CoolText>()
+            .init_asset::<SubText>()
+            .register_asset_loader(CoolTextLoader);
+        let asset_server = app.world.resource::<AssetServer>().clone();
+        let a: Handle<CoolText> = asset_server.load("a.cool.ron");
+        run_app_until(&mut app, |_| {
+            asset_server.is_loaded_with_dependencies(&a).then_some(())
+        });
+
+        let id = |path: &'static str| {
+            asset_server
+                .get_handle_untyped(path)
+                .map(|handle| handle.id())
+                .unwrap()
+        };
+        let (b, c, d) = (id("b.cool.ron"), id("c.cool.ron"), id("d.cool.ron"));
+        let a_handle = a;
+        let a = a_handle.id().untyped();
+        let sorted = |mut ids: Vec<UntypedAssetId>| {
+            ids.sort_by_key(ToString::to_string);
+            ids
+        };
+
+        assert_eq!(sorted(asset_server.dependencies(a)), sorted(vec![b, c]));
+        assert_eq!(
+            sorted(asset_server.recursive_dependencies(a)),
+            sorted(vec![b, c, d])
+        );
+        assert_eq!(asset_server.recursive_dependencies(c), [d]);
+        assert!(asset_server.dependencies(d).is_empty());
+        assert_eq!(asset_server.dependants(d), [c]);
+        assert_eq!(asset_server.recursive_dependants(d), [c, a]);
+        assert!(asset_server.recursive_dependants(a).is_empty());
+
+        let graph = asset_server.dependency_graph();
+        assert_eq!(graph.len(), 4);
+        let node = graph.get(c).unwrap();
+        assert_eq!(node.path, Some(AssetPath::from("c.cool.ron")));
+        assert_eq!(node.dependants, [a]);
+        assert!(node.loading_dependencies.is_empty());
+        assert!(node.is_loaded_with_dependencies());
+
+        let dot = graph.to_dot();
+        assert!(dot.starts_with("digraph assets {"));
+        assert!(dot.contains(&format!("\"{a}\" [label=\"a.cool.ron\", color=green];")));
+        assert!(dot.contains(&format!("\"{c}\" -> \"{d}\";")));
+        let serialized = ron::to_string(&graph).unwrap();
+        assert!(serialized.starts_with("(assets:[(id:"));
+        assert!(serialized.contains(&format!(
+            "path:Some(\"d.cool.ron\"),load_state:Loaded,dep_load_state:Loaded,rec_dep_load_state:Loaded,dependencies:[],loading_dependencies:[],failed_dependencies:[],dependants:[\"{c}\"]"
+        )));
+
+        // Loader dependencies point to the assets loaded from their path, if any.
+        let mut nodes = graph
+            .nodes()
+            .map(|node| (node.id, node.clone()))
+            .collect::<HashMap<_, _>>();
+        nodes.get_mut(&a).unwrap().loader_dependencies =
+            vec!["d.cool.ron".into(), "shader.wgsl".into()];
+        let dot = AssetDependencyGraph::new(nodes).to_dot();
+        assert!(dot.contains(&format!("\"{a}\" -> \"{d}\" [style=dashed];")));
+        assert!(dot.contains(&format!("\"{a}\" -> \"file:shader.wgsl\" [style=dashed];")));
+        assert!(dot.contains("\"file:shader.wgsl\" [label=\"shader.wgsl\", shape=note];"));
+
+        // Dropping `a` frees its dependencies, which are removed from their dependants.
+        drop(a_handle);
+        run_app_until(&mut app, |_| {
+            asset_server.dependants(d).is_empty().then_some(())
+        });
+        assert!(asset_server.dependency_graph().is_empty());
+    }
+
     #[test]
     fn manual_asset_management() {
         // The particular usage of GatedReader in this test will cause deadlocking if running single-threaded
@@ -0,0 +1,237 @@
+use crate::{
+    AssetPath, DependencyLoadState, LoadState, RecursiveDependencyLoadState, UntypedAssetId,
+};
+use bevy_utils::{HashMap, HashSet};
+use serde::{Serialize, Serializer};
+use std::{collections::VecDeque, fmt::Write};
+
+/// The state of an asset tracked by the [`AssetServer`](crate::AssetServer), and its edges in the asset
+/// dependency graph.
+///
+/// Asset ids are serialized as strings.
+#[derive(Debug, Clone, Serialize)]
+pub struct AssetDependencyNode {
+    /// The id of the asset.
+    #[serde(serialize_with = "serialize_id")]
+    pub id: UntypedAssetId,
+    /// The path of the asset, if it was loaded from one.
+    pub path: Option<AssetPath<'static>>,
+    /// The [`LoadState`] of the asset.
+    pub load_state: LoadState,
+    /// The [`DependencyLoadState`] of the asset.
+    pub dep_load_state: DependencyLoadState,
+    /// The [`RecursiveDependencyLoadState`] of the asset.
+    pub rec_dep_load_state: RecursiveDependencyLoadState,
+    /// The direct dependencies of the asset. This is empty until the asset is loaded.
+    #[serde(serialize_with = "serialize_ids")]
+    pub dependencies: Vec<UntypedAssetId>,
+    /// The direct dependencies of the asset that have not finished loading yet.
+    #[serde(serialize_with = "serialize_ids")]
+    pub loading_dependencies: Vec<UntypedAssetId>,
+    /// The direct dependencies of the asset that failed to load.
+    #[serde(serialize_with = "serialize_ids")]
+    pub failed_dependencies: Vec<UntypedAssetId>,
+    /// The assets that have this asset as a direct dependency.
+    #[serde(serialize_with = "serialize_ids")]
+    pub dependants: Vec<UntypedAssetId>,
+    /// The paths of the assets used by the loader of this asset. This is only tracked when the
+    /// [`AssetServer`](crate::AssetServer) is watching for changes.
+    pub loader_dependencies: Vec<AssetPath<'static>>,
+}
+
+impl AssetDependencyNode {
+    /// Returns the path of the asset, or its id if it has no path.
+    pub fn label(&self) -> String {
+        match &self.path {
+            Some(path) => path.to_string(),
+            None => self.id.to_string(),
+        }
+    }
+
+    /// Returns `true` if the asset and all of its dependencies (recursive) have been loaded.
+    pub fn is_loaded_with_dependencies(&self) -> bool {
+        self.load_state == LoadState::Loaded
+            && self.rec_dep_load_state == RecursiveDependencyLoadState::Loaded
+    }
+
+    fn is_failed(&self) -> bool {
+        self.load_state == LoadState::Failed
+            || self.rec_dep_load_state == RecursiveDependencyLoadState::Failed
+    }
+}
+
+/// A snapshot of every asset tracked by the [`AssetServer`](crate::AssetServer) and of their dependencies,
+/// returned by [`AssetServer::dependency_graph`](crate::AssetServer::dependency_graph).
+///
+/// The graph can be exported with [`AssetDependencyGraph::to_dot`] to be rendered by Graphviz, or
+/// serialized with `serde` to be inspected by other tools. It is serialized as an `assets` list of
+/// [`AssetDependencyNode`]s, sorted by label.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct AssetDependencyGraph {
+    #[serde(rename = "assets", serialize_with = "serialize_nodes")]
+    nodes: HashMap<UntypedAssetId, AssetDependencyNode>,
+}
+
+impl AssetDependencyGraph {
+    pub(crate) fn new(nodes: HashMap<UntypedAssetId, AssetDependencyNode>) -> Self {
+        Self { nodes }
+    }
+
+    /// Returns the node of the given asset, if it is tracked by the [`AssetServer`](crate::AssetServer).
+    pub fn get(&self, id: impl Into<UntypedAssetId>) -> Option<&AssetDependencyNode> {
+        self.nodes.get(&id.into())
+    }
+
+    /// Iterates over the nodes of the graph, in no particular order.
+    pub fn nodes(&self) -> impl Iterator<Item = &AssetDependencyNode> {
+        self.nodes.values()
+    }
+
+    /// Returns the number of assets in the graph.
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns `true` if the graph contains no assets.
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Returns the dependencies of the given asset and all of their dependencies, closest first.
+    pub fn recursive_dependencies(&self, id: impl Into<UntypedAssetId>) -> Vec<UntypedAssetId> {
+        walk_graph(id.into(), |id| {
+            self.nodes
+                .get(&id)
+                .map(|node| node.dependencies.clone())
+                .unwrap_or_default()
+        })
+    }
+
+    /// Returns the dependants of the given asset and all of their dependants, closest first.
+    pub fn recursive_dependants(&self, id: impl Into<UntypedAssetId>) -> Vec<UntypedAssetId> {
+        walk_graph(id.into(), |id| {
+            self.nodes
+                .get(&id)
+                .map(|node| node.dependants.clone())
+                .unwrap_or_default()
+        })
+    }
+
+    /// Exports the graph in the Graphviz DOT format.
+    ///
+    /// Assets are colored by their recursive load state: green once they are loaded with their
+    /// dependencies, red if they or one of their dependencies failed to load, and orange otherwise.
+    /// Loader dependencies are drawn with dashed edges, to the assets loaded from their path. Loader
+    /// dependencies that aren't tracked as assets are drawn as separate `note` nodes.
+    pub fn to_dot(&self) -> String {
+        let mut path_ids = HashMap::<String, Vec<UntypedAssetId>>::new();
+        for node in self.nodes.values() {
+            if let Some(path) = &node.path {
+                path_ids.entry(path.to_string()).or_default().push(node.id);
+            }
+        }
+        let mut files = Vec::new();
+
+        let mut dot = String::from("digraph assets {\n");
+        for node in sorted_nodes(&self.nodes) {
+            let color = if node.is_failed() {
+                "red"
+            } else if node.is_loaded_with_dependencies() {
+                "green"
+            } else {
+                "orange"
+            };
+            let _ = writeln!(
+                dot,
+                "    \"{}\" [label=\"{}\", color={}];",
+                escape_dot(&node.id.to_string()),
+                escape_dot(&node.label()),
+                color
+            );
+            for dependency in &node.dependencies {
+                let _ = writeln!(
+                    dot,
+                    "    \"{}\" -> \"{}\";",
+                    escape_dot(&node.id.to_string()),
+                    escape_dot(&dependency.to_string())
+                );
+            }
+            for loader_dependency in &node.loader_dependencies {
+                let path = loader_dependency.to_string();
+                let targets = match path_ids.get(&path) {
+                    Some(ids) => ids.iter().map(ToString::to_string).collect(),
+                    None => {
+                        files.push(path.clone());
+                        vec![format!("file:{path}")]
+                    }
+                };
+                for target in targets {
+                    let _ = writeln!(
+                        dot,
+                        "    \"{}\" -> \"{}\" [style=dashed];",
+                        escape_dot(&node.id.to_string()),
+                        escape_dot(&target)
+                    );
+                }
+            }
+        }
+        files.sort();
+        files.dedup();
+        for path in files {
+            let _ = writeln!(
+                dot,
+                "    \"{}\" [label=\"{}\", shape=note];",
+                escape_dot(&format!("file:{path}")),
+                escape_dot(&path)
+            );
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+/// Walks the graph from `start` breadth-first, returning every reachable id except `start`.
+pub(crate) fn walk_graph(
+    start: UntypedAssetId,
+    mut next: impl FnMut(UntypedAssetId) -> Vec<UntypedAssetId>,
+) -> Vec<UntypedAssetId> {
+    let mut visited = HashSet::new();
+    visited.insert(start);
+    let mut queue = VecDeque::from([start]);
+    let mut reached = Vec::new();
+    while let Some(id) = queue.pop_front() {
+        for next_id in next(id) {
+            if visited.insert(next_id) {
+                reached.push(next_id);
+                queue.push_back(next_id);
+            }
+        }
+    }
+    reached
+}
+
+fn escape_dot(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Returns the nodes sorted by label, to export them in a stable order.
+fn sorted_nodes(nodes: &HashMap<UntypedAssetId, AssetDependencyNode>) -> Vec<&AssetDependencyNode> {
+    let mut nodes = nodes.values().collect::<Vec<_>>();
+    nodes.sort_by_cached_key(|node| (node.label(), node.id.to_string()));
+    nodes
+}
+
+fn serialize_nodes<S: Serializer>(
+    nodes: &HashMap<UntypedAssetId, AssetDependencyNode>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_seq(sorted_nodes(nodes))
+}
+
+fn serialize_id<S: Serializer>(id: &UntypedAssetId, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(id)
+}
+
+fn serialize_ids<S: Serializer>(ids: &[UntypedAssetId], serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_seq(ids.iter().map(ToString::to_string))
+}
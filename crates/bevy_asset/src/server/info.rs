@@ -1,5 +1,6 @@
 use crate::{
     meta::{AssetHash, MetaTransform},
+    server::{AssetDependencyGraph, AssetDependencyNode},
     Asset, AssetHandleProvider, AssetLoadError, AssetPath, DependencyLoadState, ErasedLoadedAsset,
     Handle, InternalAssetEvent, LoadState, RecursiveDependencyLoadState, StrongHandle,
     UntypedAssetId, UntypedHandle,
@@ -21,6 +22,8 @@ pub(crate) struct AssetInfo {
     pub(crate) load_state: LoadState,
     pub(crate) dep_load_state: DependencyLoadState,
     pub(crate) rec_dep_load_state: RecursiveDependencyLoadState,
+    /// The direct dependencies of this asset, set once it is loaded.
+    dependencies: HashSet<UntypedAssetId>,
     loading_dependencies: HashSet<UntypedAssetId>,
     failed_dependencies: HashSet<UntypedAssetId>,
     loading_rec_dependencies: HashSet<UntypedAssetId>,
@@ -47,6 +50,7 @@ impl AssetInfo {
             load_state: LoadState::NotLoaded,
             dep_load_state: DependencyLoadState::NotLoaded,
             rec_dep_load_state: RecursiveDependencyLoadState::NotLoaded,
+            dependencies: HashSet::default(),
             loading_dependencies: HashSet::default(),
             failed_dependencies: HashSet::default(),
             loading_rec_dependencies: HashSet::default(),
@@ -63,6 +67,8 @@ impl AssetInfo {
 pub(crate) struct AssetInfos {
     path_to_id: HashMap<AssetPath<'static>, TypeIdMap<UntypedAssetId>>,
     infos: HashMap<UntypedAssetId, AssetInfo>,
+    /// Tracks the assets that have the "key" asset as a direct dependency, the reverse of [`AssetInfo::dependencies`].
+    dependants: HashMap<UntypedAssetId, HashSet<UntypedAssetId>>,
     /// If set to `true`, this informs [`AssetInfos`] to track data relevant to watching for changes (such as `load_dependants`)
     /// This should only be set at startup.
     pub(crate) watching_for_changes: bool,
@@ -333,6 +339,46 @@ impl AssetInfos {
         Some(UntypedHandle::Strong(strong_handle))
     }
 
+    /// Returns the direct dependencies of the given asset.
+    pub(crate) fn dependencies(&self, id: UntypedAssetId) -> Vec<UntypedAssetId> {
+        self.infos
+            .get(&id)
+            .map(|info| info.dependencies.iter().copied().collect())
+            .unwrap_or_default()
+    }
+
+    /// Returns the assets that have the given asset as a direct dependency.
+    pub(crate) fn dependants(&self, id: UntypedAssetId) -> Vec<UntypedAssetId> {
+        self.dependants
+            .get(&id)
+            .map(|dependants| dependants.iter().copied().collect())
+            .unwrap_or_default()
+    }
+
+    /// Builds a snapshot of the dependency graph of all tracked assets.
+    pub(crate) fn dependency_graph(&self) -> AssetDependencyGraph {
+        let nodes = self
+            .infos
+            .iter()
+            .map(|(id, info)| {
+                let node = AssetDependencyNode {
+                    id: *id,
+                    path: info.path.clone(),
+                    load_state: info.load_state,
+                    dep_load_state: info.dep_load_state,
+                    rec_dep_load_state: info.rec_dep_load_state,
+                    dependencies: info.dependencies.iter().copied().collect(),
+                    loading_dependencies: info.loading_dependencies.iter().copied().collect(),
+                    failed_dependencies: info.failed_dependencies.iter().copied().collect(),
+                    dependants: self.dependants(*id),
+                    loader_dependencies: info.loader_dependencies.keys().cloned().collect(),
+                };
+                (*id, node)
+            })
+            .collect();
+        AssetDependencyGraph::new(nodes)
+    }
+
     /// Returns `true` if the asset this path points to is still alive
     pub(crate) fn is_path_alive<'a>(&self, path: impl Into<AssetPath<'a>>) -> bool {
         let path = path.into();
@@ -363,6 +409,7 @@ impl AssetInfos {
         Self::process_handle_drop_internal(
             &mut self.infos,
             &mut self.path_to_id,
+            &mut self.dependants,
             &mut self.loader_dependants,
             &mut self.living_labeled_assets,
             self.watching_for_changes,
@@ -379,7 +426,8 @@ impl AssetInfos {
         sender: &Sender<InternalAssetEvent>,
     ) {
         loaded_asset.value.insert(loaded_asset_id, world);
-        let mut loading_deps = loaded_asset.dependencies;
+        let dependencies = loaded_asset.dependencies;
+        let mut loading_deps = dependencies.clone();
         let mut failed_deps = HashSet::new();
         let mut loading_rec_deps = loading_deps.clone();
         let mut failed_rec_deps = HashSet::new();
@@ -465,8 +513,16 @@ impl AssetInfos {
                 }
             }
             let info = self
-                .get_mut(loaded_asset_id)
+                .infos
+                .get_mut(&loaded_asset_id)
                 .expect("Asset info should always exist at this point");
+            Self::update_dependants(
+                &mut self.dependants,
+                loaded_asset_id,
+                &info.dependencies,
+                &dependencies,
+            );
+            info.dependencies = dependencies;
             info.loading_dependencies = loading_deps;
             info.failed_dependencies = failed_deps;
             info.loading_rec_dependencies = loading_rec_deps;
@@ -609,6 +665,26 @@ impl AssetInfos {
         }
     }
 
+    /// Updates the reverse dependency edges of `dependant` when its direct dependencies change from `old` to `new`.
+    fn update_dependants(
+        dependants: &mut HashMap<UntypedAssetId, HashSet<UntypedAssetId>>,
+        dependant: UntypedAssetId,
+        old: &HashSet<UntypedAssetId>,
+        new: &HashSet<UntypedAssetId>,
+    ) {
+        for dependency in old.difference(new) {
+            if let Entry::Occupied(mut entry) = dependants.entry(*dependency) {
+                entry.get_mut().remove(&dependant);
+                if entry.get().is_empty() {
+                    entry.remove();
+                }
+            }
+        }
+        for dependency in new.difference(old) {
+            dependants.entry(*dependency).or_default().insert(dependant);
+        }
+    }
+
     fn remove_dependants_and_labels(
         info: &AssetInfo,
         loader_dependants: &mut HashMap<AssetPath<'static>, HashSet<AssetPath<'static>>>,
@@ -641,6 +717,7 @@ impl AssetInfos {
     fn process_handle_drop_internal(
         infos: &mut HashMap<UntypedAssetId, AssetInfo>,
         path_to_id: &mut HashMap<AssetPath<'static>, TypeIdMap<UntypedAssetId>>,
+        dependants: &mut HashMap<UntypedAssetId, HashSet<UntypedAssetId>>,
         loader_dependants: &mut HashMap<AssetPath<'static>, HashSet<AssetPath<'static>>>,
         living_labeled_assets: &mut HashMap<AssetPath<'static>, HashSet<Box<str>>>,
         watching_for_changes: bool,
@@ -660,6 +737,7 @@ impl AssetInfos {
         let type_id = entry.key().type_id();
 
         let info = entry.remove();
+        Self::update_dependants(dependants, id, &info.dependencies, &HashSet::new());
         let Some(path) = &info.path else {
             return true;
         };
@@ -697,6 +775,7 @@ impl AssetInfos {
                     Self::process_handle_drop_internal(
                         &mut self.infos,
                         &mut self.path_to_id,
+                        &mut self.dependants,
                         &mut self.loader_dependants,
                         &mut self.living_labeled_assets,
                         self.watching_for_changes,
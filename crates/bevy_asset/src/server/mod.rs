@@ -1,5 +1,9 @@
+mod graph;
 mod info;
 mod loaders;
+mod queue;
+
+pub use graph::{AssetDependencyGraph, AssetDependencyNode};
 
 use crate::{
     folder::LoadedFolder,
@@ -23,10 +27,16 @@ use bevy_utils::tracing::{error, info};
 use bevy_utils::{CowArc, HashSet};
 use crossbeam_channel::{Receiver, Sender};
 use futures_lite::StreamExt;
+use graph::walk_graph;
 use info::*;
 use loaders::*;
 use parking_lot::RwLock;
+use queue::LoadQueue;
+use serde::Serialize;
+use std::future::{poll_fn, Future};
 use std::path::PathBuf;
+use std::pin::pin;
+use std::task::Poll;
 use std::{any::TypeId, path::Path, sync::Arc};
 use thiserror::Error;
 
@@ -62,6 +72,7 @@ pub(crate) struct AssetServerData {
     sources: AssetSources,
     mode: AssetServerMode,
     meta_check: AssetMetaCheck,
+    load_queue: Arc<LoadQueue>,
 }
 
 /// The "asset mode" the server is currently in.
@@ -122,6 +133,7 @@ impl AssetServer {
                 asset_event_receiver,
                 loaders,
                 infos: RwLock::new(infos),
+                load_queue: Default::default(),
             }),
         }
     }
@@ -270,7 +282,24 @@ impl AssetServer {
     /// The asset load will fail and an error will be printed to the logs if the asset stored at `path` is not of type `A`.
     #[must_use = "not using the returned strong handle may result in the unexpected release of the asset"]
     pub fn load<'a, A: Asset>(&self, path: impl Into<AssetPath<'a>>) -> Handle<A> {
-        self.load_with_meta_transform(path, None)
+        self.load_with_meta_transform(path, None, 0)
+    }
+
+    /// Begins loading an [`Asset`] of type `A` stored at `path`, like [`AssetServer::load`], with the given `priority`.
+    ///
+    /// When the [`AssetSource`] of `path` is at its [maximum number of concurrent loads](AssetServer::set_max_concurrent_loads),
+    /// loads with a higher priority start first. [`AssetServer::load`] uses a priority of `0`. If the asset is already waiting
+    /// to be loaded with a lower priority, its priority is raised.
+    ///
+    /// As with every load, the load is cancelled if all handles to the asset are dropped before it finishes, and an
+    /// [`AssetLoadFailedEvent`] with an [`AssetLoadError::Cancelled`] error is sent.
+    #[must_use = "not using the returned strong handle may result in the unexpected release of the asset"]
+    pub fn load_with_priority<'a, A: Asset>(
+        &self,
+        path: impl Into<AssetPath<'a>>,
+        priority: i32,
+    ) -> Handle<A> {
+        self.load_with_meta_transform(path, None, priority)
     }
 
     /// Begins loading an [`Asset`] of type `A` stored at `path`. The given `settings` function will override the asset's
@@ -282,13 +311,14 @@ impl AssetServer {
         path: impl Into<AssetPath<'a>>,
         settings: impl Fn(&mut S) + Send + Sync + 'static,
     ) -> Handle<A> {
-        self.load_with_meta_transform(path, Some(loader_settings_meta_transform(settings)))
+        self.load_with_meta_transform(path, Some(loader_settings_meta_transform(settings)), 0)
     }
 
     fn load_with_meta_transform<'a, A: Asset>(
         &self,
         path: impl Into<AssetPath<'a>>,
         meta_transform: Option<MetaTransform>,
+        priority: i32,
     ) -> Handle<A> {
         let path = path.into().into_owned();
         let (handle, should_load) = self.data.infos.write().get_or_create_path_handle::<A>(
@@ -297,21 +327,102 @@ impl AssetServer {
             meta_transform,
         );
 
+        let source = path.source().clone_owned();
         if should_load {
-            let owned_handle = Some(handle.clone().untyped());
+            let owned_handle = handle.clone().untyped();
             let server = self.clone();
-            IoTaskPool::get()
-                .spawn(async move {
-                    if let Err(err) = server.load_internal(owned_handle, path, false, None).await {
-                        error!("{}", err);
+            self.data
+                .load_queue
+                .spawn(source, Some(handle.id().untyped()), priority, async move {
+                    if server.cancel_if_unused(&owned_handle, &path) {
+                        return;
                     }
-                })
-                .detach();
+                    match server
+                        .load_internal(Some(owned_handle), path, false, None)
+                        .await
+                    {
+                        Ok(_) | Err(AssetLoadError::Cancelled { .. }) => {}
+                        Err(err) => error!("{}", err),
+                    }
+                });
+        } else {
+            self.data
+                .load_queue
+                .raise_priority(&source, handle.id().untyped(), priority);
         }
 
         handle
     }
 
+    /// Sets the maximum number of assets loaded at the same time from the given [`AssetSource`]. By default, there is no limit.
+    ///
+    /// Loads started while the source is at its limit wait until another load from the same source completes, starting with
+    /// the highest priority (see [`AssetServer::load_with_priority`]). A limit of `0` pauses the loads from the source.
+    /// Loads are cancelled if all handles to their asset are dropped in the meantime: loads waiting to start never run their
+    /// [`AssetLoader`], and loads whose [`AssetLoader`] already started are aborted the next time they resume, such as after
+    /// a read or a dependency load.
+    ///
+    /// This only affects loads started with [`AssetServer::load`] and its variants, including the dependencies requested by
+    /// [`AssetLoader`]s, but not direct loads such as [`AssetServer::load_untyped_async`] or reloads.
+    pub fn set_max_concurrent_loads<'a>(
+        &self,
+        source: impl Into<AssetSourceId<'a>>,
+        max: Option<usize>,
+    ) {
+        self.data
+            .load_queue
+            .set_max_concurrent(source.into().into_owned(), max);
+    }
+
+    /// Returns the number of loads from the given [`AssetSource`] waiting for a slot to start.
+    /// See [`AssetServer::set_max_concurrent_loads`].
+    pub fn pending_loads<'a>(&self, source: impl Into<AssetSourceId<'a>>) -> usize {
+        self.data.load_queue.pending(&source.into().into_owned())
+    }
+
+    /// Returns `true` if `handle` is the last strong handle to its asset, in which case its load is cancelled.
+    ///
+    /// The asset is marked as failed: drops of loading assets are deferred until they finish loading, while this lets the
+    /// drop of `handle` free the asset right away. Requesting the asset again before that starts a new load. An
+    /// [`AssetLoadError::Cancelled`] failure is sent for the asset.
+    ///
+    /// This is checked before the [`AssetLoader`] runs, and each time a running load resumes.
+    fn cancel_if_unused(&self, handle: &UntypedHandle, path: &AssetPath<'static>) -> bool {
+        let UntypedHandle::Strong(strong_handle) = handle else {
+            return false;
+        };
+        // Check the strong count before locking, as loads are almost never cancelled.
+        if Arc::strong_count(strong_handle) > 1 {
+            return false;
+        }
+        let mut infos = self.data.infos.write();
+        // New strong handles are only created while the infos are locked, so check again now that they are.
+        if Arc::strong_count(strong_handle) > 1 {
+            return false;
+        }
+        self.fail_cancelled_load(&mut infos, handle.id(), path);
+        true
+    }
+
+    /// Marks the asset `id` as failed and sends an [`AssetLoadError::Cancelled`] failure for it.
+    fn fail_cancelled_load(
+        &self,
+        infos: &mut AssetInfos,
+        id: UntypedAssetId,
+        path: &AssetPath<'static>,
+    ) {
+        if let Some(info) = infos.get_mut(id) {
+            info.load_state = LoadState::Failed;
+            info.dep_load_state = DependencyLoadState::Failed;
+            info.rec_dep_load_state = RecursiveDependencyLoadState::Failed;
+        }
+        self.send_asset_event(InternalAssetEvent::Failed {
+            id,
+            path: path.clone(),
+            error: AssetLoadError::Cancelled { path: path.clone() },
+        });
+    }
+
     /// Asynchronously load an asset that you do not know the type of statically. If you _do_ know the type of the asset,
     /// you should use [`AssetServer::load`]. If you don't know the type of the asset, but you can't use an async method,
     /// consider using [`AssetServer::load_untyped`].
@@ -370,29 +481,28 @@ impl AssetServer {
         let id = handle.id().untyped();
 
         let server = self.clone();
-        IoTaskPool::get()
-            .spawn(async move {
-                let path_clone = path.clone();
-                match server.load_untyped_async(path).await {
-                    Ok(handle) => server.send_asset_event(InternalAssetEvent::Loaded {
+        let source = path.source().clone_owned();
+        self.data.load_queue.spawn(source, Some(id), 0, async move {
+            let path_clone = path.clone();
+            match server.load_untyped_async(path).await {
+                Ok(handle) => server.send_asset_event(InternalAssetEvent::Loaded {
+                    id,
+                    loaded_asset: LoadedAsset::new_with_dependencies(
+                        LoadedUntypedAsset { handle },
+                        None,
+                    )
+                    .into(),
+                }),
+                Err(err) => {
+                    error!("{err}");
+                    server.send_asset_event(InternalAssetEvent::Failed {
                         id,
-                        loaded_asset: LoadedAsset::new_with_dependencies(
-                            LoadedUntypedAsset { handle },
-                            None,
-                        )
-                        .into(),
-                    }),
-                    Err(err) => {
-                        error!("{err}");
-                        server.send_asset_event(InternalAssetEvent::Failed {
-                            id,
-                            path: path_clone,
-                            error: err,
-                        });
-                    }
+                        path: path_clone,
+                        error: err,
+                    });
                 }
-            })
-            .detach();
+            }
+        });
 
         handle
     }
@@ -428,6 +538,14 @@ impl AssetServer {
                 e
             })?;
 
+        // Cancel queued loads whose other handles were all dropped while the meta was read, before running the loader.
+        let cancellable = input_handle.is_some() && !force;
+        if let Some(handle) = input_handle.as_ref().filter(|_| cancellable) {
+            if self.cancel_if_unused(handle, &path) {
+                return Err(AssetLoadError::Cancelled { path });
+            }
+        }
+
         // This contains Some(UntypedHandle), if it was retrievable
         // If it is None, that is because it was _not_ retrievable, due to
         //    1. The handle was not already passed in for this path, meaning we can't just use that
@@ -491,19 +609,50 @@ impl AssetServer {
                 HandleLoadingMode::Force,
                 None,
             );
-            (base_handle, base_path)
+            (Some(base_handle), base_path)
         } else {
-            (handle.clone().unwrap(), path.clone())
+            // Reuse `handle` rather than cloning it, so that the load holds a single strong handle to the asset.
+            (None, path.clone())
         };
+        let base_handle_ref = base_handle.as_ref().or(handle.as_ref()).unwrap();
+        let base_id = base_handle_ref.id();
 
-        if let Some(meta_transform) = base_handle.meta_transform() {
+        if let Some(meta_transform) = base_handle_ref.meta_transform() {
             (*meta_transform)(&mut *meta);
         }
 
-        match self
-            .load_with_meta_loader_and_reader(&base_path, meta, &*loader, &mut *reader, true, false)
-            .await
-        {
+        let load = self.load_with_meta_loader_and_reader(
+            &base_path,
+            meta,
+            &*loader,
+            &mut *reader,
+            true,
+            false,
+        );
+        let result = match handle.as_ref().filter(|_| cancellable) {
+            Some(cancellable_handle) => {
+                // Cancel the running load if all other handles are dropped by the time it resumes, such as after
+                // a read or a dependency load. Dropping the load also drops the handles to its dependencies.
+                let mut load = pin!(load);
+                poll_fn(|cx| {
+                    if self.cancel_if_unused(cancellable_handle, &path) {
+                        // The load of a labeled asset forced its base asset into the loading state, which would
+                        // otherwise never end.
+                        if let Some(base_handle) = &base_handle {
+                            let mut infos = self.data.infos.write();
+                            self.fail_cancelled_load(&mut infos, base_handle.id(), &base_path);
+                        }
+                        return Poll::Ready(Err(AssetLoadError::Cancelled {
+                            path: path.clone_owned(),
+                        }));
+                    }
+                    load.as_mut().poll(cx)
+                })
+                .await
+            }
+            None => load.await,
+        };
+        match result {
             Ok(loaded_asset) => {
                 let final_handle = if let Some(label) = path.label_cow() {
                     match loaded_asset.labeled_assets.get(&label) {
@@ -527,12 +676,13 @@ impl AssetServer {
                     handle.unwrap()
                 };
 
-                self.send_loaded_asset(base_handle.id(), loaded_asset);
+                self.send_loaded_asset(base_id, loaded_asset);
                 Ok(final_handle)
             }
+            Err(err @ AssetLoadError::Cancelled { .. }) => Err(err),
             Err(err) => {
                 self.send_asset_event(InternalAssetEvent::Failed {
-                    id: base_handle.id(),
+                    id: base_id,
                     error: err.clone(),
                     path: path.into_owned(),
                 });
@@ -794,6 +944,39 @@ impl AssetServer {
             && self.recursive_dependency_load_state(id) == RecursiveDependencyLoadState::Loaded
     }
 
+    /// Returns the direct dependencies of the given asset, in no particular order.
+    ///
+    /// The dependencies of an asset are only known once it is loaded.
+    pub fn dependencies(&self, id: impl Into<UntypedAssetId>) -> Vec<UntypedAssetId> {
+        self.data.infos.read().dependencies(id.into())
+    }
+
+    /// Returns the dependencies of the given asset and all of their dependencies, closest first.
+    ///
+    /// Combined with [`AssetServer::get_load_states`], this can be used to find the assets that keep an
+    /// asset from being [loaded with its dependencies](AssetServer::is_loaded_with_dependencies).
+    pub fn recursive_dependencies(&self, id: impl Into<UntypedAssetId>) -> Vec<UntypedAssetId> {
+        let infos = self.data.infos.read();
+        walk_graph(id.into(), |id| infos.dependencies(id))
+    }
+
+    /// Returns the assets that have the given asset as a direct dependency, in no particular order.
+    pub fn dependants(&self, id: impl Into<UntypedAssetId>) -> Vec<UntypedAssetId> {
+        self.data.infos.read().dependants(id.into())
+    }
+
+    /// Returns the dependants of the given asset and all of their dependants, closest first.
+    pub fn recursive_dependants(&self, id: impl Into<UntypedAssetId>) -> Vec<UntypedAssetId> {
+        let infos = self.data.infos.read();
+        walk_graph(id.into(), |id| infos.dependants(id))
+    }
+
+    /// Returns a snapshot of the dependency graph of every asset tracked by this [`AssetServer`],
+    /// which can be exported to DOT or serialized with `serde`.
+    pub fn dependency_graph(&self) -> AssetDependencyGraph {
+        self.data.infos.read().dependency_graph()
+    }
+
     /// Returns an active handle for the given path, if the asset at the given path has already started loading,
     /// or is still "alive".
     pub fn get_handle<'a, A: Asset>(&self, path: impl Into<AssetPath<'a>>) -> Option<Handle<A>> {
@@ -1069,7 +1252,15 @@ pub fn handle_internal_asset_events(world: &mut World) {
                     sender(world, id);
                 }
                 InternalAssetEvent::Failed { id, path, error } => {
-                    infos.process_asset_fail(id);
+                    // A cancelled asset may have been freed or requested again since its load was cancelled.
+                    let cancelled = matches!(error, AssetLoadError::Cancelled { .. });
+                    if !cancelled
+                        || infos
+                            .get(id)
+                            .is_some_and(|info| info.load_state == LoadState::Failed)
+                    {
+                        infos.process_asset_fail(id);
+                    }
 
                     // Send untyped failure event
                     untyped_failures.push(UntypedAssetLoadFailedEvent {
@@ -1186,7 +1377,7 @@ pub(crate) enum InternalAssetEvent {
 }
 
 /// The load state of an asset.
-#[derive(Component, Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+#[derive(Component, Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Serialize)]
 pub enum LoadState {
     /// The asset has not started loading yet
     NotLoaded,
@@ -1199,7 +1390,7 @@ pub enum LoadState {
 }
 
 /// The load state of an asset's dependencies.
-#[derive(Component, Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+#[derive(Component, Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Serialize)]
 pub enum DependencyLoadState {
     /// The asset has not started loading yet
     NotLoaded,
@@ -1212,7 +1403,7 @@ pub enum DependencyLoadState {
 }
 
 /// The recursive load state of an asset's dependencies.
-#[derive(Component, Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+#[derive(Component, Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Serialize)]
 pub enum RecursiveDependencyLoadState {
     /// The asset has not started loading yet
     NotLoaded,
@@ -1264,6 +1455,8 @@ pub enum AssetLoadError {
     CannotLoadProcessedAsset { path: AssetPath<'static> },
     #[error("Asset '{path}' is configured to be ignored. It cannot be loaded.")]
     CannotLoadIgnoredAsset { path: AssetPath<'static> },
+    #[error("The load of asset '{path}' was cancelled because all of its handles were dropped before it finished.")]
+    Cancelled { path: AssetPath<'static> },
     #[error("Failed to load asset '{path}' with asset loader '{loader_name}': {error}")]
     AssetLoaderError {
         path: AssetPath<'static>,
@@ -0,0 +1,195 @@
+use crate::{io::AssetSourceId, UntypedAssetId};
+use bevy_tasks::IoTaskPool;
+use bevy_utils::{BoxedFuture, ConditionalSendFuture, HashMap};
+use parking_lot::Mutex;
+use std::{cmp::Ordering, collections::BinaryHeap, sync::Arc};
+
+/// Schedules the asset load tasks of an [`AssetServer`](crate::AssetServer).
+///
+/// Each [`AssetSource`](crate::io::AssetSource) can have a maximum number of concurrent loads. Loads
+/// started while their source is at that limit wait in a queue, from the highest priority to the
+/// lowest, and in the order they were requested for equal priorities.
+#[derive(Default)]
+pub(crate) struct LoadQueue {
+    sources: Mutex<HashMap<AssetSourceId<'static>, SourceLoads>>,
+}
+
+#[derive(Default)]
+struct SourceLoads {
+    max_concurrent: Option<usize>,
+    running: usize,
+    pending: BinaryHeap<PendingLoad>,
+    next_order: u64,
+}
+
+impl SourceLoads {
+    fn has_capacity(&self) -> bool {
+        !matches!(self.max_concurrent, Some(max) if self.running >= max)
+    }
+
+    /// Pops the pending loads that can start, counting them as running.
+    fn pop_startable(&mut self, tasks: &mut Vec<BoxedFuture<'static, ()>>) {
+        while self.has_capacity() {
+            let Some(load) = self.pending.pop() else {
+                break;
+            };
+            self.running += 1;
+            tasks.push(load.task);
+        }
+    }
+}
+
+struct PendingLoad {
+    id: Option<UntypedAssetId>,
+    priority: i32,
+    order: u64,
+    task: BoxedFuture<'static, ()>,
+}
+
+impl PartialEq for PendingLoad {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for PendingLoad {}
+
+impl PartialOrd for PendingLoad {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for PendingLoad {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // `BinaryHeap` pops the greatest element first: the highest priority, then the oldest load.
+        self.priority
+            .cmp(&other.priority)
+            .then(other.order.cmp(&self.order))
+    }
+}
+
+/// Finishes a running load when dropped, even if its task panicked.
+struct RunningLoad {
+    queue: Arc<LoadQueue>,
+    source: AssetSourceId<'static>,
+}
+
+impl Drop for RunningLoad {
+    fn drop(&mut self) {
+        self.queue.finish(&self.source);
+    }
+}
+
+impl LoadQueue {
+    /// Runs `task` on the [`IoTaskPool`] once `source` is below its concurrent load limit.
+    ///
+    /// `id` is the asset being loaded, used by [`LoadQueue::raise_priority`].
+    pub(crate) fn spawn(
+        self: &Arc<Self>,
+        source: AssetSourceId<'static>,
+        id: Option<UntypedAssetId>,
+        priority: i32,
+        task: impl ConditionalSendFuture<Output = ()> + 'static,
+    ) {
+        let task: BoxedFuture<'static, ()> = Box::pin(task);
+        {
+            let mut sources = self.sources.lock();
+            let loads = sources.entry(source.clone()).or_default();
+            if !loads.has_capacity() {
+                let order = loads.next_order;
+                loads.next_order += 1;
+                loads.pending.push(PendingLoad {
+                    id,
+                    priority,
+                    order,
+                    task,
+                });
+                return;
+            }
+            loads.running += 1;
+        }
+        self.start(source, task);
+    }
+
+    /// Raises the priority of the pending load of `id` to `priority`, if it is lower.
+    pub(crate) fn raise_priority(
+        &self,
+        source: &AssetSourceId<'static>,
+        id: UntypedAssetId,
+        priority: i32,
+    ) {
+        let mut sources = self.sources.lock();
+        let Some(loads) = sources.get_mut(source) else {
+            return;
+        };
+        if !loads
+            .pending
+            .iter()
+            .any(|load| load.id == Some(id) && load.priority < priority)
+        {
+            return;
+        }
+        let mut pending = std::mem::take(&mut loads.pending).into_vec();
+        for load in &mut pending {
+            if load.id == Some(id) {
+                load.priority = load.priority.max(priority);
+            }
+        }
+        loads.pending = pending.into();
+    }
+
+    /// Sets the maximum number of concurrent loads of `source`, starting pending loads if it was raised.
+    pub(crate) fn set_max_concurrent(
+        self: &Arc<Self>,
+        source: AssetSourceId<'static>,
+        max_concurrent: Option<usize>,
+    ) {
+        let mut tasks = Vec::new();
+        {
+            let mut sources = self.sources.lock();
+            let loads = sources.entry(source.clone()).or_default();
+            loads.max_concurrent = max_concurrent;
+            loads.pop_startable(&mut tasks);
+        }
+        for task in tasks {
+            self.start(source.clone(), task);
+        }
+    }
+
+    /// Returns the number of loads of `source` waiting to start.
+    pub(crate) fn pending(&self, source: &AssetSourceId<'static>) -> usize {
+        self.sources
+            .lock()
+            .get(source)
+            .map_or(0, |loads| loads.pending.len())
+    }
+
+    fn start(self: &Arc<Self>, source: AssetSourceId<'static>, task: BoxedFuture<'static, ()>) {
+        let running = RunningLoad {
+            queue: self.clone(),
+            source,
+        };
+        IoTaskPool::get()
+            .spawn(async move {
+                let _running = running;
+                task.await;
+            })
+            .detach();
+    }
+
+    fn finish(self: &Arc<Self>, source: &AssetSourceId<'static>) {
+        let mut tasks = Vec::new();
+        {
+            let mut sources = self.sources.lock();
+            let Some(loads) = sources.get_mut(source) else {
+                return;
+            };
+            loads.running -= 1;
+            loads.pop_startable(&mut tasks);
+        }
+        for task in tasks {
+            self.start(source.clone(), task);
+        }
+    }
+}
@@ -0,0 +1,432 @@
+//! Module containing a plugin to drive [`Stepping`] from the keyboard, events and an on-screen overlay.
+
+use bevy_app::{App, MainScheduleOrder, Plugin, Update};
+use bevy_color::Color;
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    event::{Event, EventReader, EventWriter},
+    query::With,
+    schedule::{
+        InternedScheduleLabel, IntoSystemConfigs, NodeId, ScheduleLabel, Schedules, Stepping,
+    },
+    system::{Commands, Query, Res, ResMut, Resource},
+};
+use bevy_input::{keyboard::KeyCode, ButtonInput};
+use bevy_render::view::Visibility;
+use bevy_text::{Text, TextSection, TextStyle};
+use bevy_ui::{node_bundles::TextBundle, BackgroundColor, PositionType, Style, UiRect, Val};
+use bevy_utils::{default, get_short_name, tracing::info, Duration};
+
+/// Independent [`Schedule`](bevy_ecs::schedule::Schedule) for the stepping systems.
+///
+/// The stepping systems must run in their own schedule to be able to inspect
+/// all the other schedules in the [`App`]. This is because the currently
+/// executing schedule is removed from the [`Schedules`] resource while it is
+/// being run.
+#[derive(Debug, Hash, PartialEq, Eq, Clone, ScheduleLabel)]
+pub struct SteppingSchedule;
+
+/// A plugin that controls [`Stepping`] from the keyboard and displays an overlay listing the
+/// stepped schedules and their systems.
+///
+/// The overlay is only visible while stepping is enabled. For each system it shows:
+/// - `->` if the stepping cursor is on the system, i.e. it will run on the next step,
+/// - `>` if the system is selected in the overlay,
+/// - `B` if the system has a breakpoint, `S` if it is skipped, `A` if it always runs,
+/// - how long the system took the last time it ran while stepping.
+///
+/// Keys can be changed through the [`SteppingKeyBindings`] resource. Other sources, such as a
+/// remote debugger, can drive the plugin by sending [`SteppingControl`] events.
+///
+/// Systems from Bevy crates (whose name starts with `bevy`) are set to always run, so that the
+/// engine keeps working while stepping.
+///
+/// If the [`App`] already has a [`Stepping`] resource, the schedules added with
+/// [`SteppingPlugin::add_schedule`] are stepped in addition to its own.
+///
+/// Note: Stepping requires the `bevy_debug_stepping` feature.
+#[derive(Default)]
+pub struct SteppingPlugin {
+    schedule_labels: Vec<InternedScheduleLabel>,
+    top: Val,
+    left: Val,
+}
+
+impl SteppingPlugin {
+    /// Add a schedule to be stepped when stepping is enabled.
+    pub fn add_schedule(mut self, label: impl ScheduleLabel) -> Self {
+        self.schedule_labels.push(label.intern());
+        self
+    }
+
+    /// Set the location of the overlay.
+    pub fn at(self, left: Val, top: Val) -> Self {
+        Self { top, left, ..self }
+    }
+}
+
+impl Plugin for SteppingPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_schedule(SteppingSchedule);
+        app.world
+            .resource_mut::<MainScheduleOrder>()
+            .insert_after(Update, SteppingSchedule);
+
+        // Keep the schedules and behaviors of a `Stepping` configured before adding the plugin.
+        let mut stepping = app.world.get_resource_or_insert_with(Stepping::new);
+        for label in &self.schedule_labels {
+            stepping.add_schedule(*label);
+        }
+
+        app.init_resource::<SteppingKeyBindings>()
+            .insert_resource(SteppingOverlay {
+                top: self.top,
+                left: self.left,
+                schedules: Vec::new(),
+                systems: Vec::new(),
+                selected: 0,
+            })
+            .add_event::<SteppingControl>()
+            .add_systems(
+                SteppingSchedule,
+                (
+                    send_keyboard_controls,
+                    build_overlay.run_if(overlay_outdated),
+                    apply_controls,
+                    update_overlay,
+                )
+                    .chain(),
+            );
+    }
+}
+
+/// An action applied to [`Stepping`] by the [`SteppingPlugin`].
+///
+/// Actions on the selected system refer to the system highlighted in the overlay.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SteppingControl {
+    /// Enable stepping if it is disabled, disable it otherwise.
+    Toggle,
+    /// Enable stepping.
+    Enable,
+    /// Disable stepping.
+    Disable,
+    /// Run the next system in the stepping frame.
+    StepFrame,
+    /// Run the remaining systems of the stepping frame, until a breakpoint is reached.
+    ContinueFrame,
+    /// Select the next system in the overlay.
+    SelectNext,
+    /// Select the previous system in the overlay.
+    SelectPrevious,
+    /// Set or clear a breakpoint on the selected system.
+    ToggleBreakpoint,
+    /// Skip the selected system while stepping, or stop skipping it.
+    ToggleSkip,
+    /// Log the state of [`Stepping`].
+    LogState,
+}
+
+/// The keys used by the [`SteppingPlugin`].
+#[derive(Resource, Debug, Clone)]
+pub struct SteppingKeyBindings {
+    /// Sends [`SteppingControl::Toggle`].
+    pub toggle: KeyCode,
+    /// Sends [`SteppingControl::StepFrame`].
+    pub step_frame: KeyCode,
+    /// Sends [`SteppingControl::ContinueFrame`].
+    pub continue_frame: KeyCode,
+    /// Sends [`SteppingControl::SelectNext`].
+    pub select_next: KeyCode,
+    /// Sends [`SteppingControl::SelectPrevious`].
+    pub select_previous: KeyCode,
+    /// Sends [`SteppingControl::ToggleBreakpoint`].
+    pub toggle_breakpoint: KeyCode,
+    /// Sends [`SteppingControl::ToggleSkip`].
+    pub toggle_skip: KeyCode,
+    /// Sends [`SteppingControl::LogState`].
+    pub log_state: KeyCode,
+}
+
+impl Default for SteppingKeyBindings {
+    fn default() -> Self {
+        Self {
+            toggle: KeyCode::Backquote,
+            step_frame: KeyCode::KeyS,
+            continue_frame: KeyCode::Space,
+            select_next: KeyCode::ArrowDown,
+            select_previous: KeyCode::ArrowUp,
+            toggle_breakpoint: KeyCode::KeyB,
+            toggle_skip: KeyCode::KeyX,
+            log_state: KeyCode::Slash,
+        }
+    }
+}
+
+/// A system listed in the overlay.
+#[derive(Debug)]
+struct OverlaySystem {
+    schedule: InternedScheduleLabel,
+    node: NodeId,
+    name: String,
+    /// Index of the text section displaying this system.
+    section: usize,
+}
+
+#[derive(Resource, Debug)]
+struct SteppingOverlay {
+    top: Val,
+    left: Val,
+    /// The stepped schedules the overlay was built for, in order.
+    schedules: Vec<InternedScheduleLabel>,
+    systems: Vec<OverlaySystem>,
+    selected: usize,
+}
+
+#[derive(Component)]
+struct SteppingOverlayText;
+
+const FONT_SIZE: f32 = 16.0;
+const HEADER_COLOR: Color = Color::srgb(1.0, 0.85, 0.4);
+const TEXT_COLOR: Color = Color::srgb(0.9, 0.9, 0.9);
+const SELECTED_COLOR: Color = Color::srgb(0.4, 0.8, 1.0);
+
+fn text_style(color: Color) -> TextStyle {
+    TextStyle {
+        font_size: FONT_SIZE,
+        color,
+        ..default()
+    }
+}
+
+/// The overlay needs to be built again when schedules are added to or removed from [`Stepping`].
+fn overlay_outdated(overlay: Res<SteppingOverlay>, stepping: Res<Stepping>) -> bool {
+    stepping
+        .schedules()
+        .is_ok_and(|schedule_order| *schedule_order != overlay.schedules)
+}
+
+fn send_keyboard_controls(
+    keyboard_input: Option<Res<ButtonInput<KeyCode>>>,
+    keys: Res<SteppingKeyBindings>,
+    mut controls: EventWriter<SteppingControl>,
+) {
+    let Some(keyboard_input) = keyboard_input else {
+        return;
+    };
+    let bindings = [
+        (keys.toggle, SteppingControl::Toggle),
+        (keys.step_frame, SteppingControl::StepFrame),
+        (keys.continue_frame, SteppingControl::ContinueFrame),
+        (keys.select_next, SteppingControl::SelectNext),
+        (keys.select_previous, SteppingControl::SelectPrevious),
+        (keys.toggle_breakpoint, SteppingControl::ToggleBreakpoint),
+        (keys.toggle_skip, SteppingControl::ToggleSkip),
+        (keys.log_state, SteppingControl::LogState),
+    ];
+    for (key, control) in bindings {
+        if keyboard_input.just_pressed(key) {
+            controls.send(control);
+        }
+    }
+}
+
+/// Construct the overlay from the [`Schedules`] resource, replacing the previous one.
+///
+/// This system may run multiple times before constructing the overlay, as the
+/// schedule order is not known until all stepped schedules have run once.
+fn build_overlay(
+    mut commands: Commands,
+    schedules: Res<Schedules>,
+    mut stepping: ResMut<Stepping>,
+    mut overlay: ResMut<SteppingOverlay>,
+    previous_text: Query<Entity, With<SteppingOverlayText>>,
+) {
+    let Ok(schedule_order) = stepping.schedules() else {
+        return;
+    };
+    let schedule_order = schedule_order.clone();
+
+    let mut sections = Vec::new();
+    let mut systems = Vec::new();
+    let mut always_run = Vec::new();
+    for label in &schedule_order {
+        let Some(Ok(schedule_systems)) = schedules.get(*label).map(|schedule| schedule.systems())
+        else {
+            return;
+        };
+        sections.push(TextSection::new(
+            format!("{label:?}\n"),
+            text_style(HEADER_COLOR),
+        ));
+
+        for (node, system) in schedule_systems {
+            // Don't step Bevy's own systems.
+            if system.name().starts_with("bevy") {
+                always_run.push((*label, node));
+                continue;
+            }
+            systems.push(OverlaySystem {
+                schedule: *label,
+                node,
+                name: get_short_name(&system.name()),
+                section: sections.len(),
+            });
+            sections.push(TextSection::new("", text_style(TEXT_COLOR)));
+        }
+    }
+
+    for (label, node) in always_run {
+        stepping.always_run_node(label, node);
+    }
+    if overlay.selected >= systems.len() {
+        overlay.selected = 0;
+    }
+    overlay.schedules = schedule_order;
+    overlay.systems = systems;
+
+    for entity in &previous_text {
+        commands.entity(entity).despawn();
+    }
+    commands.spawn((
+        SteppingOverlayText,
+        TextBundle {
+            text: Text::from_sections(sections),
+            style: Style {
+                position_type: PositionType::Absolute,
+                top: overlay.top,
+                left: overlay.left,
+                padding: UiRect::all(Val::Px(10.0)),
+                ..default()
+            },
+            background_color: BackgroundColor(Color::srgba(0.1, 0.1, 0.1, 0.8)),
+            visibility: Visibility::Hidden,
+            ..default()
+        },
+    ));
+}
+
+fn apply_controls(
+    mut controls: EventReader<SteppingControl>,
+    mut stepping: ResMut<Stepping>,
+    mut overlay: ResMut<SteppingOverlay>,
+) {
+    for control in controls.read() {
+        let selected = overlay
+            .systems
+            .get(overlay.selected)
+            .map(|system| (system.schedule, system.node));
+        match control {
+            SteppingControl::Toggle if stepping.is_enabled() => {
+                stepping.disable();
+            }
+            SteppingControl::Toggle | SteppingControl::Enable => {
+                stepping.enable();
+            }
+            SteppingControl::Disable => {
+                stepping.disable();
+            }
+            SteppingControl::StepFrame => {
+                stepping.step_frame();
+            }
+            SteppingControl::ContinueFrame => {
+                stepping.continue_frame();
+            }
+            SteppingControl::SelectNext if !overlay.systems.is_empty() => {
+                overlay.selected = (overlay.selected + 1) % overlay.systems.len();
+            }
+            SteppingControl::SelectPrevious if !overlay.systems.is_empty() => {
+                overlay.selected = overlay
+                    .selected
+                    .checked_sub(1)
+                    .unwrap_or(overlay.systems.len() - 1);
+            }
+            SteppingControl::SelectNext | SteppingControl::SelectPrevious => {}
+            SteppingControl::ToggleBreakpoint => {
+                if let Some((schedule, node)) = selected {
+                    if stepping.is_breakpoint_node(schedule, node) {
+                        stepping.clear_breakpoint_node(schedule, node);
+                    } else {
+                        stepping.set_breakpoint_node(schedule, node);
+                    }
+                }
+            }
+            SteppingControl::ToggleSkip => {
+                if let Some((schedule, node)) = selected {
+                    if stepping.is_never_run_node(schedule, node) {
+                        stepping.clear_node(schedule, node);
+                    } else {
+                        stepping.never_run_node(schedule, node);
+                    }
+                }
+            }
+            SteppingControl::LogState => info!("{:#?}", *stepping),
+        }
+    }
+}
+
+fn format_duration(duration: Option<Duration>) -> String {
+    match duration {
+        Some(duration) => format!(" ({:.1}µs)", duration.as_secs_f64() * 1_000_000.0),
+        None => String::new(),
+    }
+}
+
+fn update_overlay(
+    overlay: Res<SteppingOverlay>,
+    stepping: Res<Stepping>,
+    mut text: Query<(&mut Text, &mut Visibility), With<SteppingOverlayText>>,
+) {
+    let Ok((mut text, mut visibility)) = text.get_single_mut() else {
+        return;
+    };
+
+    let target_visibility = if stepping.is_enabled() {
+        Visibility::Inherited
+    } else {
+        Visibility::Hidden
+    };
+    if *visibility != target_visibility {
+        *visibility = target_visibility;
+    }
+    if !stepping.is_enabled() {
+        return;
+    }
+
+    let cursor = stepping.cursor();
+    for (index, system) in overlay.systems.iter().enumerate() {
+        let schedule = system.schedule;
+        let node = system.node;
+        let cursor_mark = if cursor == Some((schedule, node)) {
+            "->"
+        } else {
+            "  "
+        };
+        let is_selected = index == overlay.selected;
+        let selected_mark = if is_selected { ">" } else { " " };
+        let behavior_mark = if stepping.is_breakpoint_node(schedule, node) {
+            "B"
+        } else if stepping.is_never_run_node(schedule, node) {
+            "S"
+        } else if stepping.is_always_run_node(schedule, node) {
+            "A"
+        } else {
+            " "
+        };
+
+        let Some(section) = text.sections.get_mut(system.section) else {
+            continue;
+        };
+        section.value = format!(
+            "{cursor_mark}{selected_mark} [{behavior_mark}] {}{}\n",
+            system.name,
+            format_duration(stepping.last_run_duration(schedule, node)),
+        );
+        section.style.color = if is_selected {
+            SELECTED_COLOR
+        } else {
+            TEXT_COLOR
+        };
+    }
+}
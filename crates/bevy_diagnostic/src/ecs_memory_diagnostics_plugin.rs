@@ -0,0 +1,119 @@
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_time::{Real, Time};
+use bevy_utils::tracing::info;
+use bevy_utils::{get_short_name, Duration};
+
+use crate::{Diagnostic, DiagnosticPath, Diagnostics, RegisterDiagnostic};
+
+/// Adds diagnostics about the memory used by the component storages of the [`World`],
+/// as computed by [`World::memory_report`].
+///
+/// Building the report walks every archetype, table and sparse set of the world, so it is only
+/// done once every [`wait_duration`](Self::wait_duration). The system building it reads the
+/// whole world and can't run in parallel with other systems, so it is skipped by a run condition
+/// in between.
+///
+/// # See also
+///
+/// [`LogDiagnosticsPlugin`](crate::LogDiagnosticsPlugin) to output diagnostics to the console.
+pub struct EcsMemoryDiagnosticsPlugin {
+    /// How often the memory report is built.
+    pub wait_duration: Duration,
+    /// When set, the given number of largest archetypes is logged each time the report is built.
+    pub log_top: Option<usize>,
+}
+
+impl Default for EcsMemoryDiagnosticsPlugin {
+    fn default() -> Self {
+        EcsMemoryDiagnosticsPlugin {
+            wait_duration: Duration::from_secs(1),
+            log_top: None,
+        }
+    }
+}
+
+/// Settings used by the [`EcsMemoryDiagnosticsPlugin`]
+#[derive(Resource)]
+struct EcsMemoryDiagnosticsSettings {
+    wait_duration: Duration,
+    log_top: Option<usize>,
+}
+
+impl Plugin for EcsMemoryDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(EcsMemoryDiagnosticsSettings {
+            wait_duration: self.wait_duration,
+            log_top: self.log_top,
+        })
+        .register_diagnostic(Diagnostic::new(Self::ALLOCATED_BYTES).with_suffix("B"))
+        .register_diagnostic(Diagnostic::new(Self::USED_BYTES).with_suffix("B"))
+        .register_diagnostic(Diagnostic::new(Self::FRAGMENTATION).with_suffix("%"))
+        .register_diagnostic(Diagnostic::new(Self::ARCHETYPE_COUNT))
+        .add_systems(PostUpdate, Self::diagnostic_system.run_if(Self::report_due));
+    }
+}
+
+impl EcsMemoryDiagnosticsPlugin {
+    /// Bytes allocated by the tables and sparse sets of the world, in use or not.
+    pub const ALLOCATED_BYTES: DiagnosticPath =
+        DiagnosticPath::const_new("ecs_memory/allocated_bytes");
+    /// Bytes used by the components stored in the tables and sparse sets of the world.
+    pub const USED_BYTES: DiagnosticPath = DiagnosticPath::const_new("ecs_memory/used_bytes");
+    /// Percentage of the allocated bytes that are not used.
+    pub const FRAGMENTATION: DiagnosticPath = DiagnosticPath::const_new("ecs_memory/fragmentation");
+    /// Number of archetypes in the world.
+    pub const ARCHETYPE_COUNT: DiagnosticPath =
+        DiagnosticPath::const_new("ecs_memory/archetype_count");
+
+    /// Returns `true` on the first run, then once every `wait_duration`.
+    fn report_due(
+        settings: Res<EcsMemoryDiagnosticsSettings>,
+        time: Res<Time<Real>>,
+        mut elapsed: Local<Option<Duration>>,
+    ) -> bool {
+        if let Some(elapsed) = elapsed.as_mut() {
+            *elapsed += time.delta();
+            if *elapsed < settings.wait_duration {
+                return false;
+            }
+        }
+        *elapsed = Some(Duration::ZERO);
+        true
+    }
+
+    fn diagnostic_system(
+        mut diagnostics: Diagnostics,
+        world: &World,
+        settings: Res<EcsMemoryDiagnosticsSettings>,
+    ) {
+        let mut report = world.memory_report();
+        report.sort_by_bytes();
+
+        diagnostics.add_measurement(&Self::ALLOCATED_BYTES, || report.allocated_bytes() as f64);
+        diagnostics.add_measurement(&Self::USED_BYTES, || report.used_bytes() as f64);
+        diagnostics.add_measurement(&Self::FRAGMENTATION, || {
+            report.fragmentation() as f64 * 100.0
+        });
+        diagnostics.add_measurement(&Self::ARCHETYPE_COUNT, || report.archetypes.len() as f64);
+
+        if let Some(log_top) = settings.log_top {
+            for archetype in report.archetypes.iter().take(log_top) {
+                if archetype.entity_count == 0 {
+                    break;
+                }
+                let components = archetype
+                    .components
+                    .iter()
+                    .map(|component| get_short_name(&component.name))
+                    .collect::<Vec<_>>();
+                info!(
+                    "{:>12} bytes {:>8} entities  ({})",
+                    archetype.bytes,
+                    archetype.entity_count,
+                    components.join(", ")
+                );
+            }
+        }
+    }
+}
@@ -7,6 +7,7 @@
 //! their ability to monitor and optimize their game's.
 
 mod diagnostic;
+mod ecs_memory_diagnostics_plugin;
 mod entity_count_diagnostics_plugin;
 mod frame_time_diagnostics_plugin;
 mod log_diagnostics_plugin;
@@ -15,6 +16,7 @@ mod system_information_diagnostics_plugin;
 
 pub use diagnostic::*;
 
+pub use ecs_memory_diagnostics_plugin::EcsMemoryDiagnosticsPlugin;
 pub use entity_count_diagnostics_plugin::EntityCountDiagnosticsPlugin;
 pub use frame_time_diagnostics_plugin::FrameTimeDiagnosticsPlugin;
 pub use log_diagnostics_plugin::LogDiagnosticsPlugin;
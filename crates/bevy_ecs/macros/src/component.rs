@@ -1,7 +1,10 @@
 use proc_macro::TokenStream;
 use proc_macro2::{Span, TokenStream as TokenStream2};
 use quote::quote;
-use syn::{parse_macro_input, parse_quote, DeriveInput, Ident, LitStr, Path, Result};
+use syn::{
+    parse_macro_input, parse_quote, spanned::Spanned, Data, DeriveInput, Ident, LitStr, Member,
+    Path, Result, Type,
+};
 
 pub fn derive_event(input: TokenStream) -> TokenStream {
     let mut ast = parse_macro_input!(input as DeriveInput);
@@ -18,6 +21,10 @@ pub fn derive_event(input: TokenStream) -> TokenStream {
     TokenStream::from(quote! {
         impl #impl_generics #bevy_ecs_path::event::Event for #struct_name #type_generics #where_clause {
         }
+
+        impl #impl_generics #bevy_ecs_path::component::Component for #struct_name #type_generics #where_clause {
+            const STORAGE_TYPE: #bevy_ecs_path::component::StorageType = #bevy_ecs_path::component::StorageType::SparseSet;
+        }
     })
 }
 
@@ -55,21 +62,162 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
         .predicates
         .push(parse_quote! { Self: Send + Sync + 'static });
 
+    let relationship = match derive_relationship(&ast, &attrs, &bevy_ecs_path) {
+        Ok(relationship) => relationship,
+        Err(e) => return e.into_compile_error().into(),
+    };
+    let relationship_target = match derive_relationship_target(&ast, &attrs, &bevy_ecs_path) {
+        Ok(relationship_target) => relationship_target,
+        Err(e) => return e.into_compile_error().into(),
+    };
+
+    let register_component_hooks = match (&attrs.relationship, &attrs.relationship_target) {
+        (Some(_), Some(_)) => {
+            return syn::Error::new(
+                ast.span(),
+                "A component can't be both a relationship and a relationship target.",
+            )
+            .into_compile_error()
+            .into();
+        }
+        (Some(_), None) => quote! {
+            fn register_component_hooks(hooks: &mut #bevy_ecs_path::component::ComponentHooks) {
+                hooks
+                    .on_insert(<Self as #bevy_ecs_path::relationship::Relationship>::on_insert)
+                    .on_replace(<Self as #bevy_ecs_path::relationship::Relationship>::on_replace);
+            }
+        },
+        (None, Some(_)) => quote! {
+            fn register_component_hooks(hooks: &mut #bevy_ecs_path::component::ComponentHooks) {
+                hooks.on_replace(<Self as #bevy_ecs_path::relationship::RelationshipTarget>::on_replace);
+            }
+        },
+        (None, None) => quote! {},
+    };
+
     let struct_name = &ast.ident;
     let (impl_generics, type_generics, where_clause) = &ast.generics.split_for_impl();
 
     TokenStream::from(quote! {
         impl #impl_generics #bevy_ecs_path::component::Component for #struct_name #type_generics #where_clause {
             const STORAGE_TYPE: #bevy_ecs_path::component::StorageType = #storage;
+
+            #register_component_hooks
         }
+
+        #relationship
+
+        #relationship_target
     })
 }
 
+/// Returns the only field of a struct deriving a relationship component.
+fn relationship_field(ast: &DeriveInput, attribute: &str) -> Result<Member> {
+    let Data::Struct(data) = &ast.data else {
+        return Err(syn::Error::new(
+            ast.span(),
+            format!("#[{attribute}] can only be used on structs."),
+        ));
+    };
+
+    let mut members = data.fields.members();
+    let (Some(member), None) = (members.next(), members.next()) else {
+        return Err(syn::Error::new(
+            data.fields.span(),
+            format!("#[{attribute}] can only be used on structs with exactly one field."),
+        ));
+    };
+
+    Ok(member)
+}
+
+fn derive_relationship(
+    ast: &DeriveInput,
+    attrs: &Attrs,
+    bevy_ecs_path: &Path,
+) -> Result<Option<TokenStream2>> {
+    let Some(Relationship {
+        relationship_target,
+    }) = &attrs.relationship
+    else {
+        return Ok(None);
+    };
+    let member = relationship_field(ast, RELATIONSHIP)?;
+
+    let struct_name = &ast.ident;
+    let (impl_generics, type_generics, where_clause) = &ast.generics.split_for_impl();
+
+    Ok(Some(quote! {
+        impl #impl_generics #bevy_ecs_path::relationship::Relationship for #struct_name #type_generics #where_clause {
+            type RelationshipTarget = #relationship_target;
+
+            #[inline(always)]
+            fn get(&self) -> #bevy_ecs_path::entity::Entity {
+                self.#member
+            }
+
+            #[inline]
+            fn from(entity: #bevy_ecs_path::entity::Entity) -> Self {
+                Self { #member: entity }
+            }
+        }
+    }))
+}
+
+fn derive_relationship_target(
+    ast: &DeriveInput,
+    attrs: &Attrs,
+    bevy_ecs_path: &Path,
+) -> Result<Option<TokenStream2>> {
+    let Some(RelationshipTarget { relationship }) = &attrs.relationship_target else {
+        return Ok(None);
+    };
+    let member = relationship_field(ast, RELATIONSHIP_TARGET)?;
+
+    let struct_name = &ast.ident;
+    let (impl_generics, type_generics, where_clause) = &ast.generics.split_for_impl();
+
+    Ok(Some(quote! {
+        impl #impl_generics #bevy_ecs_path::relationship::RelationshipTarget for #struct_name #type_generics #where_clause {
+            type Relationship = #relationship;
+
+            #[inline]
+            fn collection(&self) -> &[#bevy_ecs_path::entity::Entity] {
+                &self.#member
+            }
+
+            #[inline]
+            fn collection_mut_risky(&mut self) -> &mut Vec<#bevy_ecs_path::entity::Entity> {
+                &mut self.#member
+            }
+
+            #[inline]
+            fn from_collection_risky(collection: Vec<#bevy_ecs_path::entity::Entity>) -> Self {
+                Self { #member: collection }
+            }
+        }
+    }))
+}
+
 pub const COMPONENT: &str = "component";
 pub const STORAGE: &str = "storage";
+pub const RELATIONSHIP: &str = "relationship";
+pub const RELATIONSHIP_TARGET: &str = "relationship_target";
 
 struct Attrs {
     storage: StorageTy,
+    relationship: Option<Relationship>,
+    relationship_target: Option<RelationshipTarget>,
+}
+
+/// Parsed `#[relationship(relationship_target = ...)]` attribute.
+struct Relationship {
+    relationship_target: Type,
+}
+
+/// Parsed `#[relationship_target(relationship = ...)]` attribute.
+struct RelationshipTarget {
+    relationship: Type,
 }
 
 #[derive(Clone, Copy)]
@@ -85,6 +233,8 @@ const SPARSE_SET: &str = "SparseSet";
 fn parse_component_attr(ast: &DeriveInput) -> Result<Attrs> {
     let mut attrs = Attrs {
         storage: StorageTy::Table,
+        relationship: None,
+        relationship_target: None,
     };
 
     for meta in ast.attrs.iter().filter(|a| a.path().is_ident(COMPONENT)) {
@@ -106,6 +256,36 @@ fn parse_component_attr(ast: &DeriveInput) -> Result<Attrs> {
         })?;
     }
 
+    for meta in ast.attrs.iter().filter(|a| a.path().is_ident(RELATIONSHIP)) {
+        meta.parse_nested_meta(|nested| {
+            if nested.path.is_ident(RELATIONSHIP_TARGET) {
+                attrs.relationship = Some(Relationship {
+                    relationship_target: nested.value()?.parse()?,
+                });
+                Ok(())
+            } else {
+                Err(nested.error("Unsupported attribute"))
+            }
+        })?;
+    }
+
+    for meta in ast
+        .attrs
+        .iter()
+        .filter(|a| a.path().is_ident(RELATIONSHIP_TARGET))
+    {
+        meta.parse_nested_meta(|nested| {
+            if nested.path.is_ident(RELATIONSHIP) {
+                attrs.relationship_target = Some(RelationshipTarget {
+                    relationship: nested.value()?.parse()?,
+                });
+                Ok(())
+            } else {
+                Err(nested.error("Unsupported attribute"))
+            }
+        })?;
+    }
+
     Ok(attrs)
 }
 
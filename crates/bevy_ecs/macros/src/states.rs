@@ -1,21 +0,0 @@
-use proc_macro::TokenStream;
-use quote::{format_ident, quote};
-use syn::{parse_macro_input, DeriveInput};
-
-use crate::bevy_ecs_path;
-
-pub fn derive_states(input: TokenStream) -> TokenStream {
-    let ast = parse_macro_input!(input as DeriveInput);
-    let generics = ast.generics;
-    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
-
-    let mut trait_path = bevy_ecs_path();
-    trait_path.segments.push(format_ident!("schedule").into());
-    trait_path.segments.push(format_ident!("States").into());
-    let struct_name = &ast.ident;
-
-    quote! {
-        impl #impl_generics #trait_path for #struct_name #ty_generics #where_clause {}
-    }
-    .into()
-}
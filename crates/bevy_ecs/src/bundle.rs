@@ -12,10 +12,11 @@ use crate::{
     },
     component::{Component, ComponentId, Components, StorageType, Tick},
     entity::{Entities, Entity, EntityLocation},
+    observer::Observers,
     prelude::World,
     query::DebugCheckedUnwrap,
     storage::{SparseSetIndex, SparseSets, Storages, Table, TableRow},
-    world::unsafe_world_cell::UnsafeWorldCell,
+    world::{unsafe_world_cell::UnsafeWorldCell, ON_ADD, ON_INSERT, ON_REPLACE},
 };
 use bevy_ptr::{ConstNonNull, OwningPtr};
 use bevy_utils::all_tuples;
@@ -427,6 +428,7 @@ impl BundleInfo {
         archetypes: &mut Archetypes,
         storages: &mut Storages,
         components: &Components,
+        observers: &Observers,
         archetype_id: ArchetypeId,
     ) -> ArchetypeId {
         if let Some(add_bundle_id) = archetypes[archetype_id].edges().get_add_bundle(self.id) {
@@ -493,6 +495,7 @@ impl BundleInfo {
             // SAFETY: ids in self must be valid
             let new_archetype_id = archetypes.get_id_or_insert(
                 components,
+                observers,
                 table_id,
                 table_components,
                 sparse_set_components,
@@ -562,6 +565,7 @@ impl<'w> BundleInserter<'w> {
             &mut world.archetypes,
             &mut world.storages,
             &world.components,
+            &world.observers,
             archetype_id,
         );
         if new_archetype_id == archetype_id {
@@ -639,6 +643,31 @@ impl<'w> BundleInserter<'w> {
     ) -> EntityLocation {
         let bundle_info = self.bundle_info.as_ref();
         let add_bundle = self.add_bundle.as_ref();
+
+        let archetype = self.archetype.as_ref();
+        if archetype.has_on_replace() || archetype.has_replace_observer() {
+            // SAFETY: No references into the world are held while the hooks and observers run,
+            // and they cannot make structural changes through a `DeferredWorld`.
+            let mut deferred_world = unsafe { self.world.into_deferred() };
+            let replaced_components = || {
+                bundle_info
+                    .iter_components()
+                    .zip(add_bundle.bundle_status.iter())
+                    .filter(|(_, &status)| status == ComponentStatus::Mutated)
+                    .map(|(id, _)| id)
+            };
+            // SAFETY: All components in the bundle are guaranteed to exist in the World
+            // as they must be initialized before creating the BundleInfo.
+            unsafe {
+                if archetype.has_on_replace() {
+                    deferred_world.trigger_on_replace(entity, replaced_components());
+                }
+                if archetype.has_replace_observer() {
+                    deferred_world.trigger_observers(ON_REPLACE, entity, replaced_components());
+                }
+            }
+        }
+
         let table = self.table.as_mut();
         let archetype = self.archetype.as_mut();
 
@@ -784,24 +813,29 @@ impl<'w> BundleInserter<'w> {
         // SAFETY: We have no outstanding mutable references to world as they were dropped
         let mut deferred_world = unsafe { self.world.into_deferred() };
 
-        if new_archetype.has_on_add() {
-            // SAFETY: All components in the bundle are guaranteed to exist in the World
-            // as they must be initialized before creating the BundleInfo.
-            unsafe {
-                deferred_world.trigger_on_add(
-                    entity,
-                    bundle_info
-                        .iter_components()
-                        .zip(add_bundle.bundle_status.iter())
-                        .filter(|(_, &status)| status == ComponentStatus::Added)
-                        .map(|(id, _)| id),
-                );
+        let added_components = || {
+            bundle_info
+                .iter_components()
+                .zip(add_bundle.bundle_status.iter())
+                .filter(|(_, &status)| status == ComponentStatus::Added)
+                .map(|(id, _)| id)
+        };
+
+        // SAFETY: All components in the bundle are guaranteed to exist in the World
+        // as they must be initialized before creating the BundleInfo.
+        unsafe {
+            if new_archetype.has_on_add() {
+                deferred_world.trigger_on_add(entity, added_components());
+            }
+            if new_archetype.has_add_observer() {
+                deferred_world.trigger_observers(ON_ADD, entity, added_components());
+            }
+            if new_archetype.has_on_insert() {
+                deferred_world.trigger_on_insert(entity, bundle_info.iter_components());
+            }
+            if new_archetype.has_insert_observer() {
+                deferred_world.trigger_observers(ON_INSERT, entity, bundle_info.iter_components());
             }
-        }
-        if new_archetype.has_on_insert() {
-            // SAFETY: All components in the bundle are guaranteed to exist in the World
-            // as they must be initialized before creating the BundleInfo.
-            unsafe { deferred_world.trigger_on_insert(entity, bundle_info.iter_components()) }
         }
 
         new_location
@@ -848,6 +882,7 @@ impl<'w> BundleSpawner<'w> {
             &mut world.archetypes,
             &mut world.storages,
             &world.components,
+            &world.observers,
             ArchetypeId::EMPTY,
         );
         let archetype = &mut world.archetypes[new_archetype_id];
@@ -905,16 +940,22 @@ impl<'w> BundleSpawner<'w> {
 
         // SAFETY: We have no outstanding mutable references to world as they were dropped
         let mut deferred_world = unsafe { self.world.into_deferred() };
-        if archetype.has_on_add() {
-            // SAFETY: All components in the bundle are guaranteed to exist in the World
-            // as they must be initialized before creating the BundleInfo.
-            unsafe { deferred_world.trigger_on_add(entity, bundle_info.iter_components()) };
-        }
-        if archetype.has_on_insert() {
-            // SAFETY: All components in the bundle are guaranteed to exist in the World
-            // as they must be initialized before creating the BundleInfo.
-            unsafe { deferred_world.trigger_on_insert(entity, bundle_info.iter_components()) };
-        }
+        // SAFETY: All components in the bundle are guaranteed to exist in the World
+        // as they must be initialized before creating the BundleInfo.
+        unsafe {
+            if archetype.has_on_add() {
+                deferred_world.trigger_on_add(entity, bundle_info.iter_components());
+            }
+            if archetype.has_add_observer() {
+                deferred_world.trigger_observers(ON_ADD, entity, bundle_info.iter_components());
+            }
+            if archetype.has_on_insert() {
+                deferred_world.trigger_on_insert(entity, bundle_info.iter_components());
+            }
+            if archetype.has_insert_observer() {
+                deferred_world.trigger_observers(ON_INSERT, entity, bundle_info.iter_components());
+            }
+        };
 
         location
     }
@@ -1161,6 +1202,34 @@ mod tests {
         assert_eq!(3, world.resource::<R>().0);
     }
 
+    #[test]
+    fn component_hook_order_replace() {
+        #[derive(Component)]
+        struct V(usize);
+
+        let mut world = World::new();
+        world.init_resource::<R>();
+        world
+            .register_component_hooks::<V>()
+            .on_add(|mut world, _, _| world.resource_mut::<R>().assert_order(0))
+            .on_insert(|mut world, entity, _| {
+                let value = world.get::<V>(entity).unwrap().0;
+                world.resource_mut::<R>().assert_order(value);
+            })
+            .on_replace(|mut world, entity, _| {
+                // The old value is still present when the hook runs
+                let value = world.get::<V>(entity).unwrap().0;
+                world.resource_mut::<R>().assert_order(value + 1);
+            })
+            .on_remove(|mut world, _, _| world.resource_mut::<R>().assert_order(5));
+
+        let mut entity = world.spawn(V(1));
+        entity.insert(V(3));
+        entity.remove::<V>();
+        entity.flush();
+        assert_eq!(6, world.resource::<R>().0);
+    }
+
     #[test]
     fn component_hook_order_recursive() {
         let mut world = World::new();
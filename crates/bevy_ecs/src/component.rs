@@ -179,7 +179,7 @@ pub enum StorageType {
     SparseSet,
 }
 
-/// The type used for [`Component`] lifecycle hooks such as `on_add`, `on_insert` or `on_remove`
+/// The type used for [`Component`] lifecycle hooks such as `on_add`, `on_insert`, `on_replace` or `on_remove`
 pub type ComponentHook = for<'w> fn(DeferredWorld<'w>, Entity, ComponentId);
 
 /// Lifecycle hooks for a given [`Component`], stored in it's [`ComponentInfo`]
@@ -187,6 +187,7 @@ pub type ComponentHook = for<'w> fn(DeferredWorld<'w>, Entity, ComponentId);
 pub struct ComponentHooks {
     pub(crate) on_add: Option<ComponentHook>,
     pub(crate) on_insert: Option<ComponentHook>,
+    pub(crate) on_replace: Option<ComponentHook>,
     pub(crate) on_remove: Option<ComponentHook>,
 }
 
@@ -211,6 +212,17 @@ impl ComponentHooks {
             .expect("Component id: {:?}, already has an on_insert hook")
     }
 
+    /// Register a [`ComponentHook`] that will be run when this component's value is about to be
+    /// dropped, either because it is overwritten by an `.insert` or because it is removed.
+    /// The old value is still present on the entity when the hook runs.
+    /// An `on_replace` hook always runs before any `on_insert` or `on_remove` hooks for the same change.
+    ///
+    /// Will panic if the component already has an `on_replace` hook
+    pub fn on_replace(&mut self, hook: ComponentHook) -> &mut Self {
+        self.try_on_replace(hook)
+            .expect("Component id: {:?}, already has an on_replace hook")
+    }
+
     /// Register a [`ComponentHook`] that will be run when this component is removed from an entity.
     /// Despawning an entity counts as removing all of it's components.
     ///
@@ -240,6 +252,16 @@ impl ComponentHooks {
         Some(self)
     }
 
+    /// Fallible version of [`Self::on_replace`].
+    /// Returns `None` if the component already has an `on_replace` hook.
+    pub fn try_on_replace(&mut self, hook: ComponentHook) -> Option<&mut Self> {
+        if self.on_replace.is_some() {
+            return None;
+        }
+        self.on_replace = Some(hook);
+        Some(self)
+    }
+
     /// Fallible version of [`Self::on_remove`].
     /// Returns `None` if the component already has an `on_remove` hook.
     pub fn try_on_remove(&mut self, hook: ComponentHook) -> Option<&mut Self> {
@@ -251,12 +273,113 @@ impl ComponentHooks {
     }
 }
 
+/// The type used for [`Resource`] lifecycle hooks such as `on_add`, `on_insert`, `on_replace` or `on_remove`
+pub type ResourceHook = for<'w> fn(DeferredWorld<'w>, ComponentId);
+
+/// Lifecycle hooks for a given [`Resource`], stored in it's [`ComponentInfo`]
+///
+/// These mirror [`ComponentHooks`]: `on_add` runs when the resource is inserted into a world that
+/// did not contain it, `on_insert` runs on every insertion, `on_replace` runs before the current
+/// value is overwritten or removed and `on_remove` runs before the resource is removed.
+/// Hooks are not run by [`World::clear_resources`](crate::world::World::clear_resources) or
+/// [`World::resource_scope`](crate::world::World::resource_scope).
+///
+/// Only [`Resource`]s have hooks: [non-send resources](crate::system::NonSend) are inserted and
+/// removed without running any hook.
+#[derive(Debug, Clone, Default)]
+pub struct ResourceHooks {
+    pub(crate) on_add: Option<ResourceHook>,
+    pub(crate) on_insert: Option<ResourceHook>,
+    pub(crate) on_replace: Option<ResourceHook>,
+    pub(crate) on_remove: Option<ResourceHook>,
+}
+
+impl ResourceHooks {
+    /// Register a [`ResourceHook`] that will be run when this resource is added to the world.
+    /// An `on_add` hook will always run before `on_insert` hooks.
+    ///
+    /// Will panic if the resource already has an `on_add` hook
+    pub fn on_add(&mut self, hook: ResourceHook) -> &mut Self {
+        self.try_on_add(hook)
+            .expect("Resource already has an on_add hook")
+    }
+
+    /// Register a [`ResourceHook`] that will be run every time this resource is inserted,
+    /// whether or not it was already present. The hook won't run if the resource is only mutated.
+    ///
+    /// Will panic if the resource already has an `on_insert` hook
+    pub fn on_insert(&mut self, hook: ResourceHook) -> &mut Self {
+        self.try_on_insert(hook)
+            .expect("Resource already has an on_insert hook")
+    }
+
+    /// Register a [`ResourceHook`] that will be run when this resource's value is about to be
+    /// dropped, either because it is overwritten by an insertion or because it is removed.
+    /// The old value is still present in the world when the hook runs.
+    ///
+    /// Will panic if the resource already has an `on_replace` hook
+    pub fn on_replace(&mut self, hook: ResourceHook) -> &mut Self {
+        self.try_on_replace(hook)
+            .expect("Resource already has an on_replace hook")
+    }
+
+    /// Register a [`ResourceHook`] that will be run when this resource is removed from the world.
+    /// The value is still present in the world when the hook runs.
+    ///
+    /// Will panic if the resource already has an `on_remove` hook
+    pub fn on_remove(&mut self, hook: ResourceHook) -> &mut Self {
+        self.try_on_remove(hook)
+            .expect("Resource already has an on_remove hook")
+    }
+
+    /// Fallible version of [`Self::on_add`].
+    /// Returns `None` if the resource already has an `on_add` hook.
+    pub fn try_on_add(&mut self, hook: ResourceHook) -> Option<&mut Self> {
+        if self.on_add.is_some() {
+            return None;
+        }
+        self.on_add = Some(hook);
+        Some(self)
+    }
+
+    /// Fallible version of [`Self::on_insert`].
+    /// Returns `None` if the resource already has an `on_insert` hook.
+    pub fn try_on_insert(&mut self, hook: ResourceHook) -> Option<&mut Self> {
+        if self.on_insert.is_some() {
+            return None;
+        }
+        self.on_insert = Some(hook);
+        Some(self)
+    }
+
+    /// Fallible version of [`Self::on_replace`].
+    /// Returns `None` if the resource already has an `on_replace` hook.
+    pub fn try_on_replace(&mut self, hook: ResourceHook) -> Option<&mut Self> {
+        if self.on_replace.is_some() {
+            return None;
+        }
+        self.on_replace = Some(hook);
+        Some(self)
+    }
+
+    /// Fallible version of [`Self::on_remove`].
+    /// Returns `None` if the resource already has an `on_remove` hook.
+    pub fn try_on_remove(&mut self, hook: ResourceHook) -> Option<&mut Self> {
+        if self.on_remove.is_some() {
+            return None;
+        }
+        self.on_remove = Some(hook);
+        Some(self)
+    }
+}
+
 /// Stores metadata for a type of component or resource stored in a specific [`World`].
 #[derive(Debug, Clone)]
 pub struct ComponentInfo {
     id: ComponentId,
     descriptor: ComponentDescriptor,
     hooks: ComponentHooks,
+    resource_hooks: ResourceHooks,
 }
 
 impl ComponentInfo {
@@ -316,6 +439,7 @@ impl ComponentInfo {
             id,
             descriptor,
             hooks: ComponentHooks::default(),
+            resource_hooks: ResourceHooks::default(),
         }
     }
 
@@ -328,6 +452,9 @@ impl ComponentInfo {
         if self.hooks().on_insert.is_some() {
             flags.insert(ArchetypeFlags::ON_INSERT_HOOK);
         }
+        if self.hooks().on_replace.is_some() {
+            flags.insert(ArchetypeFlags::ON_REPLACE_HOOK);
+        }
         if self.hooks().on_remove.is_some() {
             flags.insert(ArchetypeFlags::ON_REMOVE_HOOK);
         }
@@ -337,6 +464,11 @@ impl ComponentInfo {
     pub fn hooks(&self) -> &ComponentHooks {
         &self.hooks
     }
+
+    /// Provides a reference to the collection of hooks associated with this [`Resource`]
+    pub fn resource_hooks(&self) -> &ResourceHooks {
+        &self.resource_hooks
+    }
 }
 
 /// A value which uniquely identifies the type of a [`Component`] of [`Resource`] within a
@@ -472,6 +604,23 @@ impl ComponentDescriptor {
         }
     }
 
+    /// Create a new `ComponentDescriptor` for a runtime-defined component whose value is a
+    /// [`DynamicStruct`](bevy_reflect::DynamicStruct).
+    ///
+    /// The [`TypeId`] of `DynamicStruct` is recorded so that the component can later be
+    /// safely accessed through reflection.
+    #[cfg(feature = "bevy_reflect")]
+    pub(crate) fn new_dynamic_struct(name: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            name: name.into(),
+            storage_type: StorageType::Table,
+            is_send_and_sync: true,
+            type_id: Some(TypeId::of::<bevy_reflect::DynamicStruct>()),
+            layout: Layout::new::<bevy_reflect::DynamicStruct>(),
+            drop: Some(Self::drop_ptr::<bevy_reflect::DynamicStruct> as _),
+        }
+    }
+
     /// Create a new `ComponentDescriptor` for a resource.
     ///
     /// The [`StorageType`] for resources is always [`StorageType::Table`].
@@ -634,6 +783,13 @@ impl Components {
         self.components.get_mut(id.0).map(|info| &mut info.hooks)
     }
 
+    #[inline]
+    pub(crate) fn get_resource_hooks_mut(&mut self, id: ComponentId) -> Option<&mut ResourceHooks> {
+        self.components
+            .get_mut(id.0)
+            .map(|info| &mut info.resource_hooks)
+    }
+
     /// Type-erased equivalent of [`Components::component_id()`].
     #[inline]
     pub fn get_id(&self, type_id: TypeId) -> Option<ComponentId> {
@@ -718,12 +874,17 @@ impl Components {
     /// * [`Components::resource_id()`]
     #[inline]
     pub fn init_resource<T: Resource>(&mut self) -> ComponentId {
+        let is_new = !self.resource_indices.contains_key(&TypeId::of::<T>());
         // SAFETY: The [`ComponentDescriptor`] matches the [`TypeId`]
-        unsafe {
+        let component_id = unsafe {
             self.get_or_insert_resource_with(TypeId::of::<T>(), || {
                 ComponentDescriptor::new_resource::<T>()
             })
+        };
+        if is_new {
+            T::register_resource_hooks(&mut self.components[component_id.index()].resource_hooks);
         }
+        component_id
     }
 
     /// Initializes a [non-send resource](crate::system::NonSend) of type `T` with this instance.
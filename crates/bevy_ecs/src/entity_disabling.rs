@@ -0,0 +1,231 @@
+//! Disabled entities do not show up in queries unless the query explicitly asks for them.
+//!
+//! An entity is disabled by inserting the [`Disabled`] component on it. Every [`Query`] which does
+//! not mention [`Disabled`] (through `&Disabled`, `Option<&Disabled>`, [`With<Disabled>`],
+//! [`Without<Disabled>`], [`Has<Disabled>`], ...) behaves as if it had a `Without<Disabled>` filter,
+//! so disabled entities are skipped without having to strip their other components.
+//!
+//! ```
+//! # use bevy_ecs::prelude::*;
+//! use bevy_ecs::entity_disabling::Disabled;
+//!
+//! #[derive(Component)]
+//! struct Enemy;
+//!
+//! let mut world = World::new();
+//! world.spawn(Enemy);
+//! // Keep a pooled enemy around without it being picked up by gameplay systems.
+//! let pooled = world.spawn((Enemy, Disabled)).id();
+//!
+//! assert_eq!(world.query_filtered::<(), With<Enemy>>().iter(&world).count(), 1);
+//! assert_eq!(
+//!     world.query_filtered::<Entity, (With<Enemy>, With<Disabled>)>().single(&world),
+//!     pooled
+//! );
+//!
+//! // Enabling it again is just a matter of removing the marker.
+//! world.entity_mut(pooled).remove::<Disabled>();
+//! assert_eq!(world.query_filtered::<(), With<Enemy>>().iter(&world).count(), 2);
+//! ```
+//!
+//! Other components can behave the same way by registering them in the [`DefaultQueryFilters`]
+//! resource. Default filters are applied when a [`QueryState`] is created, so they should be
+//! registered before any query that should respect them is built.
+//!
+//! [`Query`]: crate::system::Query
+//! [`QueryState`]: crate::query::QueryState
+//! [`With<Disabled>`]: crate::query::With
+//! [`Without<Disabled>`]: crate::query::Without
+//! [`Has<Disabled>`]: crate::query::Has
+
+use crate::{
+    self as bevy_ecs,
+    component::{Component, ComponentId},
+    query::FilteredAccess,
+    system::Resource,
+};
+#[cfg(feature = "bevy_reflect")]
+use bevy_reflect::Reflect;
+
+/// A marker component for disabled entities.
+///
+/// Queries skip entities with this component unless they explicitly mention it.
+/// See the [module docs](crate::entity_disabling) for more details.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Debug, PartialEq))]
+pub struct Disabled;
+
+/// The components that every query filters out by default.
+///
+/// A [`QueryState`](crate::query::QueryState) gets an implicit `Without<C>` filter for each
+/// registered component `C` it does not access or filter on itself. [`Disabled`] is registered
+/// when the [`World`](crate::world::World) is created.
+#[derive(Resource, Debug, Default, Clone)]
+pub struct DefaultQueryFilters {
+    disabling: Vec<ComponentId>,
+}
+
+impl DefaultQueryFilters {
+    /// Registers a component which disables the entities it is on.
+    ///
+    /// Only queries created after this call are affected.
+    pub fn register_disabling_component(&mut self, component_id: ComponentId) {
+        if !self.disabling.contains(&component_id) {
+            self.disabling.push(component_id);
+        }
+    }
+
+    /// Returns the ids of the registered disabling components.
+    pub fn disabling_ids(&self) -> impl Iterator<Item = ComponentId> + '_ {
+        self.disabling.iter().copied()
+    }
+
+    /// Adds a `Without` filter to `component_access` for each disabling component it does not mention.
+    pub fn modify_access(&self, component_access: &mut FilteredAccess<ComponentId>) {
+        for component_id in self.disabling_ids() {
+            if !component_access.contains(component_id) {
+                component_access.and_without(component_id);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        entity::Entity,
+        prelude::{Has, QueryBuilder, Schedule, With, Without, World},
+        query::{Or, QueryState},
+        system::{Query, ResMut},
+        world::EntityRef,
+    };
+
+    #[derive(Component)]
+    struct A;
+
+    #[derive(Component)]
+    struct Prefab;
+
+    #[test]
+    fn disabled_entities_are_skipped_by_default() {
+        let mut world = World::new();
+        let enabled = world.spawn(A).id();
+        world.spawn((A, Disabled));
+
+        assert_eq!(
+            world.query::<Entity>().iter(&world).collect::<Vec<_>>(),
+            vec![enabled]
+        );
+        assert_eq!(
+            world.query_filtered::<Entity, With<A>>().single(&world),
+            enabled
+        );
+        assert_eq!(world.query::<EntityRef>().iter(&world).count(), 1);
+        assert_eq!(
+            QueryBuilder::<Entity>::new(&mut world)
+                .with::<A>()
+                .build()
+                .iter(&world)
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn queries_mentioning_disabled_see_disabled_entities() {
+        let mut world = World::new();
+        world.spawn(A);
+        let disabled = world.spawn((A, Disabled)).id();
+
+        assert_eq!(
+            world
+                .query_filtered::<Entity, With<Disabled>>()
+                .single(&world),
+            disabled
+        );
+        assert_eq!(world.query::<&Disabled>().iter(&world).count(), 1);
+        assert_eq!(world.query::<Option<&Disabled>>().iter(&world).count(), 2);
+        assert_eq!(
+            world
+                .query_filtered::<(), Without<Disabled>>()
+                .iter(&world)
+                .count(),
+            1
+        );
+        assert_eq!(
+            world
+                .query_filtered::<(), Or<(With<A>, With<Disabled>)>>()
+                .iter(&world)
+                .count(),
+            2
+        );
+
+        let mut query = world.query::<(Entity, Has<Disabled>)>();
+        let mut results = query.iter(&world).collect::<Vec<_>>();
+        results.sort_by_key(|(_, has)| *has);
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[1], (disabled, true));
+    }
+
+    #[test]
+    fn systems_skip_disabled_entities() {
+        let mut world = World::new();
+        world.spawn(A);
+        world.spawn((A, Disabled));
+
+        #[derive(Resource, Default)]
+        struct Counts(usize, usize);
+        world.init_resource::<Counts>();
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(
+            |all: Query<&A>, disabled: Query<&A, With<Disabled>>, mut counts: ResMut<Counts>| {
+                counts.0 = all.iter().count();
+                counts.1 = disabled.iter().count();
+            },
+        );
+        schedule.run(&mut world);
+
+        let counts = world.resource::<Counts>();
+        assert_eq!((counts.0, counts.1), (1, 1));
+    }
+
+    #[test]
+    fn custom_disabling_component() {
+        let mut world = World::new();
+        let prefab = world.init_component::<Prefab>();
+        world
+            .resource_mut::<DefaultQueryFilters>()
+            .register_disabling_component(prefab);
+
+        world.spawn(A);
+        world.spawn((A, Prefab));
+        world.spawn((A, Disabled));
+
+        assert_eq!(world.query::<&A>().iter(&world).count(), 1);
+        assert_eq!(
+            world
+                .query_filtered::<&A, With<Prefab>>()
+                .iter(&world)
+                .count(),
+            1
+        );
+        assert_eq!(
+            QueryState::<&A, Or<(With<Prefab>, With<Disabled>)>>::new(&mut world)
+                .iter(&world)
+                .count(),
+            2
+        );
+    }
+
+    #[test]
+    fn no_default_filters_without_resource() {
+        let mut world = World::new();
+        world.remove_resource::<DefaultQueryFilters>();
+        world.spawn(A);
+        world.spawn((A, Disabled));
+
+        assert_eq!(world.query::<&A>().iter(&world).count(), 2);
+    }
+}
@@ -0,0 +1,133 @@
+//! Contains error types and error handlers for fallible systems and commands.
+//!
+//! Systems returning a [`Result`] can be added to schedules like any other system, and commands
+//! returning a [`Result`] can be queued with [`Commands::queue`](crate::system::Commands::queue).
+//! When they fail, the error is passed to an [`ErrorHandler`] along with an [`ErrorContext`]
+//! describing where the error comes from.
+//!
+//! The handler used for a [`World`](crate::world::World) is stored in the [`DefaultErrorHandler`]
+//! resource, and defaults to [`panic`] if the resource is missing.
+//!
+//! Since systems may return either `()` or a [`Result`], the return type of closures that never
+//! return, such as `|| panic!()` or `|| todo!()`, can't be inferred anymore when they are added as
+//! systems. Annotate them with the unit return type instead: `|| -> () { todo!() }`.
+//!
+//! ```
+//! # use bevy_ecs::prelude::*;
+//! use bevy_ecs::error::{self, DefaultErrorHandler, Result};
+//!
+//! #[derive(Resource)]
+//! struct Config(String);
+//!
+//! fn parse_config(config: Res<Config>) -> Result {
+//!     let value: u32 = config.0.parse()?;
+//!     println!("Parsed {value}");
+//!     Ok(())
+//! }
+//!
+//! let mut world = World::new();
+//! world.insert_resource(Config("not a number".to_string()));
+//! // Log errors instead of panicking.
+//! world.insert_resource(DefaultErrorHandler(error::error));
+//!
+//! let mut schedule = Schedule::default();
+//! schedule.add_systems(parse_config);
+//! schedule.run(&mut world);
+//! ```
+
+use std::{borrow::Cow, fmt};
+
+use bevy_utils::tracing::{error, warn};
+use thiserror::Error;
+
+use crate::{self as bevy_ecs, entity::Entity, system::Resource};
+
+/// A type-erased error, which any [`std::error::Error`] that is `Send + Sync` can be converted into.
+///
+/// This makes it possible to use `?` on any error in fallible systems and commands.
+pub type BoxedError = Box<dyn std::error::Error + Send + Sync + 'static>;
+
+/// A result type for fallible systems and commands, with a [`BoxedError`] by default.
+pub type Result<T = (), E = BoxedError> = std::result::Result<T, E>;
+
+/// Describes where an error passed to an [`ErrorHandler`] comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ErrorContext {
+    /// The error was returned by a system.
+    System {
+        /// The name of the system.
+        name: Cow<'static, str>,
+    },
+    /// The error was returned by a command.
+    Command {
+        /// The name of the command type, or the location where it was queued for closures,
+        /// such as `{closure@src/main.rs:4:18}`.
+        name: Cow<'static, str>,
+    },
+}
+
+impl ErrorContext {
+    /// Returns the name of the system or command that returned the error.
+    pub fn name(&self) -> &str {
+        match self {
+            ErrorContext::System { name } | ErrorContext::Command { name } => name,
+        }
+    }
+
+    /// Returns a short description of the kind of source, such as `"system"` or `"command"`.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            ErrorContext::System { .. } => "system",
+            ErrorContext::Command { .. } => "command",
+        }
+    }
+}
+
+impl fmt::Display for ErrorContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} `{}`", self.kind(), self.name())
+    }
+}
+
+/// A function which handles an error returned by a fallible system or command.
+///
+/// Bevy provides [`panic`], [`error`], [`warn`] and [`ignore`], but any function with this
+/// signature can be used.
+pub type ErrorHandler = fn(BoxedError, ErrorContext);
+
+/// The [`ErrorHandler`] used for fallible systems and commands that fail in a world.
+///
+/// If this resource is not present, errors are handled with [`panic`]. Every world has its own
+/// handler: in an app with sub apps, `App::set_error_handler` sets it in each of their worlds.
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct DefaultErrorHandler(pub ErrorHandler);
+
+impl Default for DefaultErrorHandler {
+    fn default() -> Self {
+        Self(panic)
+    }
+}
+
+/// An [`ErrorHandler`] which panics with the error and its context.
+#[track_caller]
+pub fn panic(error: BoxedError, context: ErrorContext) {
+    panic!("Encountered an error in {context}: {error}");
+}
+
+/// An [`ErrorHandler`] which logs the error at the error level.
+pub fn error(error: BoxedError, context: ErrorContext) {
+    error!("Encountered an error in {context}: {error}");
+}
+
+/// An [`ErrorHandler`] which logs the error at the warn level.
+pub fn warn(error: BoxedError, context: ErrorContext) {
+    warn!("Encountered an error in {context}: {error}");
+}
+
+/// An [`ErrorHandler`] which silently ignores the error.
+pub fn ignore(_: BoxedError, _: ErrorContext) {}
+
+/// An error returned by a fallible entity command when its target entity does not exist.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+#[error("The entity {0:?} does not exist")]
+pub struct EntityDoesNotExistError(pub Entity);
@@ -1,7 +1,10 @@
 //! Event handling types.
 
 use crate as bevy_ecs;
-use crate::system::{Local, Res, ResMut, Resource, SystemParam};
+use crate::{
+    component::Component,
+    system::{Local, Res, ResMut, Resource, SystemParam},
+};
 pub use bevy_ecs_macros::Event;
 use bevy_ecs_macros::SystemSet;
 use bevy_utils::detailed_trace;
@@ -12,14 +15,17 @@ use std::{
     hash::{Hash, Hasher},
     iter::Chain,
     marker::PhantomData,
-    slice::Iter,
+    slice::{Iter, IterMut},
 };
 
 /// A type that can be stored in an [`Events<E>`] resource
-/// You can conveniently access events using the [`EventReader`] and [`EventWriter`] system parameter.
+/// You can conveniently access events using the [`EventReader`], [`EventWriter`] and [`EventMutator`] system parameters.
 ///
 /// Events must be thread-safe.
-pub trait Event: Send + Sync + 'static {}
+///
+/// Events are also [`Component`]s so that they can be given a [`ComponentId`](crate::component::ComponentId)
+/// and used as the event type of an [`Observer`](crate::observer::Observer).
+pub trait Event: Component {}
 
 /// An `EventId` uniquely identifies an event stored in a specific [`World`].
 ///
@@ -157,7 +163,8 @@ struct EventInstance<E: Event> {
 /// An alternative call pattern would be to call [`update`](Events::update)
 /// manually across frames to control when events are cleared.
 /// This complicates consumption and risks ever-expanding memory usage if not cleaned up,
-/// but can be done by adding your event as a resource instead of using
+/// but can be done by turning off automatic updates with [`Events::set_auto_update`],
+/// or by adding your event as a resource instead of using
 /// [`add_event`](https://docs.rs/bevy/*/bevy/app/struct.App.html#method.add_event).
 ///
 /// [Example usage.](https://github.com/bevyengine/bevy/blob/latest/examples/ecs/event.rs)
@@ -171,6 +178,7 @@ pub struct Events<E: Event> {
     /// Holds the newer events.
     events_b: EventSequence<E>,
     event_count: usize,
+    auto_update: bool,
 }
 
 // Derived Default impl would incorrectly require E: Default
@@ -180,6 +188,7 @@ impl<E: Event> Default for Events<E> {
             events_a: Default::default(),
             events_b: Default::default(),
             event_count: Default::default(),
+            auto_update: true,
         }
     }
 }
@@ -279,6 +288,40 @@ impl<E: Event> Events<E> {
         self.events_b.start_event_count = self.event_count;
     }
 
+    /// Returns `true` if these events are updated by [`event_update_system`].
+    ///
+    /// This is `true` by default.
+    #[inline]
+    pub fn auto_update(&self) -> bool {
+        self.auto_update
+    }
+
+    /// Sets whether these events are updated by [`event_update_system`].
+    ///
+    /// Turning automatic updates off makes you responsible for calling [`Events::update`].
+    /// This is useful when events must outlive the usual two frames, for example events sent in
+    /// `Update` and read in `FixedUpdate`, which may not run every frame:
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #[derive(Event)]
+    /// struct Jump;
+    ///
+    /// let mut world = World::new();
+    /// world.init_resource::<Events<Jump>>();
+    /// world.resource_mut::<Events<Jump>>().set_auto_update(false);
+    ///
+    /// // Run this in `FixedUpdate`, after all readers.
+    /// fn clear_jumps(mut jumps: ResMut<Events<Jump>>) {
+    ///     jumps.update();
+    /// }
+    /// # bevy_ecs::system::assert_is_system(clear_jumps);
+    /// ```
+    #[inline]
+    pub fn set_auto_update(&mut self, auto_update: bool) {
+        self.auto_update = auto_update;
+    }
+
     /// Removes all events.
     #[inline]
     pub fn clear(&mut self) {
@@ -475,6 +518,73 @@ impl<'w, 's, E: Event> EventReader<'w, 's, E> {
     }
 }
 
+/// Mutably reads events of type `T` in order and tracks which events have already been read.
+///
+/// Events are modified in place, so [`EventReader`]s which have not read an event yet will
+/// see the modified version. An `EventMutator` keeps its own cursor: reading events with it
+/// does not mark them as read for any [`EventReader`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// #[derive(Event, Debug)]
+/// pub struct Damage(u32);
+///
+/// #[derive(Resource)]
+/// struct Armor(u32);
+///
+/// fn apply_armor(mut damage: EventMutator<Damage>, armor: Res<Armor>) {
+///     for damage in damage.read() {
+///         damage.0 = damage.0.saturating_sub(armor.0);
+///     }
+/// }
+/// # bevy_ecs::system::assert_is_system(apply_armor);
+/// ```
+///
+/// # Concurrency
+///
+/// `EventMutator` param has [`ResMut<Events<T>>`](Events) inside, so systems with an
+/// `EventMutator<T>` are not executed concurrently with systems that read or send events
+/// of the same type.
+#[derive(SystemParam, Debug)]
+pub struct EventMutator<'w, 's, E: Event> {
+    reader: Local<'s, ManualEventReader<E>>,
+    events: ResMut<'w, Events<E>>,
+}
+
+impl<'w, 's, E: Event> EventMutator<'w, 's, E> {
+    /// Iterates mutably over the events this [`EventMutator`] has not seen yet. This updates the
+    /// [`EventMutator`]'s event counter, which means subsequent event reads will not include events
+    /// that happened before now.
+    pub fn read(&mut self) -> EventMutIterator<'_, E> {
+        self.reader.read_mut(&mut self.events)
+    }
+
+    /// Like [`read`](Self::read), except also returning the [`EventId`] of the events.
+    pub fn read_with_id(&mut self) -> EventMutIteratorWithId<'_, E> {
+        self.reader.read_mut_with_id(&mut self.events)
+    }
+
+    /// Determines the number of events available to be read from this [`EventMutator`] without consuming any.
+    pub fn len(&self) -> usize {
+        self.reader.len(&self.events)
+    }
+
+    /// Returns `true` if there are no events available to read.
+    pub fn is_empty(&self) -> bool {
+        self.reader.is_empty(&self.events)
+    }
+
+    /// Consumes all available events.
+    ///
+    /// This means these events will not appear in calls to [`EventMutator::read()`] or
+    /// [`EventMutator::read_with_id()`] and [`EventMutator::is_empty()`] will return `true`.
+    pub fn clear(&mut self) {
+        self.reader.clear(&self.events);
+    }
+}
+
 /// Sends events of type `T`.
 ///
 /// # Usage
@@ -626,6 +736,19 @@ impl<E: Event> ManualEventReader<E> {
         EventIteratorWithId::new(self, events)
     }
 
+    /// See [`EventMutator::read`]
+    pub fn read_mut<'a>(&'a mut self, events: &'a mut Events<E>) -> EventMutIterator<'a, E> {
+        self.read_mut_with_id(events).without_id()
+    }
+
+    /// See [`EventMutator::read_with_id`]
+    pub fn read_mut_with_id<'a>(
+        &'a mut self,
+        events: &'a mut Events<E>,
+    ) -> EventMutIteratorWithId<'a, E> {
+        EventMutIteratorWithId::new(self, events)
+    }
+
     /// See [`EventReader::len`]
     pub fn len(&self, events: &Events<E>) -> usize {
         // The number of events in this reader is the difference between the most recent event
@@ -789,6 +912,141 @@ impl<'a, E: Event> ExactSizeIterator for EventIteratorWithId<'a, E> {
     }
 }
 
+/// An iterator that mutably yields any unread events from an [`EventMutator`] or [`ManualEventReader`].
+#[derive(Debug)]
+pub struct EventMutIterator<'a, E: Event> {
+    iter: EventMutIteratorWithId<'a, E>,
+}
+
+impl<'a, E: Event> Iterator for EventMutIterator<'a, E> {
+    type Item = &'a mut E;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(event, _)| event)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    fn count(self) -> usize {
+        self.iter.count()
+    }
+
+    fn last(self) -> Option<Self::Item>
+    where
+        Self: Sized,
+    {
+        self.iter.last().map(|(event, _)| event)
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.iter.nth(n).map(|(event, _)| event)
+    }
+}
+
+impl<'a, E: Event> ExactSizeIterator for EventMutIterator<'a, E> {
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+/// An iterator that mutably yields any unread events (and their IDs) from an [`EventMutator`] or [`ManualEventReader`].
+#[derive(Debug)]
+pub struct EventMutIteratorWithId<'a, E: Event> {
+    reader: &'a mut ManualEventReader<E>,
+    chain: Chain<IterMut<'a, EventInstance<E>>, IterMut<'a, EventInstance<E>>>,
+    unread: usize,
+}
+
+impl<'a, E: Event> EventMutIteratorWithId<'a, E> {
+    /// Creates a new iterator that mutably yields any `events` that have not yet been seen by `reader`.
+    pub fn new(reader: &'a mut ManualEventReader<E>, events: &'a mut Events<E>) -> Self {
+        let a_index = reader
+            .last_event_count
+            .saturating_sub(events.events_a.start_event_count);
+        let b_index = reader
+            .last_event_count
+            .saturating_sub(events.events_b.start_event_count);
+        let expected_unread = reader.len(events);
+        let event_count = events.event_count;
+        let a = events.events_a.get_mut(a_index..).unwrap_or_default();
+        let b = events.events_b.get_mut(b_index..).unwrap_or_default();
+
+        let unread_count = a.len() + b.len();
+        // Ensure `len` is implemented correctly
+        debug_assert_eq!(unread_count, expected_unread);
+        reader.last_event_count = event_count - unread_count;
+        // Iterate the oldest first, then the newer events
+        let chain = a.iter_mut().chain(b.iter_mut());
+
+        Self {
+            reader,
+            chain,
+            unread: unread_count,
+        }
+    }
+
+    /// Iterate over only the events.
+    pub fn without_id(self) -> EventMutIterator<'a, E> {
+        EventMutIterator { iter: self }
+    }
+}
+
+impl<'a, E: Event> Iterator for EventMutIteratorWithId<'a, E> {
+    type Item = (&'a mut E, EventId<E>);
+    fn next(&mut self) -> Option<Self::Item> {
+        match self
+            .chain
+            .next()
+            .map(|instance| (&mut instance.event, instance.event_id))
+        {
+            Some(item) => {
+                detailed_trace!("EventMutator::iter() -> {}", item.1);
+                self.reader.last_event_count += 1;
+                self.unread -= 1;
+                Some(item)
+            }
+            None => None,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.chain.size_hint()
+    }
+
+    fn count(self) -> usize {
+        self.reader.last_event_count += self.unread;
+        self.unread
+    }
+
+    fn last(self) -> Option<Self::Item>
+    where
+        Self: Sized,
+    {
+        let EventInstance { event_id, event } = self.chain.last()?;
+        self.reader.last_event_count += self.unread;
+        Some((event, *event_id))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        if let Some(EventInstance { event_id, event }) = self.chain.nth(n) {
+            self.reader.last_event_count += n + 1;
+            self.unread -= n + 1;
+            Some((event, *event_id))
+        } else {
+            self.reader.last_event_count += self.unread;
+            self.unread = 0;
+            None
+        }
+    }
+}
+
+impl<'a, E: Event> ExactSizeIterator for EventMutIteratorWithId<'a, E> {
+    fn len(&self) -> usize {
+        self.unread
+    }
+}
+
 #[doc(hidden)]
 #[derive(Resource, Default)]
 pub struct EventUpdateSignal(bool);
@@ -812,10 +1070,15 @@ pub fn reset_event_update_signal_system(signal: Option<ResMut<EventUpdateSignal>
 }
 
 /// A system that calls [`Events::update`].
+///
+/// Events with [automatic updates](Events::set_auto_update) turned off are not updated.
 pub fn event_update_system<T: Event>(
     update_signal: Option<Res<EventUpdateSignal>>,
     mut events: ResMut<Events<T>>,
 ) {
+    if !events.auto_update() {
+        return;
+    }
     if let Some(signal) = update_signal {
         // If we haven't got a signal to update the events, but we *could* get such a signal
         // return early and update the events later.
@@ -829,8 +1092,10 @@ pub fn event_update_system<T: Event>(
 
 /// A run condition that checks if the event's [`event_update_system`]
 /// needs to run or not.
+///
+/// Events with [automatic updates](Events::set_auto_update) turned off are never updated.
 pub fn event_update_condition<T: Event>(events: Res<Events<T>>) -> bool {
-    !events.events_a.is_empty() || !events.events_b.is_empty()
+    events.auto_update && (!events.events_a.is_empty() || !events.events_b.is_empty())
 }
 
 /// [`Iterator`] over sent [`EventIds`](`EventId`) from a batch.
@@ -1263,4 +1528,98 @@ mod tests {
             "Only sent two events; got more than two IDs"
         );
     }
+
+    #[test]
+    fn test_event_mutator() {
+        let mut events = Events::<TestEvent>::default();
+        let mut reader = events.get_reader();
+        let mut mutator = events.get_reader();
+
+        events.send(TestEvent { i: 0 });
+        events.send(TestEvent { i: 1 });
+        for event in mutator.read_mut(&mut events) {
+            event.i += 10;
+        }
+        assert_eq!(mutator.read_mut(&mut events).count(), 0);
+
+        // Readers have their own cursor and see the mutated events.
+        assert_eq!(
+            get_events(&events, &mut reader),
+            vec![TestEvent { i: 10 }, TestEvent { i: 11 }]
+        );
+
+        events.update();
+        events.send(TestEvent { i: 2 });
+        let (event, id) = mutator.read_mut_with_id(&mut events).last().unwrap();
+        event.i = 12;
+        assert_eq!(id.id, 2);
+        assert_eq!(get_events(&events, &mut reader), vec![TestEvent { i: 12 }]);
+    }
+
+    #[test]
+    fn test_event_mutator_system() {
+        use bevy_ecs::prelude::*;
+
+        let mut world = World::new();
+        world.init_resource::<Events<TestEvent>>();
+        world.send_event(TestEvent { i: 1 });
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(
+            (
+                |mut events: EventMutator<TestEvent>| {
+                    assert_eq!(events.len(), 1);
+                    for event in events.read() {
+                        event.i *= 3;
+                    }
+                    assert!(events.is_empty());
+                },
+                |mut events: EventReader<TestEvent>| {
+                    assert_eq!(events.read().next(), Some(&TestEvent { i: 3 }));
+                },
+            )
+                .chain(),
+        );
+        schedule.run(&mut world);
+    }
+
+    #[test]
+    fn test_events_without_auto_update() {
+        use bevy_ecs::prelude::*;
+
+        let mut world = World::new();
+        world.init_resource::<Events<TestEvent>>();
+        world
+            .resource_mut::<Events<TestEvent>>()
+            .set_auto_update(false);
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(
+            event_update_system::<TestEvent>.run_if(event_update_condition::<TestEvent>),
+        );
+
+        world.send_event(TestEvent { i: 0 });
+        schedule.run(&mut world);
+        schedule.run(&mut world);
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<Events<TestEvent>>().len(), 1);
+
+        world
+            .resource_mut::<Events<TestEvent>>()
+            .set_auto_update(true);
+        schedule.run(&mut world);
+        schedule.run(&mut world);
+        assert!(world.resource::<Events<TestEvent>>().is_empty());
+
+        // The system doesn't update the events without the run condition either.
+        world
+            .resource_mut::<Events<TestEvent>>()
+            .set_auto_update(false);
+        let mut schedule = Schedule::default();
+        schedule.add_systems(event_update_system::<TestEvent>);
+        world.send_event(TestEvent { i: 1 });
+        schedule.run(&mut world);
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<Events<TestEvent>>().len(), 1);
+    }
 }
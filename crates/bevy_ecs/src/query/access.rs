@@ -494,6 +494,20 @@ impl<T: SparseSetIndex> FilteredAccess<T> {
         self.required.is_subset(&other.required) && self.access().is_subset(other.access())
     }
 
+    /// Returns `true` if the element given by `index` is explicitly accessed or filtered on,
+    /// either through a read, a write, an archetypal access or a `With`/`Without` filter.
+    ///
+    /// Unlike [`Access::has_read`], this ignores accesses to all elements (e.g. `EntityRef`).
+    pub fn contains(&self, index: T) -> bool {
+        let index = index.sparse_set_index();
+        self.access.reads_and_writes.contains(index)
+            || self.access.archetypal.contains(index)
+            || self
+                .filter_sets
+                .iter()
+                .any(|f| f.with.contains(index) || f.without.contains(index))
+    }
+
     /// Returns the indices of the elements that this access filters for.
     pub fn with_filters(&self) -> impl Iterator<Item = T> + '_ {
         self.filter_sets
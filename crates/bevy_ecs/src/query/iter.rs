@@ -6,7 +6,7 @@ use crate::{
     storage::{Table, TableId, TableRow, Tables},
     world::unsafe_world_cell::UnsafeWorldCell,
 };
-use std::{borrow::Borrow, iter::FusedIterator, mem::MaybeUninit, ops::Range};
+use std::{borrow::Borrow, cmp::Ordering, iter::FusedIterator, mem::MaybeUninit, ops::Range};
 
 use super::{QueryData, QueryFilter, ReadOnlyQueryData};
 
@@ -15,10 +15,13 @@ use super::{QueryData, QueryFilter, ReadOnlyQueryData};
 /// This struct is created by the [`Query::iter`](crate::system::Query::iter) and
 /// [`Query::iter_mut`](crate::system::Query::iter_mut) methods.
 pub struct QueryIter<'w, 's, D: QueryData, F: QueryFilter> {
+    world: UnsafeWorldCell<'w>,
     tables: &'w Tables,
     archetypes: &'w Archetypes,
     query_state: &'s QueryState<D, F>,
     cursor: QueryIterationCursor<'w, 's, D, F>,
+    last_run: Tick,
+    this_run: Tick,
 }
 
 impl<'w, 's, D: QueryData, F: QueryFilter> QueryIter<'w, 's, D, F> {
@@ -32,12 +35,210 @@ impl<'w, 's, D: QueryData, F: QueryFilter> QueryIter<'w, 's, D, F> {
         this_run: Tick,
     ) -> Self {
         QueryIter {
+            world,
             query_state,
             // SAFETY: We only access table data that has been registered in `query_state`.
             tables: unsafe { &world.storages().tables },
             archetypes: world.archetypes(),
             // SAFETY: The invariants are uphold by the caller.
             cursor: unsafe { QueryIterationCursor::init(world, query_state, last_run, this_run) },
+            last_run,
+            this_run,
+        }
+    }
+
+    /// Sorts all query items into a new iterator, using the query lens as a key.
+    ///
+    /// The lens `L` must be a [`ReadOnlyQueryData`] whose access is a subset of the original query,
+    /// and its items must implement [`Ord`]. The lens items are only used for sorting and are
+    /// dropped before the sorted iterator is returned, so this also works with `iter_mut`.
+    /// Comparators and key functions can't hold on to the lens items they are given.
+    ///
+    /// This sort is stable (i.e., does not reorder equal elements).
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `next` has been called on `QueryIter` before, even if it returned `None`.
+    /// The only exception is a query that doesn't match any [archetype](crate::archetype::Archetype),
+    /// not even one without entities, as `next` then has nothing to advance past.
+    /// It will also panic if the lens accesses data that the original query does not.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #
+    /// #[derive(Component, PartialEq, Eq, PartialOrd, Ord)]
+    /// struct Priority(u32);
+    ///
+    /// #[derive(Component)]
+    /// struct Task(&'static str);
+    ///
+    /// fn run_tasks(query: Query<(&Task, &Priority)>) {
+    ///     // Tasks are visited from the lowest to the highest priority.
+    ///     for (task, _) in query.iter().sort::<&Priority>() {
+    ///         println!("Running {}", task.0);
+    ///     }
+    /// }
+    /// # bevy_ecs::system::assert_is_system(run_tasks);
+    /// ```
+    pub fn sort<L: ReadOnlyQueryData + 'w>(
+        self,
+    ) -> QuerySortedIter<'w, 's, D, F, std::vec::IntoIter<Entity>>
+    where
+        for<'lw> L::Item<'lw>: Ord,
+    {
+        self.sort_impl::<L>(|keyed_query| keyed_query.sort_by(|(a, _), (b, _)| a.cmp(b)))
+    }
+
+    /// Sorts all query items into a new iterator, using the query lens as a key.
+    ///
+    /// This sort is unstable (i.e., may reorder equal elements).
+    ///
+    /// See [`sort`](Self::sort) for details on the lens and panics.
+    pub fn sort_unstable<L: ReadOnlyQueryData + 'w>(
+        self,
+    ) -> QuerySortedIter<'w, 's, D, F, std::vec::IntoIter<Entity>>
+    where
+        for<'lw> L::Item<'lw>: Ord,
+    {
+        self.sort_impl::<L>(|keyed_query| keyed_query.sort_unstable_by(|(a, _), (b, _)| a.cmp(b)))
+    }
+
+    /// Sorts all query items into a new iterator with a comparator function over the query lens.
+    ///
+    /// This sort is stable (i.e., does not reorder equal elements).
+    ///
+    /// See [`sort`](Self::sort) for details on the lens and panics.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #
+    /// #[derive(Component)]
+    /// struct Height(f32);
+    ///
+    /// fn tallest_first(query: Query<(Entity, &Height)>) {
+    ///     for (entity, height) in query
+    ///         .iter()
+    ///         .sort_by::<&Height>(|a, b| b.0.total_cmp(&a.0))
+    ///     {
+    ///         println!("{entity:?} is {} tall", height.0);
+    ///     }
+    /// }
+    /// # bevy_ecs::system::assert_is_system(tallest_first);
+    /// ```
+    pub fn sort_by<L: ReadOnlyQueryData + 'w>(
+        self,
+        mut compare: impl FnMut(&L::Item<'_>, &L::Item<'_>) -> Ordering,
+    ) -> QuerySortedIter<'w, 's, D, F, std::vec::IntoIter<Entity>> {
+        self.sort_impl::<L>(move |keyed_query| {
+            keyed_query.sort_by(|(a, _), (b, _)| compare(a, b));
+        })
+    }
+
+    /// Sorts all query items into a new iterator with a comparator function over the query lens.
+    ///
+    /// This sort is unstable (i.e., may reorder equal elements).
+    ///
+    /// See [`sort`](Self::sort) for details on the lens and panics.
+    pub fn sort_unstable_by<L: ReadOnlyQueryData + 'w>(
+        self,
+        mut compare: impl FnMut(&L::Item<'_>, &L::Item<'_>) -> Ordering,
+    ) -> QuerySortedIter<'w, 's, D, F, std::vec::IntoIter<Entity>> {
+        self.sort_impl::<L>(move |keyed_query| {
+            keyed_query.sort_unstable_by(|(a, _), (b, _)| compare(a, b));
+        })
+    }
+
+    /// Sorts all query items into a new iterator with a key extraction function over the query lens.
+    ///
+    /// This sort is stable (i.e., does not reorder equal elements).
+    ///
+    /// See [`sort`](Self::sort) for details on the lens and panics.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #
+    /// #[derive(Component)]
+    /// struct Depth(i32);
+    ///
+    /// #[derive(Component)]
+    /// struct Offset(i32);
+    ///
+    /// fn stack(mut query: Query<(&Depth, &mut Offset)>) {
+    ///     for (i, (_, mut offset)) in query
+    ///         .iter_mut()
+    ///         .sort_by_key::<&Depth, _>(|depth| depth.0)
+    ///         .enumerate()
+    ///     {
+    ///         offset.0 = i as i32;
+    ///     }
+    /// }
+    /// # bevy_ecs::system::assert_is_system(stack);
+    /// ```
+    pub fn sort_by_key<L: ReadOnlyQueryData + 'w, K: Ord>(
+        self,
+        mut f: impl FnMut(&L::Item<'_>) -> K,
+    ) -> QuerySortedIter<'w, 's, D, F, std::vec::IntoIter<Entity>> {
+        self.sort_impl::<L>(move |keyed_query| keyed_query.sort_by_key(|(lens, _)| f(lens)))
+    }
+
+    /// Sorts all query items into a new iterator with a key extraction function over the query lens,
+    /// calling the key function only once per item.
+    ///
+    /// This sort is stable (i.e., does not reorder equal elements).
+    ///
+    /// See [`sort`](Self::sort) for details on the lens and panics.
+    pub fn sort_by_cached_key<L: ReadOnlyQueryData + 'w, K: Ord>(
+        self,
+        mut f: impl FnMut(&L::Item<'_>) -> K,
+    ) -> QuerySortedIter<'w, 's, D, F, std::vec::IntoIter<Entity>> {
+        self.sort_impl::<L>(move |keyed_query| keyed_query.sort_by_cached_key(|(lens, _)| f(lens)))
+    }
+
+    /// Collects the lens items of every entity matched by this query, sorts them with `sort`,
+    /// and returns an iterator over the query items in the sorted order.
+    fn sort_impl<L: ReadOnlyQueryData + 'w>(
+        self,
+        sort: impl FnOnce(&mut Vec<(L::Item<'w>, Entity)>),
+    ) -> QuerySortedIter<'w, 's, D, F, std::vec::IntoIter<Entity>> {
+        // On the first call to `next`, the cursor moves on from the first matched table or
+        // archetype, so untouched id iterators mean that iteration has not started yet.
+        if self.cursor.table_id_iter.len() != self.query_state.matched_table_ids.len()
+            || self.cursor.archetype_id_iter.len() != self.query_state.matched_archetype_ids.len()
+        {
+            panic!("it is not valid to call sort() after next()");
+        }
+
+        // SAFETY: `get_state` only uses the world to look up component ids.
+        let world = unsafe { self.world.world_metadata() };
+        let query_lens_state = self.query_state.transmute_filtered::<(L, Entity), F>(world);
+
+        // SAFETY:
+        // `self.world` has permission to access the required components, and the lens is
+        // read-only with an access that is a subset of the original query.
+        // The lens items are dropped before the original query items are fetched.
+        let query_lens =
+            unsafe { QueryIter::new(self.world, &query_lens_state, self.last_run, self.this_run) };
+        let mut keyed_query: Vec<_> = query_lens.collect();
+        sort(&mut keyed_query);
+        let entities: Vec<Entity> = keyed_query.into_iter().map(|(_, entity)| entity).collect();
+
+        // SAFETY:
+        // `self.world` has permission to access the required components.
+        // Each lens entity is unique and matched by the original query.
+        unsafe {
+            QuerySortedIter::new(
+                self.world,
+                self.query_state,
+                entities,
+                self.last_run,
+                self.this_run,
+            )
         }
     }
 
@@ -270,6 +471,129 @@ impl<'w, 's, D: QueryData, F: QueryFilter> Iterator for QueryIter<'w, 's, D, F>
 // This is correct as [`QueryIter`] always returns `None` once exhausted.
 impl<'w, 's, D: QueryData, F: QueryFilter> FusedIterator for QueryIter<'w, 's, D, F> {}
 
+/// An [`Iterator`] over sorted query results of a [`Query`](crate::system::Query).
+///
+/// This struct is created by the [`QueryIter::sort`], [`QueryIter::sort_unstable`],
+/// [`QueryIter::sort_by`], [`QueryIter::sort_unstable_by`], [`QueryIter::sort_by_key`],
+/// and [`QueryIter::sort_by_cached_key`] methods.
+pub struct QuerySortedIter<'w, 's, D: QueryData, F: QueryFilter, I>
+where
+    I: Iterator<Item = Entity>,
+{
+    entity_iter: I,
+    entities: &'w Entities,
+    tables: &'w Tables,
+    archetypes: &'w Archetypes,
+    fetch: D::Fetch<'w>,
+    query_state: &'s QueryState<D, F>,
+}
+
+impl<'w, 's, D: QueryData, F: QueryFilter, I> QuerySortedIter<'w, 's, D, F, I>
+where
+    I: Iterator<Item = Entity>,
+{
+    /// # Safety
+    /// - `world` must have permission to access any of the components registered in `query_state`.
+    /// - `world` must be the same one used to initialize `query_state`.
+    /// - `entity_list` must only contain unique entities, all of which are matched by `query_state`.
+    pub(crate) unsafe fn new<EntityList: IntoIterator<IntoIter = I>>(
+        world: UnsafeWorldCell<'w>,
+        query_state: &'s QueryState<D, F>,
+        entity_list: EntityList,
+        last_run: Tick,
+        this_run: Tick,
+    ) -> QuerySortedIter<'w, 's, D, F, I> {
+        let fetch = D::init_fetch(world, &query_state.fetch_state, last_run, this_run);
+        QuerySortedIter {
+            query_state,
+            entities: world.entities(),
+            archetypes: world.archetypes(),
+            // SAFETY: We only access table data that has been registered in `query_state`.
+            // This means `world` has permission to access the data we use.
+            tables: &world.storages().tables,
+            fetch,
+            entity_iter: entity_list.into_iter(),
+        }
+    }
+
+    /// # Safety
+    /// `entity` must stem from `self.entity_iter`, and not have been passed before.
+    #[inline(always)]
+    unsafe fn fetch_next(&mut self, entity: Entity) -> D::Item<'w> {
+        let (location, archetype, table);
+        // SAFETY:
+        // `tables` and `archetypes` belong to the same world that the [`QueryIter`]
+        // was initialized for, and the entity is matched by the query.
+        unsafe {
+            location = self.entities.get(entity).debug_checked_unwrap();
+            archetype = self
+                .archetypes
+                .get(location.archetype_id)
+                .debug_checked_unwrap();
+            table = self.tables.get(location.table_id).debug_checked_unwrap();
+        }
+
+        // SAFETY: `archetype` is from the world that `fetch` was created for,
+        // `fetch_state` is the state that `fetch` was initialized with
+        unsafe {
+            D::set_archetype(
+                &mut self.fetch,
+                &self.query_state.fetch_state,
+                archetype,
+                table,
+            );
+        }
+
+        // SAFETY:
+        // - set_archetype was called prior.
+        // - `location.table_row` is in range of the current table, because the entity is matched.
+        // - fetch is only called once for each entity, since the entities are unique.
+        unsafe { D::fetch(&mut self.fetch, entity, location.table_row) }
+    }
+}
+
+impl<'w, 's, D: QueryData, F: QueryFilter, I> Iterator for QuerySortedIter<'w, 's, D, F, I>
+where
+    I: Iterator<Item = Entity>,
+{
+    type Item = D::Item<'w>;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        let entity = self.entity_iter.next()?;
+        // SAFETY: `entity` is passed from `entity_iter` the first time.
+        unsafe { Some(self.fetch_next(entity)) }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.entity_iter.size_hint()
+    }
+}
+
+impl<'w, 's, D: QueryData, F: QueryFilter, I> DoubleEndedIterator
+    for QuerySortedIter<'w, 's, D, F, I>
+where
+    I: DoubleEndedIterator<Item = Entity>,
+{
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let entity = self.entity_iter.next_back()?;
+        // SAFETY: `entity` is passed from `entity_iter` the first time.
+        unsafe { Some(self.fetch_next(entity)) }
+    }
+}
+
+impl<'w, 's, D: QueryData, F: QueryFilter, I> ExactSizeIterator for QuerySortedIter<'w, 's, D, F, I> where
+    I: ExactSizeIterator<Item = Entity>
+{
+}
+
+// This is correct as [`QuerySortedIter`] returns `None` once exhausted if `entity_iter` does.
+impl<'w, 's, D: QueryData, F: QueryFilter, I> FusedIterator for QuerySortedIter<'w, 's, D, F, I> where
+    I: FusedIterator<Item = Entity>
+{
+}
+
 /// An [`Iterator`] over the query items generated from an iterator of [`Entity`]s.
 ///
 /// Items are returned in the order of the provided iterator.
@@ -244,6 +244,117 @@ mod tests {
         assert_all_sizes_equal::<Entity, (With<C>, With<D>)>(&mut world, 6);
     }
 
+    #[test]
+    fn query_sorted_iter() {
+        let mut world = World::new();
+        world.spawn((A(3), B(0)));
+        world.spawn((A(1), B(1), Sparse(0)));
+        world.spawn((A(2), B(2)));
+        world.spawn((A(0), B(3), Sparse(1)));
+        world.spawn((A(4), Sparse(2)));
+
+        let mut query = world.query::<(&A, &B)>();
+        let values: Vec<usize> = query
+            .iter(&world)
+            .sort_by_key::<&A, _>(|a| a.0)
+            .map(|(a, _)| a.0)
+            .collect();
+        assert_eq!(values, vec![0, 1, 2, 3]);
+
+        let values: Vec<usize> = query
+            .iter(&world)
+            .sort_unstable_by::<&B>(|a, b| b.0.cmp(&a.0))
+            .map(|(_, b)| b.0)
+            .collect();
+        assert_eq!(values, vec![3, 2, 1, 0]);
+
+        let sorted = query.iter(&world).sort_by_key::<&A, _>(|a| a.0);
+        assert_eq!(sorted.len(), 4);
+        let values: Vec<usize> = sorted.rev().map(|(a, _)| a.0).collect();
+        assert_eq!(values, vec![3, 2, 1, 0]);
+
+        // Filters and sparse set components are respected.
+        let mut query = world.query_filtered::<(&A, &Sparse), With<B>>();
+        let values: Vec<usize> = query
+            .iter(&world)
+            .sort_by_cached_key::<&Sparse, _>(|sparse| usize::MAX - sparse.0)
+            .map(|(a, _)| a.0)
+            .collect();
+        assert_eq!(values, vec![0, 1]);
+    }
+
+    #[test]
+    fn query_sorted_iter_mut() {
+        #[derive(Component, PartialEq, Eq, PartialOrd, Ord)]
+        struct Order(u32);
+
+        let mut world = World::new();
+        world.spawn((Order(2), A(0)));
+        world.spawn((Order(0), A(0)));
+        world.spawn((Order(1), A(0)));
+
+        let mut query = world.query::<(&Order, &mut A)>();
+        for (i, (_, mut a)) in query.iter_mut(&mut world).sort::<&Order>().enumerate() {
+            a.0 = i;
+        }
+
+        let mut values: Vec<(u32, usize)> = world
+            .query::<(&Order, &A)>()
+            .iter(&world)
+            .map(|(order, a)| (order.0, a.0))
+            .collect();
+        values.sort_unstable();
+        assert_eq!(values, vec![(0, 0), (1, 1), (2, 2)]);
+    }
+
+    #[test]
+    #[should_panic = "it is not valid to call sort() after next()"]
+    fn query_sort_after_next() {
+        let mut world = World::new();
+        world.spawn((A(0), B(0)));
+        world.spawn((A(1), B(1)));
+
+        let mut query = world.query::<(&A, &B)>();
+        let mut iter = query.iter(&world);
+        iter.next();
+        let _ = iter.sort_by_key::<&A, _>(|a| a.0);
+    }
+
+    #[test]
+    #[should_panic = "it is not valid to call sort() after next()"]
+    fn query_sort_after_next_without_entities() {
+        let mut world = World::new();
+        let entity = world.spawn((A(0), B(0))).id();
+        world.despawn(entity);
+
+        // The query still matches the now empty archetype, which `next` moves past.
+        let mut query = world.query::<(&A, &B)>();
+        let mut iter = query.iter(&world);
+        assert!(iter.next().is_none());
+        let _ = iter.sort_by_key::<&A, _>(|a| a.0);
+    }
+
+    #[test]
+    fn query_sort_after_next_without_archetypes() {
+        let mut world = World::new();
+        world.spawn(A(0));
+
+        let mut query = world.query::<(&A, &B)>();
+        let mut iter = query.iter(&world);
+        assert!(iter.next().is_none());
+        assert_eq!(iter.sort_by_key::<&A, _>(|a| a.0).len(), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn query_sort_with_unavailable_lens() {
+        let mut world = World::new();
+        world.spawn((A(0), B(0)));
+
+        let mut query = world.query::<&A>();
+        let _ = query.iter(&world).sort_by_key::<&B, _>(|b| b.0);
+    }
+
     #[test]
     fn query_iter_combinations() {
         let mut world = World::new();
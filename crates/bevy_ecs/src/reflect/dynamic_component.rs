@@ -0,0 +1,710 @@
+//! Components defined at runtime, such as from a scripting language, and accessed through reflection.
+//!
+//! A dynamic component is registered with a name and a [`DynamicStruct`] template describing its
+//! fields and their default values. Its values are inserted from any [`Reflect`] struct whose fields
+//! match the template, are read back as `&dyn Reflect` from dynamic queries, and are modified
+//! through a [`DynamicComponentMut`], which rejects values that don't match the fields.
+//!
+//! ```
+//! # use bevy_ecs::prelude::*;
+//! # use bevy_ecs::world::{FilteredEntityMut, FilteredEntityRef};
+//! # use bevy_reflect::{DynamicStruct, GetField, ReflectRef};
+//! let mut world = World::new();
+//!
+//! let mut template = DynamicStruct::default();
+//! template.insert("current", 100u32);
+//! template.insert("max", 100u32);
+//! let health = world.register_dynamic_component("Health", template).unwrap();
+//!
+//! // Only the fields that differ from the template need to be provided.
+//! let mut value = DynamicStruct::default();
+//! value.insert("current", 40u32);
+//! world.spawn_empty().insert_dynamic(health, &value).unwrap();
+//!
+//! // Dynamic components are queried with a `QueryBuilder`.
+//! let mut query = QueryBuilder::<FilteredEntityMut>::new(&mut world)
+//!     .mut_id(health)
+//!     .build();
+//! for mut entity in query.iter_mut(&mut world) {
+//!     let mut health = entity.get_dynamic_mut(health).unwrap();
+//!     *health.get_field_mut::<u32>("current").unwrap() += 10;
+//!     // Values that don't match the fields of the component are rejected.
+//!     let mut max = DynamicStruct::default();
+//!     max.insert("max", 1.0f32);
+//!     assert!(health.apply(&max).is_err());
+//! }
+//!
+//! let mut query = QueryBuilder::<FilteredEntityRef>::new(&mut world)
+//!     .ref_id(health)
+//!     .build();
+//! let entity = query.single(&world);
+//! let health = entity.get_dynamic(health).unwrap();
+//! let ReflectRef::Struct(health) = health.reflect_ref() else { unreachable!() };
+//! assert_eq!(health.get_field::<u32>("current"), Some(&50));
+//! assert_eq!(health.get_field::<u32>("max"), Some(&100));
+//! ```
+
+use std::any::TypeId;
+
+use crate as bevy_ecs;
+use crate::{
+    change_detection::{DetectChanges, DetectChangesMut, Mut},
+    component::{ComponentDescriptor, ComponentId, Components, Tick},
+    system::Resource,
+    world::{EntityWorldMut, World},
+};
+use bevy_ptr::OwningPtr;
+use bevy_reflect::{DynamicStruct, Reflect, ReflectRef, Struct, TypeInfo, VariantInfo};
+use bevy_utils::HashMap;
+use thiserror::Error;
+
+/// A [`Resource`] storing the dynamic components registered with
+/// [`World::register_dynamic_component`].
+#[derive(Resource, Default)]
+pub struct DynamicComponents {
+    ids: HashMap<String, ComponentId>,
+    templates: HashMap<ComponentId, DynamicStruct>,
+}
+
+impl DynamicComponents {
+    /// Returns the id of the dynamic component with the given name.
+    pub fn get_id(&self, name: &str) -> Option<ComponentId> {
+        self.ids.get(name).copied()
+    }
+
+    /// Returns the template the dynamic component was registered with.
+    pub fn template(&self, id: ComponentId) -> Option<&DynamicStruct> {
+        self.templates.get(&id)
+    }
+
+    /// Iterates over the names and ids of the registered dynamic components.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, ComponentId)> + '_ {
+        self.ids.iter().map(|(name, id)| (name.as_str(), *id))
+    }
+}
+
+/// An error when registering or inserting a dynamic component.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum DynamicComponentError {
+    /// A dynamic component with the same name was already registered.
+    #[error("A dynamic component named `{0}` is already registered")]
+    AlreadyRegistered(String),
+    /// The component was not registered with [`World::register_dynamic_component`].
+    #[error("Component {0:?} is not a dynamic component")]
+    NotDynamic(ComponentId),
+    /// The inserted value is not a struct.
+    #[error("Dynamic component values must be structs")]
+    NotAStruct,
+    /// The inserted value has a field which is not in the template.
+    #[error("The dynamic component has no field `{0}`")]
+    UnknownField(String),
+    /// The inserted value has a field whose type does not match the template.
+    #[error("Field `{0}` does not match the type of the dynamic component's field")]
+    MismatchedField(String),
+}
+
+/// Returns `true` if `id` belongs to a component registered with
+/// [`World::register_dynamic_component`].
+pub(crate) fn is_dynamic_component(components: &Components, id: ComponentId) -> bool {
+    components
+        .get_info(id)
+        .and_then(|info| info.type_id())
+        .is_some_and(|type_id| type_id == TypeId::of::<DynamicStruct>())
+}
+
+/// Checks that every field of `value` exists in `template` with a compatible type.
+fn check_fields(
+    template: &dyn Struct,
+    value: &dyn Struct,
+    path: &str,
+) -> Result<(), DynamicComponentError> {
+    for (index, value_field) in value.iter_fields().enumerate() {
+        let name = value.name_at(index).unwrap_or_default();
+        let field_path = if path.is_empty() {
+            name.to_string()
+        } else {
+            format!("{path}.{name}")
+        };
+        let template_field = template
+            .field(name)
+            .ok_or_else(|| DynamicComponentError::UnknownField(field_path.clone()))?;
+
+        check_value(template_field, value_field, &field_path)?;
+    }
+    Ok(())
+}
+
+/// Returns the type path of `value`, or of the type it represents if it is a dynamic value.
+fn represented_type_path(value: &dyn Reflect) -> Option<&str> {
+    if value.is_dynamic() {
+        value.get_represented_type_info().map(TypeInfo::type_path)
+    } else {
+        Some(value.reflect_type_path())
+    }
+}
+
+/// Checks that `value` can be applied to `template` without panicking.
+///
+/// Values of known types must have the same type as the template. Dynamic values are checked
+/// recursively, element by element and variant by variant.
+fn check_value(
+    template: &dyn Reflect,
+    value: &dyn Reflect,
+    path: &str,
+) -> Result<(), DynamicComponentError> {
+    let mismatch = || DynamicComponentError::MismatchedField(path.to_string());
+    if template.reflect_kind() != value.reflect_kind() {
+        return Err(mismatch());
+    }
+    if let (Some(template_type), Some(value_type)) = (
+        represented_type_path(template),
+        represented_type_path(value),
+    ) {
+        if template_type != value_type {
+            return Err(mismatch());
+        }
+    }
+    if !template.is_dynamic() && !value.is_dynamic() {
+        return Ok(());
+    }
+
+    // New elements and variants are checked against the types declared by the template's type:
+    // concrete containers build them with `FromReflect`, which panics on a mismatch, and dynamic
+    // containers representing a type must stay convertible to it. Untyped containers accept anything.
+    let info = template.get_represented_type_info();
+    match (template.reflect_ref(), value.reflect_ref()) {
+        (ReflectRef::Struct(template), ReflectRef::Struct(value)) => {
+            check_fields(template, value, path)?;
+        }
+        (ReflectRef::TupleStruct(template), ReflectRef::TupleStruct(value)) => {
+            for (index, field) in value.iter_fields().enumerate() {
+                let template_field = template.field(index).ok_or_else(mismatch)?;
+                check_value(template_field, field, &format!("{path}.{index}"))?;
+            }
+        }
+        (ReflectRef::Tuple(template), ReflectRef::Tuple(value)) => {
+            for (index, field) in value.iter_fields().enumerate() {
+                let template_field = template.field(index).ok_or_else(mismatch)?;
+                check_value(template_field, field, &format!("{path}.{index}"))?;
+            }
+        }
+        (ReflectRef::List(template), ReflectRef::List(value)) => {
+            let item_type = match info {
+                Some(TypeInfo::List(info)) => Some(info.item_type_path_table().path()),
+                _ => None,
+            };
+            for (index, item) in value.iter().enumerate() {
+                check_item(
+                    template.get(index),
+                    item_type,
+                    item,
+                    &format!("{path}[{index}]"),
+                )?;
+            }
+        }
+        (ReflectRef::Array(template), ReflectRef::Array(value)) => {
+            if template.len() != value.len() {
+                return Err(mismatch());
+            }
+            for (index, item) in value.iter().enumerate() {
+                let template_item = template.get(index).ok_or_else(mismatch)?;
+                check_value(template_item, item, &format!("{path}[{index}]"))?;
+            }
+        }
+        (ReflectRef::Map(template), ReflectRef::Map(value)) => {
+            let (key_type, value_type) = match info {
+                Some(TypeInfo::Map(info)) => (
+                    Some(info.key_type_path_table().path()),
+                    Some(info.value_type_path_table().path()),
+                ),
+                _ => (None, None),
+            };
+            for (key, item) in value.iter() {
+                match template.get(key) {
+                    Some(template_item) => check_value(template_item, item, path)?,
+                    None => {
+                        check_item(None, key_type, key, path)?;
+                        check_item(None, value_type, item, path)?;
+                    }
+                }
+            }
+        }
+        (ReflectRef::Enum(template), ReflectRef::Enum(value)) => {
+            if template.variant_name() == value.variant_name() {
+                for (index, field) in value.iter_fields().enumerate() {
+                    let (template_field, field_path) = match field.name() {
+                        Some(name) => (template.field(name), format!("{path}.{name}")),
+                        None => (template.field_at(index), format!("{path}.{index}")),
+                    };
+                    check_value(
+                        template_field.ok_or_else(mismatch)?,
+                        field.value(),
+                        &field_path,
+                    )?;
+                }
+            } else if let Some(TypeInfo::Enum(info)) = info {
+                // Applying a different variant replaces the value, so the fields are checked
+                // against the variant's declared types.
+                let variant = info.variant(value.variant_name()).ok_or_else(mismatch)?;
+                for (index, field) in value.iter_fields().enumerate() {
+                    let (field_type, field_path) = match (variant, field.name()) {
+                        (VariantInfo::Struct(variant), Some(name)) => (
+                            variant.field(name).ok_or_else(mismatch)?.type_path(),
+                            format!("{path}.{name}"),
+                        ),
+                        (VariantInfo::Tuple(variant), None) => (
+                            variant.field_at(index).ok_or_else(mismatch)?.type_path(),
+                            format!("{path}.{index}"),
+                        ),
+                        _ => return Err(mismatch()),
+                    };
+                    check_item(None, Some(field_type), field.value(), &field_path)?;
+                }
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Checks an element of a container against the matching element of the template if there is
+/// one, or else against the element type declared by the template's type, if any.
+fn check_item(
+    template: Option<&dyn Reflect>,
+    item_type: Option<&str>,
+    item: &dyn Reflect,
+    path: &str,
+) -> Result<(), DynamicComponentError> {
+    match (template, item_type) {
+        (Some(template), _) => check_value(template, item, path),
+        (None, Some(item_type)) if represented_type_path(item) != Some(item_type) => {
+            Err(DynamicComponentError::MismatchedField(path.to_string()))
+        }
+        _ => Ok(()),
+    }
+}
+
+impl World {
+    /// Registers a component defined at runtime, whose fields and their default values are
+    /// described by `template`.
+    ///
+    /// Values of the component are inserted with [`EntityWorldMut::insert_dynamic`], and accessed
+    /// with [`FilteredEntityRef::get_dynamic`](crate::world::FilteredEntityRef::get_dynamic) and
+    /// [`FilteredEntityMut::get_dynamic_mut`](crate::world::FilteredEntityMut::get_dynamic_mut).
+    ///
+    /// See the [module docs](crate::reflect::dynamic_component) for an example.
+    pub fn register_dynamic_component(
+        &mut self,
+        name: impl Into<String>,
+        template: DynamicStruct,
+    ) -> Result<ComponentId, DynamicComponentError> {
+        let name = name.into();
+        let registry = self.get_resource_or_insert_with(DynamicComponents::default);
+        if registry.ids.contains_key(&name) {
+            return Err(DynamicComponentError::AlreadyRegistered(name));
+        }
+        let id = self
+            .init_component_with_descriptor(ComponentDescriptor::new_dynamic_struct(name.clone()));
+        let mut registry = self.resource_mut::<DynamicComponents>();
+        registry.ids.insert(name, id);
+        registry.templates.insert(id, template);
+        Ok(id)
+    }
+
+    /// Returns the id of the dynamic component with the given name.
+    ///
+    /// See [`World::register_dynamic_component`].
+    pub fn dynamic_component_id(&self, name: &str) -> Option<ComponentId> {
+        self.get_resource::<DynamicComponents>()?.get_id(name)
+    }
+}
+
+impl<'w> EntityWorldMut<'w> {
+    /// Inserts a dynamic component into the entity.
+    ///
+    /// The component starts from the template it was registered with, and the fields of `value`
+    /// are then applied to it. Every field of `value` must exist in the template with the same type.
+    ///
+    /// This will overwrite any previous value of the same component.
+    ///
+    /// See [`World::register_dynamic_component`].
+    pub fn insert_dynamic(
+        &mut self,
+        component_id: ComponentId,
+        value: &dyn Reflect,
+    ) -> Result<&mut Self, DynamicComponentError> {
+        if !is_dynamic_component(self.world().components(), component_id) {
+            return Err(DynamicComponentError::NotDynamic(component_id));
+        }
+        let template = self
+            .world()
+            .get_resource::<DynamicComponents>()
+            .and_then(|registry| registry.template(component_id))
+            .ok_or(DynamicComponentError::NotDynamic(component_id))?;
+        let ReflectRef::Struct(value) = value.reflect_ref() else {
+            return Err(DynamicComponentError::NotAStruct);
+        };
+        check_fields(template, value, "")?;
+
+        let mut component = template.clone_dynamic();
+        component.apply(value.as_reflect());
+        OwningPtr::make(component, |ptr| {
+            // SAFETY: `component_id` is a dynamic component of this world, which stores a `DynamicStruct`.
+            unsafe { self.insert_by_id(component_id, ptr) };
+        });
+        Ok(self)
+    }
+}
+
+/// Mutable access to a dynamic component, returned by
+/// [`FilteredEntityMut::get_dynamic_mut`](crate::world::FilteredEntityMut::get_dynamic_mut).
+///
+/// Unlike a `&mut dyn Reflect`, this only accepts writes that keep the fields of the component
+/// matching the template it was registered with, as checked by [`EntityWorldMut::insert_dynamic`].
+/// Accessing the component mutably marks it as changed.
+pub struct DynamicComponentMut<'w> {
+    value: Mut<'w, DynamicStruct>,
+}
+
+impl<'w> DynamicComponentMut<'w> {
+    pub(crate) fn new(value: Mut<'w, DynamicStruct>) -> Self {
+        Self { value }
+    }
+
+    /// Returns the value of the component.
+    pub fn get(&self) -> &dyn Reflect {
+        self.value.as_reflect()
+    }
+
+    /// Applies the fields of `value` to the component.
+    ///
+    /// Every field of `value` must exist in the component with the same type, otherwise an error
+    /// is returned and the component is left unchanged.
+    pub fn apply(&mut self, value: &dyn Reflect) -> Result<(), DynamicComponentError> {
+        let ReflectRef::Struct(value) = value.reflect_ref() else {
+            return Err(DynamicComponentError::NotAStruct);
+        };
+        // The component was built from its template with checked values, so its fields have the
+        // types of the template's fields.
+        check_fields(&*self.value, value, "")?;
+        self.value.apply(value.as_reflect());
+        Ok(())
+    }
+
+    /// Returns a mutable reference to the field `name`, if it exists and has the type `T`.
+    ///
+    /// Fields stored as dynamic values, such as lists or nested structs, can't be modified in
+    /// place as they would accept values of any type. Use [`DynamicComponentMut::apply`] instead.
+    pub fn get_field_mut<T: Reflect>(&mut self, name: &str) -> Option<&mut T> {
+        let field = self.value.bypass_change_detection().field(name)?;
+        if field.is_dynamic() || !field.is::<T>() {
+            return None;
+        }
+        self.value.field_mut(name)?.downcast_mut::<T>()
+    }
+
+    /// Returns `true` if the component was added after the system last ran.
+    pub fn is_added(&self) -> bool {
+        self.value.is_added()
+    }
+
+    /// Returns `true` if the component was added or mutably dereferenced after the system last ran.
+    pub fn is_changed(&self) -> bool {
+        self.value.is_changed()
+    }
+
+    /// Returns the change tick recording the time this component was most recently changed.
+    pub fn last_changed(&self) -> Tick {
+        self.value.last_changed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        prelude::QueryBuilder,
+        world::{FilteredEntityMut, FilteredEntityRef},
+    };
+    use bevy_reflect::{DynamicEnum, DynamicList, DynamicTuple, FromReflect, GetField};
+
+    #[derive(Reflect, Default)]
+    struct Position {
+        x: f32,
+        y: f32,
+    }
+
+    fn position_template() -> DynamicStruct {
+        let mut template = DynamicStruct::default();
+        template.insert("x", 0.0f32);
+        template.insert("y", 0.0f32);
+        template.insert("label", String::from("none"));
+        template
+    }
+
+    fn get_field<T: Reflect + Clone>(value: &dyn Reflect, name: &str) -> Option<T> {
+        let ReflectRef::Struct(value) = value.reflect_ref() else {
+            return None;
+        };
+        value.get_field::<T>(name).cloned()
+    }
+
+    #[test]
+    fn register_and_insert() {
+        let mut world = World::new();
+        let id = world
+            .register_dynamic_component("Position", position_template())
+            .unwrap();
+        assert_eq!(world.dynamic_component_id("Position"), Some(id));
+        assert_eq!(world.components().get_info(id).unwrap().name(), "Position");
+        assert_eq!(
+            world.register_dynamic_component("Position", DynamicStruct::default()),
+            Err(DynamicComponentError::AlreadyRegistered("Position".into()))
+        );
+
+        // Values can come from concrete reflected types or from dynamic structs.
+        let a = world
+            .spawn_empty()
+            .insert_dynamic(id, &Position { x: 1.0, y: 2.0 })
+            .unwrap()
+            .id();
+        let mut label = DynamicStruct::default();
+        label.insert("label", String::from("b"));
+        let b = world.spawn_empty().insert_dynamic(id, &label).unwrap().id();
+
+        let entity = FilteredEntityRef::from(world.entity(a));
+        let position = entity.get_dynamic(id).unwrap();
+        assert_eq!(get_field::<f32>(position, "y"), Some(2.0));
+        assert_eq!(get_field::<String>(position, "label"), Some("none".into()));
+
+        let entity = FilteredEntityRef::from(world.entity(b));
+        let position = entity.get_dynamic(id).unwrap();
+        assert_eq!(get_field::<f32>(position, "x"), Some(0.0));
+        assert_eq!(get_field::<String>(position, "label"), Some("b".into()));
+    }
+
+    #[test]
+    fn insert_errors() {
+        #[derive(Reflect)]
+        struct Wrong {
+            x: u32,
+        }
+
+        #[derive(Reflect)]
+        struct Unknown {
+            z: f32,
+        }
+
+        let mut world = World::new();
+        let id = world
+            .register_dynamic_component("Position", position_template())
+            .unwrap();
+        let typed = world.init_component::<crate::entity_disabling::Disabled>();
+
+        let mut entity = world.spawn_empty();
+        assert_eq!(
+            entity.insert_dynamic(id, &Wrong { x: 1 }).err(),
+            Some(DynamicComponentError::MismatchedField("x".into()))
+        );
+        assert_eq!(
+            entity.insert_dynamic(id, &Unknown { z: 1.0 }).err(),
+            Some(DynamicComponentError::UnknownField("z".into()))
+        );
+        assert_eq!(
+            entity.insert_dynamic(id, &1.0f32).err(),
+            Some(DynamicComponentError::NotAStruct)
+        );
+        assert_eq!(
+            entity.insert_dynamic(typed, &Position::default()).err(),
+            Some(DynamicComponentError::NotDynamic(typed))
+        );
+        assert!(!entity.contains_id(id));
+    }
+
+    #[test]
+    fn insert_mismatched_containers() {
+        #[derive(Reflect)]
+        struct Items {
+            items: Vec<String>,
+        }
+
+        #[derive(Reflect)]
+        struct Maybe {
+            maybe: Option<String>,
+        }
+
+        let mut template = DynamicStruct::default();
+        template.insert("items", vec![1u32]);
+        template.insert("maybe", None::<u32>);
+        let mut world = World::new();
+        let id = world
+            .register_dynamic_component("Inventory", template)
+            .unwrap();
+
+        let mut entity = world.spawn_empty();
+        assert_eq!(
+            entity.insert_dynamic(id, &Items { items: vec![] }).err(),
+            Some(DynamicComponentError::MismatchedField("items".into()))
+        );
+        assert_eq!(
+            entity.insert_dynamic(id, &Maybe { maybe: None }).err(),
+            Some(DynamicComponentError::MismatchedField("maybe".into()))
+        );
+
+        // Dynamic values are checked element by element, including elements that would be added.
+        let mut items = DynamicList::default();
+        items.push(2u32);
+        items.push(String::from("three"));
+        let mut value = DynamicStruct::default();
+        value.insert("items", items);
+        assert_eq!(
+            entity.insert_dynamic(id, &value).err(),
+            Some(DynamicComponentError::MismatchedField("items[1]".into()))
+        );
+
+        let mut fields = DynamicTuple::default();
+        fields.insert(String::from("some"));
+        let mut value = DynamicStruct::default();
+        value.insert("maybe", DynamicEnum::new("Some", fields));
+        assert_eq!(
+            entity.insert_dynamic(id, &value).err(),
+            Some(DynamicComponentError::MismatchedField("maybe.0".into()))
+        );
+        assert!(!entity.contains_id(id));
+
+        let mut items = DynamicList::default();
+        items.push(2u32);
+        items.push(3u32);
+        let mut fields = DynamicTuple::default();
+        fields.insert(4u32);
+        let mut value = DynamicStruct::default();
+        value.insert("items", items);
+        value.insert("maybe", DynamicEnum::new("Some", fields));
+        let entity = entity.insert_dynamic(id, &value).unwrap().id();
+
+        let entity = FilteredEntityRef::from(world.entity(entity));
+        let ReflectRef::Struct(inventory) = entity.get_dynamic(id).unwrap().reflect_ref() else {
+            panic!("dynamic components are structs");
+        };
+        // Fields are stored as dynamic values, so they are converted back with `FromReflect`.
+        let items = Vec::<u32>::from_reflect(inventory.field("items").unwrap());
+        assert_eq!(items, Some(vec![2, 3]));
+        let maybe = Option::<u32>::from_reflect(inventory.field("maybe").unwrap());
+        assert_eq!(maybe, Some(Some(4)));
+    }
+
+    #[test]
+    fn query_dynamic_components() {
+        let mut world = World::new();
+        let position = world
+            .register_dynamic_component("Position", position_template())
+            .unwrap();
+        let mut velocity_template = DynamicStruct::default();
+        velocity_template.insert("x", 1.0f32);
+        let velocity = world
+            .register_dynamic_component("Velocity", velocity_template)
+            .unwrap();
+
+        for _ in 0..3 {
+            world
+                .spawn_empty()
+                .insert_dynamic(position, &Position::default())
+                .unwrap()
+                .insert_dynamic(velocity, &DynamicStruct::default())
+                .unwrap();
+        }
+        world
+            .spawn_empty()
+            .insert_dynamic(position, &Position::default())
+            .unwrap();
+
+        let mut query = QueryBuilder::<FilteredEntityMut>::new(&mut world)
+            .mut_id(position)
+            .ref_id(velocity)
+            .build();
+        assert_eq!(query.iter(&world).count(), 3);
+        for mut entity in query.iter_mut(&mut world) {
+            let dx = get_field::<f32>(entity.get_dynamic(velocity).unwrap(), "x").unwrap();
+            // Only the accessed components are available, and only with the requested access.
+            assert!(entity.get_dynamic_mut(velocity).is_none());
+
+            let mut position = entity.get_dynamic_mut(position).unwrap();
+            *position.get_field_mut::<f32>("x").unwrap() += dx;
+        }
+
+        let mut query = QueryBuilder::<FilteredEntityRef>::new(&mut world)
+            .ref_id(position)
+            .build();
+        let total: f32 = query
+            .iter(&world)
+            .map(|entity| get_field::<f32>(entity.get_dynamic(position).unwrap(), "x").unwrap())
+            .sum();
+        assert_eq!(total, 3.0);
+    }
+
+    #[test]
+    fn mutate_dynamic_components() {
+        let mut template = DynamicStruct::default();
+        template.insert("x", 0.0f32);
+        template.insert("items", vec![1u32]);
+        let mut world = World::new();
+        let id = world.register_dynamic_component("Items", template).unwrap();
+        let entity = world
+            .spawn_empty()
+            .insert_dynamic(id, &DynamicStruct::default())
+            .unwrap()
+            .id();
+        world.clear_trackers();
+
+        let mut entity = FilteredEntityMut::from(world.entity_mut(entity));
+        let mut component = entity.get_dynamic_mut(id).unwrap();
+        assert!(!component.is_changed());
+
+        // Only fields of concrete types can be modified in place, with their own type.
+        assert!(component.get_field_mut::<u32>("x").is_none());
+        assert!(component.get_field_mut::<DynamicList>("items").is_none());
+        assert!(component.get_field_mut::<Vec<u32>>("items").is_none());
+        *component.get_field_mut::<f32>("x").unwrap() = 2.0;
+        assert!(component.is_changed());
+
+        // Mismatched writes are rejected, including elements of dynamic fields.
+        let mut value = DynamicStruct::default();
+        value.insert("x", 1u32);
+        assert_eq!(
+            component.apply(&value),
+            Err(DynamicComponentError::MismatchedField("x".into()))
+        );
+        let mut value = DynamicStruct::default();
+        value.insert("y", 1.0f32);
+        assert_eq!(
+            component.apply(&value),
+            Err(DynamicComponentError::UnknownField("y".into()))
+        );
+        let mut items = DynamicList::default();
+        items.push(2u32);
+        items.push(String::from("three"));
+        let mut value = DynamicStruct::default();
+        value.insert("items", items);
+        assert_eq!(
+            component.apply(&value),
+            Err(DynamicComponentError::MismatchedField("items[1]".into()))
+        );
+        assert_eq!(
+            component.apply(&1.0f32),
+            Err(DynamicComponentError::NotAStruct)
+        );
+        assert_eq!(get_field::<f32>(component.get(), "x"), Some(2.0));
+
+        let mut items = DynamicList::default();
+        items.push(2u32);
+        items.push(3u32);
+        let mut value = DynamicStruct::default();
+        value.insert("items", items);
+        component.apply(&value).unwrap();
+        let ReflectRef::Struct(component) = component.get().reflect_ref() else {
+            panic!("dynamic components are structs");
+        };
+        let items = Vec::<u32>::from_reflect(component.field("items").unwrap());
+        assert_eq!(items, Some(vec![2, 3]));
+    }
+}
@@ -0,0 +1,361 @@
+//! Relationships between entities, kept consistent on both sides.
+//!
+//! A relationship is a pair of [`Component`]s:
+//!
+//! - a [`Relationship`] (e.g. `Targeting`), stored on the *source* entity, which holds the [`Entity`] it points to.
+//! - a [`RelationshipTarget`] (e.g. `TargetedBy`), stored on the *target* entity, which holds the list
+//!   of every source entity currently pointing to it.
+//!
+//! Only the [`Relationship`] side should be edited. Inserting, replacing or removing it (or despawning
+//! the source entity) updates the [`RelationshipTarget`] of the target entity through component hooks.
+//! Removing the [`RelationshipTarget`] (or despawning the target entity) removes the [`Relationship`]
+//! component from all of its sources. A [`RelationshipTarget`] is removed once its last source is gone.
+//! Adding or removing a [`RelationshipTarget`] component is deferred to a command, so with direct
+//! [`World`] access the target side is only updated once commands are flushed, as shown below.
+//!
+//! Both traits are usually implemented through the [`Component`] derive:
+//!
+//! ```
+//! # use bevy_ecs::prelude::*;
+//! #[derive(Component)]
+//! #[relationship(relationship_target = TargetedBy)]
+//! struct Targeting(Entity);
+//!
+//! #[derive(Component)]
+//! #[relationship_target(relationship = Targeting)]
+//! struct TargetedBy(Vec<Entity>);
+//!
+//! let mut world = World::new();
+//! let target = world.spawn_empty().id();
+//! let hunter = world.spawn(Targeting(target)).id();
+//! world.flush_commands();
+//!
+//! assert_eq!(world.get::<TargetedBy>(target).unwrap().collection(), &[hunter]);
+//!
+//! world.entity_mut(hunter).remove::<Targeting>();
+//! world.flush_commands();
+//!
+//! assert!(world.get::<TargetedBy>(target).is_none());
+//! ```
+//!
+//! See [`RelationshipQueryExt`] for traversal methods on [`Query`](crate::system::Query).
+
+mod related_methods;
+mod relationship_query;
+
+pub use relationship_query::*;
+
+use crate::{
+    component::{Component, ComponentId},
+    entity::Entity,
+    world::{DeferredWorld, World},
+};
+use bevy_utils::tracing::warn;
+use std::any::type_name;
+
+/// A [`Component`] on a source entity that points to a target entity, mirrored on the target by the
+/// [`RelationshipTarget`] component `Self::RelationshipTarget`.
+///
+/// The [`RelationshipTarget`] of the target entity is kept up to date by the [`Relationship::on_insert`]
+/// and [`Relationship::on_replace`] hooks, which must be registered for this component.
+/// Deriving [`Component`] with `#[relationship(relationship_target = ...)]` does this for you.
+///
+/// Hooks can't insert components, so when the target entity has no [`RelationshipTarget`] yet, it is
+/// inserted by a command. With direct [`World`] access, the target side is only up to date once
+/// commands are flushed (e.g. with [`World::flush_commands`]); systems and [`Commands`] see both sides
+/// consistent as soon as the commands of the inserting system are applied. The same holds for
+/// removing a [`RelationshipTarget`] once its last source is gone, and for removing an invalid
+/// relationship.
+///
+/// [`Commands`]: crate::system::Commands
+///
+/// A relationship pointing to its own entity or to an entity that doesn't exist is invalid:
+/// it is removed and a warning is logged.
+pub trait Relationship: Component + Sized {
+    /// The [`Component`] added to the target entities of this relationship.
+    type RelationshipTarget: RelationshipTarget<Relationship = Self>;
+
+    /// Gets the [`Entity`] this relationship points to.
+    fn get(&self) -> Entity;
+
+    /// Creates this [`Relationship`] pointing to the given `entity`.
+    fn from(entity: Entity) -> Self;
+
+    /// The `on_insert` component hook that adds `entity` to the [`RelationshipTarget`] of the target entity.
+    fn on_insert(mut world: DeferredWorld, entity: Entity, _: ComponentId) {
+        let target_entity = world.get::<Self>(entity).unwrap().get();
+        if target_entity == entity {
+            warn!(
+                "The {}({target_entity:?}) relationship on entity {entity:?} points to itself. The invalid relationship has been removed.",
+                type_name::<Self>()
+            );
+            world.commands().entity(entity).remove::<Self>();
+            return;
+        }
+
+        if let Some(mut target) = world.get_mut::<Self::RelationshipTarget>(target_entity) {
+            target.collection_mut_risky().push(entity);
+        } else if world.get_entity(target_entity).is_some() {
+            // The target component can only be inserted through commands. Several sources may be
+            // related to the same target before they are applied, so the command merges them.
+            world.commands().add(move |world: &mut World| {
+                add_source::<Self>(world, target_entity, entity);
+            });
+        } else {
+            warn!(
+                "The {}({target_entity:?}) relationship on entity {entity:?} points to an entity that doesn't exist. The invalid relationship has been removed.",
+                type_name::<Self>()
+            );
+            world.commands().entity(entity).remove::<Self>();
+        }
+    }
+
+    /// The `on_replace` component hook that removes `entity` from the [`RelationshipTarget`] of the
+    /// target entity, removing the [`RelationshipTarget`] once it is empty.
+    fn on_replace(mut world: DeferredWorld, entity: Entity, _: ComponentId) {
+        let Some(target_entity) = world.get::<Self>(entity).map(Relationship::get) else {
+            return;
+        };
+
+        if let Some(mut target) = world.get_mut::<Self::RelationshipTarget>(target_entity) {
+            let collection = target.collection_mut_risky();
+            collection.retain(|&source| source != entity);
+            if collection.is_empty() {
+                world.commands().add(move |world: &mut World| {
+                    remove_empty_target::<Self>(world, target_entity);
+                });
+            }
+        }
+    }
+}
+
+/// A [`Component`] on a target entity listing every source entity whose [`Relationship`] points to it.
+///
+/// This component is managed by the hooks of `Self::Relationship` and should not be created or edited
+/// manually, hence the `_risky` methods. Its own [`RelationshipTarget::on_replace`] hook removes the
+/// [`Relationship`] from every source when it is removed, including when the target entity is despawned.
+/// Deriving [`Component`] with `#[relationship_target(relationship = ...)]` registers this hook for you.
+pub trait RelationshipTarget: Component + Sized {
+    /// The [`Relationship`] that populates this [`RelationshipTarget`].
+    type Relationship: Relationship<RelationshipTarget = Self>;
+
+    /// Returns the source entities of this relationship.
+    fn collection(&self) -> &[Entity];
+
+    /// Returns a mutable reference to the source entities of this relationship.
+    ///
+    /// Editing the collection directly desynchronizes it from the [`Relationship`] components of the sources.
+    fn collection_mut_risky(&mut self) -> &mut Vec<Entity>;
+
+    /// Creates a new [`RelationshipTarget`] from the given source entities.
+    ///
+    /// The source entities are not updated to point to this target.
+    fn from_collection_risky(collection: Vec<Entity>) -> Self;
+
+    /// The `on_replace` component hook that removes the [`Relationship`] from every source entity.
+    fn on_replace(mut world: DeferredWorld, entity: Entity, _: ComponentId) {
+        let Some(sources) = world
+            .get::<Self>(entity)
+            .map(|target| target.collection().to_vec())
+        else {
+            return;
+        };
+
+        let mut commands = world.commands();
+        for source in sources {
+            commands.add(move |world: &mut World| {
+                let Some(mut source) = world.get_entity_mut(source) else {
+                    return;
+                };
+                // The source may have been related to another target in the meantime.
+                if source
+                    .get::<Self::Relationship>()
+                    .is_some_and(|relationship| relationship.get() == entity)
+                {
+                    source.remove::<Self::Relationship>();
+                }
+            });
+        }
+    }
+
+    /// Iterates over the source entities of this relationship.
+    fn iter(&self) -> std::iter::Copied<std::slice::Iter<'_, Entity>> {
+        self.collection().iter().copied()
+    }
+
+    /// Returns the number of source entities of this relationship.
+    fn len(&self) -> usize {
+        self.collection().len()
+    }
+
+    /// Returns `true` if this relationship has no source entities.
+    fn is_empty(&self) -> bool {
+        self.collection().is_empty()
+    }
+}
+
+fn add_source<R: Relationship>(world: &mut World, target_entity: Entity, source: Entity) {
+    // The source may have been despawned or related to another target before this command ran.
+    let Some(relationship) = world.get::<R>(source) else {
+        return;
+    };
+    if relationship.get() != target_entity {
+        return;
+    }
+    let Some(mut target_entity) = world.get_entity_mut(target_entity) else {
+        return;
+    };
+
+    if let Some(mut target) = target_entity.get_mut::<R::RelationshipTarget>() {
+        let collection = target.collection_mut_risky();
+        if !collection.contains(&source) {
+            collection.push(source);
+        }
+    } else {
+        target_entity.insert(R::RelationshipTarget::from_collection_risky(vec![source]));
+    }
+}
+
+fn remove_empty_target<R: Relationship>(world: &mut World, target_entity: Entity) {
+    let Some(mut target_entity) = world.get_entity_mut(target_entity) else {
+        return;
+    };
+
+    if target_entity
+        .get::<R::RelationshipTarget>()
+        .is_some_and(RelationshipTarget::is_empty)
+    {
+        target_entity.remove::<R::RelationshipTarget>();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate as bevy_ecs;
+    use crate::prelude::*;
+    use crate::relationship::RelationshipTarget;
+
+    #[derive(Component)]
+    #[relationship(relationship_target = TargetedBy)]
+    struct Targeting(Entity);
+
+    #[derive(Component)]
+    #[relationship_target(relationship = Targeting)]
+    struct TargetedBy(Vec<Entity>);
+
+    #[derive(Component)]
+    #[relationship(relationship_target = Contains)]
+    struct InInventory {
+        inventory: Entity,
+    }
+
+    #[derive(Component)]
+    #[relationship_target(relationship = InInventory)]
+    struct Contains {
+        items: Vec<Entity>,
+    }
+
+    fn sources<T: RelationshipTarget>(world: &World, target: Entity) -> Vec<Entity> {
+        world
+            .get::<T>(target)
+            .map(|target| target.iter().collect())
+            .unwrap_or_default()
+    }
+
+    #[test]
+    fn insert_relationship_updates_target() {
+        let mut world = World::new();
+        let target = world.spawn_empty().id();
+        let a = world.spawn(Targeting(target)).id();
+        let b = world.spawn(Targeting(target)).id();
+        world.flush_commands();
+
+        assert_eq!(sources::<TargetedBy>(&world, target), vec![a, b]);
+    }
+
+    #[test]
+    fn named_field_relationship() {
+        let mut world = World::new();
+        let inventory = world.spawn_empty().id();
+        let sword = world.spawn(InInventory { inventory }).id();
+        world.flush_commands();
+
+        assert_eq!(world.get::<Contains>(inventory).unwrap().items, vec![sword]);
+    }
+
+    #[test]
+    fn replace_relationship_moves_source() {
+        let mut world = World::new();
+        let first = world.spawn_empty().id();
+        let second = world.spawn_empty().id();
+        let hunter = world.spawn(Targeting(first)).id();
+        world.flush_commands();
+
+        world.entity_mut(hunter).insert(Targeting(second));
+        world.flush_commands();
+
+        assert!(world.get::<TargetedBy>(first).is_none());
+        assert_eq!(sources::<TargetedBy>(&world, second), vec![hunter]);
+    }
+
+    #[test]
+    fn remove_and_despawn_source() {
+        let mut world = World::new();
+        let target = world.spawn_empty().id();
+        let a = world.spawn(Targeting(target)).id();
+        let b = world.spawn(Targeting(target)).id();
+        world.flush_commands();
+
+        world.entity_mut(a).remove::<Targeting>();
+        world.flush_commands();
+        assert_eq!(sources::<TargetedBy>(&world, target), vec![b]);
+
+        world.despawn(b);
+        world.flush_commands();
+        assert!(world.get::<TargetedBy>(target).is_none());
+    }
+
+    #[test]
+    fn despawn_target_removes_relationships() {
+        let mut world = World::new();
+        let target = world.spawn_empty().id();
+        let a = world.spawn(Targeting(target)).id();
+        let b = world.spawn(Targeting(target)).id();
+        world.flush_commands();
+
+        world.despawn(target);
+        world.flush_commands();
+
+        assert!(world.get::<Targeting>(a).is_none());
+        assert!(world.get::<Targeting>(b).is_none());
+    }
+
+    #[test]
+    fn invalid_relationships_are_removed() {
+        let mut world = World::new();
+        let missing = world.spawn_empty().id();
+        world.despawn(missing);
+
+        let a = world.spawn_empty().id();
+        world.entity_mut(a).insert(Targeting(a));
+        let b = world.spawn(Targeting(missing)).id();
+        world.flush_commands();
+
+        assert!(world.get::<Targeting>(a).is_none());
+        assert!(world.get::<TargetedBy>(a).is_none());
+        assert!(world.get::<Targeting>(b).is_none());
+    }
+
+    #[test]
+    fn relationships_through_commands() {
+        let mut world = World::new();
+        let target = world.spawn_empty().id();
+
+        let mut commands = world.commands();
+        let a = commands.spawn(Targeting(target)).id();
+        let b = commands.spawn(Targeting(target)).id();
+        world.flush_commands();
+
+        assert_eq!(sources::<TargetedBy>(&world, target), vec![a, b]);
+    }
+}
@@ -0,0 +1,116 @@
+use crate::{
+    entity::Entity,
+    system::EntityCommands,
+    world::{EntityWorldMut, World},
+};
+
+use super::{Relationship, RelationshipTarget};
+
+impl<'w> EntityWorldMut<'w> {
+    /// Relates the given `related` entities to this entity, by inserting the [`Relationship`] `R`
+    /// pointing to this entity on each of them.
+    ///
+    /// Any previous relationship of type `R` of the `related` entities is replaced.
+    pub fn add_related<R: Relationship>(&mut self, related: &[Entity]) -> &mut Self {
+        let id = self.id();
+        self.world_scope(|world| {
+            for related in related {
+                world.entity_mut(*related).insert(R::from(id));
+            }
+            world.flush_commands();
+        });
+        self
+    }
+
+    /// Removes the [`Relationship`] `R` from every entity currently related to this entity,
+    /// which also removes this entity's [`RelationshipTarget`].
+    pub fn clear_related<R: Relationship>(&mut self) -> &mut Self {
+        let Some(related) = self
+            .get::<R::RelationshipTarget>()
+            .map(|target| target.collection().to_vec())
+        else {
+            return self;
+        };
+        self.world_scope(|world| {
+            for related in related {
+                if let Some(mut related) = world.get_entity_mut(related) {
+                    related.remove::<R>();
+                }
+            }
+            world.flush_commands();
+        });
+        self.update_location();
+        self
+    }
+}
+
+impl<'a> EntityCommands<'a> {
+    /// Relates the given `related` entities to this entity.
+    ///
+    /// See [`EntityWorldMut::add_related`] for details.
+    pub fn add_related<R: Relationship>(&mut self, related: &[Entity]) -> &mut Self {
+        let related = related.to_vec();
+        self.add(move |id: Entity, world: &mut World| {
+            if let Some(mut entity) = world.get_entity_mut(id) {
+                entity.add_related::<R>(&related);
+            }
+        })
+    }
+
+    /// Removes the relationship `R` from every entity currently related to this entity.
+    ///
+    /// See [`EntityWorldMut::clear_related`] for details.
+    pub fn clear_related<R: Relationship>(&mut self) -> &mut Self {
+        self.add(|id: Entity, world: &mut World| {
+            if let Some(mut entity) = world.get_entity_mut(id) {
+                entity.clear_related::<R>();
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate as bevy_ecs;
+    use crate::prelude::*;
+
+    #[derive(Component)]
+    #[relationship(relationship_target = TargetedBy)]
+    struct Targeting(Entity);
+
+    #[derive(Component)]
+    #[relationship_target(relationship = Targeting)]
+    struct TargetedBy(Vec<Entity>);
+
+    #[test]
+    fn add_and_clear_related() {
+        let mut world = World::new();
+        let a = world.spawn_empty().id();
+        let b = world.spawn_empty().id();
+        let target = world.spawn_empty().add_related::<Targeting>(&[a, b]).id();
+        world.flush_commands();
+
+        assert_eq!(world.get::<TargetedBy>(target).unwrap().0, vec![a, b]);
+
+        world.entity_mut(target).clear_related::<Targeting>();
+        world.flush_commands();
+        assert!(world.get::<Targeting>(a).is_none());
+        assert!(world.get::<Targeting>(b).is_none());
+        assert!(world.get::<TargetedBy>(target).is_none());
+    }
+
+    #[test]
+    fn add_related_with_commands() {
+        let mut world = World::new();
+        let a = world.spawn_empty().id();
+        let target = world
+            .commands()
+            .spawn_empty()
+            .add_related::<Targeting>(&[a])
+            .id();
+        world.flush_commands();
+
+        assert_eq!(world.get::<Targeting>(a).unwrap().0, target);
+        assert_eq!(world.get::<TargetedBy>(target).unwrap().0, vec![a]);
+    }
+}
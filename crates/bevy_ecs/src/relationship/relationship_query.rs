@@ -0,0 +1,285 @@
+use std::{collections::VecDeque, marker::PhantomData};
+
+use crate::{
+    entity::Entity,
+    query::{QueryData, QueryFilter, WorldQuery},
+    system::Query,
+};
+
+use super::{Relationship, RelationshipTarget};
+
+/// An extension trait for [`Query`] that adds methods to traverse [`Relationship`]s.
+///
+/// Traversal methods assume that the relationship graph has no cycles:
+/// iterating over the ancestors or descendants of an entity that is part of a cycle never ends.
+pub trait RelationshipQueryExt<'w, 's, D: QueryData, F: QueryFilter> {
+    /// Returns the entity that `entity`'s [`Relationship`] points to, if any.
+    ///
+    /// Can only be called on a [`Query`] of a [`Relationship`] (e.g. `Query<&Targeting>`).
+    fn related<R: Relationship>(&'w self, entity: Entity) -> Option<Entity>
+    where
+        D::ReadOnly: WorldQuery<Item<'w> = &'w R>;
+
+    /// Returns the entities whose [`Relationship`] points to `entity`.
+    ///
+    /// Can only be called on a [`Query`] of a [`RelationshipTarget`] (e.g. `Query<&TargetedBy>`).
+    fn relationship_sources<S: RelationshipTarget>(&'w self, entity: Entity) -> &'w [Entity]
+    where
+        D::ReadOnly: WorldQuery<Item<'w> = &'w S>;
+
+    /// Returns the last entity reached by following `entity`'s [`Relationship`] transitively.
+    ///
+    /// Returns `entity` itself if it has no [`Relationship`].
+    ///
+    /// Can only be called on a [`Query`] of a [`Relationship`] (e.g. `Query<&Targeting>`).
+    fn root_related_ancestor<R: Relationship>(&'w self, entity: Entity) -> Entity
+    where
+        D::ReadOnly: WorldQuery<Item<'w> = &'w R>;
+
+    /// Returns an [`Iterator`] of [`Entity`]s reached by following `entity`'s [`Relationship`] transitively.
+    ///
+    /// Can only be called on a [`Query`] of a [`Relationship`] (e.g. `Query<&Targeting>`).
+    ///
+    /// # Examples
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ecs::relationship::RelationshipQueryExt;
+    /// #[derive(Component)]
+    /// #[relationship(relationship_target = Contains)]
+    /// struct InInventory(Entity);
+    ///
+    /// #[derive(Component)]
+    /// #[relationship_target(relationship = InInventory)]
+    /// struct Contains(Vec<Entity>);
+    ///
+    /// # #[derive(Component)]
+    /// # struct Coin;
+    /// fn system(coins: Query<Entity, With<Coin>>, inventories: Query<&InInventory>) {
+    ///     for coin in &coins {
+    ///         for container in inventories.iter_related_ancestors(coin) {
+    ///             // Do something!
+    ///         }
+    ///     }
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    fn iter_related_ancestors<R: Relationship>(
+        &'w self,
+        entity: Entity,
+    ) -> RelatedAncestorIter<'w, 's, D, F, R>
+    where
+        D::ReadOnly: WorldQuery<Item<'w> = &'w R>;
+
+    /// Returns an [`Iterator`] of [`Entity`]s over all of the sources of `entity`, and their own sources, transitively.
+    ///
+    /// Can only be called on a [`Query`] of a [`RelationshipTarget`] (e.g. `Query<&TargetedBy>`).
+    ///
+    /// Traverses the relationships breadth-first.
+    ///
+    /// # Examples
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ecs::relationship::RelationshipQueryExt;
+    /// #[derive(Component)]
+    /// #[relationship(relationship_target = Contains)]
+    /// struct InInventory(Entity);
+    ///
+    /// #[derive(Component)]
+    /// #[relationship_target(relationship = InInventory)]
+    /// struct Contains(Vec<Entity>);
+    ///
+    /// # #[derive(Component)]
+    /// # struct Player;
+    /// fn system(player: Query<Entity, With<Player>>, contents: Query<&Contains>) {
+    ///     let player = player.single();
+    ///     for item in contents.iter_related_descendants(player) {
+    ///         // Do something!
+    ///     }
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    fn iter_related_descendants<S: RelationshipTarget>(
+        &'w self,
+        entity: Entity,
+    ) -> RelatedDescendantIter<'w, 's, D, F, S>
+    where
+        D::ReadOnly: WorldQuery<Item<'w> = &'w S>;
+}
+
+impl<'w, 's, D: QueryData, F: QueryFilter> RelationshipQueryExt<'w, 's, D, F>
+    for Query<'w, 's, D, F>
+{
+    fn related<R: Relationship>(&'w self, entity: Entity) -> Option<Entity>
+    where
+        D::ReadOnly: WorldQuery<Item<'w> = &'w R>,
+    {
+        self.get(entity).map(R::get).ok()
+    }
+
+    fn relationship_sources<S: RelationshipTarget>(&'w self, entity: Entity) -> &'w [Entity]
+    where
+        D::ReadOnly: WorldQuery<Item<'w> = &'w S>,
+    {
+        self.get(entity)
+            .map(RelationshipTarget::collection)
+            .unwrap_or(&[])
+    }
+
+    fn root_related_ancestor<R: Relationship>(&'w self, entity: Entity) -> Entity
+    where
+        D::ReadOnly: WorldQuery<Item<'w> = &'w R>,
+    {
+        self.iter_related_ancestors(entity).last().unwrap_or(entity)
+    }
+
+    fn iter_related_ancestors<R: Relationship>(
+        &'w self,
+        entity: Entity,
+    ) -> RelatedAncestorIter<'w, 's, D, F, R>
+    where
+        D::ReadOnly: WorldQuery<Item<'w> = &'w R>,
+    {
+        RelatedAncestorIter::new(self, entity)
+    }
+
+    fn iter_related_descendants<S: RelationshipTarget>(
+        &'w self,
+        entity: Entity,
+    ) -> RelatedDescendantIter<'w, 's, D, F, S>
+    where
+        D::ReadOnly: WorldQuery<Item<'w> = &'w S>,
+    {
+        RelatedDescendantIter::new(self, entity)
+    }
+}
+
+/// An [`Iterator`] of [`Entity`]s reached by following the [`Relationship`] of an [`Entity`] transitively.
+pub struct RelatedAncestorIter<'w, 's, D: QueryData, F: QueryFilter, R: Relationship>
+where
+    D::ReadOnly: WorldQuery<Item<'w> = &'w R>,
+{
+    relationship_query: &'w Query<'w, 's, D, F>,
+    next: Option<Entity>,
+    marker: PhantomData<R>,
+}
+
+impl<'w, 's, D: QueryData, F: QueryFilter, R: Relationship> RelatedAncestorIter<'w, 's, D, F, R>
+where
+    D::ReadOnly: WorldQuery<Item<'w> = &'w R>,
+{
+    /// Returns a new [`RelatedAncestorIter`].
+    pub fn new(relationship_query: &'w Query<'w, 's, D, F>, entity: Entity) -> Self {
+        RelatedAncestorIter {
+            relationship_query,
+            next: Some(entity),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<'w, 's, D: QueryData, F: QueryFilter, R: Relationship> Iterator
+    for RelatedAncestorIter<'w, 's, D, F, R>
+where
+    D::ReadOnly: WorldQuery<Item<'w> = &'w R>,
+{
+    type Item = Entity;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next = self
+            .relationship_query
+            .get(self.next?)
+            .ok()
+            .map(Relationship::get);
+        self.next
+    }
+}
+
+/// An [`Iterator`] of [`Entity`]s over the sources of an [`Entity`], and their own sources, transitively.
+///
+/// Traverses the relationships breadth-first.
+pub struct RelatedDescendantIter<'w, 's, D: QueryData, F: QueryFilter, S: RelationshipTarget>
+where
+    D::ReadOnly: WorldQuery<Item<'w> = &'w S>,
+{
+    target_query: &'w Query<'w, 's, D, F>,
+    vecdeque: VecDeque<Entity>,
+}
+
+impl<'w, 's, D: QueryData, F: QueryFilter, S: RelationshipTarget>
+    RelatedDescendantIter<'w, 's, D, F, S>
+where
+    D::ReadOnly: WorldQuery<Item<'w> = &'w S>,
+{
+    /// Returns a new [`RelatedDescendantIter`].
+    pub fn new(target_query: &'w Query<'w, 's, D, F>, entity: Entity) -> Self {
+        RelatedDescendantIter {
+            target_query,
+            vecdeque: target_query
+                .get(entity)
+                .into_iter()
+                .flat_map(RelationshipTarget::iter)
+                .collect(),
+        }
+    }
+}
+
+impl<'w, 's, D: QueryData, F: QueryFilter, S: RelationshipTarget> Iterator
+    for RelatedDescendantIter<'w, 's, D, F, S>
+where
+    D::ReadOnly: WorldQuery<Item<'w> = &'w S>,
+{
+    type Item = Entity;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let entity = self.vecdeque.pop_front()?;
+
+        if let Ok(target) = self.target_query.get(entity) {
+            self.vecdeque.extend(target.iter());
+        }
+
+        Some(entity)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate as bevy_ecs;
+    use crate::prelude::*;
+    use crate::relationship::RelationshipQueryExt;
+    use crate::system::SystemState;
+
+    #[derive(Component)]
+    #[relationship(relationship_target = Contains)]
+    struct InInventory(Entity);
+
+    #[derive(Component)]
+    #[relationship_target(relationship = InInventory)]
+    struct Contains(Vec<Entity>);
+
+    #[test]
+    fn traversal() {
+        let mut world = World::new();
+        let player = world.spawn_empty().id();
+        let bag = world.spawn(InInventory(player)).id();
+        let sword = world.spawn(InInventory(player)).id();
+        let coin = world.spawn(InInventory(bag)).id();
+        world.flush_commands();
+
+        let mut system_state =
+            SystemState::<(Query<&InInventory>, Query<&Contains>)>::new(&mut world);
+        let (inventories, contents) = system_state.get(&world);
+
+        assert_eq!(inventories.related(coin), Some(bag));
+        assert_eq!(inventories.related(player), None);
+        assert_eq!(contents.relationship_sources(player), &[bag, sword]);
+        assert_eq!(contents.relationship_sources(coin), &[]);
+
+        let ancestors: Vec<_> = inventories.iter_related_ancestors(coin).collect();
+        assert_eq!(ancestors, vec![bag, player]);
+        assert_eq!(inventories.root_related_ancestor(coin), player);
+        assert_eq!(inventories.root_related_ancestor(player), player);
+
+        let descendants: Vec<_> = contents.iter_related_descendants(player).collect();
+        assert_eq!(descendants, vec![bag, sword, coin]);
+    }
+}
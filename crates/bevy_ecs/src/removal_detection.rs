@@ -1,4 +1,4 @@
-//! Alerting events when a component is removed from an entity.
+//! Alerting events when a component is removed from an entity, or a resource from the world.
 
 use crate::{
     self as bevy_ecs,
@@ -6,8 +6,8 @@ use crate::{
     entity::Entity,
     event::{Event, EventId, EventIterator, EventIteratorWithId, Events, ManualEventReader},
     prelude::Local,
-    storage::SparseSet,
-    system::{ReadOnlySystemParam, SystemMeta, SystemParam},
+    storage::{ResourceData, SparseSet},
+    system::{ReadOnlySystemParam, Resource, SystemMeta, SystemParam},
     world::{unsafe_world_cell::UnsafeWorldCell, World},
 };
 
@@ -263,3 +263,89 @@ unsafe impl<'a> SystemParam for &'a RemovedComponentEvents {
         world.removed_components()
     }
 }
+
+/// A [`SystemParam`] that reports whether the [`Resource`] `R` was removed from the world
+/// since the system last ran.
+///
+/// Unlike [`RemovedComponents`], this does not need to be flushed with
+/// [`World::clear_trackers`]: it uses change ticks, like [`Res::is_added`](crate::system::Res).
+/// The resource may have been inserted again since it was removed.
+///
+/// # Examples
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::removal_detection::RemovedResources;
+/// #
+/// # #[derive(Resource)]
+/// # struct Config;
+/// fn react_on_removal(removed: RemovedResources<Config>) {
+///     if removed.is_removed() {
+///         println!("Config was removed");
+///     }
+/// }
+/// # bevy_ecs::system::assert_is_system(react_on_removal);
+/// ```
+pub struct RemovedResources<R: Resource> {
+    removed_tick: Option<Tick>,
+    last_run: Tick,
+    this_run: Tick,
+    marker: PhantomData<fn() -> R>,
+}
+
+impl<R: Resource> RemovedResources<R> {
+    /// Returns `true` if the resource was removed since the system last ran.
+    pub fn is_removed(&self) -> bool {
+        self.removed_tick
+            .map(|removed| removed.is_newer_than(self.last_run, self.this_run))
+            .unwrap_or(false)
+    }
+
+    /// Returns the tick at which the resource was last removed, if it ever was.
+    pub fn removed_tick(&self) -> Option<Tick> {
+        self.removed_tick
+    }
+}
+
+impl<R: Resource> Debug for RemovedResources<R> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RemovedResources")
+            .field("resource", &std::any::type_name::<R>())
+            .field("removed_tick", &self.removed_tick)
+            .finish()
+    }
+}
+
+// SAFETY: Only reads the removal tick of a resource, which is only written with exclusive world access.
+unsafe impl<R: Resource> ReadOnlySystemParam for RemovedResources<R> {}
+
+// SAFETY: no resource value access.
+unsafe impl<R: Resource> SystemParam for RemovedResources<R> {
+    type State = ComponentId;
+    type Item<'w, 's> = RemovedResources<R>;
+
+    fn init_state(world: &mut World, _system_meta: &mut SystemMeta) -> Self::State {
+        world.components.init_resource::<R>()
+    }
+
+    #[inline]
+    unsafe fn get_param<'w, 's>(
+        &mut component_id: &'s mut Self::State,
+        system_meta: &SystemMeta,
+        world: UnsafeWorldCell<'w>,
+        change_tick: Tick,
+    ) -> Self::Item<'w, 's> {
+        // SAFETY: The removal tick is only written through `&mut World`, which cannot exist while
+        // systems are running, and no other resource data is read.
+        let removed_tick = unsafe { world.storages() }
+            .resources
+            .get(component_id)
+            .and_then(ResourceData::removed_tick);
+        RemovedResources {
+            removed_tick,
+            last_run: system_meta.last_run,
+            this_run: change_tick,
+            marker: PhantomData,
+        }
+    }
+}
@@ -1,13 +1,14 @@
 use bevy_utils::all_tuples;
 
 use crate::{
+    error::{BoxedError, DefaultErrorHandler, ErrorContext},
     schedule::{
         condition::{BoxedCondition, Condition},
         graph_utils::{Ambiguity, Dependency, DependencyKind, GraphInfo},
         set::{InternedSystemSet, IntoSystemSet, SystemSet},
         Chain,
     },
-    system::{BoxedSystem, IntoSystem, System},
+    system::{BoxedSystem, In, IntoSystem, PipeSystem, Res, System},
 };
 
 fn new_condition<M>(condition: impl Condition<M>) -> BoxedCondition {
@@ -33,7 +34,13 @@ fn ambiguous_with(graph_info: &mut GraphInfo, set: InternedSystemSet) {
     }
 }
 
-impl<Marker, F> IntoSystemConfigs<Marker> for F
+#[doc(hidden)]
+pub struct InfallibleSystemMarker;
+
+#[doc(hidden)]
+pub struct FallibleSystemMarker;
+
+impl<Marker, F> IntoSystemConfigs<(InfallibleSystemMarker, Marker)> for F
 where
     F: IntoSystem<(), (), Marker>,
 {
@@ -42,6 +49,31 @@ where
     }
 }
 
+/// Systems returning a [`Result`] have their errors passed to the [`DefaultErrorHandler`] of the world.
+impl<Marker, F, E> IntoSystemConfigs<(FallibleSystemMarker, E, Marker)> for F
+where
+    F: IntoSystem<(), Result<(), E>, Marker>,
+    E: Into<BoxedError> + 'static,
+{
+    fn into_configs(self) -> SystemConfigs {
+        let system = IntoSystem::into_system(self);
+        let name = system.name();
+        let context = ErrorContext::System { name: name.clone() };
+        let handle_error =
+            move |In(result): In<Result<(), E>>, handler: Option<Res<DefaultErrorHandler>>| {
+                if let Err(err) = result {
+                    let handler = handler.map(|handler| *handler).unwrap_or_default();
+                    (handler.0)(err.into(), context.clone());
+                }
+            };
+        SystemConfigs::new_system(Box::new(PipeSystem::new(
+            system,
+            IntoSystem::into_system(handle_error),
+            name,
+        )))
+    }
+}
+
 impl IntoSystemConfigs<()> for BoxedSystem<(), ()> {
     fn into_configs(self) -> SystemConfigs {
         SystemConfigs::new_system(self)
@@ -282,6 +314,20 @@ impl<T> NodeConfigs<T> {
 ///     )
 /// );
 /// ```
+///
+/// # Fallible systems
+///
+/// Systems returning a [`Result`] are accepted as well, and their errors are passed to the
+/// [`DefaultErrorHandler`] of the world. See the [`error`](crate::error) module for more details.
+///
+/// A closure that never returns, such as `|| todo!()`, could be either an infallible or a fallible
+/// system, so its return type can't be inferred. Write the unit return type out to add it:
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// let mut schedule = Schedule::default();
+/// schedule.add_systems(|| -> () { todo!() });
+/// ```
 pub trait IntoSystemConfigs<Marker>
 where
     Self: Sized,
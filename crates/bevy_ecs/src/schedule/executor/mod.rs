@@ -6,6 +6,7 @@ pub use self::multi_threaded::{MainThreadExecutor, MultiThreadedExecutor};
 pub use self::simple::SimpleExecutor;
 pub use self::single_threaded::SingleThreadedExecutor;
 
+use bevy_utils::Duration;
 use fixedbitset::FixedBitSet;
 
 use crate::{
@@ -73,6 +74,9 @@ pub struct SystemSchedule {
     pub(super) set_conditions: Vec<Vec<BoxedCondition>>,
     /// Indexed by system set node id.
     pub(super) systems_in_sets_with_conditions: Vec<FixedBitSet>,
+    /// Indexed by system node id.
+    /// How long each system took the last time it ran while the schedule was being stepped.
+    pub(super) system_run_durations: Vec<Option<Duration>>,
 }
 
 impl SystemSchedule {
@@ -88,6 +92,7 @@ impl SystemSchedule {
             system_dependents: Vec::new(),
             sets_with_conditions_of_systems: Vec::new(),
             systems_in_sets_with_conditions: Vec::new(),
+            system_run_durations: Vec::new(),
         }
     }
 }
@@ -4,10 +4,10 @@ use std::{
 };
 
 use bevy_tasks::{ComputeTaskPool, Scope, TaskPool, ThreadExecutor};
-use bevy_utils::default;
 use bevy_utils::syncunsafecell::SyncUnsafeCell;
 #[cfg(feature = "trace")]
 use bevy_utils::tracing::{info_span, Span};
+use bevy_utils::{default, Duration, Instant};
 use std::panic::AssertUnwindSafe;
 
 use concurrent_queue::ConcurrentQueue;
@@ -81,6 +81,8 @@ struct SystemTaskMetadata {
 struct SystemResult {
     system_index: usize,
     success: bool,
+    /// How long the system took to run, if it was timed.
+    duration: Option<Duration>,
 }
 
 /// Runs the schedule using a thread pool. Non-conflicting systems can run in parallel.
@@ -132,6 +134,10 @@ pub struct ExecutorState {
     unapplied_systems: FixedBitSet,
     /// When set, stops the executor from running any more systems.
     stop_spawning: bool,
+    /// When set, system tasks measure how long their system takes to run.
+    record_run_durations: bool,
+    /// How long each system took the last time it was timed.
+    system_run_durations: Vec<Option<Duration>>,
 }
 
 /// References to data required by the executor.
@@ -186,6 +192,7 @@ impl SystemExecutor for MultiThreadedExecutor {
         }
 
         state.num_dependencies_remaining = Vec::with_capacity(sys_count);
+        state.system_run_durations = vec![None; sys_count];
     }
 
     fn run(
@@ -231,6 +238,9 @@ impl SystemExecutor for MultiThreadedExecutor {
             }
         }
 
+        // Systems are only timed while the schedule is being stepped.
+        state.record_run_durations = _skip_systems.is_some();
+
         let thread_executor = world
             .get_resource::<MainThreadExecutor>()
             .map(|e| e.0.clone());
@@ -272,6 +282,12 @@ impl SystemExecutor for MultiThreadedExecutor {
             std::panic::resume_unwind(payload);
         }
 
+        if state.record_run_durations {
+            schedule
+                .system_run_durations
+                .clone_from(&state.system_run_durations);
+        }
+
         debug_assert!(state.ready_systems.is_clear());
         debug_assert!(state.running_systems.is_clear());
         state.active_access.clear();
@@ -291,6 +307,7 @@ impl<'scope, 'env: 'scope, 'sys> Context<'scope, 'env, 'sys> {
         system_index: usize,
         res: Result<(), Box<dyn Any + Send>>,
         system: &BoxedSystem,
+        duration: Option<Duration>,
     ) {
         // tell the executor that the system finished
         self.environment
@@ -299,6 +316,7 @@ impl<'scope, 'env: 'scope, 'sys> Context<'scope, 'env, 'sys> {
             .push(SystemResult {
                 system_index,
                 success: res.is_ok(),
+                duration,
             })
             .unwrap_or_else(|error| unreachable!("{}", error));
         if let Err(payload) = res {
@@ -367,6 +385,8 @@ impl ExecutorState {
             completed_systems: FixedBitSet::new(),
             unapplied_systems: FixedBitSet::new(),
             stop_spawning: false,
+            record_run_durations: false,
+            system_run_durations: Vec::new(),
         }
     }
 
@@ -612,7 +632,9 @@ impl ExecutorState {
 
         #[cfg(feature = "trace")]
         let system_span = system_meta.system_task_span.clone();
+        let record_run_duration = self.record_run_durations;
         let task = async move {
+            let start = record_run_duration.then(Instant::now);
             let res = std::panic::catch_unwind(AssertUnwindSafe(|| {
                 #[cfg(feature = "trace")]
                 let _span = system_span.enter();
@@ -627,7 +649,8 @@ impl ExecutorState {
                     );
                 };
             }));
-            context.system_completed(system_index, res, system);
+            let duration = start.map(|start| start.elapsed());
+            context.system_completed(system_index, res, system, duration);
         };
 
         self.active_access
@@ -666,18 +689,21 @@ impl ExecutorState {
                     let _span = system_span.enter();
                     apply_deferred(&unapplied_systems, context.environment.systems, world)
                 };
-                context.system_completed(system_index, res, system);
+                context.system_completed(system_index, res, system, None);
             };
 
             context.scope.spawn_on_scope(task);
         } else {
+            let record_run_duration = self.record_run_durations;
             let task = async move {
+                let start = record_run_duration.then(Instant::now);
                 let res = std::panic::catch_unwind(AssertUnwindSafe(|| {
                     #[cfg(feature = "trace")]
                     let _span = system_span.enter();
                     __rust_begin_short_backtrace::run(&mut **system, world);
                 }));
-                context.system_completed(system_index, res, system);
+                let duration = start.map(|start| start.elapsed());
+                context.system_completed(system_index, res, system, duration);
             };
 
             context.scope.spawn_on_scope(task);
@@ -691,8 +717,13 @@ impl ExecutorState {
         let SystemResult {
             system_index,
             success,
+            duration,
         } = result;
 
+        if duration.is_some() {
+            self.system_run_durations[system_index] = duration;
+        }
+
         if self.system_task_metadata[system_index].is_exclusive {
             self.exclusive_running = false;
         }
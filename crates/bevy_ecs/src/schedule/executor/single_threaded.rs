@@ -1,5 +1,6 @@
 #[cfg(feature = "trace")]
 use bevy_utils::tracing::info_span;
+use bevy_utils::Instant;
 use fixedbitset::FixedBitSet;
 use std::panic::AssertUnwindSafe;
 
@@ -54,6 +55,9 @@ impl SystemExecutor for SingleThreadedExecutor {
             self.completed_systems |= skipped_systems;
         }
 
+        // Systems are only timed while the schedule is being stepped.
+        let record_run_durations = _skip_systems.is_some();
+
         for system_index in 0..schedule.systems.len() {
             #[cfg(feature = "trace")]
             let name = schedule.systems[system_index].name();
@@ -101,6 +105,7 @@ impl SystemExecutor for SingleThreadedExecutor {
                 continue;
             }
 
+            let start = record_run_durations.then(Instant::now);
             let res = std::panic::catch_unwind(AssertUnwindSafe(|| {
                 if system.is_exclusive() {
                     __rust_begin_short_backtrace::run(&mut **system, world);
@@ -113,6 +118,9 @@ impl SystemExecutor for SingleThreadedExecutor {
                     unsafe { __rust_begin_short_backtrace::run_unsafe(&mut **system, world) };
                 }
             }));
+            if let Some(start) = start {
+                schedule.system_run_durations[system_index] = Some(start.elapsed());
+            }
             if let Err(payload) = res {
                 eprintln!("Encountered a panic in system `{}`!", &*system.name());
                 std::panic::resume_unwind(payload);
@@ -0,0 +1,172 @@
+use std::fmt::Write;
+
+use serde::{Deserialize, Serialize};
+
+use crate::schedule::NodeId;
+
+/// A snapshot of the systems, system sets and edges of a [`Schedule`](super::Schedule),
+/// used to inspect or visualize why systems run in the order they do.
+///
+/// This is created by [`Schedule::export_graph`](super::Schedule::export_graph), and can be
+/// serialized with `serde` or converted to the Graphviz DOT format with [`ScheduleGraphExport::to_dot`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScheduleGraphExport {
+    /// The label of the schedule.
+    pub label: String,
+    /// All systems in the schedule.
+    pub systems: Vec<SystemNodeExport>,
+    /// All system sets in the schedule.
+    pub sets: Vec<SystemSetNodeExport>,
+    /// Edges from system sets to their members, which are either systems or system sets.
+    pub hierarchy: Vec<(NodeId, NodeId)>,
+    /// Edges from systems or system sets to the systems or system sets that must run after them.
+    ///
+    /// These are the ordering constraints added to the schedule, including the ones from
+    /// [`chain`](super::IntoSystemConfigs::chain) and automatically inserted sync points.
+    pub dependencies: Vec<(NodeId, NodeId)>,
+    /// The systems in the order they are run in, if the schedule has been initialized.
+    pub topological_order: Vec<NodeId>,
+    /// Pairs of systems with conflicting data access and no ordering between them.
+    ///
+    /// This is only populated once the schedule has been initialized.
+    pub ambiguities: Vec<AmbiguityExport>,
+    /// Pairs of systems or system sets whose ambiguities were explicitly ignored with
+    /// [`ambiguous_with`](super::IntoSystemConfigs::ambiguous_with).
+    pub ignored_ambiguities: Vec<(NodeId, NodeId)>,
+    /// Systems or system sets whose ambiguities with every other node are ignored, as with
+    /// [`ambiguous_with_all`](super::IntoSystemConfigs::ambiguous_with_all).
+    pub ambiguous_with_all: Vec<NodeId>,
+}
+
+/// A system in a [`ScheduleGraphExport`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SystemNodeExport {
+    /// The id of the system in the schedule.
+    pub id: NodeId,
+    /// The name of the system.
+    pub name: String,
+    /// The names of the run conditions of the system.
+    pub conditions: Vec<String>,
+}
+
+/// A system set in a [`ScheduleGraphExport`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SystemSetNodeExport {
+    /// The id of the system set in the schedule.
+    pub id: NodeId,
+    /// The name of the system set.
+    pub name: String,
+    /// Whether this is the set automatically created for the type of a system, used
+    /// when ordering relative to a system function such as `a.before(b)`.
+    pub system_type: bool,
+    /// The names of the run conditions of the system set.
+    pub conditions: Vec<String>,
+}
+
+/// An ambiguity between two systems in a [`ScheduleGraphExport`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AmbiguityExport {
+    /// The first system.
+    pub first: NodeId,
+    /// The second system.
+    pub second: NodeId,
+    /// The names of the components and resources both systems access, with at least one mutably.
+    ///
+    /// If empty, the systems conflict on access to the whole [`World`](crate::world::World).
+    pub conflicts: Vec<String>,
+}
+
+impl ScheduleGraphExport {
+    /// Converts this graph into the Graphviz DOT format.
+    ///
+    /// Systems are drawn as boxes and system sets as ellipses. Dependencies are solid arrows,
+    /// set membership is drawn with dotted arrows, and ambiguities with red dashed lines
+    /// labeled with the conflicting data.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::new();
+        // Writing to a `String` never fails.
+        let _ = self.write_dot(&mut dot);
+        dot
+    }
+
+    fn write_dot(&self, dot: &mut String) -> std::fmt::Result {
+        writeln!(dot, "digraph {} {{", quote(&self.label))?;
+        writeln!(dot, "\tnode [fontname = \"monospace\"];")?;
+        for system in &self.systems {
+            writeln!(
+                dot,
+                "\t{} [label = {}, shape = box];",
+                node_name(system.id),
+                quote(&with_conditions(&system.name, &system.conditions))
+            )?;
+        }
+        for set in &self.sets {
+            let style = if set.system_type { "dotted" } else { "solid" };
+            writeln!(
+                dot,
+                "\t{} [label = {}, shape = ellipse, style = {style}];",
+                node_name(set.id),
+                quote(&with_conditions(&set.name, &set.conditions))
+            )?;
+        }
+        for (set, member) in &self.hierarchy {
+            writeln!(
+                dot,
+                "\t{} -> {} [style = dotted, color = gray];",
+                node_name(*set),
+                node_name(*member)
+            )?;
+        }
+        for (before, after) in &self.dependencies {
+            writeln!(dot, "\t{} -> {};", node_name(*before), node_name(*after))?;
+        }
+        for ambiguity in &self.ambiguities {
+            let label = if ambiguity.conflicts.is_empty() {
+                "World".to_string()
+            } else {
+                ambiguity.conflicts.join(", ")
+            };
+            writeln!(
+                dot,
+                "\t{} -> {} [dir = none, style = dashed, color = red, label = {}];",
+                node_name(ambiguity.first),
+                node_name(ambiguity.second),
+                quote(&label)
+            )?;
+        }
+        writeln!(dot, "}}")
+    }
+}
+
+fn node_name(id: NodeId) -> String {
+    match id {
+        NodeId::System(index) => format!("system_{index}"),
+        NodeId::Set(index) => format!("set_{index}"),
+    }
+}
+
+fn with_conditions(name: &str, conditions: &[String]) -> String {
+    if conditions.is_empty() {
+        name.to_string()
+    } else {
+        format!("{name}\nrun if: {}", conditions.join(", "))
+    }
+}
+
+/// Quotes and escapes a string to be used as a DOT identifier or label.
+fn quote(string: &str) -> String {
+    let mut quoted = String::with_capacity(string.len() + 2);
+    quoted.push('"');
+    for c in string.chars() {
+        match c {
+            '"' | '\\' => {
+                quoted.push('\\');
+                quoted.push(c);
+            }
+            '\n' => quoted.push_str("\\n"),
+            _ => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
@@ -3,13 +3,14 @@ use std::fmt::Debug;
 use bevy_utils::{HashMap, HashSet};
 use fixedbitset::FixedBitSet;
 use petgraph::{algo::TarjanScc, graphmap::NodeTrait, prelude::*};
+use serde::{Deserialize, Serialize};
 
 use crate::schedule::set::*;
 
 /// Unique identifier for a system or system set stored in a [`ScheduleGraph`].
 ///
 /// [`ScheduleGraph`]: super::ScheduleGraph
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub enum NodeId {
     /// Identifier for a system.
     System(usize),
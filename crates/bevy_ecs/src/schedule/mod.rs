@@ -3,20 +3,20 @@
 mod condition;
 mod config;
 mod executor;
+mod graph_export;
 mod graph_utils;
 #[allow(clippy::module_inception)]
 mod schedule;
 mod set;
-mod state;
 mod stepping;
 
 pub use self::condition::*;
 pub use self::config::*;
 pub use self::executor::*;
+pub use self::graph_export::*;
 use self::graph_utils::*;
 pub use self::schedule::*;
 pub use self::set::*;
-pub use self::state::*;
 
 pub use self::graph_utils::NodeId;
 
@@ -118,6 +118,64 @@ mod tests {
 
             schedule.run(&mut world);
         }
+
+        #[test]
+        fn run_fallible_systems() {
+            use crate::error::{BoxedError, DefaultErrorHandler, ErrorContext, Result};
+
+            static ERRORS: AtomicU32 = AtomicU32::new(0);
+
+            fn count_errors(error: BoxedError, context: ErrorContext) {
+                assert_eq!(error.to_string(), "failed");
+                assert!(context.name().contains("failing"));
+                ERRORS.fetch_add(1, Ordering::Relaxed);
+            }
+
+            fn failing_system(mut resource: ResMut<SystemOrder>) -> Result {
+                resource.0.push(0);
+                Err("failed".into())
+            }
+
+            fn failing_exclusive_system(world: &mut World) -> Result<(), std::fmt::Error> {
+                world.resource_mut::<SystemOrder>().0.push(1);
+                Err(std::fmt::Error)
+            }
+
+            fn succeeding_system(mut resource: ResMut<SystemOrder>) -> Result {
+                resource.0.push(2);
+                Ok(())
+            }
+
+            let mut world = World::default();
+            let mut schedule = Schedule::default();
+
+            world.init_resource::<SystemOrder>();
+            world.insert_resource(DefaultErrorHandler(count_errors));
+
+            schedule.add_systems((failing_system, succeeding_system).chain());
+            schedule.run(&mut world);
+
+            assert_eq!(world.resource::<SystemOrder>().0, vec![0, 2]);
+            assert_eq!(ERRORS.load(Ordering::Relaxed), 1);
+
+            let mut schedule = Schedule::default();
+            schedule.add_systems(failing_exclusive_system);
+            world.insert_resource(DefaultErrorHandler(crate::error::ignore));
+            schedule.run(&mut world);
+
+            assert_eq!(world.resource::<SystemOrder>().0, vec![0, 2, 1]);
+            assert_eq!(ERRORS.load(Ordering::Relaxed), 1);
+        }
+
+        #[test]
+        #[should_panic = "Encountered an error in system"]
+        fn fallible_system_panics_by_default() {
+            let mut world = World::default();
+            let mut schedule = Schedule::default();
+
+            schedule.add_systems(|| -> crate::error::Result { Err("failed".into()) });
+            schedule.run(&mut world);
+        }
     }
 
     mod system_ordering {
@@ -1112,9 +1170,10 @@ mod tests {
             ($executor:expr) => {
                 // create a test schedule
                 let mut schedule = Schedule::new(TestSchedule);
+                // The unit return type is needed, as a diverging closure could also be a fallible system.
                 schedule
                     .set_executor_kind($executor)
-                    .add_systems(|| panic!("Executor ignored Stepping"));
+                    .add_systems(|| -> () { panic!("Executor ignored Stepping") });
 
                 // Add our schedule to stepping & and enable stepping; this should
                 // prevent any systems in the schedule from running
@@ -342,6 +342,12 @@ impl Schedule {
 
             self.executor
                 .run(&mut self.executable, world, skip_systems.as_ref());
+
+            if skip_systems.is_some() {
+                if let Some(mut stepping) = world.get_resource_mut::<Stepping>() {
+                    stepping.record_run_durations(self);
+                }
+            }
         }
     }
 
@@ -447,6 +453,163 @@ impl Schedule {
         Ok(iter)
     }
 
+    /// Exports the systems, system sets, ordering dependencies and ambiguities of this schedule,
+    /// which can be serialized or converted to the Graphviz DOT format with
+    /// [`ScheduleGraphExport::to_dot`].
+    ///
+    /// The execution order and the ambiguities are only known once the schedule has been
+    /// initialized, for example with [`Schedule::initialize`] or by running it.
+    /// `components` are used to name the data that ambiguous systems conflict on.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #[derive(Resource, Default)]
+    /// struct Score(u32);
+    ///
+    /// fn add_points(mut score: ResMut<Score>) {
+    ///     score.0 += 1;
+    /// }
+    ///
+    /// fn reset_score(mut score: ResMut<Score>) {
+    ///     score.0 = 0;
+    /// }
+    ///
+    /// let mut world = World::new();
+    /// world.init_resource::<Score>();
+    ///
+    /// let mut schedule = Schedule::default();
+    /// schedule.add_systems((add_points, reset_score));
+    /// schedule.initialize(&mut world).unwrap();
+    ///
+    /// let graph = schedule.export_graph(world.components());
+    /// assert_eq!(graph.ambiguities.len(), 1);
+    /// println!("{}", graph.to_dot());
+    /// ```
+    pub fn export_graph(&self, components: &Components) -> ScheduleGraphExport {
+        let graph = &self.graph;
+        let shorten = |name: String| {
+            if graph.settings.use_shortnames {
+                bevy_utils::get_short_name(&name)
+            } else {
+                name
+            }
+        };
+        let condition_names = |conditions: &[BoxedCondition]| -> Vec<String> {
+            conditions
+                .iter()
+                .map(|condition| shorten(condition.name().to_string()))
+                .collect()
+        };
+
+        // Initialized systems and conditions are moved from the graph into the executable schedule.
+        let mut system_names = vec![String::new(); graph.systems.len()];
+        let mut system_conditions = vec![Vec::new(); graph.systems.len()];
+        for (index, node) in graph.systems.iter().enumerate() {
+            if let Some(system) = node.get() {
+                system_names[index] = shorten(system.name().to_string());
+                system_conditions[index] = condition_names(&graph.system_conditions[index]);
+            }
+        }
+        for ((id, system), conditions) in self
+            .executable
+            .system_ids
+            .iter()
+            .zip(&self.executable.systems)
+            .zip(&self.executable.system_conditions)
+        {
+            system_names[id.index()] = shorten(system.name().to_string());
+            system_conditions[id.index()] = condition_names(conditions);
+        }
+        let mut set_conditions: Vec<_> = graph
+            .system_set_conditions
+            .iter()
+            .map(|conditions| condition_names(conditions))
+            .collect();
+        for (id, conditions) in self
+            .executable
+            .set_ids
+            .iter()
+            .zip(&self.executable.set_conditions)
+        {
+            set_conditions[id.index()] = condition_names(conditions);
+        }
+
+        let node_name = |id: NodeId| match id {
+            NodeId::System(index) => system_names[index].clone(),
+            NodeId::Set(index) => {
+                let set = &graph.system_sets[index];
+                if set.is_anonymous() {
+                    let members: Vec<_> = graph
+                        .hierarchy
+                        .graph
+                        .neighbors_directed(id, Outgoing)
+                        .filter(NodeId::is_system)
+                        .map(|member| system_names[member.index()].clone())
+                        .collect();
+                    format!("({})", members.join(", "))
+                } else {
+                    shorten(set.name())
+                }
+            }
+        };
+
+        let systems = (0..graph.systems.len())
+            .map(|index| SystemNodeExport {
+                id: NodeId::System(index),
+                name: system_names[index].clone(),
+                conditions: std::mem::take(&mut system_conditions[index]),
+            })
+            .collect();
+        let sets = (0..graph.system_sets.len())
+            .map(|index| SystemSetNodeExport {
+                id: NodeId::Set(index),
+                name: node_name(NodeId::Set(index)),
+                system_type: graph.system_sets[index].is_system_type(),
+                conditions: std::mem::take(&mut set_conditions[index]),
+            })
+            .collect();
+        let ambiguities = graph
+            .conflicting_systems
+            .iter()
+            .map(|(first, second, conflicts)| AmbiguityExport {
+                first: *first,
+                second: *second,
+                conflicts: conflicts
+                    .iter()
+                    .map(|id| shorten(components.get_name(*id).unwrap_or_default().to_string()))
+                    .collect(),
+            })
+            .collect();
+
+        ScheduleGraphExport {
+            label: format!("{:?}", self.label),
+            systems,
+            sets,
+            hierarchy: graph
+                .hierarchy
+                .graph
+                .all_edges()
+                .map(|(set, member, _)| (set, member))
+                .collect(),
+            dependencies: graph
+                .dependency
+                .graph
+                .all_edges()
+                .map(|(before, after, _)| (before, after))
+                .collect(),
+            topological_order: self.executable.system_ids.clone(),
+            ambiguities,
+            ignored_ambiguities: graph
+                .ambiguous_with
+                .all_edges()
+                .map(|(a, b, _)| (a, b))
+                .collect(),
+            ambiguous_with_all: graph.ambiguous_with_all.iter().copied().collect(),
+        }
+    }
+
     /// Returns the number of systems in this schedule.
     pub fn systems_len(&self) -> usize {
         if !self.executor_initialized {
@@ -1394,6 +1557,7 @@ impl ScheduleGraph {
             system_dependents,
             sets_with_conditions_of_systems,
             systems_in_sets_with_conditions,
+            system_run_durations: vec![None; sys_count],
         }
     }
 
@@ -1981,7 +2145,7 @@ mod tests {
 
         schedule.configure_sets(Set.run_if(|| false));
         schedule.add_systems(
-            (|| panic!("This system must not run"))
+            (|| -> () { panic!("This system must not run") })
                 .ambiguous_with(|| ())
                 .in_set(Set),
         );
@@ -2421,4 +2585,76 @@ mod tests {
             });
         }
     }
+
+    #[test]
+    fn export_graph() {
+        use crate::schedule::NodeId;
+        use crate::system::ResMut;
+
+        #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+        struct Set;
+
+        #[derive(Resource)]
+        struct Counter(u32);
+
+        fn increment(mut counter: ResMut<Counter>) {
+            counter.0 += 1;
+        }
+
+        fn reset(mut counter: ResMut<Counter>) {
+            counter.0 = 0;
+        }
+
+        fn read(_: Res<Counter>) {}
+
+        let mut world = World::new();
+        world.insert_resource(Counter(0));
+        let mut schedule = Schedule::default();
+        schedule.configure_sets(Set.run_if(|| true));
+        schedule.add_systems(((increment, read).chain(), reset.in_set(Set)));
+
+        let export = schedule.export_graph(world.components());
+        assert_eq!(export.systems.len(), 3);
+        assert!(export.topological_order.is_empty());
+        assert!(export.ambiguities.is_empty());
+
+        schedule.initialize(&mut world).unwrap();
+        let export = schedule.export_graph(world.components());
+
+        let id_of = |name: &str| {
+            export
+                .systems
+                .iter()
+                .find(|system| system.name == name)
+                .unwrap()
+                .id
+        };
+        let (increment, read, reset) = (id_of("increment"), id_of("read"), id_of("reset"));
+        assert!(export.dependencies.contains(&(increment, read)));
+        assert_eq!(export.topological_order.len(), 3);
+
+        let set = export.sets.iter().find(|set| set.name == "Set").unwrap();
+        assert_eq!(set.conditions.len(), 1);
+        assert!(export.hierarchy.contains(&(set.id, reset)));
+
+        // `reset` is unordered with both other systems, which access the same resource.
+        assert_eq!(export.ambiguities.len(), 2);
+        for ambiguity in &export.ambiguities {
+            let pair = [ambiguity.first, ambiguity.second];
+            assert!(pair.contains(&reset));
+            assert_eq!(ambiguity.conflicts, vec!["Counter".to_string()]);
+        }
+
+        let dot = export.to_dot();
+        assert!(dot.starts_with("digraph \"DefaultSchedule\" {"));
+        let edge = |a: NodeId, b: NodeId| {
+            let name = |id: NodeId| match id {
+                NodeId::System(index) => format!("system_{index}"),
+                NodeId::Set(index) => format!("set_{index}"),
+            };
+            format!("{} -> {}", name(a), name(b))
+        };
+        assert!(dot.contains(&format!("{};", edge(increment, read))));
+        assert!(dot.contains("label = \"Counter\""));
+    }
 }
@@ -8,7 +8,7 @@ use crate::{
 };
 use bevy_utils::{
     tracing::{error, info, warn},
-    TypeIdMap,
+    Duration, TypeIdMap,
 };
 use thiserror::Error;
 
@@ -183,6 +183,9 @@ impl Stepping {
     }
 
     /// Enable stepping for the provided schedule
+    ///
+    /// Does nothing if stepping is already enabled for the schedule, keeping the
+    /// behaviors configured for its systems.
     pub fn add_schedule(&mut self, schedule: impl ScheduleLabel) -> &mut Self {
         self.updates.push(Update::AddSchedule(schedule.intern()));
         self
@@ -369,6 +372,77 @@ impl Stepping {
         self
     }
 
+    /// Returns `true` if a breakpoint is set for the system instance.
+    ///
+    /// Changes made with [`Stepping::set_breakpoint`] and similar methods are only
+    /// visible here once they are applied at the start of the next render frame.
+    pub fn is_breakpoint_node(&self, schedule: impl ScheduleLabel, node: NodeId) -> bool {
+        matches!(
+            self.node_behavior(schedule.intern(), node),
+            Some(SystemBehavior::Break)
+        )
+    }
+
+    /// Returns `true` if the system instance is set to always run while stepping.
+    ///
+    /// See [`Stepping::is_breakpoint_node`] for when changes become visible.
+    pub fn is_always_run_node(&self, schedule: impl ScheduleLabel, node: NodeId) -> bool {
+        matches!(
+            self.node_behavior(schedule.intern(), node),
+            Some(SystemBehavior::AlwaysRun)
+        )
+    }
+
+    /// Returns `true` if the system instance is set to never run while stepping.
+    ///
+    /// See [`Stepping::is_breakpoint_node`] for when changes become visible.
+    pub fn is_never_run_node(&self, schedule: impl ScheduleLabel, node: NodeId) -> bool {
+        matches!(
+            self.node_behavior(schedule.intern(), node),
+            Some(SystemBehavior::NeverRun)
+        )
+    }
+
+    /// Returns how long the system instance took the last time it ran while its
+    /// schedule was being stepped.
+    pub fn last_run_duration(
+        &self,
+        schedule: impl ScheduleLabel,
+        node: NodeId,
+    ) -> Option<Duration> {
+        self.schedule_states
+            .get(&schedule.intern())?
+            .run_durations
+            .get(&node)
+            .copied()
+    }
+
+    fn node_behavior(&self, label: InternedScheduleLabel, node: NodeId) -> Option<SystemBehavior> {
+        self.schedule_states
+            .get(&label)?
+            .behaviors
+            .get(&node)
+            .copied()
+    }
+
+    /// Store how long each system of `schedule` took to run, if the schedule was stepped.
+    #[cfg(feature = "bevy_debug_stepping")]
+    pub(crate) fn record_run_durations(&mut self, schedule: &Schedule) {
+        let Some(state) = self.schedule_states.get_mut(&schedule.label()) else {
+            return;
+        };
+        let executable = schedule.executable();
+        for (node_id, duration) in executable
+            .system_ids
+            .iter()
+            .zip(&executable.system_run_durations)
+        {
+            if let Some(duration) = duration {
+                state.run_durations.insert(*node_id, *duration);
+            }
+        }
+    }
+
     /// lookup the first system for the supplied schedule index
     fn first_system_index_for_schedule(&self, index: usize) -> usize {
         let label = match self.schedule_order.get(index) {
@@ -464,7 +538,7 @@ impl Stepping {
                     self.action = action;
                 }
                 Update::AddSchedule(l) => {
-                    self.schedule_states.insert(l, ScheduleState::default());
+                    self.schedule_states.entry(l).or_default();
                 }
                 Update::RemoveSchedule(label) => {
                     self.schedule_states.remove(&label);
@@ -619,6 +693,9 @@ struct ScheduleState {
 
     /// This field contains the first steppable system in the schedule.
     first: Option<usize>,
+
+    /// How long each system took the last time it ran while stepping
+    run_durations: HashMap<NodeId, Duration>,
 }
 
 impl ScheduleState {
@@ -828,7 +905,10 @@ impl ScheduleState {
 mod tests {
     use super::*;
     use crate::prelude::*;
-    use crate::{schedule::ScheduleLabel, world::World};
+    use crate::{
+        schedule::{ExecutorKind, ScheduleLabel},
+        world::World,
+    };
 
     pub use crate as bevy_ecs;
 
@@ -1063,6 +1143,21 @@ mod tests {
         assert_schedule_runs!(&schedule, &mut stepping, second_system);
     }
 
+    #[test]
+    fn add_schedule_again_keeps_behaviors() {
+        let (schedule, _world) = setup();
+
+        let mut stepping = Stepping::new();
+        stepping
+            .add_schedule(TestSchedule)
+            .enable()
+            .never_run(TestSchedule, first_system)
+            .add_schedule(TestSchedule)
+            .step_frame();
+
+        assert_schedule_runs!(&schedule, &mut stepping, second_system);
+    }
+
     #[test]
     fn continue_never_run() {
         let (schedule, _world) = setup();
@@ -1350,8 +1445,9 @@ mod tests {
         // all of our systems need real bodies that do things.
         //
         // first system will be configured as `run_if(|| false)`, so it can
-        // just panic if called
-        let first_system = move || panic!("first_system should not be run");
+        // just panic if called.  It is annotated to return `()`, as it would
+        // otherwise be ambiguous with systems returning a `Result`.
+        let first_system = move || -> () { panic!("first_system should not be run") };
 
         // The second system, we need to know when it has been called, so we'll
         // add a resource for tracking if it has been run.  The system will
@@ -1557,4 +1653,51 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn system_behavior_queries_and_run_durations() {
+        for kind in [
+            ExecutorKind::Simple,
+            ExecutorKind::SingleThreaded,
+            ExecutorKind::MultiThreaded,
+        ] {
+            let (mut schedule, mut world) = setup();
+            schedule.set_executor_kind(kind);
+            schedule.initialize(&mut world).unwrap();
+            let (first, second) = {
+                let mut nodes = schedule.systems().unwrap().map(|(node, _)| node);
+                (nodes.next().unwrap(), nodes.next().unwrap())
+            };
+
+            let mut stepping = Stepping::new();
+            stepping
+                .add_schedule(TestSchedule)
+                .enable()
+                .set_breakpoint_node(TestSchedule, second)
+                .never_run_node(TestSchedule, first);
+            world.insert_resource(stepping);
+
+            // updates are applied at the start of the next frame
+            let stepping = world.resource::<Stepping>();
+            assert!(!stepping.is_breakpoint_node(TestSchedule, second));
+            world.resource_mut::<Stepping>().next_frame();
+            let stepping = world.resource::<Stepping>();
+            assert!(stepping.is_breakpoint_node(TestSchedule, second));
+            assert!(stepping.is_never_run_node(TestSchedule, first));
+            assert!(!stepping.is_always_run_node(TestSchedule, first));
+
+            // nothing has run while waiting
+            schedule.run(&mut world);
+            let stepping = world.resource::<Stepping>();
+            assert_eq!(stepping.last_run_duration(TestSchedule, first), None);
+            assert_eq!(stepping.last_run_duration(TestSchedule, second), None);
+
+            // stepping skips the never run system, and runs the breakpoint
+            world.resource_mut::<Stepping>().step_frame().next_frame();
+            schedule.run(&mut world);
+            let stepping = world.resource::<Stepping>();
+            assert_eq!(stepping.last_run_duration(TestSchedule, first), None);
+            assert!(stepping.last_run_duration(TestSchedule, second).is_some());
+        }
+    }
 }
@@ -14,6 +14,7 @@ pub struct ResourceData<const SEND: bool> {
     data: ManuallyDrop<BlobVec>,
     added_ticks: UnsafeCell<Tick>,
     changed_ticks: UnsafeCell<Tick>,
+    removed_tick: Option<Tick>,
     type_name: String,
     id: ArchetypeComponentId,
     origin_thread_id: Option<ThreadId>,
@@ -103,6 +104,20 @@ impl<const SEND: bool> ResourceData<SEND> {
         }
     }
 
+    /// Returns the tick at which the resource was last removed from the [`World`], if it ever was.
+    ///
+    /// [`World`]: crate::world::World
+    #[inline]
+    pub fn removed_tick(&self) -> Option<Tick> {
+        self.removed_tick
+    }
+
+    /// Records that the resource was removed from the world at `change_tick`.
+    #[inline]
+    pub(crate) fn set_removed_tick(&mut self, change_tick: Tick) {
+        self.removed_tick = Some(change_tick);
+    }
+
     /// Returns references to the resource and its change ticks, if it exists.
     ///
     /// # Panics
@@ -245,6 +260,9 @@ impl<const SEND: bool> ResourceData<SEND> {
     pub(crate) fn check_change_ticks(&mut self, change_tick: Tick) {
         self.added_ticks.get_mut().check_tick(change_tick);
         self.changed_ticks.get_mut().check_tick(change_tick);
+        if let Some(removed_tick) = &mut self.removed_tick {
+            removed_tick.check_tick(change_tick);
+        }
     }
 }
 
@@ -330,6 +348,7 @@ impl<const SEND: bool> Resources<SEND> {
                 data: ManuallyDrop::new(data),
                 added_ticks: UnsafeCell::new(Tick::new(0)),
                 changed_ticks: UnsafeCell::new(Tick::new(0)),
+                removed_tick: None,
                 type_name: String::from(component_info.name()),
                 id: f(),
                 origin_thread_id: None,
@@ -158,6 +158,27 @@ impl ComponentSparseSet {
         self.dense.len() == 0
     }
 
+    /// Returns the number of bytes used by the component values and their change detection ticks.
+    #[inline]
+    pub fn used_bytes(&self) -> usize {
+        self.dense.used_bytes()
+    }
+
+    /// Returns the number of bytes allocated to map entities to their component values.
+    pub fn overhead_bytes(&self) -> usize {
+        fn vec_bytes<T>(vec: &Vec<T>) -> usize {
+            vec.capacity() * std::mem::size_of::<T>()
+        }
+
+        vec_bytes(&self.entities) + vec_bytes(&self.sparse.values)
+    }
+
+    /// Returns the number of bytes allocated by the sparse set, including its
+    /// [overhead](Self::overhead_bytes).
+    pub fn allocated_bytes(&self) -> usize {
+        self.dense.allocated_bytes() + self.overhead_bytes()
+    }
+
     /// Inserts the `entity` key and component `value` pair into this sparse
     /// set.
     ///
@@ -216,6 +216,26 @@ impl Column {
         self.data.is_empty()
     }
 
+    /// Returns the number of bytes used by the values stored in the column and their change
+    /// detection ticks.
+    #[inline]
+    pub fn used_bytes(&self) -> usize {
+        self.len() * (self.item_layout().size() + 2 * std::mem::size_of::<Tick>())
+    }
+
+    /// Returns the number of bytes allocated by the column, including the change detection ticks.
+    ///
+    /// This is greater than [`Column::used_bytes`] when the column has spare capacity.
+    #[inline]
+    pub fn allocated_bytes(&self) -> usize {
+        // Zero-sized components report a capacity of `usize::MAX` without allocating.
+        self.data
+            .capacity()
+            .saturating_mul(self.item_layout().size())
+            + (self.added_ticks.capacity() + self.changed_ticks.capacity())
+                * std::mem::size_of::<Tick>()
+    }
+
     /// Removes an element from the [`Column`].
     ///
     /// - The value will be dropped if it implements [`Drop`].
@@ -771,6 +791,24 @@ impl Table {
         self.entities.is_empty()
     }
 
+    /// Returns the number of bytes used by the entities stored in the table and their components.
+    pub fn used_bytes(&self) -> usize {
+        self.entities.len() * std::mem::size_of::<Entity>()
+            + self.columns.values().map(Column::used_bytes).sum::<usize>()
+    }
+
+    /// Returns the number of bytes allocated by the table.
+    ///
+    /// This is greater than [`Table::used_bytes`] when the table has spare capacity.
+    pub fn allocated_bytes(&self) -> usize {
+        self.entities.capacity() * std::mem::size_of::<Entity>()
+            + self
+                .columns
+                .values()
+                .map(Column::allocated_bytes)
+                .sum::<usize>()
+    }
+
     pub(crate) fn check_change_ticks(&mut self, change_tick: Tick) {
         for column in self.columns.values_mut() {
             column.check_change_ticks(change_tick);
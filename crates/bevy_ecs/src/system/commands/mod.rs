@@ -5,13 +5,19 @@ use crate::{
     self as bevy_ecs,
     bundle::Bundle,
     entity::{Entities, Entity},
-    system::{RunSystemWithInput, SystemId},
-    world::{Command, CommandQueue, EntityWorldMut, FromWorld, World},
+    error::{BoxedError, DefaultErrorHandler, EntityDoesNotExistError, ErrorContext, ErrorHandler},
+    event::Event,
+    observer::{Observer, TriggerEvent, TriggerTargets},
+    system::{
+        IntoObserverSystem, IntoSystem, RunSystemCachedWith, RunSystemWithInput, SystemId,
+        UnregisterSystemCached,
+    },
+    world::{Command, CommandQueue, EntityWorldMut, FallibleCommand, FromWorld, World},
 };
 use bevy_ecs_macros::SystemParam;
 use bevy_utils::tracing::{error, info};
 pub use parallel_scope::*;
-use std::marker::PhantomData;
+use std::{any::type_name, borrow::Cow, marker::PhantomData, panic::Location};
 
 /// A [`Command`] queue to perform structural changes to the [`World`].
 ///
@@ -520,6 +526,58 @@ impl<'w, 's> Commands<'w, 's> {
             .push(RunSystemWithInput::new_with_input(id, input));
     }
 
+    /// Runs a cached system, registering it if necessary.
+    ///
+    /// Calls [`World::run_system_cached`](World::run_system_cached).
+    ///
+    /// There is no way to get the output of a system when run as a command, because the
+    /// execution of the system happens later. To get the output of a system, use
+    /// [`World::run_system_cached`] instead of running the system as a command.
+    ///
+    /// See [`World::register_system_cached`] for more information.
+    pub fn run_system_cached<M: 'static, S: IntoSystem<(), (), M> + Send + 'static>(
+        &mut self,
+        system: S,
+    ) {
+        self.run_system_cached_with(system, ());
+    }
+
+    /// Runs a cached system with an input, registering it if necessary.
+    ///
+    /// Calls [`World::run_system_cached_with`](World::run_system_cached_with).
+    ///
+    /// There is no way to get the output of a system when run as a command, because the
+    /// execution of the system happens later. To get the output of a system, use
+    /// [`World::run_system_cached_with`] instead of running the system as a command.
+    ///
+    /// See [`World::register_system_cached`] for more information.
+    pub fn run_system_cached_with<
+        I: 'static + Send,
+        M: 'static,
+        S: IntoSystem<I, (), M> + Send + 'static,
+    >(
+        &mut self,
+        system: S,
+        input: I,
+    ) {
+        self.queue.push(RunSystemCachedWith::new(system, input));
+    }
+
+    /// Removes a cached system and its [`CachedSystemId`](crate::system::CachedSystemId) resource.
+    ///
+    /// See [`World::unregister_system_cached`] for more information.
+    pub fn unregister_system_cached<
+        I: 'static,
+        O: 'static,
+        M: 'static,
+        S: IntoSystem<I, O, M> + Send + 'static,
+    >(
+        &mut self,
+        system: S,
+    ) {
+        self.queue.push(UnregisterSystemCached::new(system));
+    }
+
     /// Pushes a generic [`Command`] to the command queue.
     ///
     /// `command` can be a built-in command, custom struct that implements [`Command`] or a closure
@@ -556,6 +614,99 @@ impl<'w, 's> Commands<'w, 's> {
     pub fn add<C: Command>(&mut self, command: C) {
         self.queue.push(command);
     }
+
+    /// Pushes a [`FallibleCommand`] to the command queue.
+    ///
+    /// If the command returns an error when applied, it is passed to the
+    /// [`DefaultErrorHandler`] of the world, which panics by default.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// use bevy_ecs::error::Result;
+    ///
+    /// #[derive(Resource)]
+    /// struct Gold(u32);
+    ///
+    /// fn spend_gold_system(mut commands: Commands) {
+    ///     commands.queue(|world: &mut World| -> Result {
+    ///         let mut gold = world.get_resource_mut::<Gold>().ok_or("no gold to spend")?;
+    ///         gold.0 = gold.0.checked_sub(10).ok_or("not enough gold")?;
+    ///         Ok(())
+    ///     });
+    /// }
+    /// # bevy_ecs::system::assert_is_system(spend_gold_system);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`queue_handled`](Self::queue_handled) to handle the error with a specific [`ErrorHandler`].
+    #[track_caller]
+    pub fn queue<C: FallibleCommand>(&mut self, command: C) {
+        let location = Location::caller();
+        self.queue.push(move |world: &mut World| {
+            if let Err(err) = command.try_apply(world) {
+                handle_command_error::<C>(world, err, None, location);
+            }
+        });
+    }
+
+    /// Pushes a [`FallibleCommand`] to the command queue, passing any error it returns when applied
+    /// to `error_handler` instead of the [`DefaultErrorHandler`] of the world.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// use bevy_ecs::error::{self, Result};
+    ///
+    /// fn cleanup_system(mut commands: Commands) {
+    ///     commands.queue_handled(
+    ///         |world: &mut World| -> Result {
+    ///             world.get_resource::<Time>().ok_or("time is not ready yet")?;
+    ///             Ok(())
+    ///         },
+    ///         error::warn,
+    ///     );
+    /// }
+    /// # #[derive(Resource)]
+    /// # struct Time;
+    /// # bevy_ecs::system::assert_is_system(cleanup_system);
+    /// ```
+    #[track_caller]
+    pub fn queue_handled<C: FallibleCommand>(&mut self, command: C, error_handler: ErrorHandler) {
+        let location = Location::caller();
+        self.queue.push(move |world: &mut World| {
+            if let Err(err) = command.try_apply(world) {
+                handle_command_error::<C>(world, err, Some(error_handler), location);
+            }
+        });
+    }
+
+    /// Sends a "global" [`Trigger`] without any targets. This will run any [`Observer`] of the `event` that
+    /// isn't scoped to specific targets.
+    ///
+    /// [`Trigger`]: crate::observer::Trigger
+    pub fn trigger(&mut self, event: impl Event) {
+        self.add(TriggerEvent { event, targets: () });
+    }
+
+    /// Sends a [`Trigger`] for the given targets. This will run any [`Observer`] of the `event` that
+    /// watches those targets.
+    ///
+    /// [`Trigger`]: crate::observer::Trigger
+    pub fn trigger_targets(&mut self, event: impl Event, targets: impl TriggerTargets) {
+        self.add(TriggerEvent { event, targets });
+    }
+
+    /// Spawns an [`Observer`] and returns the [`EntityCommands`] associated with the entity that stores the observer.
+    pub fn observe<E: Event, B: Bundle, M>(
+        &mut self,
+        observer: impl IntoObserverSystem<E, B, M>,
+    ) -> EntityCommands {
+        self.spawn(Observer::new(observer))
+    }
 }
 
 /// A [`Command`] which gets executed for a given [`Entity`].
@@ -861,8 +1012,22 @@ impl EntityCommands<'_> {
         self.add(despawn);
     }
 
+    /// Despawns the entity, if it exists.
+    ///
+    /// Unlike [`Self::despawn`], this will not emit a warning if the associated entity does not exist.
+    ///
+    /// See [`World::despawn`] for more details.
+    pub fn try_despawn(&mut self) {
+        self.add(try_despawn);
+    }
+
     /// Pushes an [`EntityCommand`] to the queue, which will get executed for the current [`Entity`].
     ///
+    /// # Panics
+    ///
+    /// Closures taking an [`EntityWorldMut`] will panic when applied if the associated entity does
+    /// not exist. See [`Self::try_add`] for a variant that skips the command instead.
+    ///
     /// # Examples
     ///
     /// ```
@@ -882,6 +1047,87 @@ impl EntityCommands<'_> {
         self
     }
 
+    /// Pushes an [`EntityCommand`] to the queue, which will get executed for the current [`Entity`]
+    /// if it still exists.
+    ///
+    /// Unlike [`Self::add`], this will not panic if the associated entity does not exist. Use
+    /// [`Self::queue`] to report the missing entity as an error instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # fn my_system(mut commands: Commands, query: Query<Entity>) {
+    /// for entity in &query {
+    ///     commands.entity(entity).try_add(|entity: EntityWorldMut| {
+    ///         println!("{:?} still exists", entity.id());
+    ///     });
+    /// }
+    /// # }
+    /// # bevy_ecs::system::assert_is_system(my_system);
+    /// ```
+    pub fn try_add<M: 'static>(&mut self, command: impl EntityCommand<M>) -> &mut Self {
+        let entity = self.entity;
+        self.commands.add(move |world: &mut World| {
+            if world.get_entity(entity).is_some() {
+                command.apply(entity, world);
+            }
+        });
+        self
+    }
+
+    /// Pushes a fallible command to the queue, which will get executed for the current [`Entity`].
+    ///
+    /// If the entity does not exist when the command is applied, an [`EntityDoesNotExistError`]
+    /// is reported instead of running the command. Errors are passed to the
+    /// [`DefaultErrorHandler`] of the world, which panics by default.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// use bevy_ecs::error::Result;
+    ///
+    /// #[derive(Component)]
+    /// struct Health(u32);
+    ///
+    /// fn heal_system(mut commands: Commands, query: Query<Entity>) {
+    ///     for entity in &query {
+    ///         commands.entity(entity).queue(|mut entity: EntityWorldMut| -> Result {
+    ///             let mut health = entity.get_mut::<Health>().ok_or("entity has no health")?;
+    ///             health.0 += 10;
+    ///             Ok(())
+    ///         });
+    ///     }
+    /// }
+    /// # bevy_ecs::system::assert_is_system(heal_system);
+    /// ```
+    #[track_caller]
+    pub fn queue<C, E>(&mut self, command: C) -> &mut Self
+    where
+        C: FnOnce(EntityWorldMut) -> Result<(), E> + Send + 'static,
+        E: Into<BoxedError>,
+    {
+        self.commands
+            .queue(with_entity_fallible(self.entity, command));
+        self
+    }
+
+    /// Pushes a fallible command to the queue, which will get executed for the current [`Entity`],
+    /// passing any error to `error_handler` instead of the [`DefaultErrorHandler`] of the world.
+    ///
+    /// See [`Self::queue`] for more details.
+    #[track_caller]
+    pub fn queue_handled<C, E>(&mut self, command: C, error_handler: ErrorHandler) -> &mut Self
+    where
+        C: FnOnce(EntityWorldMut) -> Result<(), E> + Send + 'static,
+        E: Into<BoxedError>,
+    {
+        self.commands
+            .queue_handled(with_entity_fallible(self.entity, command), error_handler);
+        self
+    }
+
     /// Removes all components except the given [`Bundle`] from the entity.
     ///
     /// This can also be used to remove all the components from the entity by passing it an empty Bundle.
@@ -939,6 +1185,15 @@ impl EntityCommands<'_> {
     pub fn commands(&mut self) -> Commands {
         self.commands.reborrow()
     }
+
+    /// Creates an [`Observer`] listening for a trigger of type `T` that targets this entity.
+    pub fn observe<E: Event, B: Bundle, M>(
+        &mut self,
+        system: impl IntoObserverSystem<E, B, M>,
+    ) -> &mut Self {
+        self.add(observe(system));
+        self
+    }
 }
 
 impl<F> Command for F
@@ -950,6 +1205,16 @@ where
     }
 }
 
+impl<F, E> FallibleCommand for F
+where
+    F: FnOnce(&mut World) -> Result<(), E> + Send + 'static,
+    E: Into<BoxedError>,
+{
+    fn try_apply(self, world: &mut World) -> crate::error::Result {
+        self(world).map_err(Into::into)
+    }
+}
+
 impl<F> EntityCommand<World> for F
 where
     F: FnOnce(EntityWorldMut) + Send + 'static,
@@ -1012,6 +1277,64 @@ fn despawn(entity: Entity, world: &mut World) {
     world.despawn(entity);
 }
 
+/// A [`Command`] that despawns a specific entity, if it exists.
+fn try_despawn(entity: Entity, world: &mut World) {
+    if let Some(entity) = world.get_entity_mut(entity) {
+        entity.despawn();
+    }
+}
+
+/// Turns a fallible command for an entity into a [`FallibleCommand`],
+/// which fails with an [`EntityDoesNotExistError`] if the entity does not exist.
+fn with_entity_fallible<C, E>(entity: Entity, command: C) -> impl FallibleCommand
+where
+    C: FnOnce(EntityWorldMut) -> Result<(), E> + Send + 'static,
+    E: Into<BoxedError>,
+{
+    move |world: &mut World| -> crate::error::Result {
+        let entity = world
+            .get_entity_mut(entity)
+            .ok_or(EntityDoesNotExistError(entity))?;
+        command(entity).map_err(Into::into)
+    }
+}
+
+/// Passes an error returned by the command `C`, queued at `location`, to `error_handler`,
+/// or to the [`DefaultErrorHandler`] of the world if it is `None`.
+fn handle_command_error<C>(
+    world: &World,
+    error: BoxedError,
+    error_handler: Option<ErrorHandler>,
+    location: &'static Location<'static>,
+) {
+    let error_handler = error_handler.unwrap_or_else(|| {
+        world
+            .get_resource::<DefaultErrorHandler>()
+            .copied()
+            .unwrap_or_default()
+            .0
+    });
+    error_handler(
+        error,
+        ErrorContext::Command {
+            name: command_name::<C>(location),
+        },
+    );
+}
+
+/// Returns the name of the command `C` to report in an [`ErrorContext`].
+///
+/// The type names of closures aren't meaningful, so closures are named after the location
+/// where they were queued instead, in the same format as the compiler: `{closure@src/main.rs:4:18}`.
+fn command_name<C>(location: &'static Location<'static>) -> Cow<'static, str> {
+    let name = type_name::<C>();
+    if name.contains("{{closure}}") {
+        format!("{{closure@{location}}}").into()
+    } else {
+        bevy_utils::get_short_name(name).into()
+    }
+}
+
 /// An [`EntityCommand`] that adds the components in a [`Bundle`] to an entity.
 fn insert<T: Bundle>(bundle: T) -> impl EntityCommand {
     move |entity: Entity, world: &mut World| {
@@ -1078,14 +1401,25 @@ fn log_components(entity: Entity, world: &mut World) {
     info!("Entity {:?}: {:?}", entity, debug_infos);
 }
 
+fn observe<E: Event, B: Bundle, M>(
+    observer: impl IntoObserverSystem<E, B, M>,
+) -> impl EntityCommand {
+    move |entity, world: &mut World| {
+        if let Some(mut entity) = world.get_entity_mut(entity) {
+            entity.observe(observer);
+        }
+    }
+}
+
 #[cfg(test)]
 #[allow(clippy::float_cmp, clippy::approx_constant)]
 mod tests {
     use crate::{
         self as bevy_ecs,
         component::Component,
+        error::{ignore, BoxedError, DefaultErrorHandler, ErrorContext, Result},
         system::{Commands, Resource},
-        world::{CommandQueue, World},
+        world::{CommandQueue, EntityWorldMut, World},
     };
     use std::sync::{
         atomic::{AtomicUsize, Ordering},
@@ -1257,4 +1591,78 @@ mod tests {
         assert!(world.contains_resource::<W<i32>>());
         assert!(world.contains_resource::<W<f64>>());
     }
+
+    #[test]
+    fn fallible_commands() {
+        static ERRORS: AtomicUsize = AtomicUsize::new(0);
+
+        fn count_errors(_: BoxedError, context: ErrorContext) {
+            assert!(matches!(context, ErrorContext::Command { .. }));
+            assert!(context.name().starts_with("{closure@"));
+            assert!(context.name().contains("commands"));
+            ERRORS.fetch_add(1, Ordering::Relaxed);
+        }
+
+        let mut world = World::default();
+        world.insert_resource(DefaultErrorHandler(count_errors));
+        let mut command_queue = CommandQueue::default();
+        let entity = world.spawn(W(1u32)).id();
+        let despawned = world.spawn_empty().id();
+
+        {
+            let mut commands = Commands::new(&mut command_queue, &world);
+            commands.queue(|world: &mut World| -> Result {
+                world.insert_resource(W(1u8));
+                Ok(())
+            });
+            commands.queue(|_: &mut World| -> Result { Err("failed".into()) });
+            commands
+                .entity(entity)
+                .queue(|mut entity: EntityWorldMut| -> Result {
+                    entity.get_mut::<W<u32>>().ok_or("missing component")?.0 += 1;
+                    Ok(())
+                })
+                .queue(|mut entity: EntityWorldMut| -> Result {
+                    entity.get_mut::<W<u64>>().ok_or("missing component")?.0 += 1;
+                    Ok(())
+                });
+            commands.entity(despawned).despawn();
+            commands
+                .entity(despawned)
+                .queue(|_: EntityWorldMut| -> Result { Ok(()) })
+                .try_insert(W(0u32))
+                .try_add(|_: EntityWorldMut| panic!("the entity does not exist"))
+                .try_despawn();
+            commands
+                .entity(entity)
+                .try_add(|mut entity: EntityWorldMut| entity.get_mut::<W<u32>>().unwrap().0 += 1);
+        }
+        command_queue.apply(&mut world);
+
+        assert!(world.contains_resource::<W<u8>>());
+        assert_eq!(world.get::<W<u32>>(entity).unwrap().0, 3);
+        assert_eq!(ERRORS.load(Ordering::Relaxed), 3);
+
+        {
+            let mut commands = Commands::new(&mut command_queue, &world);
+            commands.queue_handled(|_: &mut World| -> Result { Err("failed".into()) }, ignore);
+        }
+        command_queue.apply(&mut world);
+        assert_eq!(ERRORS.load(Ordering::Relaxed), 3);
+    }
+
+    #[test]
+    #[should_panic = "Encountered an error in command"]
+    fn fallible_command_panics_by_default() {
+        let mut world = World::default();
+        let mut command_queue = CommandQueue::default();
+        let entity = world.spawn_empty().id();
+
+        let mut commands = Commands::new(&mut command_queue, &world);
+        commands.entity(entity).despawn();
+        commands
+            .entity(entity)
+            .queue(|_: EntityWorldMut| -> Result { Ok(()) });
+        command_queue.apply(&mut world);
+    }
 }
@@ -1,8 +1,9 @@
 use crate::entity::Entity;
-use crate::system::{BoxedSystem, IntoSystem};
-use crate::world::{Command, World};
+use crate::system::{BoxedSystem, IntoSystem, Resource, System};
+use crate::world::{Command, Mut, World};
 use crate::{self as bevy_ecs};
 use bevy_ecs_macros::Component;
+use std::marker::PhantomData;
 use thiserror::Error;
 
 /// A small wrapper for [`BoxedSystem`] that also keeps track whether or not the system has been initialized.
@@ -302,6 +303,105 @@ impl World {
         }
         Ok(result)
     }
+
+    /// Registers a system or returns its cached [`SystemId`].
+    ///
+    /// If you want to run the system immediately and you don't need its `SystemId`, see
+    /// [`World::run_system_cached`].
+    ///
+    /// The first time this function is called for a particular system, it will register it and
+    /// store its [`SystemId`] in a [`CachedSystemId`] resource for later. If you would rather
+    /// manage the `SystemId` yourself, or register multiple copies of the same system, use
+    /// [`World::register_system`] instead.
+    ///
+    /// # Limitations
+    ///
+    /// This function only accepts ZST (zero-sized) systems to guarantee that any two systems of
+    /// the same type must be equal. This means that closures that capture the environment, and
+    /// function pointers, are not accepted.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `system` is not zero-sized.
+    pub fn register_system_cached<I: 'static, O: 'static, M, S: IntoSystem<I, O, M> + 'static>(
+        &mut self,
+        system: S,
+    ) -> SystemId<I, O> {
+        assert!(
+            std::mem::size_of::<S>() == 0,
+            "Non-ZST systems (e.g. capturing closures, function pointers) cannot be cached.",
+        );
+
+        if !self.contains_resource::<CachedSystemId<S::System>>() {
+            let id = self.register_system(system);
+            self.insert_resource(CachedSystemId::<S::System>(id));
+            return id;
+        }
+
+        self.resource_scope(|world, mut id: Mut<CachedSystemId<S::System>>| {
+            if let Some(mut entity) = world.get_entity_mut(id.0 .0) {
+                if !entity.contains::<RegisteredSystem<I, O>>() {
+                    entity.insert(RegisteredSystem {
+                        initialized: false,
+                        system: Box::new(IntoSystem::into_system(system)),
+                    });
+                }
+            } else {
+                id.0 = world.register_system(system);
+            }
+            id.0
+        })
+    }
+
+    /// Removes a cached system and its [`CachedSystemId`] resource.
+    ///
+    /// See [`World::register_system_cached`] for more information.
+    pub fn unregister_system_cached<I: 'static, O: 'static, M, S: IntoSystem<I, O, M> + 'static>(
+        &mut self,
+        _system: S,
+    ) -> Result<RemovedSystem<I, O>, RegisteredSystemError<I, O>> {
+        let id = self
+            .remove_resource::<CachedSystemId<S::System>>()
+            .ok_or(RegisteredSystemError::SystemNotCached)?;
+        self.remove_system(id.0)
+    }
+
+    /// Runs a cached system, registering it if necessary.
+    ///
+    /// The [`Local`](crate::system::Local) state and change detection of the system are kept
+    /// between calls, unlike with [`RunSystemOnce::run_system_once`](crate::system::RunSystemOnce::run_system_once).
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// fn increment(mut counter: Local<u8>) -> u8 {
+    ///     *counter += 1;
+    ///     *counter
+    /// }
+    ///
+    /// let mut world = World::default();
+    /// assert_eq!(world.run_system_cached(increment).unwrap(), 1);
+    /// assert_eq!(world.run_system_cached(increment).unwrap(), 2);
+    /// ```
+    ///
+    /// See [`World::register_system_cached`] for more information.
+    pub fn run_system_cached<O: 'static, M, S: IntoSystem<(), O, M> + 'static>(
+        &mut self,
+        system: S,
+    ) -> Result<O, RegisteredSystemError<(), O>> {
+        self.run_system_cached_with(system, ())
+    }
+
+    /// Runs a cached system with an input, registering it if necessary.
+    ///
+    /// See [`World::register_system_cached`] for more information.
+    pub fn run_system_cached_with<I: 'static, O: 'static, M, S: IntoSystem<I, O, M> + 'static>(
+        &mut self,
+        system: S,
+        input: I,
+    ) -> Result<O, RegisteredSystemError<I, O>> {
+        let id = self.register_system_cached(system);
+        self.run_system_with_input(id, input)
+    }
 }
 
 /// The [`Command`] type for [`World::run_system`] or [`World::run_system_with_input`].
@@ -356,6 +456,84 @@ impl<I: 'static + Send> Command for RunSystemWithInput<I> {
     }
 }
 
+/// The [`SystemId`] of a system registered with [`World::register_system_cached`].
+///
+/// There is one such resource for each cached system type.
+#[derive(Resource)]
+pub struct CachedSystemId<S: System>(pub SystemId<S::In, S::Out>);
+
+/// The [`Command`] type for [`World::run_system_cached`] or [`World::run_system_cached_with`].
+///
+/// This command runs systems in an exclusive and single threaded way.
+/// Running slow systems can become a bottleneck.
+///
+/// See [`World::register_system_cached`] for the limitations of cached systems.
+pub struct RunSystemCachedWith<S, I, M> {
+    system: S,
+    input: I,
+    _marker: PhantomData<fn() -> M>,
+}
+
+impl<S, I, M> RunSystemCachedWith<S, I, M>
+where
+    I: 'static,
+    S: IntoSystem<I, (), M>,
+{
+    /// Creates a new [`Command`] struct, which can be added to [`Commands`](crate::system::Commands)
+    /// in order to run the specified system with the provided [`In<_>`](crate::system::In) input value.
+    pub fn new(system: S, input: I) -> Self {
+        Self {
+            system,
+            input,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<S, I, M> Command for RunSystemCachedWith<S, I, M>
+where
+    I: 'static + Send,
+    M: 'static,
+    S: IntoSystem<I, (), M> + Send + 'static,
+{
+    #[inline]
+    fn apply(self, world: &mut World) {
+        let _ = world.run_system_cached_with(self.system, self.input);
+    }
+}
+
+/// The [`Command`] type for [`World::unregister_system_cached`].
+pub struct UnregisterSystemCached<S, I, O, M> {
+    system: S,
+    _marker: PhantomData<fn(I) -> (O, M)>,
+}
+
+impl<S, I, O, M> UnregisterSystemCached<S, I, O, M>
+where
+    S: IntoSystem<I, O, M>,
+{
+    /// Creates a new [`Command`] struct, which can be added to [`Commands`](crate::system::Commands).
+    pub fn new(system: S) -> Self {
+        Self {
+            system,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<S, I, O, M> Command for UnregisterSystemCached<S, I, O, M>
+where
+    I: 'static,
+    O: 'static,
+    M: 'static,
+    S: IntoSystem<I, O, M> + Send + 'static,
+{
+    #[inline]
+    fn apply(self, world: &mut World) {
+        let _ = world.unregister_system_cached(self.system);
+    }
+}
+
 /// An operation with stored systems failed.
 #[derive(Error)]
 pub enum RegisteredSystemError<I = (), O = ()> {
@@ -370,6 +548,10 @@ pub enum RegisteredSystemError<I = (), O = ()> {
     /// A system tried to remove itself.
     #[error("System {0:?} tried to remove itself")]
     SelfRemove(SystemId<I, O>),
+    /// A cached system was unregistered, but it was never registered with
+    /// [`World::register_system_cached`].
+    #[error("Cached system was not found")]
+    SystemNotCached,
 }
 
 impl<I, O> std::fmt::Debug for RegisteredSystemError<I, O> {
@@ -380,6 +562,7 @@ impl<I, O> std::fmt::Debug for RegisteredSystemError<I, O> {
             }
             Self::Recursive(arg0) => f.debug_tuple("Recursive").field(arg0).finish(),
             Self::SelfRemove(arg0) => f.debug_tuple("SelfRemove").field(arg0).finish(),
+            Self::SystemNotCached => write!(f, "SystemNotCached"),
         }
     }
 }
@@ -578,4 +761,91 @@ mod tests {
         let _ = world.run_system(nested_id);
         assert_eq!(*world.resource::<Counter>(), Counter(5));
     }
+
+    #[test]
+    fn cached_system() {
+        use crate::system::RegisteredSystemError;
+
+        fn four() -> i32 {
+            4
+        }
+
+        let mut world = World::new();
+        let old = world.register_system_cached(four);
+        let new = world.register_system_cached(four);
+        assert_eq!(old, new);
+
+        let result = world.unregister_system_cached(four);
+        assert!(result.is_ok());
+        let new = world.register_system_cached(four);
+        assert_ne!(old, new);
+
+        let output = world.run_system(old);
+        assert!(matches!(
+            output,
+            Err(RegisteredSystemError::SystemIdNotRegistered(x)) if x == old,
+        ));
+        let output = world.run_system(new);
+        assert!(matches!(output, Ok(x) if x == four()));
+        let output = world.run_system_cached(four);
+        assert!(matches!(output, Ok(x) if x == four()));
+        let output = world.run_system_cached_with(|In(x): In<i32>| x * 2, 3);
+        assert!(matches!(output, Ok(6)));
+
+        world.unregister_system_cached(four).unwrap();
+        assert!(matches!(
+            world.unregister_system_cached(four),
+            Err(RegisteredSystemError::SystemNotCached)
+        ));
+    }
+
+    #[test]
+    fn cached_system_keeps_state() {
+        fn count(mut counter: Local<u8>, mut total: ResMut<Counter>) {
+            *counter += 1;
+            total.0 = *counter;
+        }
+
+        let mut world = World::new();
+        world.init_resource::<Counter>();
+        let entity_count = world.entities.len();
+        for _ in 0..3 {
+            world.run_system_cached(count).unwrap();
+        }
+        assert_eq!(*world.resource::<Counter>(), Counter(3));
+        // Only one entity was spawned to hold the system.
+        assert_eq!(world.entities.len(), entity_count + 1);
+    }
+
+    #[test]
+    fn cached_system_commands() {
+        fn sys(mut counter: ResMut<Counter>) {
+            counter.0 += 1;
+        }
+
+        fn add(In(amount): In<u8>, mut counter: ResMut<Counter>) {
+            counter.0 += amount;
+        }
+
+        let mut world = World::new();
+        world.insert_resource(Counter(0));
+
+        world.commands().run_system_cached(sys);
+        world.commands().run_system_cached_with(add, 10);
+        world.flush_commands();
+        assert_eq!(*world.resource::<Counter>(), Counter(11));
+
+        let id = world.register_system_cached(sys);
+        world.commands().unregister_system_cached(sys);
+        world.flush_commands();
+        assert!(world.run_system(id).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "Non-ZST systems")]
+    fn non_zst_cached_system() {
+        let mut world = World::new();
+        let amount = 1;
+        let _ = world.run_system_cached(move || amount);
+    }
 }
@@ -0,0 +1,36 @@
+//! Internal components used by bevy with a fixed component id.
+//! Constants are used to skip [`TypeId`] lookups in hot paths.
+//!
+//! [`TypeId`]: std::any::TypeId
+
+use super::*;
+use crate::{self as bevy_ecs};
+
+/// [`ComponentId`] for [`OnAdd`]
+pub const ON_ADD: ComponentId = ComponentId::new(0);
+/// [`ComponentId`] for [`OnInsert`]
+pub const ON_INSERT: ComponentId = ComponentId::new(1);
+/// [`ComponentId`] for [`OnRemove`]
+pub const ON_REMOVE: ComponentId = ComponentId::new(2);
+/// [`ComponentId`] for [`OnReplace`]
+pub const ON_REPLACE: ComponentId = ComponentId::new(3);
+
+/// Trigger emitted when a component is added to an entity.
+/// See [`ComponentHooks::on_add`] for the equivalent hook.
+#[derive(Event)]
+pub struct OnAdd;
+
+/// Trigger emitted when a component is inserted onto an entity.
+/// See [`ComponentHooks::on_insert`] for the equivalent hook.
+#[derive(Event)]
+pub struct OnInsert;
+
+/// Trigger emitted when a component's value is about to be replaced or removed.
+/// See [`ComponentHooks::on_replace`] for the equivalent hook.
+#[derive(Event)]
+pub struct OnReplace;
+
+/// Trigger emitted when a component is removed from an entity.
+/// See [`ComponentHooks::on_remove`] for the equivalent hook.
+#[derive(Event)]
+pub struct OnRemove;
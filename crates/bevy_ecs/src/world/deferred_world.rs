@@ -5,6 +5,7 @@ use crate::{
     component::ComponentId,
     entity::Entity,
     event::{Event, EventId, Events, SendBatchIds},
+    observer::{Observers, TriggerTargets},
     prelude::{Component, QueryState},
     query::{QueryData, QueryFilter},
     system::{Commands, Query, Resource},
@@ -52,6 +53,12 @@ impl<'w> From<&'w mut World> for DeferredWorld<'w> {
 }
 
 impl<'w> DeferredWorld<'w> {
+    /// Reborrow self as a new instance of [`DeferredWorld`]
+    #[inline]
+    pub fn reborrow(&mut self) -> DeferredWorld {
+        DeferredWorld { world: self.world }
+    }
+
     /// Creates a [`Commands`] instance that pushes to the world's command queue
     #[inline]
     pub fn commands(&mut self) -> Commands {
@@ -296,6 +303,25 @@ impl<'w> DeferredWorld<'w> {
         }
     }
 
+    /// Triggers all `on_replace` hooks for [`ComponentId`] in target.
+    ///
+    /// # Safety
+    /// Caller must ensure [`ComponentId`] in target exist in self.
+    #[inline]
+    pub(crate) unsafe fn trigger_on_replace(
+        &mut self,
+        entity: Entity,
+        targets: impl Iterator<Item = ComponentId>,
+    ) {
+        for component_id in targets {
+            // SAFETY: Caller ensures that these components exist
+            let hooks = unsafe { self.world.components().get_info_unchecked(component_id) }.hooks();
+            if let Some(hook) = hooks.on_replace {
+                hook(DeferredWorld { world: self.world }, entity, component_id);
+            }
+        }
+    }
+
     /// Triggers all `on_remove` hooks for [`ComponentId`] in target.
     ///
     /// # Safety
@@ -314,4 +340,52 @@ impl<'w> DeferredWorld<'w> {
             }
         }
     }
+
+    /// Triggers all event observers for [`ComponentId`] in target.
+    ///
+    /// # Safety
+    /// Caller must ensure observers listening for `event` can accept ZST pointers
+    #[inline]
+    pub(crate) unsafe fn trigger_observers(
+        &mut self,
+        event: ComponentId,
+        entity: Entity,
+        components: impl Iterator<Item = ComponentId>,
+    ) {
+        Observers::invoke(self.reborrow(), event, entity, components, &mut ());
+    }
+
+    /// Triggers all event observers for [`ComponentId`] in target.
+    ///
+    /// # Safety
+    /// Caller must ensure `E` is accessible as the type represented by `event`
+    #[inline]
+    pub(crate) unsafe fn trigger_observers_with_data<E>(
+        &mut self,
+        event: ComponentId,
+        entity: Entity,
+        components: impl Iterator<Item = ComponentId>,
+        data: &mut E,
+    ) {
+        Observers::invoke(self.reborrow(), event, entity, components, data);
+    }
+
+    /// Sends a "global" [`Trigger`](crate::observer::Trigger) without any targets.
+    pub fn trigger(&mut self, trigger: impl Event) {
+        self.commands().trigger(trigger);
+    }
+
+    /// Sends a [`Trigger`](crate::observer::Trigger) with the given `targets`.
+    pub fn trigger_targets(&mut self, trigger: impl Event, targets: impl TriggerTargets) {
+        self.commands().trigger_targets(trigger, targets);
+    }
+
+    /// Gets an [`UnsafeWorldCell`] containing the underlying world.
+    ///
+    /// # Safety
+    /// - must only be used to to make non-structural ECS changes
+    #[inline]
+    pub(crate) fn as_unsafe_world_cell(&mut self) -> UnsafeWorldCell {
+        self.world
+    }
 }
@@ -4,10 +4,13 @@ use crate::{
     change_detection::MutUntyped,
     component::{Component, ComponentId, ComponentTicks, Components, StorageType},
     entity::{Entities, Entity, EntityLocation},
+    event::Event,
+    observer::{Observer, Observers},
     query::{Access, DebugCheckedUnwrap},
     removal_detection::RemovedComponentEvents,
     storage::Storages,
-    world::{Mut, World},
+    system::IntoObserverSystem,
+    world::{Mut, World, ON_REMOVE, ON_REPLACE},
 };
 use bevy_ptr::{OwningPtr, Ptr};
 use std::{any::TypeId, marker::PhantomData};
@@ -870,6 +873,7 @@ impl<'w> EntityWorldMut<'w> {
                 &mut world.archetypes,
                 storages,
                 components,
+                &world.observers,
                 old_location.archetype_id,
                 bundle_info,
                 false,
@@ -892,11 +896,20 @@ impl<'w> EntityWorldMut<'w> {
             )
         };
 
-        if old_archetype.has_on_remove() {
-            // SAFETY: All components in the archetype exist in world
-            unsafe {
+        // SAFETY: All components in the archetype exist in world
+        unsafe {
+            if old_archetype.has_on_replace() {
+                deferred_world.trigger_on_replace(entity, bundle_info.iter_components());
+            }
+            if old_archetype.has_replace_observer() {
+                deferred_world.trigger_observers(ON_REPLACE, entity, bundle_info.iter_components());
+            }
+            if old_archetype.has_on_remove() {
                 deferred_world.trigger_on_remove(entity, bundle_info.iter_components());
             }
+            if old_archetype.has_remove_observer() {
+                deferred_world.trigger_observers(ON_REMOVE, entity, bundle_info.iter_components());
+            }
         }
 
         let archetypes = &mut world.archetypes;
@@ -1045,6 +1058,7 @@ impl<'w> EntityWorldMut<'w> {
             &mut world.archetypes,
             &mut world.storages,
             &world.components,
+            &world.observers,
             location.archetype_id,
             bundle_info,
             true,
@@ -1066,11 +1080,20 @@ impl<'w> EntityWorldMut<'w> {
             )
         };
 
-        if old_archetype.has_on_remove() {
-            // SAFETY: All components in the archetype exist in world
-            unsafe {
+        // SAFETY: All components in the archetype exist in world
+        unsafe {
+            if old_archetype.has_on_replace() {
+                deferred_world.trigger_on_replace(entity, bundle_info.iter_components());
+            }
+            if old_archetype.has_replace_observer() {
+                deferred_world.trigger_observers(ON_REPLACE, entity, bundle_info.iter_components());
+            }
+            if old_archetype.has_on_remove() {
                 deferred_world.trigger_on_remove(entity, bundle_info.iter_components());
             }
+            if old_archetype.has_remove_observer() {
+                deferred_world.trigger_observers(ON_REMOVE, entity, bundle_info.iter_components());
+            }
         }
 
         let old_archetype = &world.archetypes[location.archetype_id];
@@ -1165,11 +1188,20 @@ impl<'w> EntityWorldMut<'w> {
             (&*archetype, world.into_deferred())
         };
 
-        if archetype.has_on_remove() {
-            // SAFETY: All components in the archetype exist in world
-            unsafe {
+        // SAFETY: All components in the archetype exist in world
+        unsafe {
+            if archetype.has_on_replace() {
+                deferred_world.trigger_on_replace(self.entity, archetype.components());
+            }
+            if archetype.has_replace_observer() {
+                deferred_world.trigger_observers(ON_REPLACE, self.entity, archetype.components());
+            }
+            if archetype.has_on_remove() {
                 deferred_world.trigger_on_remove(self.entity, archetype.components());
             }
+            if archetype.has_remove_observer() {
+                deferred_world.trigger_observers(ON_REMOVE, self.entity, archetype.components());
+            }
         }
 
         for component_id in archetype.components() {
@@ -1235,6 +1267,18 @@ impl<'w> EntityWorldMut<'w> {
         world.flush_commands();
     }
 
+    /// Creates an [`Observer`] listening for events of type `E` targeting this entity.
+    pub fn observe<E: Event, B: Bundle, M>(
+        &mut self,
+        observer: impl IntoObserverSystem<E, B, M>,
+    ) -> &mut Self {
+        self.world
+            .spawn(Observer::new(observer).with_entity(self.entity));
+        self.world.flush_commands();
+        self.update_location();
+        self
+    }
+
     /// Ensures any commands triggered by the actions of Self are applied, equivalent to [`World::flush_commands`]
     pub fn flush(self) -> Entity {
         self.world.flush_commands();
@@ -1855,6 +1899,23 @@ impl<'w> FilteredEntityRef<'w> {
             // SAFETY: We have read access so we must have the component
             .then(|| unsafe { self.entity.get_by_id(component_id).debug_checked_unwrap() })
     }
+
+    /// Gets the dynamic component of the given [`ComponentId`] from the entity.
+    ///
+    /// Returns `None` if the component is not accessible, or if it was not registered with
+    /// [`World::register_dynamic_component`].
+    #[cfg(feature = "bevy_reflect")]
+    #[inline]
+    pub fn get_dynamic(&self, component_id: ComponentId) -> Option<&'w dyn bevy_reflect::Reflect> {
+        if !crate::reflect::is_dynamic_component(self.entity.world().components(), component_id) {
+            return None;
+        }
+        self.get_by_id(component_id).map(|ptr| {
+            // SAFETY: Dynamic components store a `DynamicStruct`.
+            let value = unsafe { ptr.deref::<bevy_reflect::DynamicStruct>() };
+            value as &dyn bevy_reflect::Reflect
+        })
+    }
 }
 
 impl<'w> From<FilteredEntityMut<'w>> for FilteredEntityRef<'w> {
@@ -2118,6 +2179,40 @@ impl<'w> FilteredEntityMut<'w> {
                 .debug_checked_unwrap()
         })
     }
+
+    /// Gets the dynamic component of the given [`ComponentId`] from the entity.
+    ///
+    /// Returns `None` if the component is not accessible, or if it was not registered with
+    /// [`World::register_dynamic_component`].
+    #[cfg(feature = "bevy_reflect")]
+    #[inline]
+    pub fn get_dynamic(&self, component_id: ComponentId) -> Option<&'_ dyn bevy_reflect::Reflect> {
+        self.as_readonly().get_dynamic(component_id)
+    }
+
+    /// Gets mutable access to the dynamic component of the given [`ComponentId`] from the entity.
+    ///
+    /// The returned [`DynamicComponentMut`](crate::reflect::DynamicComponentMut) only accepts values
+    /// matching the fields of the component.
+    ///
+    /// Returns `None` if the component is not mutably accessible, or if it was not registered with
+    /// [`World::register_dynamic_component`].
+    #[cfg(feature = "bevy_reflect")]
+    #[inline]
+    pub fn get_dynamic_mut(
+        &mut self,
+        component_id: ComponentId,
+    ) -> Option<crate::reflect::DynamicComponentMut<'_>> {
+        if !crate::reflect::is_dynamic_component(self.entity.world().components(), component_id) {
+            return None;
+        }
+        self.get_mut_by_id(component_id).map(|value| {
+            crate::reflect::DynamicComponentMut::new(value.map_unchanged(|ptr| {
+                // SAFETY: Dynamic components store a `DynamicStruct`.
+                unsafe { ptr.deref_mut::<bevy_reflect::DynamicStruct>() }
+            }))
+        })
+    }
 }
 
 impl<'a> From<EntityMut<'a>> for FilteredEntityMut<'a> {
@@ -2232,6 +2327,7 @@ unsafe fn remove_bundle_from_archetype(
     archetypes: &mut Archetypes,
     storages: &mut Storages,
     components: &Components,
+    observers: &Observers,
     archetype_id: ArchetypeId,
     bundle_info: &BundleInfo,
     intersection: bool,
@@ -2302,6 +2398,7 @@ unsafe fn remove_bundle_from_archetype(
 
         let new_archetype_id = archetypes.get_id_or_insert(
             components,
+            observers,
             next_table_id,
             next_table_components,
             next_sparse_set_components,
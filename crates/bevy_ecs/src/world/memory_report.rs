@@ -0,0 +1,383 @@
+//! Aggregated statistics about the memory used to store components.
+
+use std::fmt;
+
+use bevy_utils::get_short_name;
+
+use crate::{
+    archetype::ArchetypeId,
+    component::{ComponentId, Components, StorageType, Tick},
+    storage::TableId,
+    world::World,
+};
+
+/// The memory used by the component storages of a [`World`], as returned by [`World::memory_report`].
+///
+/// Byte counts only cover the storage owned by the [`World`] itself: heap allocations owned by
+/// component values, such as the contents of a `Vec`, are not included.
+///
+/// The [`Display`](fmt::Display) implementation prints a summary of the report, which is
+/// most useful after calling [`MemoryReport::sort_by_bytes`].
+#[derive(Debug, Clone, Default)]
+pub struct MemoryReport {
+    /// The archetypes of the world, in the order of their [`ArchetypeId`].
+    pub archetypes: Vec<ArchetypeMemory>,
+    /// The tables of the world, in the order of their [`TableId`].
+    pub tables: Vec<TableMemory>,
+    /// The sparse sets of the world, in the order of their [`ComponentId`].
+    pub sparse_sets: Vec<SparseSetMemory>,
+}
+
+/// A component in a [`MemoryReport`].
+#[derive(Debug, Clone)]
+pub struct ComponentMemory {
+    /// The id of the component.
+    pub id: ComponentId,
+    /// The name of the component.
+    pub name: String,
+    /// The size of one value of the component, in bytes.
+    pub size: usize,
+    /// How the component is stored.
+    pub storage_type: StorageType,
+}
+
+/// The memory used by the entities of an [`Archetype`](crate::archetype::Archetype).
+#[derive(Debug, Clone)]
+pub struct ArchetypeMemory {
+    /// The id of the archetype.
+    pub id: ArchetypeId,
+    /// The table storing the table components of the archetype.
+    pub table_id: TableId,
+    /// The number of entities in the archetype.
+    pub entity_count: usize,
+    /// The components of the archetype.
+    pub components: Vec<ComponentMemory>,
+    /// The number of bytes used by the components of the archetype's entities, including
+    /// their change detection ticks.
+    pub bytes: usize,
+}
+
+/// The memory used by a [`Table`](crate::storage::Table).
+#[derive(Debug, Clone)]
+pub struct TableMemory {
+    /// The id of the table.
+    pub id: TableId,
+    /// The number of entities stored in the table.
+    pub entity_count: usize,
+    /// The number of entities the table can store without reallocating.
+    pub entity_capacity: usize,
+    /// The number of components stored in the table.
+    pub component_count: usize,
+    /// The number of bytes used by the entities stored in the table.
+    pub used_bytes: usize,
+    /// The number of bytes allocated by the table.
+    pub allocated_bytes: usize,
+}
+
+impl TableMemory {
+    /// The fraction of the allocated bytes which are unused, between `0.0` and `1.0`.
+    pub fn fragmentation(&self) -> f32 {
+        fragmentation(self.used_bytes, self.allocated_bytes)
+    }
+}
+
+/// The memory used by a [`ComponentSparseSet`](crate::storage::ComponentSparseSet).
+#[derive(Debug, Clone)]
+pub struct SparseSetMemory {
+    /// The component stored in the sparse set.
+    pub component: ComponentMemory,
+    /// The number of component values in the sparse set.
+    pub len: usize,
+    /// The number of bytes used by the component values.
+    pub used_bytes: usize,
+    /// The number of bytes allocated to map entities to their component values.
+    pub overhead_bytes: usize,
+    /// The number of bytes allocated by the sparse set, including the overhead.
+    pub allocated_bytes: usize,
+}
+
+impl SparseSetMemory {
+    /// The fraction of the allocated bytes which are not used by component values, between `0.0` and `1.0`.
+    pub fn fragmentation(&self) -> f32 {
+        fragmentation(self.used_bytes, self.allocated_bytes)
+    }
+}
+
+fn fragmentation(used_bytes: usize, allocated_bytes: usize) -> f32 {
+    if allocated_bytes == 0 {
+        0.0
+    } else {
+        1.0 - used_bytes as f32 / allocated_bytes as f32
+    }
+}
+
+impl MemoryReport {
+    /// The number of bytes allocated by all tables and sparse sets.
+    pub fn allocated_bytes(&self) -> usize {
+        self.tables
+            .iter()
+            .map(|table| table.allocated_bytes)
+            .chain(self.sparse_sets.iter().map(|set| set.allocated_bytes))
+            .sum()
+    }
+
+    /// The number of bytes used by all tables and sparse sets.
+    pub fn used_bytes(&self) -> usize {
+        self.tables
+            .iter()
+            .map(|table| table.used_bytes)
+            .chain(self.sparse_sets.iter().map(|set| set.used_bytes))
+            .sum()
+    }
+
+    /// The fraction of the allocated bytes which are unused, between `0.0` and `1.0`.
+    pub fn fragmentation(&self) -> f32 {
+        fragmentation(self.used_bytes(), self.allocated_bytes())
+    }
+
+    /// Sorts the archetypes, tables and sparse sets of the report from the largest to the smallest.
+    ///
+    /// Archetypes are sorted by [`bytes`](ArchetypeMemory::bytes), tables and sparse sets by
+    /// allocated bytes.
+    pub fn sort_by_bytes(&mut self) {
+        self.archetypes
+            .sort_by(|a, b| b.bytes.cmp(&a.bytes).then(a.id.cmp(&b.id)));
+        self.tables.sort_by(|a, b| {
+            b.allocated_bytes
+                .cmp(&a.allocated_bytes)
+                .then(a.id.as_u32().cmp(&b.id.as_u32()))
+        });
+        self.sparse_sets.sort_by(|a, b| {
+            b.allocated_bytes
+                .cmp(&a.allocated_bytes)
+                .then(a.component.id.cmp(&b.component.id))
+        });
+    }
+}
+
+impl fmt::Display for MemoryReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{} bytes allocated, {} bytes used ({:.1}% fragmentation)",
+            self.allocated_bytes(),
+            self.used_bytes(),
+            self.fragmentation() * 100.0
+        )?;
+
+        writeln!(f, "Archetypes:")?;
+        for archetype in self.archetypes.iter().filter(|a| a.entity_count > 0) {
+            let components = archetype
+                .components
+                .iter()
+                .map(|component| get_short_name(&component.name))
+                .collect::<Vec<_>>();
+            writeln!(
+                f,
+                "  {:>12} bytes {:>8} entities  ({})",
+                archetype.bytes,
+                archetype.entity_count,
+                components.join(", ")
+            )?;
+        }
+
+        writeln!(f, "Tables:")?;
+        for table in self.tables.iter().filter(|t| t.allocated_bytes > 0) {
+            writeln!(
+                f,
+                "  {:>12} bytes {:>8} / {} entities, {} components ({:.1}% fragmentation)",
+                table.allocated_bytes,
+                table.entity_count,
+                table.entity_capacity,
+                table.component_count,
+                table.fragmentation() * 100.0
+            )?;
+        }
+
+        writeln!(f, "Sparse sets:")?;
+        for set in self.sparse_sets.iter().filter(|s| s.allocated_bytes > 0) {
+            writeln!(
+                f,
+                "  {:>12} bytes {:>8} values, {} bytes overhead  ({})",
+                set.allocated_bytes,
+                set.len,
+                set.overhead_bytes,
+                get_short_name(&set.component.name)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+fn component_memory(components: &Components, id: ComponentId) -> ComponentMemory {
+    let info = components.get_info(id).expect("component is registered");
+    ComponentMemory {
+        id,
+        name: info.name().to_string(),
+        size: info.layout().size(),
+        storage_type: info.storage_type(),
+    }
+}
+
+impl World {
+    /// Returns a report of the memory used by the archetypes, tables and sparse sets of the world.
+    ///
+    /// Use it to find the component combinations that take the most memory:
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #[derive(Component)]
+    /// struct Position([f32; 3]);
+    ///
+    /// #[derive(Component)]
+    /// struct Mesh([u8; 1024]);
+    ///
+    /// let mut world = World::new();
+    /// world.spawn_batch((0..100).map(|_| Position([0.0; 3])));
+    /// world.spawn_batch((0..10).map(|_| (Position([0.0; 3]), Mesh([0; 1024]))));
+    ///
+    /// let mut report = world.memory_report();
+    /// report.sort_by_bytes();
+    /// assert_eq!(report.archetypes[0].entity_count, 10);
+    /// println!("{report}");
+    /// ```
+    pub fn memory_report(&self) -> MemoryReport {
+        let components = self.components();
+        let storages = self.storages();
+        let tick_bytes = 2 * std::mem::size_of::<Tick>();
+
+        let archetypes = self
+            .archetypes()
+            .iter()
+            .map(|archetype| {
+                let components = archetype
+                    .components()
+                    .map(|id| component_memory(components, id))
+                    .collect::<Vec<_>>();
+                let bytes = archetype.len()
+                    * components
+                        .iter()
+                        .map(|component| component.size + tick_bytes)
+                        .sum::<usize>();
+                ArchetypeMemory {
+                    id: archetype.id(),
+                    table_id: archetype.table_id(),
+                    entity_count: archetype.len(),
+                    components,
+                    bytes,
+                }
+            })
+            .collect();
+
+        let tables = storages
+            .tables
+            .iter()
+            .enumerate()
+            .map(|(index, table)| TableMemory {
+                id: TableId::from_usize(index),
+                entity_count: table.entity_count(),
+                entity_capacity: table.entity_capacity(),
+                component_count: table.component_count(),
+                used_bytes: table.used_bytes(),
+                allocated_bytes: table.allocated_bytes(),
+            })
+            .collect();
+
+        let sparse_sets = storages
+            .sparse_sets
+            .iter()
+            .map(|(id, set)| SparseSetMemory {
+                component: component_memory(components, id),
+                len: set.len(),
+                used_bytes: set.used_bytes(),
+                overhead_bytes: set.overhead_bytes(),
+                allocated_bytes: set.allocated_bytes(),
+            })
+            .collect();
+
+        MemoryReport {
+            archetypes,
+            tables,
+            sparse_sets,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate as bevy_ecs;
+    use crate::prelude::*;
+
+    #[derive(Component)]
+    #[allow(dead_code)]
+    struct A(u64);
+
+    #[derive(Component)]
+    #[allow(dead_code)]
+    struct B([u8; 100]);
+
+    #[derive(Component)]
+    #[allow(dead_code)]
+    #[component(storage = "SparseSet")]
+    struct S(u32);
+
+    #[test]
+    fn memory_report() {
+        let mut world = World::new();
+        world.spawn_batch((0..10).map(A));
+        world.spawn_batch((0..3).map(|i| (A(i), B([0; 100]))));
+        let with_sparse = world.spawn((A(0), S(0))).id();
+
+        let mut report = world.memory_report();
+        report.sort_by_bytes();
+
+        let largest = &report.archetypes[0];
+        assert_eq!(largest.entity_count, 3);
+        assert_eq!(largest.components.len(), 2);
+        assert_eq!(largest.bytes, 3 * (8 + 100 + 2 * 2 * 4));
+
+        let a_only = report
+            .archetypes
+            .iter()
+            .find(|archetype| archetype.components.len() == 1)
+            .unwrap();
+        assert_eq!(a_only.entity_count, 10);
+        assert_eq!(a_only.bytes, 10 * (8 + 8));
+
+        // `A` and `A, S` share a table.
+        let table = report
+            .tables
+            .iter()
+            .find(|table| table.id == a_only.table_id)
+            .unwrap();
+        assert_eq!(table.entity_count, 11);
+        assert!(table.entity_capacity >= 11);
+        assert!(table.allocated_bytes >= table.used_bytes);
+
+        let s_id = world.component_id::<S>().unwrap();
+        let sparse_set = report
+            .sparse_sets
+            .iter()
+            .find(|set| set.component.id == s_id)
+            .unwrap();
+        assert_eq!(sparse_set.len, 1);
+        assert_eq!(sparse_set.component.size, 4);
+        assert!(sparse_set.overhead_bytes > 0);
+
+        assert!(report.allocated_bytes() >= report.used_bytes());
+        assert!((0.0..=1.0).contains(&report.fragmentation()));
+
+        // Despawning entities keeps the allocations around.
+        world.despawn(with_sparse);
+        let report = world.memory_report();
+        let sparse_set = report
+            .sparse_sets
+            .iter()
+            .find(|set| set.component.id == s_id)
+            .unwrap();
+        assert_eq!(sparse_set.len, 0);
+        assert_eq!(sparse_set.used_bytes, 0);
+        assert_eq!(sparse_set.fragmentation(), 1.0);
+        assert!(report.to_string().contains("(A, B)"));
+    }
+}
@@ -1,19 +1,25 @@
 //! Defines the [`World`] and APIs for accessing it directly.
 
 mod command_queue;
+mod component_constants;
 mod deferred_world;
 mod entity_ref;
 pub mod error;
+mod memory_report;
 mod spawn_batch;
 pub mod unsafe_world_cell;
 
 pub use crate::change_detection::{Mut, Ref, CHECK_TICK_THRESHOLD};
 pub use crate::world::command_queue::CommandQueue;
+pub use component_constants::*;
 pub use deferred_world::DeferredWorld;
 pub use entity_ref::{
     EntityMut, EntityRef, EntityWorldMut, Entry, FilteredEntityMut, FilteredEntityRef,
     OccupiedEntry, VacantEntry,
 };
+pub use memory_report::{
+    ArchetypeMemory, ComponentMemory, MemoryReport, SparseSetMemory, TableMemory,
+};
 pub use spawn_batch::*;
 
 use crate::{
@@ -22,10 +28,12 @@ use crate::{
     change_detection::{MutUntyped, TicksMut},
     component::{
         Component, ComponentDescriptor, ComponentHooks, ComponentId, ComponentInfo, ComponentTicks,
-        Components, Tick,
+        Components, ResourceHook, ResourceHooks, Tick,
     },
     entity::{AllocAtWithoutReplacement, Entities, Entity, EntityLocation},
+    entity_disabling::{DefaultQueryFilters, Disabled},
     event::{Event, EventId, Events, SendBatchIds},
+    observer::Observers,
     query::{DebugCheckedUnwrap, QueryData, QueryEntityError, QueryFilter, QueryState},
     removal_detection::RemovedComponentEvents,
     schedule::{Schedule, ScheduleLabel, Schedules},
@@ -82,6 +90,37 @@ pub trait Command: Send + 'static {
     fn apply(self, world: &mut World);
 }
 
+/// A [`World`] mutation that can fail.
+///
+/// Should be used with [`Commands::queue`], which passes any error to the
+/// [`DefaultErrorHandler`](crate::error::DefaultErrorHandler) of the world,
+/// or [`Commands::queue_handled`] to use a specific [`ErrorHandler`](crate::error::ErrorHandler).
+///
+/// Closures taking `&mut World` and returning a [`Result`](crate::error::Result) implement this trait.
+///
+/// # Usage
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// use bevy_ecs::error::Result;
+///
+/// #[derive(Resource)]
+/// struct Score(u32);
+///
+/// fn double_score(mut commands: Commands) {
+///     commands.queue(|world: &mut World| -> Result {
+///         let mut score = world.get_resource_mut::<Score>().ok_or("no score to double")?;
+///         score.0 *= 2;
+///         Ok(())
+///     });
+/// }
+/// # bevy_ecs::system::assert_is_system(double_score);
+/// ```
+pub trait FallibleCommand: Send + 'static {
+    /// Applies this command, returning an error if it could not be completed.
+    fn try_apply(self, world: &mut World) -> crate::error::Result;
+}
+
 /// Stores and exposes operations on [entities](Entity), [components](Component), resources,
 /// and their associated metadata.
 ///
@@ -108,34 +147,55 @@ pub struct World {
     pub(crate) archetypes: Archetypes,
     pub(crate) storages: Storages,
     pub(crate) bundles: Bundles,
+    pub(crate) observers: Observers,
     pub(crate) removed_components: RemovedComponentEvents,
     pub(crate) change_tick: AtomicU32,
     pub(crate) last_change_tick: Tick,
     pub(crate) last_check_tick: Tick,
+    pub(crate) last_trigger_id: u32,
     pub(crate) command_queue: CommandQueue,
 }
 
 impl Default for World {
     fn default() -> Self {
-        Self {
+        let mut world = Self {
             id: WorldId::new().expect("More `bevy` `World`s have been created than is supported"),
             entities: Entities::new(),
             components: Default::default(),
             archetypes: Archetypes::new(),
             storages: Default::default(),
             bundles: Default::default(),
+            observers: Observers::default(),
             removed_components: Default::default(),
             // Default value is `1`, and `last_change_tick`s default to `0`, such that changes
             // are detected on first system runs and for direct world queries.
             change_tick: AtomicU32::new(1),
             last_change_tick: Tick::new(0),
             last_check_tick: Tick::new(0),
+            last_trigger_id: 0,
             command_queue: CommandQueue::default(),
-        }
+        };
+        world.bootstrap();
+        world
     }
 }
 
 impl World {
+    /// This performs initialization that _must_ happen for every [`World`] immediately upon creation (such as claiming specific component ids).
+    /// This _must_ be run as part of constructing a [`World`], before it is returned to the caller.
+    #[inline]
+    fn bootstrap(&mut self) {
+        assert_eq!(ON_ADD, self.init_component::<OnAdd>());
+        assert_eq!(ON_INSERT, self.init_component::<OnInsert>());
+        assert_eq!(ON_REMOVE, self.init_component::<OnRemove>());
+        assert_eq!(ON_REPLACE, self.init_component::<OnReplace>());
+
+        let disabled = self.init_component::<Disabled>();
+        let mut filters = DefaultQueryFilters::default();
+        filters.register_disabling_component(disabled);
+        self.insert_resource(filters);
+    }
+
     /// Creates a new empty [`World`].
     ///
     /// # Panics
@@ -212,6 +272,13 @@ impl World {
         &self.removed_components
     }
 
+    /// Returns the id of the last ECS event that was fired.
+    /// Used internally to ensure observers don't trigger multiple times for the same event.
+    #[inline]
+    pub(crate) fn last_trigger_id(&self) -> u32 {
+        self.last_trigger_id
+    }
+
     /// Creates a new [`Commands`] instance that writes to the world's command queue
     /// Use [`World::flush_commands`] to apply all queued commands
     #[inline]
@@ -245,6 +312,46 @@ impl World {
         self.components.get_hooks_mut(id)
     }
 
+    /// Returns a mutable reference to the [`ResourceHooks`] for a [`Resource`] type.
+    ///
+    /// Unlike component hooks, resource hooks can be registered while the resource exists.
+    /// They only apply to insertions and removals made afterwards.
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #[derive(Resource)]
+    /// struct Config(u32);
+    ///
+    /// #[derive(Resource, Default)]
+    /// struct ConfigRemoved(bool);
+    ///
+    /// let mut world = World::new();
+    /// world.init_resource::<ConfigRemoved>();
+    /// world
+    ///     .register_resource_hooks::<Config>()
+    ///     .on_remove(|mut world, _| world.resource_mut::<ConfigRemoved>().0 = true);
+    ///
+    /// world.insert_resource(Config(1));
+    /// world.remove_resource::<Config>();
+    /// assert!(world.resource::<ConfigRemoved>().0);
+    /// ```
+    pub fn register_resource_hooks<R: Resource>(&mut self) -> &mut ResourceHooks {
+        let index = self.components.init_resource::<R>();
+        // SAFETY: We just created this resource
+        unsafe {
+            self.components
+                .get_resource_hooks_mut(index)
+                .debug_checked_unwrap()
+        }
+    }
+
+    /// Returns a mutable reference to the [`ResourceHooks`] for a [`Resource`] with the given id if it exists.
+    ///
+    /// Hooks registered for a [non-send resource](crate::system::NonSend) are never run.
+    pub fn register_resource_hooks_by_id(&mut self, id: ComponentId) -> Option<&mut ResourceHooks> {
+        self.components.get_resource_hooks_mut(id)
+    }
+
     /// Initializes a new [`Component`] type and returns the [`ComponentId`] created for it.
     ///
     /// This method differs from [`World::init_component`] in that it uses a [`ComponentDescriptor`]
@@ -1193,9 +1300,19 @@ impl World {
     #[inline]
     pub fn remove_resource<R: Resource>(&mut self) -> Option<R> {
         let component_id = self.components.get_resource_id(TypeId::of::<R>())?;
-        let (ptr, _) = self.storages.resources.get_mut(component_id)?.remove()?;
+        if !self.storages.resources.get(component_id)?.is_present() {
+            return None;
+        }
+        self.trigger_resource_hook(component_id, |hooks| hooks.on_replace);
+        self.trigger_resource_hook(component_id, |hooks| hooks.on_remove);
+
+        let change_tick = self.change_tick();
+        let data = self.storages.resources.get_mut(component_id)?;
+        let (ptr, _) = data.remove()?;
         // SAFETY: `component_id` was gotten via looking up the `R` type
-        unsafe { Some(ptr.read::<R>()) }
+        let value = unsafe { ptr.read::<R>() };
+        data.set_removed_tick(change_tick);
+        Some(value)
     }
 
     /// Removes a `!Send` resource from the world and returns it, if present.
@@ -1274,6 +1391,29 @@ impl World {
             .unwrap_or(false)
     }
 
+    /// Returns `true` if a resource of type `R` was removed since the world's
+    /// [`last_change_tick`](World::last_change_tick()). Otherwise, this returns `false`.
+    ///
+    /// The resource may have been inserted again since it was removed.
+    /// See [`RemovedResources`](crate::removal_detection::RemovedResources) for the system parameter.
+    pub fn is_resource_removed<R: Resource>(&self) -> bool {
+        self.components
+            .get_resource_id(TypeId::of::<R>())
+            .map(|component_id| self.is_resource_removed_by_id(component_id))
+            .unwrap_or(false)
+    }
+
+    /// Returns `true` if a resource with id `component_id` was removed since the world's
+    /// [`last_change_tick`](World::last_change_tick()). Otherwise, this returns `false`.
+    pub fn is_resource_removed_by_id(&self, component_id: ComponentId) -> bool {
+        self.storages
+            .resources
+            .get(component_id)
+            .and_then(ResourceData::removed_tick)
+            .map(|removed| removed.is_newer_than(self.last_change_tick(), self.read_change_tick()))
+            .unwrap_or(false)
+    }
+
     /// Returns `true` if a resource of type `R` exists and was modified since the world's
     /// [`last_change_tick`](World::last_change_tick()). Otherwise, this returns `false`.
     ///
@@ -1437,16 +1577,16 @@ impl World {
         let last_change_tick = self.last_change_tick();
 
         let component_id = self.components.init_resource::<R>();
-        let data = self.initialize_resource_internal(component_id);
-        if !data.is_present() {
+        if !self.initialize_resource_internal(component_id).is_present() {
             OwningPtr::make(func(), |ptr| {
                 // SAFETY: component_id was just initialized and corresponds to resource of type R.
                 unsafe {
-                    data.insert(ptr, change_tick);
+                    self.insert_resource_by_id(component_id, ptr);
                 }
             });
         }
 
+        let data = self.initialize_resource_internal(component_id);
         // SAFETY: The resource must be present, as we would have inserted it if it was empty.
         let data = unsafe {
             data.get_mut(last_change_tick, change_tick)
@@ -1786,11 +1926,21 @@ impl World {
     ) {
         let change_tick = self.change_tick();
 
+        let was_present = self.initialize_resource_internal(component_id).is_present();
+        if was_present {
+            self.trigger_resource_hook(component_id, |hooks| hooks.on_replace);
+        }
+
         let resource = self.initialize_resource_internal(component_id);
         // SAFETY: `value` is valid for `component_id`, ensured by caller
         unsafe {
             resource.insert(value, change_tick);
         }
+
+        if !was_present {
+            self.trigger_resource_hook(component_id, |hooks| hooks.on_add);
+        }
+        self.trigger_resource_hook(component_id, |hooks| hooks.on_insert);
     }
 
     /// Inserts a new `!Send` resource with the given `value`. Will replace the value if it already
@@ -1850,6 +2000,24 @@ impl World {
             })
     }
 
+    /// Runs the [`ResourceHook`] chosen by `hook` for the resource with id `component_id`, if it is registered.
+    ///
+    /// As with component hooks, commands queued by the hook are applied the next time the world's commands are flushed.
+    #[inline]
+    fn trigger_resource_hook(
+        &mut self,
+        component_id: ComponentId,
+        hook: impl FnOnce(&ResourceHooks) -> Option<ResourceHook>,
+    ) {
+        if let Some(hook) = self
+            .components
+            .get_info(component_id)
+            .and_then(|info| hook(info.resource_hooks()))
+        {
+            hook(DeferredWorld::from(&mut *self), component_id);
+        }
+    }
+
     /// Empties queued entities and adds them to the empty [`Archetype`](crate::archetype::Archetype).
     /// This should be called before doing operations that might operate on queued entities,
     /// such as inserting a [`Component`].
@@ -2072,6 +2240,8 @@ impl World {
         self.storages.sparse_sets.clear_entities();
         self.archetypes.clear_entities();
         self.entities.clear();
+        // Observers are entities, so any registered observers were just despawned.
+        self.observers = Observers::default();
     }
 
     /// Clears all resources in this [`World`].
@@ -2081,6 +2251,9 @@ impl World {
     ///
     /// This can easily cause systems expecting certain resources to immediately start panicking.
     /// Use with caution.
+    ///
+    /// The resources are dropped without running their `on_replace` or `on_remove`
+    /// [hooks](crate::component::ResourceHooks), and are not reported as removed.
     pub fn clear_resources(&mut self) {
         self.storages.resources.clear();
         self.storages.non_send_resources.clear();
@@ -2088,6 +2261,40 @@ impl World {
 }
 
 impl World {
+    /// Iterates over all resources in the world, along with their [`ComponentInfo`].
+    ///
+    /// Change ticks and removal ticks can be read with [`World::get_resource_change_ticks_by_id`]
+    /// and [`World::is_resource_removed_by_id`].
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #[derive(Resource)]
+    /// struct Score(u32);
+    ///
+    /// let mut world = World::new();
+    /// world.insert_resource(Score(3));
+    ///
+    /// let names = world
+    ///     .iter_resources()
+    ///     .map(|(info, _)| info.name())
+    ///     .collect::<Vec<_>>();
+    /// assert!(names.iter().any(|name| name.ends_with("Score")));
+    /// ```
+    pub fn iter_resources(&self) -> impl Iterator<Item = (&ComponentInfo, Ptr<'_>)> {
+        self.storages
+            .resources
+            .iter()
+            .filter_map(|(component_id, data)| {
+                // SAFETY: All resources in the world have a registered `ComponentInfo`.
+                let info = unsafe {
+                    self.components
+                        .get_info(component_id)
+                        .debug_checked_unwrap()
+                };
+                Some((info, data.get_data()?))
+            })
+    }
+
     /// Gets a pointer to the resource with the id [`ComponentId`] if it exists.
     /// The returned pointer must not be used to modify the resource, and must not be
     /// dereferenced after the immutable borrow of the [`World`] ends.
@@ -2167,10 +2374,15 @@ impl World {
     /// **You should prefer to use the typed API [`World::remove_resource`] where possible and only
     /// use this in cases where the actual types are not known at compile time.**
     pub fn remove_resource_by_id(&mut self, component_id: ComponentId) -> Option<()> {
-        self.storages
-            .resources
-            .get_mut(component_id)?
-            .remove_and_drop();
+        if self.storages.resources.get(component_id)?.is_present() {
+            self.trigger_resource_hook(component_id, |hooks| hooks.on_replace);
+            self.trigger_resource_hook(component_id, |hooks| hooks.on_remove);
+
+            let change_tick = self.change_tick();
+            let data = self.storages.resources.get_mut(component_id)?;
+            data.remove_and_drop();
+            data.set_removed_tick(change_tick);
+        }
         Some(())
     }
 
@@ -2405,9 +2617,10 @@ mod tests {
     use super::{FromWorld, World};
     use crate::{
         change_detection::DetectChangesMut,
-        component::{ComponentDescriptor, ComponentInfo, StorageType},
+        component::{ComponentDescriptor, ComponentInfo, ResourceHooks, StorageType},
         ptr::OwningPtr,
-        system::Resource,
+        removal_detection::RemovedResources,
+        system::{IntoSystem, Resource, System},
     };
     use bevy_ecs_macros::Component;
     use bevy_utils::{HashMap, HashSet};
@@ -2540,6 +2753,9 @@ mod tests {
     #[derive(Resource)]
     struct TestResource(u32);
 
+    #[derive(Resource)]
+    struct TestResource2(u32);
+
     #[test]
     fn get_resource_by_id() {
         let mut world = World::new();
@@ -2659,6 +2875,139 @@ mod tests {
         assert_eq!(resource.0, 0);
     }
 
+    #[derive(Resource, Default)]
+    struct HookLog(Vec<&'static str>);
+
+    struct HookedResource;
+
+    impl Resource for HookedResource {
+        fn register_resource_hooks(hooks: &mut ResourceHooks) {
+            hooks
+                .on_add(|mut world, _| world.resource_mut::<HookLog>().0.push("add"))
+                .on_insert(|mut world, _| world.resource_mut::<HookLog>().0.push("insert"))
+                .on_replace(|mut world, _| world.resource_mut::<HookLog>().0.push("replace"))
+                .on_remove(|mut world, _| world.resource_mut::<HookLog>().0.push("remove"));
+        }
+    }
+
+    #[test]
+    fn resource_hooks() {
+        let mut world = World::new();
+        world.init_resource::<HookLog>();
+
+        world.insert_resource(HookedResource);
+        world.insert_resource(HookedResource);
+        world.remove_resource::<HookedResource>();
+        world.get_resource_or_insert_with(|| HookedResource);
+        let id = world.components().resource_id::<HookedResource>().unwrap();
+        world.remove_resource_by_id(id);
+        // Removing a missing resource does not run any hook.
+        world.remove_resource::<HookedResource>();
+
+        assert_eq!(
+            world.resource::<HookLog>().0,
+            [
+                "add", "insert", "replace", "insert", "replace", "remove", "add", "insert",
+                "replace", "remove"
+            ]
+        );
+    }
+
+    #[test]
+    fn resource_hooks_can_queue_commands() {
+        let mut world = World::new();
+        world
+            .register_resource_hooks::<TestResource>()
+            .on_remove(|mut world, _| {
+                let value = world.resource::<TestResource>().0;
+                world.commands().insert_resource(TestResource2(value));
+            });
+
+        world.insert_resource(TestResource(7));
+        world.remove_resource::<TestResource>();
+        // Commands queued by hooks are applied when the world's commands are flushed.
+        assert!(!world.contains_resource::<TestResource2>());
+
+        world.flush_commands();
+        assert_eq!(world.resource::<TestResource2>().0, 7);
+    }
+
+    #[test]
+    fn clear_resources_skips_resource_hooks() {
+        static HOOK_RUNS: AtomicU32 = AtomicU32::new(0);
+
+        struct CountedResource;
+
+        impl Resource for CountedResource {
+            fn register_resource_hooks(hooks: &mut ResourceHooks) {
+                hooks
+                    .on_replace(|_, _| {
+                        HOOK_RUNS.fetch_add(1, Ordering::Relaxed);
+                    })
+                    .on_remove(|_, _| {
+                        HOOK_RUNS.fetch_add(1, Ordering::Relaxed);
+                    });
+            }
+        }
+
+        let mut world = World::new();
+        world.insert_resource(CountedResource);
+
+        world.clear_resources();
+        assert!(!world.contains_resource::<CountedResource>());
+        assert!(!world.is_resource_removed::<CountedResource>());
+        assert_eq!(HOOK_RUNS.load(Ordering::Relaxed), 0);
+    }
+
+    #[test]
+    fn removed_resources() {
+        let mut world = World::new();
+        assert!(!world.is_resource_removed::<TestResource>());
+
+        world.insert_resource(TestResource(0));
+        world.clear_trackers();
+        assert!(!world.is_resource_removed::<TestResource>());
+
+        world.remove_resource::<TestResource>();
+        assert!(world.is_resource_removed::<TestResource>());
+        world.clear_trackers();
+        assert!(!world.is_resource_removed::<TestResource>());
+
+        let mut system =
+            IntoSystem::into_system(|removed: RemovedResources<TestResource>| removed.is_removed());
+        system.initialize(&mut world);
+        assert!(system.run((), &mut world));
+        assert!(!system.run((), &mut world));
+
+        world.insert_resource(TestResource(1));
+        world.remove_resource::<TestResource>();
+        world.insert_resource(TestResource(2));
+        assert!(system.run((), &mut world));
+        assert!(!system.run((), &mut world));
+    }
+
+    #[test]
+    fn iter_resources() {
+        let mut world = World::new();
+        world.insert_resource(TestResource(42));
+        world.insert_resource(TestResource2(0));
+        world.init_resource::<HookLog>();
+        world.remove_resource::<HookLog>();
+
+        let (_, ptr) = world
+            .iter_resources()
+            .find(|(info, _)| info.type_id() == Some(TypeId::of::<TestResource>()))
+            .unwrap();
+        // SAFETY: The pointer comes from the `TestResource` resource.
+        assert_eq!(unsafe { ptr.deref::<TestResource>() }.0, 42);
+        assert!(world
+            .iter_resources()
+            .any(|(info, _)| info.type_id() == Some(TypeId::of::<TestResource2>())));
+        assert!(world
+            .iter_resources()
+            .all(|(info, _)| info.type_id() != Some(TypeId::of::<HookLog>())));
+    }
+
     #[derive(Component)]
     struct Foo;
 
@@ -9,6 +9,7 @@ use crate::{
     change_detection::{MutUntyped, Ticks, TicksMut},
     component::{ComponentId, ComponentTicks, Components, StorageType, Tick, TickCells},
     entity::{Entities, Entity, EntityLocation},
+    observer::Observers,
     prelude::Component,
     removal_detection::RemovedComponentEvents,
     storage::{Column, ComponentSparseSet, Storages},
@@ -238,6 +239,14 @@ impl<'w> UnsafeWorldCell<'w> {
         &unsafe { self.world_metadata() }.bundles
     }
 
+    /// Retrieves this world's [`Observers`] collection.
+    #[inline]
+    pub(crate) fn observers(self) -> &'w Observers {
+        // SAFETY:
+        // - we only access world metadata
+        &unsafe { self.world_metadata() }.observers
+    }
+
     /// Gets the current change tick of this world.
     #[inline]
     pub fn change_tick(self) -> Tick {
@@ -570,6 +579,14 @@ impl<'w> UnsafeWorldCell<'w> {
         // - caller ensures that we have permission to access the queue
         unsafe { &mut *addr_of_mut!((*self.0).command_queue) }
     }
+
+    /// # Safety
+    /// It is the callers responsibility to ensure that there are no outstanding
+    /// references to `last_trigger_id`.
+    pub(crate) unsafe fn increment_trigger_id(self) {
+        // SAFETY: Caller ensure there are no outstanding references
+        unsafe { (*self.0).last_trigger_id = (*self.0).last_trigger_id.wrapping_add(1) }
+    }
 }
 
 impl Debug for UnsafeWorldCell<'_> {
@@ -0,0 +1,23 @@
+use bevy_ecs::prelude::*;
+
+#[derive(Component, PartialEq, Eq, PartialOrd, Ord)]
+struct A(usize);
+
+fn sort_by_system(mut query: Query<&mut A>) {
+    let mut leaked: Option<&A> = None;
+    for mut a in query.iter_mut().sort_by::<&A>(|l, r| {
+        leaked = Some(*l);
+        l.cmp(r)
+    }) {
+        a.0 += 1;
+        // this should fail to compile
+        println!("{}", leaked.unwrap().0);
+    }
+}
+
+fn sort_by_key_system(mut query: Query<&mut A>) {
+    let mut iter = query.iter_mut().sort_by_key::<&A, _>(|a| *a);
+    let _ = &mut *iter.next().unwrap();
+}
+
+fn main() {}
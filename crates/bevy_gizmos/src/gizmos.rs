@@ -7,7 +7,7 @@ use bevy_color::{Color, LinearRgba};
 use bevy_ecs::{
     component::Tick,
     system::{Deferred, ReadOnlySystemParam, Res, Resource, SystemBuffer, SystemMeta, SystemParam},
-    world::{unsafe_world_cell::UnsafeWorldCell, World},
+    world::{unsafe_world_cell::UnsafeWorldCell, DeferredWorld, World},
 };
 use bevy_math::{Dir3, Quat, Rotation2d, Vec2, Vec3};
 use bevy_transform::TransformPoint;
@@ -68,6 +68,9 @@ unsafe impl<T: GizmoConfigGroup> SystemParam for Gizmos<'_, '_, T> {
     fn apply(state: &mut Self::State, system_meta: &SystemMeta, world: &mut World) {
         GizmosState::<T>::apply(&mut state.state, system_meta, world);
     }
+    fn queue(state: &mut Self::State, system_meta: &SystemMeta, world: DeferredWorld) {
+        GizmosState::<T>::queue(&mut state.state, system_meta, world);
+    }
     unsafe fn get_param<'w, 's>(
         state: &'s mut Self::State,
         system_meta: &SystemMeta,
@@ -115,6 +118,14 @@ impl<T: GizmoConfigGroup> SystemBuffer for GizmoBuffer<T> {
         storage.strip_positions.append(&mut self.strip_positions);
         storage.strip_colors.append(&mut self.strip_colors);
     }
+
+    fn queue(&mut self, _system_meta: &SystemMeta, mut world: DeferredWorld) {
+        let mut storage = world.resource_mut::<GizmoStorage<T>>();
+        storage.list_positions.append(&mut self.list_positions);
+        storage.list_colors.append(&mut self.list_colors);
+        storage.strip_positions.append(&mut self.strip_positions);
+        storage.strip_colors.append(&mut self.strip_colors);
+    }
 }
 
 impl<'w, 's, T: GizmoConfigGroup> Gizmos<'w, 's, T> {
@@ -14,7 +14,10 @@ pub use loader::*;
 
 use bevy_app::prelude::*;
 use bevy_asset::{Asset, AssetApp, Handle};
-use bevy_ecs::{prelude::Component, reflect::ReflectComponent};
+use bevy_ecs::{
+    prelude::Component,
+    reflect::{AppTypeRegistry, ReflectComponent},
+};
 use bevy_pbr::StandardMaterial;
 use bevy_reflect::{Reflect, TypePath};
 use bevy_render::{
@@ -62,10 +65,14 @@ impl Plugin for GltfPlugin {
 
             None => CompressedImageFormats::NONE,
         };
-        app.register_asset_loader(GltfLoader {
-            supported_compressed_formats,
-            custom_vertex_attributes: self.custom_vertex_attributes.clone(),
-        });
+        let type_registry = app.world.resource::<AppTypeRegistry>().0.clone();
+        app.register_asset_loader(
+            GltfLoader::new(
+                supported_compressed_formats,
+                self.custom_vertex_attributes.clone(),
+            )
+            .with_type_registry(type_registry),
+        );
     }
 }
 
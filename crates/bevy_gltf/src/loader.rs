@@ -14,6 +14,7 @@ use bevy_pbr::{
     DirectionalLight, DirectionalLightBundle, PbrBundle, PointLight, PointLightBundle, SpotLight,
     SpotLightBundle, StandardMaterial, MAX_JOINTS,
 };
+use bevy_reflect::TypeRegistryArc;
 use bevy_render::{
     alpha::AlphaMode,
     camera::{Camera, OrthographicProjection, PerspectiveProjection, Projection, ScalingMode},
@@ -109,6 +110,33 @@ pub struct GltfLoader {
     /// See [this section of the glTF specification](https://registry.khronos.org/glTF/specs/2.0/glTF-2.0.html#meshes-overview)
     /// for additional details on custom attributes.
     pub custom_vertex_attributes: HashMap<Box<str>, MeshVertexAttribute>,
+    /// The type registry used to deserialize reflected data, such as the animation events
+    /// stored in the `bevy_animation_events` field of the extras of an animation.
+    type_registry: TypeRegistryArc,
+}
+
+impl GltfLoader {
+    /// Creates a loader for the given compressed image formats and custom vertex attributes.
+    ///
+    /// Its type registry is empty, so reflected data such as animation events is not loaded
+    /// unless a registry is set with [`GltfLoader::with_type_registry`].
+    pub fn new(
+        supported_compressed_formats: CompressedImageFormats,
+        custom_vertex_attributes: HashMap<Box<str>, MeshVertexAttribute>,
+    ) -> Self {
+        Self {
+            supported_compressed_formats,
+            custom_vertex_attributes,
+            type_registry: TypeRegistryArc::default(),
+        }
+    }
+
+    /// Sets the type registry used to deserialize reflected data, such as the animation events
+    /// stored in the `bevy_animation_events` field of the extras of an animation.
+    pub fn with_type_registry(mut self, type_registry: TypeRegistryArc) -> Self {
+        self.type_registry = type_registry;
+        self
+    }
 }
 
 /// Specifies optional settings for processing gltfs at load time. By default, all recognized contents of
@@ -293,6 +321,18 @@ async fn load_gltf<'a, 'b, 'c>(
                     );
                 }
             }
+            if let Some(extras) = animation.extras() {
+                if let Err(err) = load_animation_events(
+                    &mut animation_clip,
+                    extras.get(),
+                    &loader.type_registry.read(),
+                ) {
+                    warn!(
+                        "Events of animation {} couldn't be loaded: {err}",
+                        animation.index()
+                    );
+                }
+            }
             let handle = load_context
                 .add_labeled_asset(format!("Animation{}", animation.index()), animation_clip);
             if let Some(name) = animation.name() {
@@ -695,6 +735,34 @@ async fn load_gltf<'a, 'b, 'c>(
     })
 }
 
+/// Adds the events listed in the `bevy_animation_events` field of the extras of a glTF animation
+/// to `clip`, in the format of an [`AnimationEventsSerializer`](bevy_animation::AnimationEventsSerializer):
+///
+/// ```json
+/// { "bevy_animation_events": [{ "time": 0.5, "event": { "my_game::Footstep": { "volume": 1.0 } } }] }
+/// ```
+#[cfg(feature = "bevy_animation")]
+fn load_animation_events(
+    clip: &mut bevy_animation::AnimationClip,
+    extras: &str,
+    type_registry: &bevy_reflect::TypeRegistry,
+) -> Result<(), serde_json::Error> {
+    use serde::de::DeserializeSeed;
+
+    let extras: serde_json::Value = serde_json::from_str(extras)?;
+    let Some(events) = extras.get("bevy_animation_events") else {
+        return Ok(());
+    };
+    for event in
+        bevy_animation::AnimationEventsDeserializer::new(type_registry).deserialize(events)?
+    {
+        if let bevy_animation::AnimationEvent::Reflected(value) = event.event {
+            clip.add_reflected_event(event.time, value);
+        }
+    }
+    Ok(())
+}
+
 fn get_gltf_extras(extras: &gltf::json::Extras) -> Option<GltfExtras> {
     extras.as_ref().map(|extras| GltfExtras {
         value: extras.get().to_string(),
@@ -1659,6 +1727,48 @@ mod test {
             }
         }
     }
+    #[cfg(feature = "bevy_animation")]
+    #[test]
+    fn animation_events_from_extras() {
+        use bevy_animation::{AnimationClip, AnimationEvent};
+        use bevy_ecs::event::Event;
+        use bevy_reflect::{FromReflect, Reflect, TypePath, TypeRegistry};
+
+        #[derive(Event, Reflect, Debug, PartialEq)]
+        struct Footstep {
+            volume: f32,
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Footstep>();
+        let extras = format!(
+            r#"{{"author": "me", "bevy_animation_events": [{{"time": 0.5, "event": {{"{}": {{"volume": 0.25}}}}}}]}}"#,
+            Footstep::type_path()
+        );
+
+        let mut clip = AnimationClip::default();
+        super::load_animation_events(&mut clip, &extras, &registry).unwrap();
+        assert_eq!(clip.events().len(), 1);
+        assert_eq!(clip.events()[0].time, 0.5);
+        let AnimationEvent::Reflected(event) = &clip.events()[0].event else {
+            panic!("loaded events are reflected");
+        };
+        assert_eq!(
+            Footstep::from_reflect(event.as_ref()),
+            Some(Footstep { volume: 0.25 })
+        );
+
+        // Extras without events are ignored, and invalid events are reported.
+        super::load_animation_events(&mut clip, r#"{"author": "me"}"#, &registry).unwrap();
+        assert!(super::load_animation_events(
+            &mut clip,
+            r#"{"bevy_animation_events": [{"time": 1.0, "event": {"Unknown": {}}}]}"#,
+            &registry
+        )
+        .is_err());
+        assert_eq!(clip.events().len(), 1);
+    }
+
     #[test]
     fn node_hierarchy_single_node() {
         let result = resolve_node_hierarchy(
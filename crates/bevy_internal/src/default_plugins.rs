@@ -9,6 +9,7 @@ use bevy_app::{Plugin, PluginGroup, PluginGroupBuilder};
 /// * [`TimePlugin`](crate::time::TimePlugin)
 /// * [`TransformPlugin`](crate::transform::TransformPlugin)
 /// * [`HierarchyPlugin`](crate::hierarchy::HierarchyPlugin)
+/// * [`StatesPlugin`](crate::state::app::StatesPlugin)
 /// * [`DiagnosticsPlugin`](crate::diagnostic::DiagnosticsPlugin)
 /// * [`InputPlugin`](crate::input::InputPlugin)
 /// * [`WindowPlugin`](crate::window::WindowPlugin)
@@ -51,6 +52,7 @@ impl PluginGroup for DefaultPlugins {
             .add(bevy_time::TimePlugin)
             .add(bevy_transform::TransformPlugin)
             .add(bevy_hierarchy::HierarchyPlugin)
+            .add(bevy_state::app::StatesPlugin)
             .add(bevy_diagnostic::DiagnosticsPlugin)
             .add(bevy_input::InputPlugin)
             .add(bevy_window::WindowPlugin::default())
@@ -79,6 +79,11 @@ pub mod scene {
     pub use bevy_scene::*;
 }
 
+pub mod state {
+    //! Finite states and state-scoped entities.
+    pub use bevy_state::*;
+}
+
 pub mod tasks {
     //! Pools for async, IO, and compute tasks.
     pub use bevy_tasks::*;
@@ -210,3 +215,9 @@ pub mod dev_tools {
     //! Collection of developer tools
     pub use bevy_dev_tools::*;
 }
+
+#[cfg(feature = "bevy_remote")]
+pub mod remote {
+    //! The Bevy Remote Protocol
+    pub use bevy_remote::*;
+}
@@ -8,6 +8,8 @@ use std::{
 use glam::{Quat, Vec2, Vec3, Vec3A, Vec4};
 use thiserror::Error;
 
+use crate::curve::{Curve, Interval};
+
 /// A point in space of any dimension that supports the math ops needed for cubic spline
 /// interpolation.
 pub trait Point:
@@ -1204,13 +1206,141 @@ impl<P: Point> From<CubicCurve<P>> for RationalCurve<P> {
     }
 }
 
+impl<P: Point> Curve<P> for CubicSegment<P> {
+    #[inline]
+    fn domain(&self) -> Interval {
+        Interval::UNIT
+    }
+
+    #[inline]
+    fn sample_unchecked(&self, t: f32) -> P {
+        self.position(t)
+    }
+}
+
+/// A [`CubicCurve`] with at least one segment, which implements [`Curve`] with the domain `[0, N]`,
+/// where `N` is the number of segments.
+///
+/// It is created from a [`CubicCurve`] with [`TryFrom`], which fails if the curve is empty:
+///
+/// ```
+/// # use bevy_math::{cubic_splines::*, curve::Curve, vec2};
+/// let points = [[vec2(-1.0, -20.0), vec2(3.0, 2.0), vec2(5.0, 3.0), vec2(9.0, 8.0)]];
+/// let curve = NonEmptyCubicCurve::try_from(CubicBezier::new(points).to_curve()).unwrap();
+/// assert_eq!(curve.domain().end(), 1.0);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct NonEmptyCubicCurve<P: Point> {
+    curve: CubicCurve<P>,
+    domain: Interval,
+}
+
+impl<P: Point> NonEmptyCubicCurve<P> {
+    /// Returns the underlying [`CubicCurve`].
+    pub fn curve(&self) -> &CubicCurve<P> {
+        &self.curve
+    }
+
+    /// Returns the underlying [`CubicCurve`], consuming `self`.
+    pub fn into_curve(self) -> CubicCurve<P> {
+        self.curve
+    }
+}
+
+impl<P: Point> TryFrom<CubicCurve<P>> for NonEmptyCubicCurve<P> {
+    type Error = EmptyCurveError;
+
+    fn try_from(curve: CubicCurve<P>) -> Result<Self, Self::Error> {
+        let domain =
+            Interval::new(0.0, curve.segments.len() as f32).map_err(|_| EmptyCurveError)?;
+        Ok(Self { curve, domain })
+    }
+}
+
+impl<P: Point> Curve<P> for NonEmptyCubicCurve<P> {
+    #[inline]
+    fn domain(&self) -> Interval {
+        self.domain
+    }
+
+    #[inline]
+    fn sample_unchecked(&self, t: f32) -> P {
+        self.curve.position(t)
+    }
+}
+
+impl<P: Point> Curve<P> for RationalSegment<P> {
+    #[inline]
+    fn domain(&self) -> Interval {
+        Interval::UNIT
+    }
+
+    #[inline]
+    fn sample_unchecked(&self, t: f32) -> P {
+        self.position(t)
+    }
+}
+
+/// A [`RationalCurve`] with a non-empty domain, which implements [`Curve`] with the domain `[0, L]`,
+/// where `L` is the sum of the knot spans of its segments, as returned by [`RationalCurve::domain`].
+///
+/// It is created from a [`RationalCurve`] with [`TryFrom`], which fails if the curve has no
+/// segments or if its knot spans add up to zero.
+#[derive(Clone, Debug, PartialEq)]
+pub struct NonEmptyRationalCurve<P: Point> {
+    curve: RationalCurve<P>,
+    domain: Interval,
+}
+
+impl<P: Point> NonEmptyRationalCurve<P> {
+    /// Returns the underlying [`RationalCurve`].
+    pub fn curve(&self) -> &RationalCurve<P> {
+        &self.curve
+    }
+
+    /// Returns the underlying [`RationalCurve`], consuming `self`.
+    pub fn into_curve(self) -> RationalCurve<P> {
+        self.curve
+    }
+}
+
+impl<P: Point> TryFrom<RationalCurve<P>> for NonEmptyRationalCurve<P> {
+    type Error = EmptyCurveError;
+
+    fn try_from(curve: RationalCurve<P>) -> Result<Self, Self::Error> {
+        let domain = Interval::new(0.0, curve.domain()).map_err(|_| EmptyCurveError)?;
+        Ok(Self { curve, domain })
+    }
+}
+
+impl<P: Point> Curve<P> for NonEmptyRationalCurve<P> {
+    #[inline]
+    fn domain(&self) -> Interval {
+        self.domain
+    }
+
+    #[inline]
+    fn sample_unchecked(&self, t: f32) -> P {
+        self.curve.position(t)
+    }
+}
+
+/// An error returned when converting a curve with an empty domain into a [`NonEmptyCubicCurve`]
+/// or a [`NonEmptyRationalCurve`].
+#[derive(Debug, Error)]
+#[error("The curve has an empty domain")]
+pub struct EmptyCurveError;
+
 #[cfg(test)]
 mod tests {
     use glam::{vec2, Vec2};
 
-    use crate::cubic_splines::{
-        CubicBSpline, CubicBezier, CubicGenerator, CubicNurbs, CubicSegment, RationalCurve,
-        RationalGenerator,
+    use crate::{
+        cubic_splines::{
+            CubicBSpline, CubicBezier, CubicGenerator, CubicHermite, CubicNurbs, CubicSegment,
+            NonEmptyCubicCurve, NonEmptyRationalCurve, RationalCurve, RationalGenerator,
+        },
+        curve::Curve,
     };
 
     /// How close two floats can be and still be considered equal
@@ -1233,6 +1363,28 @@ mod tests {
         }
     }
 
+    /// Empty curves can't be converted into a [`Curve`].
+    #[test]
+    fn curve_trait_requires_segments() {
+        let empty = CubicBezier::<Vec2>::new(Vec::<[Vec2; 4]>::new()).to_curve();
+        assert!(NonEmptyCubicCurve::try_from(empty.clone()).is_err());
+        assert!(NonEmptyRationalCurve::try_from(RationalCurve::from(empty)).is_err());
+        let single_point = CubicHermite::new([vec2(1.0, 2.0)], [vec2(0.0, 1.0)]).to_curve();
+        assert!(NonEmptyCubicCurve::try_from(single_point).is_err());
+
+        let points = [[
+            vec2(-1.0, -20.0),
+            vec2(3.0, 2.0),
+            vec2(5.0, 3.0),
+            vec2(9.0, 8.0),
+        ]];
+        let curve = NonEmptyCubicCurve::try_from(CubicBezier::new(points).to_curve()).unwrap();
+        assert_eq!(curve.domain().end(), 1.0);
+        let rational =
+            NonEmptyRationalCurve::try_from(RationalCurve::from(curve.into_curve())).unwrap();
+        assert_eq!(rational.domain().end(), 1.0);
+    }
+
     /// Manual, hardcoded function for computing the position along a cubic bezier.
     fn cubic_manual(t: f32, points: [Vec2; 4]) -> Vec2 {
         let p = points;
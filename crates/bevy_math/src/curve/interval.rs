@@ -0,0 +1,234 @@
+//! The [`Interval`] type for nonempty intervals used by the [`Curve`](super::Curve) trait.
+
+use std::ops::RangeInclusive;
+
+use thiserror::Error;
+
+use crate::FloatExt;
+
+/// A nonempty closed interval, possibly unbounded in either direction.
+///
+/// In other words, the interval may stretch all the way to positive or negative infinity, but it
+/// will always have some nonempty interior.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct Interval {
+    start: f32,
+    end: f32,
+}
+
+/// An error that indicates that an operation would have returned an invalid [`Interval`].
+#[derive(Debug, Error)]
+#[error("The resulting interval would be invalid (empty or with a NaN endpoint)")]
+pub struct InvalidIntervalError;
+
+/// An error indicating that spaced points could not be extracted from an unbounded interval.
+#[derive(Debug, Error)]
+#[error("Cannot extract spaced points from an unbounded interval, or with fewer than two points")]
+pub struct SpacedPointsError;
+
+impl Interval {
+    /// The unit interval covering the range between `0.0` and `1.0`.
+    pub const UNIT: Self = Self {
+        start: 0.0,
+        end: 1.0,
+    };
+
+    /// An interval which stretches across the entire real line from negative infinity to infinity.
+    pub const EVERYWHERE: Self = Self {
+        start: f32::NEG_INFINITY,
+        end: f32::INFINITY,
+    };
+
+    /// Create a new [`Interval`] with the specified `start` and `end`.
+    ///
+    /// The interval can be unbounded, but cannot be empty (`start` must be less than `end`) and
+    /// neither endpoint can be NaN.
+    #[inline]
+    pub fn new(start: f32, end: f32) -> Result<Self, InvalidIntervalError> {
+        if start >= end || start.is_nan() || end.is_nan() {
+            Err(InvalidIntervalError)
+        } else {
+            Ok(Self { start, end })
+        }
+    }
+
+    /// Get the start of this interval.
+    #[inline]
+    pub const fn start(self) -> f32 {
+        self.start
+    }
+
+    /// Get the end of this interval.
+    #[inline]
+    pub const fn end(self) -> f32 {
+        self.end
+    }
+
+    /// Get the length of this interval. Note that the result may be infinite (`f32::INFINITY`).
+    #[inline]
+    pub fn length(self) -> f32 {
+        self.end - self.start
+    }
+
+    /// Returns `true` if both endpoints of this interval are finite.
+    #[inline]
+    pub fn is_bounded(self) -> bool {
+        self.has_finite_start() && self.has_finite_end()
+    }
+
+    /// Returns `true` if this interval has a finite start.
+    #[inline]
+    pub fn has_finite_start(self) -> bool {
+        self.start.is_finite()
+    }
+
+    /// Returns `true` if this interval has a finite end.
+    #[inline]
+    pub fn has_finite_end(self) -> bool {
+        self.end.is_finite()
+    }
+
+    /// Returns `true` if `item` is contained in this interval.
+    #[inline]
+    pub fn contains(self, item: f32) -> bool {
+        (self.start..=self.end).contains(&item)
+    }
+
+    /// Returns `true` if the other interval is contained in this interval.
+    #[inline]
+    pub fn contains_interval(self, other: Self) -> bool {
+        self.start <= other.start && self.end >= other.end
+    }
+
+    /// Get the intersection of this interval with another.
+    ///
+    /// Returns an error if the intersection is empty or a single point.
+    #[inline]
+    pub fn intersect(self, other: Self) -> Result<Self, InvalidIntervalError> {
+        let start = self.start.max(other.start);
+        let end = self.end.min(other.end);
+        Self::new(start, end)
+    }
+
+    /// Clamp the given `value` to lie within this interval.
+    #[inline]
+    pub fn clamp(self, value: f32) -> f32 {
+        value.clamp(self.start, self.end)
+    }
+
+    /// Get an iterator over equally-spaced points from this interval in increasing order.
+    ///
+    /// The first and last points are the endpoints of the interval. Returns an error if this
+    /// interval is unbounded or if `points` is less than 2.
+    pub fn spaced_points(
+        self,
+        points: usize,
+    ) -> Result<impl Iterator<Item = f32>, SpacedPointsError> {
+        if points < 2 || !self.is_bounded() {
+            return Err(SpacedPointsError);
+        }
+
+        let step = 1.0 / (points - 1) as f32;
+        Ok((0..points).map(move |i| {
+            if i == points - 1 {
+                // Avoid rounding errors at the end of the interval.
+                self.end
+            } else {
+                self.start.lerp(self.end, i as f32 * step)
+            }
+        }))
+    }
+}
+
+impl TryFrom<RangeInclusive<f32>> for Interval {
+    type Error = InvalidIntervalError;
+
+    fn try_from(range: RangeInclusive<f32>) -> Result<Self, Self::Error> {
+        Interval::new(*range.start(), *range.end())
+    }
+}
+
+/// Create an [`Interval`] with a given `start` and `end`. Alias of [`Interval::new`].
+#[inline]
+pub fn interval(start: f32, end: f32) -> Result<Interval, InvalidIntervalError> {
+    Interval::new(start, end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn make_intervals() {
+        let ivl = Interval::new(2.0, -1.0);
+        assert!(ivl.is_err());
+
+        let ivl = Interval::new(-0.0, 0.0);
+        assert!(ivl.is_err());
+
+        let ivl = Interval::new(f32::NAN, 1.0);
+        assert!(ivl.is_err());
+
+        let ivl = Interval::new(f32::NEG_INFINITY, 3.0);
+        assert!(ivl.is_ok());
+
+        let ivl: Result<Interval, _> = (-1.0..=1.0).try_into();
+        assert_eq!(ivl.unwrap(), interval(-1.0, 1.0).unwrap());
+    }
+
+    #[test]
+    fn lengths() {
+        let ivl = interval(-5.0, 10.0).unwrap();
+        assert_eq!(ivl.length(), 15.0);
+        assert!(ivl.is_bounded());
+
+        let ivl = interval(5.0, f32::INFINITY).unwrap();
+        assert_eq!(ivl.length(), f32::INFINITY);
+        assert!(ivl.has_finite_start());
+        assert!(!ivl.has_finite_end());
+
+        assert_eq!(Interval::EVERYWHERE.length(), f32::INFINITY);
+        assert!(!Interval::EVERYWHERE.is_bounded());
+    }
+
+    #[test]
+    fn intersections() {
+        let ivl1 = interval(-1.0, 1.0).unwrap();
+        let ivl2 = interval(0.0, 2.0).unwrap();
+        let ivl3 = interval(2.0, 3.0).unwrap();
+
+        assert_eq!(ivl1.intersect(ivl2).unwrap(), interval(0.0, 1.0).unwrap());
+        assert!(ivl1.intersect(ivl3).is_err());
+        assert!(ivl2.intersect(ivl3).is_err());
+        assert_eq!(Interval::EVERYWHERE.intersect(ivl3).unwrap(), ivl3);
+    }
+
+    #[test]
+    fn containment() {
+        let ivl = Interval::UNIT;
+        assert!(ivl.contains(0.0));
+        assert!(ivl.contains(1.0));
+        assert!(!ivl.contains(-0.5));
+        assert!(!ivl.contains(f32::NAN));
+
+        assert!(Interval::EVERYWHERE.contains_interval(ivl));
+        assert!(!ivl.contains_interval(interval(0.5, 1.5).unwrap()));
+
+        assert_eq!(ivl.clamp(2.0), 1.0);
+        assert_eq!(ivl.clamp(-2.0), 0.0);
+    }
+
+    #[test]
+    fn spaced_points() {
+        let points: Vec<f32> = interval(0.0, 2.0)
+            .unwrap()
+            .spaced_points(5)
+            .unwrap()
+            .collect();
+        assert_eq!(points, vec![0.0, 0.5, 1.0, 1.5, 2.0]);
+
+        assert!(Interval::UNIT.spaced_points(1).is_err());
+        assert!(Interval::EVERYWHERE.spaced_points(3).is_err());
+    }
+}
@@ -0,0 +1,852 @@
+//! The [`Curve`] trait, used to describe curves in a number of different domains. This module also
+//! contains the [`Interval`] type, along with a selection of core data structures used to back
+//! curves that are interpolated from samples.
+//!
+//! A curve is a function of a single `f32` parameter `t` over an [`Interval`], called the curve's
+//! *domain*. Curves can be sampled, transformed with [`Curve::map`] and
+//! [`Curve::reparametrize`], combined with [`Curve::zip`] and [`Curve::chain`], and resampled into
+//! sample-backed curves like [`SampleCurve`] using [`Curve::resample`].
+//!
+//! The existing spline types, such as [`CubicCurve`] and [`RationalCurve`], can be empty, so they
+//! are converted into a [`NonEmptyCubicCurve`] or a [`NonEmptyRationalCurve`] with [`TryFrom`] to
+//! be used as a [`Curve`].
+//!
+//! [`CubicCurve`]: crate::cubic_splines::CubicCurve
+//! [`RationalCurve`]: crate::cubic_splines::RationalCurve
+//! [`NonEmptyCubicCurve`]: crate::cubic_splines::NonEmptyCubicCurve
+//! [`NonEmptyRationalCurve`]: crate::cubic_splines::NonEmptyRationalCurve
+
+pub mod interval;
+mod sample_curves;
+
+pub use interval::{interval, Interval, InvalidIntervalError, SpacedPointsError};
+pub use sample_curves::{SampleCurve, UnevenSampleCurve};
+
+use std::marker::PhantomData;
+use std::ops::Deref;
+
+use thiserror::Error;
+
+use crate::FloatExt;
+
+/// A trait for a type that can represent values of type `T` parametrized over a fixed interval.
+///
+/// Typical examples of this are actual geometric curves where `T: VectorSpace`, but other kinds
+/// of output data can be represented as well, such as rotations or morph weights.
+pub trait Curve<T> {
+    /// The interval over which this curve is parametrized.
+    ///
+    /// This is the range of values of `t` where we can sample the curve and receive valid output.
+    fn domain(&self) -> Interval;
+
+    /// Sample a point on this curve at the parameter value `t`, extracting the associated value.
+    /// This is the unchecked version of sampling, which should only be used if the sample time `t`
+    /// is already known to lie within the curve's domain.
+    ///
+    /// Values sampled from outside of a curve's domain are generally considered invalid; data
+    /// which is nonsensical or otherwise useless may be returned in such a circumstance, and
+    /// extrapolation beyond a curve's domain should not be relied upon.
+    fn sample_unchecked(&self, t: f32) -> T;
+
+    /// Sample a point on this curve at the parameter value `t`, returning `None` if the point is
+    /// outside of the curve's domain.
+    fn sample(&self, t: f32) -> Option<T> {
+        match self.domain().contains(t) {
+            true => Some(self.sample_unchecked(t)),
+            false => None,
+        }
+    }
+
+    /// Sample a point on this curve at the parameter value `t`, clamping `t` to lie inside the
+    /// domain of the curve.
+    fn sample_clamped(&self, t: f32) -> T {
+        let t = self.domain().clamp(t);
+        self.sample_unchecked(t)
+    }
+
+    /// Extract an iterator over evenly-spaced samples from this curve, including both endpoints
+    /// of its domain.
+    ///
+    /// Returns an error if the domain of the curve is unbounded or if `samples` is less than 2.
+    fn samples(&self, samples: usize) -> Result<impl Iterator<Item = T>, ResamplingError>
+    where
+        Self: Sized,
+    {
+        if samples < 2 {
+            return Err(ResamplingError::NotEnoughSamples(samples));
+        }
+        let times = self
+            .domain()
+            .spaced_points(samples)
+            .map_err(|_| ResamplingError::UnboundedDomain)?;
+        Ok(times.map(|t| self.sample_unchecked(t)))
+    }
+
+    /// Create a new curve by mapping the values of this curve via a function `f`; i.e., if the
+    /// sample at time `t` for this curve is `x`, the value at time `t` on the new curve will be
+    /// `f(x)`.
+    fn map<S, F>(self, f: F) -> MapCurve<T, S, Self, F>
+    where
+        Self: Sized,
+        F: Fn(T) -> S,
+    {
+        MapCurve {
+            preimage: self,
+            f,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Create a new [`Curve`] whose parameter space is related to the parameter space of this
+    /// curve by `f`. For each time `t`, the sample from the new curve at time `t` is the sample
+    /// from this curve at time `f(t)`. The given `domain` will be the domain of the new curve.
+    ///
+    /// The function `f` is expected to take `domain` into `self.domain()`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_math::curve::*;
+    /// let my_curve = FunctionCurve::new(Interval::UNIT, |t| t * t);
+    ///
+    /// // Play the curve twice as fast, over the interval [0, 0.5].
+    /// let faster = my_curve.reparametrize(interval(0.0, 0.5).unwrap(), |t| t * 2.0);
+    /// assert_eq!(faster.sample(0.5), Some(1.0));
+    /// ```
+    fn reparametrize<F>(self, domain: Interval, f: F) -> ReparamCurve<T, Self, F>
+    where
+        Self: Sized,
+        F: Fn(f32) -> f32,
+    {
+        ReparamCurve {
+            domain,
+            base: self,
+            f,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Linearly reparametrize this [`Curve`], producing a new curve whose domain is the given
+    /// `domain` instead of the current one. This operation is only valid for curves with bounded
+    /// domains; if either this curve's domain or the given `domain` is unbounded, an error is
+    /// returned.
+    fn reparametrize_linear(
+        self,
+        domain: Interval,
+    ) -> Result<LinearReparamCurve<T, Self>, LinearReparamError>
+    where
+        Self: Sized,
+    {
+        if !self.domain().is_bounded() {
+            return Err(LinearReparamError::SourceCurveUnbounded);
+        }
+
+        if !domain.is_bounded() {
+            return Err(LinearReparamError::TargetIntervalUnbounded);
+        }
+
+        Ok(LinearReparamCurve {
+            base: self,
+            new_domain: domain,
+            _phantom: PhantomData,
+        })
+    }
+
+    /// Reparametrize this [`Curve`] by sampling from another curve which outputs parameter values.
+    /// The resulting curve samples this curve at the time given by `other` and has the domain of
+    /// `other`.
+    ///
+    /// This is useful for easing: `other` is expected to map its domain into `self.domain()`.
+    fn reparametrize_by_curve<C>(self, other: C) -> CurveReparamCurve<T, Self, C>
+    where
+        Self: Sized,
+        C: Curve<f32>,
+    {
+        CurveReparamCurve {
+            base: self,
+            reparam_curve: other,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Create a new [`Curve`] which is the graph of this one; that is, its output includes the
+    /// parameter itself in the samples. For example, if this curve outputs `x` at time `t`, then
+    /// the produced curve will produce `(t, x)` at time `t`.
+    fn graph(self) -> GraphCurve<T, Self>
+    where
+        Self: Sized,
+    {
+        GraphCurve {
+            base: self,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Create a new [`Curve`] by zipping this curve together with another. The sample at time `t`
+    /// in the new curve is `(x, y)`, where `x` is the sample of `self` at time `t` and `y` is the
+    /// sample of `other` at time `t`. The domain of the new curve is the intersection of the
+    /// domains of its constituents.
+    ///
+    /// Returns an error if the domains of the two curves don't overlap.
+    fn zip<S, C>(self, other: C) -> Result<ZipCurve<T, S, Self, C>, InvalidIntervalError>
+    where
+        Self: Sized,
+        C: Curve<S>,
+    {
+        let domain = self.domain().intersect(other.domain())?;
+        Ok(ZipCurve {
+            domain,
+            first: self,
+            second: other,
+            _phantom: PhantomData,
+        })
+    }
+
+    /// Create a new [`Curve`] by composing this curve end-to-end with another, producing another
+    /// curve with outputs of the same type. The domain of the other curve is translated so that
+    /// its start coincides with where this curve ends.
+    ///
+    /// Returns an error if this curve's domain doesn't have a finite end or if `other`'s domain
+    /// doesn't have a finite start.
+    fn chain<C>(self, other: C) -> Result<ChainCurve<T, Self, C>, ChainError>
+    where
+        Self: Sized,
+        C: Curve<T>,
+    {
+        if !self.domain().has_finite_end() {
+            return Err(ChainError::FirstEndInfinite);
+        }
+        if !other.domain().has_finite_start() {
+            return Err(ChainError::SecondStartInfinite);
+        }
+        Ok(ChainCurve {
+            first: self,
+            second: other,
+            _phantom: PhantomData,
+        })
+    }
+
+    /// Resample this [`Curve`] to produce a new one that is defined by interpolation over equally
+    /// spaced sample values, using the provided `interpolation` to interpolate between adjacent
+    /// samples. The curve is divided into `segments` segments, so `segments + 1` samples are
+    /// taken.
+    ///
+    /// The interpolation takes two values and a parameter in `[0, 1]`, and should return the
+    /// first value at `0` and the second value at `1`.
+    ///
+    /// Returns an error if `segments` is zero or if this curve has an unbounded domain.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_math::*;
+    /// # use bevy_math::curve::*;
+    /// let quarter_rotation = FunctionCurve::new(interval(0.0, 90.0).unwrap(), |t| {
+    ///     Rotation2d::degrees(t)
+    /// });
+    ///
+    /// // A curve which only stores three data points and uses `nlerp` to interpolate them:
+    /// let resampled = quarter_rotation.resample(2, |x, y, t| x.nlerp(*y, t)).unwrap();
+    /// ```
+    fn resample<I>(
+        &self,
+        segments: usize,
+        interpolation: I,
+    ) -> Result<SampleCurve<T, I>, ResamplingError>
+    where
+        Self: Sized,
+        I: Fn(&T, &T, f32) -> T,
+    {
+        let samples = self.samples(segments + 1)?.collect::<Vec<_>>();
+        SampleCurve::new(self.domain(), samples, interpolation)
+    }
+
+    /// Resample this [`Curve`] to produce a new one that is defined by interpolation over samples
+    /// taken at the given set of times. The given `interpolation` is used to interpolate between
+    /// adjacent samples, as in [`Curve::resample`].
+    ///
+    /// The sample times are sorted, and those which are outside of the curve's domain, non-finite
+    /// or duplicated are filtered out. The domain of the new curve spans the remaining times.
+    ///
+    /// Returns an error if fewer than two valid sample times remain.
+    fn resample_uneven<I>(
+        &self,
+        sample_times: impl IntoIterator<Item = f32>,
+        interpolation: I,
+    ) -> Result<UnevenSampleCurve<T, I>, ResamplingError>
+    where
+        Self: Sized,
+        I: Fn(&T, &T, f32) -> T,
+    {
+        let domain = self.domain();
+        let timed_samples = sample_times
+            .into_iter()
+            .filter(|t| t.is_finite() && domain.contains(*t))
+            .map(|t| (t, self.sample_unchecked(t)));
+        UnevenSampleCurve::new(timed_samples, interpolation)
+    }
+
+    /// Borrow this curve rather than taking ownership of it. This is essentially an alias for a
+    /// prefix `&`; the point is that intermediate operations can be performed while retaining
+    /// access to the original curve.
+    fn by_ref(&self) -> &Self
+    where
+        Self: Sized,
+    {
+        self
+    }
+}
+
+impl<T, C, D> Curve<T> for D
+where
+    C: Curve<T> + ?Sized,
+    D: Deref<Target = C>,
+{
+    fn domain(&self) -> Interval {
+        <C as Curve<T>>::domain(self)
+    }
+
+    fn sample_unchecked(&self, t: f32) -> T {
+        <C as Curve<T>>::sample_unchecked(self, t)
+    }
+}
+
+/// An error indicating that a resampling operation could not be performed because of
+/// malformed inputs.
+#[derive(Debug, Error)]
+#[error("Could not resample from this curve because of bad inputs")]
+pub enum ResamplingError {
+    /// This resampling operation was not provided with enough samples to have well-formed output.
+    #[error("Not enough unique samples to construct resampled curve")]
+    NotEnoughSamples(usize),
+
+    /// This resampling operation failed because of an unbounded interval.
+    #[error("Could not resample because this curve has unbounded domain")]
+    UnboundedDomain,
+}
+
+/// An error indicating that a linear reparametrization couldn't be performed because of
+/// malformed inputs.
+#[derive(Debug, Error)]
+#[error("Could not build a linear function to reparametrize this curve")]
+pub enum LinearReparamError {
+    /// The source curve that was to be reparametrized had unbounded domain.
+    #[error("This curve has unbounded domain")]
+    SourceCurveUnbounded,
+
+    /// The target interval for reparametrization was unbounded.
+    #[error("The target interval for reparametrization is unbounded")]
+    TargetIntervalUnbounded,
+}
+
+/// An error indicating that an end-to-end composition couldn't be performed because of
+/// malformed inputs.
+#[derive(Debug, Error)]
+#[error("Could not compose these curves together")]
+pub enum ChainError {
+    /// The right endpoint of the first curve was infinite.
+    #[error("The first curve's domain has an infinite end")]
+    FirstEndInfinite,
+
+    /// The left endpoint of the second curve was infinite.
+    #[error("The second curve's domain has an infinite start")]
+    SecondStartInfinite,
+}
+
+/// A curve with a constant value over its domain.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct ConstantCurve<T> {
+    domain: Interval,
+    value: T,
+}
+
+impl<T> ConstantCurve<T>
+where
+    T: Clone,
+{
+    /// Create a constant curve, which has the given `domain` and always produces the given `value`.
+    pub fn new(domain: Interval, value: T) -> Self {
+        Self { domain, value }
+    }
+}
+
+impl<T> Curve<T> for ConstantCurve<T>
+where
+    T: Clone,
+{
+    #[inline]
+    fn domain(&self) -> Interval {
+        self.domain
+    }
+
+    #[inline]
+    fn sample_unchecked(&self, _t: f32) -> T {
+        self.value.clone()
+    }
+}
+
+/// A curve defined by a function together with a fixed domain.
+///
+/// This is a very general type of curve, for example to drive an animation procedurally.
+#[derive(Clone, Debug)]
+pub struct FunctionCurve<T, F> {
+    domain: Interval,
+    f: F,
+    _phantom: PhantomData<fn() -> T>,
+}
+
+impl<T, F> FunctionCurve<T, F>
+where
+    F: Fn(f32) -> T,
+{
+    /// Create a new curve with the given `domain` from the given `function`. When sampled, the
+    /// `function` is evaluated at the sample time to compute the output.
+    pub fn new(domain: Interval, function: F) -> Self {
+        FunctionCurve {
+            domain,
+            f: function,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T, F> Curve<T> for FunctionCurve<T, F>
+where
+    F: Fn(f32) -> T,
+{
+    #[inline]
+    fn domain(&self) -> Interval {
+        self.domain
+    }
+
+    #[inline]
+    fn sample_unchecked(&self, t: f32) -> T {
+        (self.f)(t)
+    }
+}
+
+/// A curve whose samples are defined by mapping samples from another curve through a
+/// given function. Curves of this type are produced by [`Curve::map`].
+#[derive(Clone, Debug)]
+pub struct MapCurve<S, T, C, F> {
+    preimage: C,
+    f: F,
+    _phantom: PhantomData<fn(S) -> T>,
+}
+
+impl<S, T, C, F> Curve<T> for MapCurve<S, T, C, F>
+where
+    C: Curve<S>,
+    F: Fn(S) -> T,
+{
+    #[inline]
+    fn domain(&self) -> Interval {
+        self.preimage.domain()
+    }
+
+    #[inline]
+    fn sample_unchecked(&self, t: f32) -> T {
+        (self.f)(self.preimage.sample_unchecked(t))
+    }
+}
+
+/// A curve whose sample space is mapped onto that of some base curve's before sampling.
+/// Curves of this type are produced by [`Curve::reparametrize`].
+#[derive(Clone, Debug)]
+pub struct ReparamCurve<T, C, F> {
+    domain: Interval,
+    base: C,
+    f: F,
+    _phantom: PhantomData<fn() -> T>,
+}
+
+impl<T, C, F> Curve<T> for ReparamCurve<T, C, F>
+where
+    C: Curve<T>,
+    F: Fn(f32) -> f32,
+{
+    #[inline]
+    fn domain(&self) -> Interval {
+        self.domain
+    }
+
+    #[inline]
+    fn sample_unchecked(&self, t: f32) -> T {
+        self.base.sample_unchecked((self.f)(t))
+    }
+}
+
+/// A curve that has had its domain changed by a linear reparametrization (stretching and scaling).
+/// Curves of this type are produced by [`Curve::reparametrize_linear`].
+#[derive(Clone, Debug)]
+pub struct LinearReparamCurve<T, C> {
+    /// Invariants: The domain of this curve must always be bounded.
+    base: C,
+    /// Invariants: This interval must always be bounded.
+    new_domain: Interval,
+    _phantom: PhantomData<fn() -> T>,
+}
+
+impl<T, C> Curve<T> for LinearReparamCurve<T, C>
+where
+    C: Curve<T>,
+{
+    #[inline]
+    fn domain(&self) -> Interval {
+        self.new_domain
+    }
+
+    #[inline]
+    fn sample_unchecked(&self, t: f32) -> T {
+        let f = (t - self.new_domain.start()) / self.new_domain.length();
+        let base_domain = self.base.domain();
+        let s = base_domain.start().lerp(base_domain.end(), f);
+        self.base.sample_unchecked(s)
+    }
+}
+
+/// A curve that has been reparametrized by another curve, using that curve to transform the
+/// sample times before sampling. Curves of this type are produced by
+/// [`Curve::reparametrize_by_curve`].
+#[derive(Clone, Debug)]
+pub struct CurveReparamCurve<T, C, D> {
+    base: C,
+    reparam_curve: D,
+    _phantom: PhantomData<fn() -> T>,
+}
+
+impl<T, C, D> Curve<T> for CurveReparamCurve<T, C, D>
+where
+    C: Curve<T>,
+    D: Curve<f32>,
+{
+    #[inline]
+    fn domain(&self) -> Interval {
+        self.reparam_curve.domain()
+    }
+
+    #[inline]
+    fn sample_unchecked(&self, t: f32) -> T {
+        let sample_time = self.reparam_curve.sample_unchecked(t);
+        self.base.sample_unchecked(sample_time)
+    }
+}
+
+/// A curve that is the graph of another curve over its parameter space. Curves of this type are
+/// produced by [`Curve::graph`].
+#[derive(Clone, Debug)]
+pub struct GraphCurve<T, C> {
+    base: C,
+    _phantom: PhantomData<fn() -> T>,
+}
+
+impl<T, C> Curve<(f32, T)> for GraphCurve<T, C>
+where
+    C: Curve<T>,
+{
+    #[inline]
+    fn domain(&self) -> Interval {
+        self.base.domain()
+    }
+
+    #[inline]
+    fn sample_unchecked(&self, t: f32) -> (f32, T) {
+        (t, self.base.sample_unchecked(t))
+    }
+}
+
+/// A curve that combines the output data from two constituent curves into a tuple output. Curves
+/// of this type are produced by [`Curve::zip`].
+#[derive(Clone, Debug)]
+pub struct ZipCurve<S, T, C, D> {
+    domain: Interval,
+    first: C,
+    second: D,
+    _phantom: PhantomData<fn() -> (S, T)>,
+}
+
+impl<S, T, C, D> Curve<(S, T)> for ZipCurve<S, T, C, D>
+where
+    C: Curve<S>,
+    D: Curve<T>,
+{
+    #[inline]
+    fn domain(&self) -> Interval {
+        self.domain
+    }
+
+    #[inline]
+    fn sample_unchecked(&self, t: f32) -> (S, T) {
+        (
+            self.first.sample_unchecked(t),
+            self.second.sample_unchecked(t),
+        )
+    }
+}
+
+/// The curve that results from chaining one curve with another. The second curve is
+/// effectively reparametrized so that its start is at the end of the first.
+///
+/// For this to be well-formed, the first curve's domain must be right-finite and the second's
+/// must be left-finite. Curves of this type are produced by [`Curve::chain`].
+#[derive(Clone, Debug)]
+pub struct ChainCurve<T, C, D> {
+    first: C,
+    second: D,
+    _phantom: PhantomData<fn() -> T>,
+}
+
+impl<T, C, D> Curve<T> for ChainCurve<T, C, D>
+where
+    C: Curve<T>,
+    D: Curve<T>,
+{
+    #[inline]
+    fn domain(&self) -> Interval {
+        // This unwrap always succeeds because `first` has a valid Interval as its domain and the
+        // length of `second` cannot be NAN. It's still fine if it's infinity.
+        Interval::new(
+            self.first.domain().start(),
+            self.first.domain().end() + self.second.domain().length(),
+        )
+        .unwrap()
+    }
+
+    #[inline]
+    fn sample_unchecked(&self, t: f32) -> T {
+        if t > self.first.domain().end() {
+            self.second.sample_unchecked(
+                // `t - first.domain.end` computes the offset into the domain of the second.
+                t - self.first.domain().end() + self.second.domain().start(),
+            )
+        } else {
+            self.first.sample_unchecked(t)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        cubic_splines::{CubicBezier, CubicGenerator, NonEmptyCubicCurve},
+        Quat, Vec2, Vec3,
+    };
+    use approx::assert_abs_diff_eq;
+    use std::f32::consts::TAU;
+
+    #[test]
+    fn constant_curves() {
+        let curve = ConstantCurve::new(Interval::EVERYWHERE, 5.0);
+        assert!(curve.sample_unchecked(-35.0) == 5.0);
+
+        let curve = ConstantCurve::new(Interval::UNIT, true);
+        assert!(curve.sample_unchecked(2.0));
+        assert!(curve.sample(2.0).is_none());
+    }
+
+    #[test]
+    fn function_curves() {
+        let curve = FunctionCurve::new(Interval::EVERYWHERE, |t| t * t);
+        assert_abs_diff_eq!(curve.sample_unchecked(2.0), 4.0);
+        assert_abs_diff_eq!(curve.sample_unchecked(-3.0), 9.0);
+
+        let curve = FunctionCurve::new(interval(0.0, f32::INFINITY).unwrap(), f32::log2);
+        assert_eq!(curve.sample_unchecked(3.5), f32::log2(3.5));
+        assert!(curve.sample_unchecked(-1.0).is_nan());
+        assert!(curve.sample(-1.0).is_none());
+    }
+
+    #[test]
+    fn mapping() {
+        let curve = FunctionCurve::new(Interval::EVERYWHERE, |t| t * 3.0 + 1.0);
+        let mapped_curve = curve.map(|x| x / 7.0);
+        assert_eq!(mapped_curve.sample_unchecked(3.5), (3.5 * 3.0 + 1.0) / 7.0);
+        assert_eq!(mapped_curve.sample_unchecked(-1.0), (-3.0 + 1.0) / 7.0);
+        assert_eq!(mapped_curve.domain(), Interval::EVERYWHERE);
+
+        let curve = FunctionCurve::new(Interval::UNIT, |t| t * TAU);
+        let mapped_curve = curve.map(Quat::from_rotation_z);
+        assert_eq!(mapped_curve.sample_unchecked(0.0), Quat::IDENTITY);
+        assert!(mapped_curve.sample_unchecked(1.0).is_near_identity());
+        assert_eq!(mapped_curve.domain(), Interval::UNIT);
+    }
+
+    #[test]
+    fn reparametrization() {
+        let curve = FunctionCurve::new(interval(1.0, f32::INFINITY).unwrap(), f32::log2);
+        let reparametrized_curve = curve
+            .by_ref()
+            .reparametrize(interval(0.0, f32::INFINITY).unwrap(), f32::exp2);
+        assert_abs_diff_eq!(reparametrized_curve.sample_unchecked(3.5), 3.5);
+        assert_abs_diff_eq!(reparametrized_curve.sample_unchecked(100.0), 100.0);
+        assert_eq!(
+            reparametrized_curve.domain(),
+            interval(0.0, f32::INFINITY).unwrap()
+        );
+
+        let reparametrized_curve = curve
+            .by_ref()
+            .reparametrize(interval(0.0, 1.0).unwrap(), |t| t + 1.0);
+        assert_abs_diff_eq!(reparametrized_curve.sample_unchecked(0.0), 0.0);
+        assert_abs_diff_eq!(reparametrized_curve.sample_unchecked(1.0), 1.0);
+        assert_eq!(reparametrized_curve.domain(), interval(0.0, 1.0).unwrap());
+    }
+
+    #[test]
+    fn linear_reparametrization() {
+        let curve = FunctionCurve::new(interval(0.0, 2.0).unwrap(), |t| t * 2.0);
+        let reparametrized = curve
+            .by_ref()
+            .reparametrize_linear(interval(10.0, 11.0).unwrap())
+            .unwrap();
+        assert_abs_diff_eq!(reparametrized.sample_unchecked(10.0), 0.0);
+        assert_abs_diff_eq!(reparametrized.sample_unchecked(10.5), 2.0);
+        assert_abs_diff_eq!(reparametrized.sample_unchecked(11.0), 4.0);
+
+        assert!(curve
+            .by_ref()
+            .reparametrize_linear(Interval::EVERYWHERE)
+            .is_err());
+        let unbounded = FunctionCurve::new(Interval::EVERYWHERE, |t| t);
+        assert!(unbounded.reparametrize_linear(Interval::UNIT).is_err());
+    }
+
+    #[test]
+    fn reparametrization_by_curve() {
+        let curve = FunctionCurve::new(Interval::UNIT, |t| t * 10.0);
+        let easing = FunctionCurve::new(interval(0.0, 2.0).unwrap(), |t| (t / 2.0).powi(2));
+        let eased = curve.reparametrize_by_curve(easing);
+        assert_eq!(eased.domain(), interval(0.0, 2.0).unwrap());
+        assert_abs_diff_eq!(eased.sample_unchecked(1.0), 2.5);
+        assert_abs_diff_eq!(eased.sample_unchecked(2.0), 10.0);
+    }
+
+    #[test]
+    fn graph() {
+        let curve = FunctionCurve::new(Interval::UNIT, |t| t + 1.0);
+        let graph = curve.graph();
+        assert_eq!(graph.sample_unchecked(0.5), (0.5, 1.5));
+        assert_eq!(graph.domain(), Interval::UNIT);
+    }
+
+    #[test]
+    fn zipping() {
+        let curve1 = FunctionCurve::new(interval(0.0, 2.0).unwrap(), |t| t * 2.0);
+        let curve2 = ConstantCurve::new(interval(1.0, 3.0).unwrap(), Vec2::ONE);
+        let zipped = curve1.zip(curve2).unwrap();
+        assert_eq!(zipped.domain(), interval(1.0, 2.0).unwrap());
+        assert_eq!(zipped.sample_unchecked(1.5), (3.0, Vec2::ONE));
+
+        let curve1 = ConstantCurve::new(interval(0.0, 1.0).unwrap(), 0.0);
+        let curve2 = ConstantCurve::new(interval(2.0, 3.0).unwrap(), 0.0);
+        assert!(curve1.zip(curve2).is_err());
+    }
+
+    #[test]
+    fn chaining() {
+        let curve1 = FunctionCurve::new(interval(0.0, 1.0).unwrap(), |t| t);
+        let curve2 = FunctionCurve::new(interval(5.0, 7.0).unwrap(), |t| t * 10.0);
+        let chained = curve1.chain(curve2).unwrap();
+        assert_eq!(chained.domain(), interval(0.0, 3.0).unwrap());
+        assert_abs_diff_eq!(chained.sample_unchecked(0.5), 0.5);
+        assert_abs_diff_eq!(chained.sample_unchecked(1.0), 1.0);
+        assert_abs_diff_eq!(chained.sample_unchecked(2.0), 60.0);
+        assert_abs_diff_eq!(chained.sample_unchecked(3.0), 70.0);
+
+        let unbounded = FunctionCurve::new(Interval::EVERYWHERE, |t| t);
+        let bounded = FunctionCurve::new(Interval::UNIT, |t| t);
+        assert!(unbounded.by_ref().chain(bounded.by_ref()).is_err());
+        assert!(bounded.chain(unbounded).is_err());
+    }
+
+    #[test]
+    fn resampling() {
+        let curve = FunctionCurve::new(interval(1.0, 4.0).unwrap(), f32::log2);
+
+        // Need at least one segment to sample.
+        let nice_try = curve.by_ref().resample(0, |x, y, t| x.lerp(*y, t));
+        assert!(nice_try.is_err());
+
+        // The values of a resampled curve should be very close at the sample points.
+        // Because of denominators, it's not literally equal.
+        // (This is a tradeoff against O(1) sampling.)
+        let resampled_curve = curve
+            .by_ref()
+            .resample(100, |x, y, t| x.lerp(*y, t))
+            .unwrap();
+        for test_pt in curve.domain().spaced_points(101).unwrap() {
+            let expected = curve.sample_unchecked(test_pt);
+            assert_abs_diff_eq!(
+                resampled_curve.sample_unchecked(test_pt),
+                expected,
+                epsilon = 1e-6
+            );
+        }
+
+        // Another example.
+        let curve = FunctionCurve::new(interval(0.0, TAU).unwrap(), f32::cos);
+        let resampled_curve = curve
+            .by_ref()
+            .resample(1000, |x, y, t| x.lerp(*y, t))
+            .unwrap();
+        for test_pt in curve.domain().spaced_points(1001).unwrap() {
+            let expected = curve.sample_unchecked(test_pt);
+            assert_abs_diff_eq!(
+                resampled_curve.sample_unchecked(test_pt),
+                expected,
+                epsilon = 1e-6
+            );
+        }
+
+        let unbounded = FunctionCurve::new(Interval::EVERYWHERE, |t| t);
+        assert!(unbounded.resample(10, |x, y, t| x.lerp(*y, t)).is_err());
+    }
+
+    #[test]
+    fn uneven_resampling() {
+        let curve = FunctionCurve::new(interval(0.0, f32::INFINITY).unwrap(), f32::exp);
+
+        // Need at least two points to resample.
+        let nice_try = curve
+            .by_ref()
+            .resample_uneven([1.0; 1], |x, y, t| x.lerp(*y, t));
+        assert!(nice_try.is_err());
+
+        // Uneven sampling should produce literal equality at the sample points.
+        // (This is part of what you get in exchange for O(log(n)) sampling.)
+        let sample_points = (0..100).map(|idx| idx as f32 * 0.1);
+        let resampled_curve = curve
+            .by_ref()
+            .resample_uneven(sample_points.clone(), |x, y, t| x.lerp(*y, t))
+            .unwrap();
+        for idx in 0..100 {
+            let test_pt = idx as f32 * 0.1;
+            let expected = curve.sample_unchecked(test_pt);
+            assert_eq!(resampled_curve.sample_unchecked(test_pt), expected);
+        }
+        assert_abs_diff_eq!(resampled_curve.domain().start(), 0.0);
+        assert_abs_diff_eq!(resampled_curve.domain().end(), 9.9, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn spline_curves() {
+        let bezier = CubicBezier::new([[
+            Vec3::ZERO,
+            Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(1.0, 1.0, 0.0),
+            Vec3::new(0.0, 1.0, 0.0),
+        ]])
+        .to_curve();
+        let bezier = NonEmptyCubicCurve::try_from(bezier).unwrap();
+        assert_eq!(bezier.domain(), Interval::UNIT);
+        assert_eq!(bezier.sample(0.0), Some(Vec3::ZERO));
+        assert_eq!(bezier.sample(1.0), Some(Vec3::new(0.0, 1.0, 0.0)));
+        assert!(bezier.sample(1.5).is_none());
+
+        // Spline curves can be boxed and used as trait objects, e.g. to drive animations.
+        let boxed: Box<dyn Curve<Vec3>> = Box::new(bezier.map(|point| point * 2.0));
+        assert_eq!(boxed.sample(1.0), Some(Vec3::new(0.0, 2.0, 0.0)));
+    }
+}
@@ -0,0 +1,201 @@
+//! Curves backed by samples, produced by resampling other curves or built directly from data.
+
+use super::{Curve, Interval, ResamplingError};
+
+/// A curve that is defined by explicit neighbor interpolation over a set of evenly-spaced samples.
+///
+/// The samples are spread evenly over the domain, with the first and last samples at the
+/// endpoints. Sampling takes constant time.
+#[derive(Clone, Debug)]
+pub struct SampleCurve<T, I> {
+    /// Invariants: This interval must always be bounded.
+    domain: Interval,
+    /// Invariants: There must always be at least two samples.
+    samples: Vec<T>,
+    interpolation: I,
+}
+
+impl<T, I> SampleCurve<T, I>
+where
+    I: Fn(&T, &T, f32) -> T,
+{
+    /// Create a new [`SampleCurve`] using the specified `interpolation` to interpolate between
+    /// the given `samples`, which are evenly spaced over `domain`.
+    ///
+    /// The interpolation takes two values and a parameter in `[0, 1]`, and should return the
+    /// first value at `0` and the second value at `1`.
+    ///
+    /// Returns an error if `domain` is unbounded or if fewer than two samples are given.
+    pub fn new(
+        domain: Interval,
+        samples: impl IntoIterator<Item = T>,
+        interpolation: I,
+    ) -> Result<Self, ResamplingError> {
+        if !domain.is_bounded() {
+            return Err(ResamplingError::UnboundedDomain);
+        }
+        let samples: Vec<T> = samples.into_iter().collect();
+        if samples.len() < 2 {
+            return Err(ResamplingError::NotEnoughSamples(samples.len()));
+        }
+        Ok(Self {
+            domain,
+            samples,
+            interpolation,
+        })
+    }
+
+    /// The samples of this curve, evenly spaced over its domain.
+    pub fn samples(&self) -> &[T] {
+        &self.samples
+    }
+}
+
+impl<T, I> Curve<T> for SampleCurve<T, I>
+where
+    I: Fn(&T, &T, f32) -> T,
+{
+    #[inline]
+    fn domain(&self) -> Interval {
+        self.domain
+    }
+
+    #[inline]
+    fn sample_unchecked(&self, t: f32) -> T {
+        // The position of `t` in units of segments, clamped so that out-of-domain times are
+        // extrapolated from the first or last segment.
+        let segments = (self.samples.len() - 1) as f32;
+        let position = (t - self.domain.start()) / self.domain.length() * segments;
+        let index = (position.floor().max(0.0) as usize).min(self.samples.len() - 2);
+        let s = position - index as f32;
+        (self.interpolation)(&self.samples[index], &self.samples[index + 1], s)
+    }
+}
+
+/// A curve that is defined by interpolation over unevenly spaced samples with explicit
+/// interpolation.
+///
+/// Sampling takes logarithmic time in the number of samples.
+#[derive(Clone, Debug)]
+pub struct UnevenSampleCurve<T, I> {
+    /// Invariants: There must be at least two times, which are finite, sorted and unique.
+    times: Vec<f32>,
+    /// Invariants: There must be as many samples as times.
+    samples: Vec<T>,
+    interpolation: I,
+}
+
+impl<T, I> UnevenSampleCurve<T, I>
+where
+    I: Fn(&T, &T, f32) -> T,
+{
+    /// Create a new [`UnevenSampleCurve`] using the provided `interpolation` to interpolate
+    /// between adjacent `timed_samples`. The domain of the curve spans from the earliest to the
+    /// latest sample time.
+    ///
+    /// The samples are sorted by time, and samples with non-finite or duplicated times are
+    /// filtered out. Returns an error if fewer than two samples remain.
+    pub fn new(
+        timed_samples: impl IntoIterator<Item = (f32, T)>,
+        interpolation: I,
+    ) -> Result<Self, ResamplingError> {
+        let mut timed_samples: Vec<(f32, T)> = timed_samples
+            .into_iter()
+            .filter(|(t, _)| t.is_finite())
+            .collect();
+        timed_samples.sort_by(|(t0, _), (t1, _)| t0.total_cmp(t1));
+        timed_samples.dedup_by_key(|(t, _)| *t);
+
+        if timed_samples.len() < 2 {
+            return Err(ResamplingError::NotEnoughSamples(timed_samples.len()));
+        }
+
+        let (times, samples) = timed_samples.into_iter().unzip();
+        Ok(Self {
+            times,
+            samples,
+            interpolation,
+        })
+    }
+
+    /// The times of the samples of this curve, in increasing order.
+    pub fn times(&self) -> &[f32] {
+        &self.times
+    }
+
+    /// The samples of this curve, in the same order as [`UnevenSampleCurve::times`].
+    pub fn samples(&self) -> &[T] {
+        &self.samples
+    }
+}
+
+impl<T, I> Curve<T> for UnevenSampleCurve<T, I>
+where
+    I: Fn(&T, &T, f32) -> T,
+{
+    #[inline]
+    fn domain(&self) -> Interval {
+        // The invariants guarantee that the times are sorted, unique and finite.
+        Interval::new(self.times[0], self.times[self.times.len() - 1]).unwrap()
+    }
+
+    #[inline]
+    fn sample_unchecked(&self, t: f32) -> T {
+        // Find the segment containing `t`, clamping to the first or last segment outside of
+        // the domain.
+        let index = self
+            .times
+            .partition_point(|time| *time <= t)
+            .clamp(1, self.times.len() - 1)
+            - 1;
+        let (start, end) = (self.times[index], self.times[index + 1]);
+        let s = (t - start) / (end - start);
+        (self.interpolation)(&self.samples[index], &self.samples[index + 1], s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::curve::interval;
+    use crate::FloatExt;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn sample_curve() {
+        let curve = SampleCurve::new(
+            interval(0.0, 2.0).unwrap(),
+            [0.0, 10.0, 0.0],
+            |x: &f32, y: &f32, t| x.lerp(*y, t),
+        )
+        .unwrap();
+        assert_abs_diff_eq!(curve.sample_unchecked(0.5), 5.0);
+        assert_abs_diff_eq!(curve.sample_unchecked(1.0), 10.0);
+        assert_abs_diff_eq!(curve.sample_unchecked(1.5), 5.0);
+        assert_abs_diff_eq!(curve.sample_unchecked(2.0), 0.0);
+
+        let not_enough =
+            SampleCurve::new(Interval::UNIT, [0.0], |x: &f32, y: &f32, t| x.lerp(*y, t));
+        assert!(not_enough.is_err());
+    }
+
+    #[test]
+    fn uneven_sample_curve() {
+        let curve = UnevenSampleCurve::new(
+            [
+                (2.0, 20.0),
+                (0.0, 0.0),
+                (1.0, 10.0),
+                (1.0, 5.0),
+                (f32::NAN, 3.0),
+            ],
+            |x: &f32, y: &f32, t| x.lerp(*y, t),
+        )
+        .unwrap();
+        assert_eq!(curve.times(), &[0.0, 1.0, 2.0]);
+        assert_eq!(curve.domain(), interval(0.0, 2.0).unwrap());
+        assert_abs_diff_eq!(curve.sample_unchecked(0.5), 5.0);
+        assert_abs_diff_eq!(curve.sample_unchecked(1.5), 15.0);
+        assert_abs_diff_eq!(curve.sample_unchecked(2.0), 20.0);
+    }
+}
@@ -9,6 +9,7 @@ mod affine3;
 mod aspect_ratio;
 pub mod bounding;
 pub mod cubic_splines;
+pub mod curve;
 mod direction;
 pub mod primitives;
 mod ray;
@@ -35,9 +36,10 @@ pub mod prelude {
     pub use crate::{
         cubic_splines::{
             CubicBSpline, CubicBezier, CubicCardinalSpline, CubicCurve, CubicGenerator,
-            CubicHermite, CubicNurbs, CubicNurbsError, CubicSegment, RationalCurve,
-            RationalGenerator, RationalSegment,
+            CubicHermite, CubicNurbs, CubicNurbsError, CubicSegment, NonEmptyCubicCurve,
+            NonEmptyRationalCurve, RationalCurve, RationalGenerator, RationalSegment,
         },
+        curve::{Curve, Interval},
         direction::{Dir2, Dir3, Dir3A},
         primitives::*,
         BVec2, BVec3, BVec4, EulerRot, FloatExt, IRect, IVec2, IVec3, IVec4, Mat2, Mat3, Mat4,
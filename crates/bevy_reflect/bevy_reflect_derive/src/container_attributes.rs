@@ -23,6 +23,7 @@ mod kw {
     syn::custom_keyword!(PartialEq);
     syn::custom_keyword!(Hash);
     syn::custom_keyword!(no_field_bounds);
+    syn::custom_keyword!(methods);
 }
 
 // The "special" trait idents that are used internally for reflection.
@@ -223,6 +224,7 @@ pub(crate) struct ContainerAttributes {
     type_path_attrs: TypePathAttrs,
     custom_where: Option<WhereClause>,
     no_field_bounds: bool,
+    methods: Vec<Ident>,
     idents: Vec<Ident>,
 }
 
@@ -265,6 +267,8 @@ impl ContainerAttributes {
             self.parse_type_path(input, trait_)
         } else if lookahead.peek(kw::no_field_bounds) {
             self.parse_no_field_bounds(input)
+        } else if lookahead.peek(kw::methods) {
+            self.parse_methods(input)
         } else if lookahead.peek(kw::Debug) {
             self.parse_debug(input)
         } else if lookahead.peek(kw::PartialEq) {
@@ -373,6 +377,29 @@ impl ContainerAttributes {
         Ok(())
     }
 
+    /// Parse `methods` attribute.
+    ///
+    /// Examples:
+    /// - `#[reflect(methods(heal, take_damage))]`
+    fn parse_methods(&mut self, input: ParseStream) -> syn::Result<()> {
+        let ident = input.parse::<kw::methods>()?;
+
+        if !cfg!(feature = "functions") {
+            return Err(syn::Error::new(
+                ident.span,
+                "reflecting methods requires the `functions` feature of `bevy_reflect`",
+            ));
+        }
+
+        let content;
+        parenthesized!(content in input);
+        for method in content.parse_terminated(Ident::parse_any, Token![,])? {
+            add_unique_method(&mut self.methods, method)?;
+        }
+
+        Ok(())
+    }
+
     /// Parse `where` attribute.
     ///
     /// Examples:
@@ -531,6 +558,11 @@ impl ContainerAttributes {
         self.no_field_bounds
     }
 
+    /// The methods exposed through the `methods` attribute on this type.
+    pub fn methods(&self) -> &[Ident] {
+        &self.methods
+    }
+
     /// Merges the trait implementations of this [`ContainerAttributes`] with another one.
     ///
     /// An error is returned if the two [`ContainerAttributes`] have conflicting implementations.
@@ -544,6 +576,7 @@ impl ContainerAttributes {
             type_path_attrs,
             custom_where,
             no_field_bounds,
+            methods,
             idents,
         } = self;
 
@@ -557,6 +590,10 @@ impl ContainerAttributes {
 
         *no_field_bounds |= other.no_field_bounds;
 
+        for method in other.methods {
+            add_unique_method(methods, method)?;
+        }
+
         for ident in other.idents {
             add_unique_ident(idents, ident)?;
         }
@@ -589,6 +626,21 @@ fn add_unique_ident(idents: &mut Vec<Ident>, ident: Ident) -> Result<(), syn::Er
     Ok(())
 }
 
+/// Adds a method to the list of reflected methods if it is not already present.
+///
+/// Returns an error if the method already exists in the list.
+fn add_unique_method(methods: &mut Vec<Ident>, method: Ident) -> Result<(), syn::Error> {
+    if methods.contains(&method) {
+        return Err(syn::Error::new(
+            method.span(),
+            format!("method `{method}` is already reflected"),
+        ));
+    }
+
+    methods.push(method);
+    Ok(())
+}
+
 /// Extract a boolean value from an expression.
 ///
 /// The mapper exists so that the caller can conditionally choose to use the given
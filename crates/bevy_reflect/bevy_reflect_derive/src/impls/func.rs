@@ -0,0 +1,55 @@
+use crate::derive_data::ReflectMeta;
+use bevy_macro_utils::fq_std::{FQBox, FQResult};
+use quote::quote;
+
+/// Implements `FromArg`, `GetOwnership`, and `IntoReturn` for the given type data,
+/// allowing it to be passed to and returned from reflected functions by value.
+///
+/// Nothing is generated if `FromReflect` is not automatically derived,
+/// as taking an argument by value relies on it.
+pub(crate) fn impl_function_traits(meta: &ReflectMeta) -> Option<proc_macro2::TokenStream> {
+    if !meta.from_reflect().should_auto_derive() {
+        return None;
+    }
+
+    let bevy_reflect_path = meta.bevy_reflect_path();
+    let type_path = meta.type_path();
+
+    let (impl_generics, ty_generics, where_clause) = type_path.generics().split_for_impl();
+    let where_clause = match where_clause {
+        Some(where_clause) => {
+            let predicates = where_clause.predicates.iter();
+            quote!(where Self: #bevy_reflect_path::FromReflect + #bevy_reflect_path::TypePath, #(#predicates,)*)
+        }
+        None => {
+            quote!(where Self: #bevy_reflect_path::FromReflect + #bevy_reflect_path::TypePath,)
+        }
+    };
+
+    Some(quote! {
+        impl #impl_generics #bevy_reflect_path::func::FromArg for #type_path #ty_generics #where_clause {
+            type Item<'from_arg> = Self;
+
+            fn from_arg(
+                arg: #bevy_reflect_path::func::Arg<'_>,
+            ) -> #FQResult<Self::Item<'_>, #bevy_reflect_path::func::ArgError> {
+                arg.take_owned()
+            }
+        }
+
+        impl #impl_generics #bevy_reflect_path::func::GetOwnership for #type_path #ty_generics #where_clause {
+            fn ownership() -> #bevy_reflect_path::func::Ownership {
+                #bevy_reflect_path::func::Ownership::Owned
+            }
+        }
+
+        impl #impl_generics #bevy_reflect_path::func::IntoReturn for #type_path #ty_generics #where_clause {
+            fn into_return<'into_return>(self) -> #bevy_reflect_path::func::Return<'into_return>
+            where
+                Self: 'into_return,
+            {
+                #bevy_reflect_path::func::Return::Owned(#FQBox::new(self))
+            }
+        }
+    })
+}
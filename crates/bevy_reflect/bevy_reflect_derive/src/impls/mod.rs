@@ -1,10 +1,14 @@
 mod enums;
+#[cfg(feature = "functions")]
+mod func;
 mod structs;
 mod tuple_structs;
 mod typed;
 mod values;
 
 pub(crate) use enums::impl_enum;
+#[cfg(feature = "functions")]
+pub(crate) use func::impl_function_traits;
 pub(crate) use structs::impl_struct;
 pub(crate) use tuple_structs::impl_tuple_struct;
 pub(crate) use typed::impl_type_path;
@@ -59,6 +59,11 @@ fn match_reflect_impls(ast: DeriveInput, source: ReflectImplSource) -> TokenStre
         Err(err) => return err.into_compile_error().into(),
     };
 
+    #[cfg(feature = "functions")]
+    let function_impls = impls::impl_function_traits(derive_data.meta());
+    #[cfg(not(feature = "functions"))]
+    let function_impls: Option<proc_macro2::TokenStream> = None;
+
     let (reflect_impls, from_reflect_impl) = match derive_data {
         ReflectDerive::Struct(struct_data) | ReflectDerive::UnitStruct(struct_data) => (
             impls::impl_struct(&struct_data),
@@ -98,6 +103,7 @@ fn match_reflect_impls(ast: DeriveInput, source: ReflectImplSource) -> TokenStre
         const _: () = {
             #reflect_impls
             #from_reflect_impl
+            #function_impls
         };
     })
 }
@@ -271,6 +277,30 @@ fn match_reflect_impls(ast: DeriveInput, source: ReflectImplSource) -> TokenStre
 /// // {/* ... */}
 /// ```
 ///
+/// ## `#[reflect(methods(method_a, method_b, ...))]`
+///
+/// This attribute registers the given methods of the type as `ReflectMethods` type data,
+/// allowing them to be called by name through reflection.
+/// Each method must be convertible into a `DynamicFunction` using `IntoFunction`.
+///
+/// This attribute requires the `functions` feature of `bevy_reflect`.
+///
+/// ### Example
+///
+/// ```ignore
+/// #[derive(Reflect)]
+/// #[reflect(methods(heal))]
+/// struct Player {
+///   health: u32,
+/// }
+///
+/// impl Player {
+///   fn heal(&mut self, amount: u32) {
+///     self.health += amount;
+///   }
+/// }
+/// ```
+///
 /// # Field Attributes
 ///
 /// Along with the container attributes, this macro comes with some attributes that may be applied
@@ -504,10 +534,16 @@ pub fn impl_reflect_value(input: TokenStream) -> TokenStream {
     let reflect_impls = impls::impl_value(&meta);
     let from_reflect_impl = from_reflect::impl_value(&meta);
 
+    #[cfg(feature = "functions")]
+    let function_impls = impls::impl_function_traits(&meta);
+    #[cfg(not(feature = "functions"))]
+    let function_impls: Option<proc_macro2::TokenStream> = None;
+
     TokenStream::from(quote! {
         const _: () = {
             #reflect_impls
             #from_reflect_impl
+            #function_impls
         };
     })
 }
@@ -38,6 +38,18 @@ pub(crate) fn impl_get_type_registration<'a>(
         None
     };
 
+    let methods = meta.attrs().methods();
+    let methods_data = (!methods.is_empty()).then(|| {
+        let method_names = methods.iter().map(ToString::to_string);
+        quote! {
+            registration.insert::<#bevy_reflect_path::func::ReflectMethods>(
+                #bevy_reflect_path::func::ReflectMethods::new([
+                    #(#bevy_reflect_path::func::IntoFunction::into_function(Self::#methods).with_name(#method_names),)*
+                ])
+            );
+        }
+    });
+
     let serialization_data = serialization_data.map(|data| {
         let serialization_data = data.as_serialization_data(bevy_reflect_path);
         quote! {
@@ -53,6 +65,7 @@ pub(crate) fn impl_get_type_registration<'a>(
                 registration.insert::<#bevy_reflect_path::ReflectFromPtr>(#bevy_reflect_path::FromType::<Self>::from_type());
                 #from_reflect_data
                 #serialization_data
+                #methods_data
                 #(registration.insert::<#registration_data>(#bevy_reflect_path::FromType::<Self>::from_type());)*
                 registration
             }
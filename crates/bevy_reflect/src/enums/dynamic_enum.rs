@@ -420,6 +420,11 @@ impl Reflect for DynamicEnum {
         enum_debug(self, f)?;
         write!(f, ")")
     }
+
+    #[inline]
+    fn is_dynamic(&self) -> bool {
+        true
+    }
 }
 
 impl_type_path!((in bevy_reflect) DynamicEnum);
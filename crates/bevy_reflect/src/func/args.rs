@@ -0,0 +1,378 @@
+use std::borrow::Cow;
+use std::collections::VecDeque;
+
+use thiserror::Error;
+
+use crate::func::Ownership;
+use crate::{FromReflect, Reflect, TypePath};
+
+/// The value of an [`Arg`], along with its [ownership].
+///
+/// [ownership]: Ownership
+#[derive(Debug)]
+pub enum ArgValue<'a> {
+    /// An owned argument.
+    Owned(Box<dyn Reflect>),
+    /// An immutably borrowed argument.
+    Ref(&'a dyn Reflect),
+    /// A mutably borrowed argument.
+    Mut(&'a mut dyn Reflect),
+}
+
+impl<'a> ArgValue<'a> {
+    /// Returns the [`Ownership`] of this value.
+    pub fn ownership(&self) -> Ownership {
+        match self {
+            ArgValue::Owned(_) => Ownership::Owned,
+            ArgValue::Ref(_) => Ownership::Ref,
+            ArgValue::Mut(_) => Ownership::Mut,
+        }
+    }
+
+    /// Returns the underlying value as a reflected reference.
+    pub fn as_reflect(&self) -> &dyn Reflect {
+        match self {
+            ArgValue::Owned(value) => value.as_ref(),
+            ArgValue::Ref(value) => *value,
+            ArgValue::Mut(value) => &**value,
+        }
+    }
+}
+
+/// An argument passed to a [`DynamicFunction`].
+///
+/// [`DynamicFunction`]: crate::func::DynamicFunction
+#[derive(Debug)]
+pub struct Arg<'a> {
+    index: usize,
+    value: ArgValue<'a>,
+}
+
+impl<'a> Arg<'a> {
+    /// Creates a new [`Arg`] with the given index and value.
+    pub fn new(index: usize, value: ArgValue<'a>) -> Self {
+        Self { index, value }
+    }
+
+    /// The index of this argument in the [`ArgList`].
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// The value of this argument.
+    pub fn value(&self) -> &ArgValue<'a> {
+        &self.value
+    }
+
+    /// Takes the value of this argument.
+    pub fn take_value(self) -> ArgValue<'a> {
+        self.value
+    }
+
+    /// Takes this argument by value, converting it to `T`.
+    ///
+    /// If the value is not of type `T` (e.g. it is a dynamic type), it is converted
+    /// using [`FromReflect`].
+    ///
+    /// Returns an error if the argument is borrowed or cannot be converted to `T`.
+    pub fn take_owned<T: FromReflect + TypePath>(self) -> Result<T, ArgError> {
+        match self.value {
+            ArgValue::Owned(value) => value.take::<T>().or_else(|value| {
+                T::from_reflect(&*value).ok_or_else(|| ArgError::UnexpectedType {
+                    index: self.index,
+                    expected: Cow::Borrowed(T::type_path()),
+                    received: Cow::Owned(value.reflect_type_path().to_string()),
+                })
+            }),
+            value => Err(ArgError::InvalidOwnership {
+                index: self.index,
+                expected: Ownership::Owned,
+                received: value.ownership(),
+            }),
+        }
+    }
+
+    /// Takes this argument as an immutable reference to `T`.
+    ///
+    /// Returns an error if the argument is owned or is not of type `T`.
+    pub fn take_ref<T: Reflect + TypePath>(self) -> Result<&'a T, ArgError> {
+        let index = self.index;
+        let value: &'a dyn Reflect = match self.value {
+            ArgValue::Ref(value) => value,
+            ArgValue::Mut(value) => value,
+            ArgValue::Owned(_) => {
+                return Err(ArgError::InvalidOwnership {
+                    index,
+                    expected: Ownership::Ref,
+                    received: Ownership::Owned,
+                })
+            }
+        };
+        value
+            .downcast_ref::<T>()
+            .ok_or_else(|| ArgError::UnexpectedType {
+                index,
+                expected: Cow::Borrowed(T::type_path()),
+                received: Cow::Owned(value.reflect_type_path().to_string()),
+            })
+    }
+
+    /// Takes this argument as a mutable reference to `T`.
+    ///
+    /// Returns an error if the argument is not mutably borrowed or is not of type `T`.
+    pub fn take_mut<T: Reflect + TypePath>(self) -> Result<&'a mut T, ArgError> {
+        let index = self.index;
+        let ArgValue::Mut(value) = self.value else {
+            return Err(ArgError::InvalidOwnership {
+                index,
+                expected: Ownership::Mut,
+                received: self.value.ownership(),
+            });
+        };
+        if !value.is::<T>() {
+            return Err(ArgError::UnexpectedType {
+                index,
+                expected: Cow::Borrowed(T::type_path()),
+                received: Cow::Owned(value.reflect_type_path().to_string()),
+            });
+        }
+        Ok(value.downcast_mut::<T>().unwrap())
+    }
+}
+
+/// An ordered list of arguments that can be passed to a [`DynamicFunction`].
+///
+/// Arguments are taken from the front of the list, in the order they were pushed.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::func::ArgList;
+/// let foo = 123;
+/// let mut bar = String::from("hello");
+///
+/// let args = ArgList::new()
+///     // Push an owned argument
+///     .push_owned(456_i32)
+///     // Push an immutable reference
+///     .push_ref(&foo)
+///     // Push a mutable reference
+///     .push_mut(&mut bar);
+///
+/// assert_eq!(args.len(), 3);
+/// ```
+///
+/// [`DynamicFunction`]: crate::func::DynamicFunction
+#[derive(Default, Debug)]
+pub struct ArgList<'a> {
+    list: VecDeque<Arg<'a>>,
+    next_index: usize,
+}
+
+impl<'a> ArgList<'a> {
+    /// Creates an empty [`ArgList`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Pushes an argument with the given value onto the back of the list.
+    pub fn push_arg(mut self, value: ArgValue<'a>) -> Self {
+        self.list.push_back(Arg::new(self.next_index, value));
+        self.next_index += 1;
+        self
+    }
+
+    /// Pushes an owned argument onto the back of the list.
+    pub fn push_owned<T: Reflect>(self, value: T) -> Self {
+        self.push_arg(ArgValue::Owned(Box::new(value)))
+    }
+
+    /// Pushes an owned, boxed argument onto the back of the list.
+    pub fn push_boxed(self, value: Box<dyn Reflect>) -> Self {
+        self.push_arg(ArgValue::Owned(value))
+    }
+
+    /// Pushes an immutably borrowed argument onto the back of the list.
+    pub fn push_ref(self, value: &'a dyn Reflect) -> Self {
+        self.push_arg(ArgValue::Ref(value))
+    }
+
+    /// Pushes a mutably borrowed argument onto the back of the list.
+    pub fn push_mut(self, value: &'a mut dyn Reflect) -> Self {
+        self.push_arg(ArgValue::Mut(value))
+    }
+
+    /// Takes the next argument from the front of the list.
+    ///
+    /// Returns [`ArgError::EmptyArgList`] if there are no arguments left.
+    pub fn take_arg(&mut self) -> Result<Arg<'a>, ArgError> {
+        self.list.pop_front().ok_or(ArgError::EmptyArgList)
+    }
+
+    /// Takes the next argument from the front of the list and converts it to `T`.
+    ///
+    /// The ownership of the argument must match the one of `T`:
+    /// `T` takes an owned argument, `&T` an immutable reference and `&mut T` a mutable reference.
+    pub fn take<T: FromArg>(&mut self) -> Result<T::Item<'a>, ArgError> {
+        T::from_arg(self.take_arg()?)
+    }
+
+    /// Takes the next argument from the front of the list by value.
+    ///
+    /// See [`Arg::take_owned`] for details.
+    pub fn take_owned<T: FromReflect + TypePath>(&mut self) -> Result<T, ArgError> {
+        self.take_arg()?.take_owned()
+    }
+
+    /// Takes the next argument from the front of the list as an immutable reference.
+    ///
+    /// See [`Arg::take_ref`] for details.
+    pub fn take_ref<T: Reflect + TypePath>(&mut self) -> Result<&'a T, ArgError> {
+        self.take_arg()?.take_ref()
+    }
+
+    /// Takes the next argument from the front of the list as a mutable reference.
+    ///
+    /// See [`Arg::take_mut`] for details.
+    pub fn take_mut<T: Reflect + TypePath>(&mut self) -> Result<&'a mut T, ArgError> {
+        self.take_arg()?.take_mut()
+    }
+
+    /// Returns the number of arguments left in the list.
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    /// Returns `true` if there are no arguments left in the list.
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+}
+
+/// A trait for types that can be created from an [`Arg`].
+///
+/// This trait is used to convert the arguments of a [`DynamicFunction`] to the
+/// parameter types of the underlying function.
+/// The [`Item`](FromArg::Item) type allows references to be tied to the lifetime of the argument.
+///
+/// This trait is automatically implemented for types deriving [`Reflect`] when the `functions`
+/// feature is enabled, and for references to any [`Reflect`] type.
+///
+/// [`DynamicFunction`]: crate::func::DynamicFunction
+pub trait FromArg {
+    /// The type created from the argument, with the lifetime of the argument.
+    type Item<'from_arg>;
+
+    /// Creates an item from the given argument.
+    fn from_arg(arg: Arg<'_>) -> Result<Self::Item<'_>, ArgError>;
+}
+
+impl<T: Reflect + TypePath> FromArg for &'static T {
+    type Item<'from_arg> = &'from_arg T;
+
+    fn from_arg(arg: Arg<'_>) -> Result<Self::Item<'_>, ArgError> {
+        arg.take_ref()
+    }
+}
+
+impl<T: Reflect + TypePath> FromArg for &'static mut T {
+    type Item<'from_arg> = &'from_arg mut T;
+
+    fn from_arg(arg: Arg<'_>) -> Result<Self::Item<'_>, ArgError> {
+        arg.take_mut()
+    }
+}
+
+/// An error that occurs when converting an [`Arg`].
+#[derive(Debug, Error, PartialEq)]
+pub enum ArgError {
+    /// The argument is not of the expected type.
+    #[error("expected `{expected}` but received `{received}` (@ argument index {index})")]
+    UnexpectedType {
+        /// The index of the argument.
+        index: usize,
+        /// The type path of the expected type.
+        expected: Cow<'static, str>,
+        /// The type path of the received value.
+        received: Cow<'static, str>,
+    },
+    /// The argument doesn't have the expected ownership.
+    #[error("expected {expected} value but received {received} value (@ argument index {index})")]
+    InvalidOwnership {
+        /// The index of the argument.
+        index: usize,
+        /// The expected ownership.
+        expected: Ownership,
+        /// The ownership of the received value.
+        received: Ownership,
+    },
+    /// An argument was expected, but the list is empty.
+    #[error("expected an argument but received none")]
+    EmptyArgList,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DynamicTuple;
+
+    #[test]
+    fn should_take_args_in_order() {
+        let a = 1_u32;
+        let mut b = String::from("b");
+        let mut args = ArgList::new()
+            .push_owned(2_i32)
+            .push_ref(&a)
+            .push_mut(&mut b);
+
+        assert_eq!(args.take_owned::<i32>(), Ok(2));
+        assert_eq!(args.take_ref::<u32>(), Ok(&1));
+        args.take_mut::<String>().unwrap().push('!');
+        assert_eq!(args.take_arg().unwrap_err(), ArgError::EmptyArgList);
+        assert_eq!(b, "b!");
+    }
+
+    #[test]
+    fn should_convert_dynamic_owned_args() {
+        let mut tuple = DynamicTuple::default();
+        tuple.insert(1_u32);
+        tuple.insert(2_u32);
+        let mut args = ArgList::new().push_owned(tuple);
+
+        assert_eq!(args.take_owned::<(u32, u32)>(), Ok((1, 2)));
+    }
+
+    #[test]
+    fn should_error_on_invalid_args() {
+        let value = 1_u32;
+        let mut args = ArgList::new()
+            .push_ref(&value)
+            .push_owned(1_u32)
+            .push_ref(&value);
+
+        assert_eq!(
+            args.take_owned::<u32>(),
+            Err(ArgError::InvalidOwnership {
+                index: 0,
+                expected: Ownership::Owned,
+                received: Ownership::Ref,
+            })
+        );
+        assert_eq!(
+            args.take_owned::<i32>(),
+            Err(ArgError::UnexpectedType {
+                index: 1,
+                expected: Cow::Borrowed("i32"),
+                received: Cow::Borrowed("u32"),
+            })
+        );
+        assert_eq!(
+            args.take_mut::<u32>(),
+            Err(ArgError::InvalidOwnership {
+                index: 2,
+                expected: Ownership::Mut,
+                received: Ownership::Ref,
+            })
+        );
+    }
+}
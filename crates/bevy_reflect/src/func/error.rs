@@ -0,0 +1,38 @@
+use std::borrow::Cow;
+
+use thiserror::Error;
+
+use crate::func::args::ArgError;
+
+/// An error that occurs when calling a [`DynamicFunction`].
+///
+/// [`DynamicFunction`]: crate::func::DynamicFunction
+#[derive(Debug, Error, PartialEq)]
+pub enum FunctionError {
+    /// An error occurred while converting an argument.
+    #[error(transparent)]
+    ArgError(#[from] ArgError),
+    /// The number of arguments provided does not match the expected number.
+    #[error("expected {expected} arguments but received {received}")]
+    ArgCountMismatch {
+        /// The number of arguments the function takes.
+        expected: usize,
+        /// The number of arguments that were provided.
+        received: usize,
+    },
+}
+
+/// An error that occurs when registering a function into a [`FunctionRegistry`].
+///
+/// [`FunctionRegistry`]: crate::func::FunctionRegistry
+#[derive(Debug, Error, PartialEq)]
+pub enum FunctionRegistrationError {
+    /// A function with the given name has already been registered.
+    ///
+    /// Contains the duplicate function name.
+    #[error("a function has already been registered with name {0:?}")]
+    DuplicateName(Cow<'static, str>),
+    /// The function is missing a name by which it can be registered.
+    #[error("function name is missing")]
+    MissingName,
+}
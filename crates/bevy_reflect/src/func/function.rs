@@ -0,0 +1,189 @@
+use std::borrow::Cow;
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+
+use crate::func::args::ArgList;
+use crate::func::info::FunctionInfo;
+use crate::func::return_type::Return;
+use crate::func::{FunctionError, IntoFunction};
+
+/// The result of calling a [`DynamicFunction`].
+///
+/// Returns `Ok(value)` if the function was called successfully,
+/// where `value` is the [`Return`] value of the function.
+pub type FunctionResult<'a> = Result<Return<'a>, FunctionError>;
+
+/// A dynamic representation of a Rust function.
+///
+/// For our purposes, a "function" is just a callable that may not reference its environment
+/// mutably: plain functions, methods, and closures that only capture their environment
+/// immutably (or by value).
+///
+/// This type can be used to represent any such function, regardless of its actual signature,
+/// and allows it to be called with arguments provided through an [`ArgList`].
+///
+/// Most functions and closures can be converted into a [`DynamicFunction`] using [`IntoFunction`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::func::{ArgList, DynamicFunction, IntoFunction};
+/// fn add(a: i32, b: i32) -> i32 {
+///   a + b
+/// }
+///
+/// let func: DynamicFunction = add.into_function();
+/// assert!(func.name().unwrap().ends_with("add"));
+///
+/// let args = ArgList::new().push_owned(25_i32).push_owned(75_i32);
+/// let value = func.call(args).unwrap().unwrap_owned();
+/// assert_eq!(value.take::<i32>().unwrap(), 100);
+/// ```
+///
+/// The `'env` lifetime is the lifetime of the environment captured by the function.
+/// Only functions with a `'static` environment can be stored in a [`FunctionRegistry`].
+///
+/// [`FunctionRegistry`]: crate::func::FunctionRegistry
+pub struct DynamicFunction<'env> {
+    info: FunctionInfo,
+    func: Arc<dyn for<'a> Fn(ArgList<'a>) -> FunctionResult<'a> + Send + Sync + 'env>,
+}
+
+impl<'env> DynamicFunction<'env> {
+    /// Create a new [`DynamicFunction`] from the given callback and its [`FunctionInfo`].
+    ///
+    /// The callback is responsible for checking the number of arguments it receives,
+    /// and their types.
+    pub fn new<F: for<'a> Fn(ArgList<'a>) -> FunctionResult<'a> + Send + Sync + 'env>(
+        func: F,
+        info: FunctionInfo,
+    ) -> Self {
+        Self {
+            info,
+            func: Arc::new(func),
+        }
+    }
+
+    /// Set the name of the function.
+    ///
+    /// This is useful for closures, which are unnamed by default,
+    /// or to register a function under a shorter name.
+    pub fn with_name(mut self, name: impl Into<Cow<'static, str>>) -> Self {
+        self.info = self.info.with_name(name);
+        self
+    }
+
+    /// Call the function with the given arguments.
+    pub fn call<'a>(&self, args: ArgList<'a>) -> FunctionResult<'a> {
+        (self.func)(args)
+    }
+
+    /// Returns the function info.
+    pub fn info(&self) -> &FunctionInfo {
+        &self.info
+    }
+
+    /// The name of the function, if it has one.
+    ///
+    /// See [`FunctionInfo::name`] for details.
+    pub fn name(&self) -> Option<&Cow<'static, str>> {
+        self.info.name()
+    }
+}
+
+impl<'env> Clone for DynamicFunction<'env> {
+    fn clone(&self) -> Self {
+        Self {
+            info: self.info.clone(),
+            func: Arc::clone(&self.func),
+        }
+    }
+}
+
+/// Outputs the function signature.
+///
+/// This takes the format: `DynamicFunction(fn {name}({arg1}: {type1}, {arg2}: {type2}, ...) -> {return_type})`.
+///
+/// Names for arguments are optional and will default to `_` if not provided.
+impl<'env> Debug for DynamicFunction<'env> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = self.info.name().map(Cow::as_ref).unwrap_or("_");
+        write!(f, "DynamicFunction(fn {name}(")?;
+
+        for (index, arg) in self.info.args().iter().enumerate() {
+            let name = arg.name().unwrap_or("_");
+            let ty = arg.type_path();
+            write!(f, "{name}: {ty}")?;
+
+            if index + 1 < self.info.args().len() {
+                write!(f, ", ")?;
+            }
+        }
+
+        let ret = self.info.return_info().type_path();
+        write!(f, ") -> {ret})")
+    }
+}
+
+impl<'env> IntoFunction<'env, ()> for DynamicFunction<'env> {
+    #[inline]
+    fn into_function(self) -> DynamicFunction<'env> {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::func::ArgInfo;
+
+    #[test]
+    fn should_overwrite_function_name() {
+        let func = (|a: i32, b: i32| a + b).into_function();
+        assert!(func.name().is_none());
+
+        let func = func.with_name("my_function");
+        assert_eq!(func.name().unwrap(), "my_function");
+    }
+
+    #[test]
+    fn should_error_on_arg_count_mismatch() {
+        let func = (|a: i32, b: i32| a + b).into_function();
+        let args = ArgList::new().push_owned(25_i32);
+
+        assert_eq!(
+            func.call(args).unwrap_err(),
+            FunctionError::ArgCountMismatch {
+                expected: 2,
+                received: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn should_create_manual_functions() {
+        let func = DynamicFunction::new(
+            |mut args| {
+                let a = args.take_owned::<i32>()?;
+                let b = args.take_ref::<i32>()?;
+                Ok(Return::Owned(Box::new(a * b)))
+            },
+            FunctionInfo::new()
+                .with_name("mul")
+                .with_args(vec![
+                    ArgInfo::new::<i32>(0).with_name("a"),
+                    ArgInfo::new::<&i32>(1).with_name("b"),
+                ])
+                .with_return_info(crate::func::ReturnInfo::new::<i32>()),
+        );
+
+        assert_eq!(
+            format!("{func:?}"),
+            "DynamicFunction(fn mul(a: i32, b: &i32) -> i32)"
+        );
+
+        let args = ArgList::new().push_owned(3_i32).push_ref(&4_i32);
+        let value = func.call(args).unwrap().unwrap_owned();
+        assert_eq!(value.take::<i32>().unwrap(), 12);
+    }
+}
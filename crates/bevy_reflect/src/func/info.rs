@@ -0,0 +1,172 @@
+use std::borrow::Cow;
+
+use crate::func::{GetOwnership, Ownership};
+use crate::TypePath;
+
+/// Type information for a [`DynamicFunction`].
+///
+/// [`DynamicFunction`]: crate::func::DynamicFunction
+#[derive(Debug, Clone)]
+pub struct FunctionInfo {
+    name: Option<Cow<'static, str>>,
+    args: Vec<ArgInfo>,
+    return_info: ReturnInfo,
+}
+
+impl FunctionInfo {
+    /// Create a new [`FunctionInfo`] for a function with no arguments returning `()`.
+    ///
+    /// The name of the function defaults to `None`.
+    pub fn new() -> Self {
+        Self {
+            name: None,
+            args: Vec::new(),
+            return_info: ReturnInfo::new::<()>(),
+        }
+    }
+
+    /// Set the name of the function.
+    pub fn with_name(mut self, name: impl Into<Cow<'static, str>>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Push an argument of type `T` onto the arguments of the function.
+    pub fn with_arg<T: TypePath + GetOwnership>(mut self) -> Self {
+        self.args.push(ArgInfo::new::<T>(self.args.len()));
+        self
+    }
+
+    /// Set the arguments of the function.
+    ///
+    /// Arguments are expected to be ordered by their index.
+    pub fn with_args(mut self, args: Vec<ArgInfo>) -> Self {
+        self.args = args;
+        self
+    }
+
+    /// Set the return information of the function.
+    pub fn with_return_info(mut self, return_info: ReturnInfo) -> Self {
+        self.return_info = return_info;
+        self
+    }
+
+    /// The name of the function, if it has one.
+    ///
+    /// Functions created with [`IntoFunction`] are named after the function's [type name],
+    /// while closures are left unnamed.
+    ///
+    /// [`IntoFunction`]: crate::func::IntoFunction
+    /// [type name]: std::any::type_name
+    pub fn name(&self) -> Option<&Cow<'static, str>> {
+        self.name.as_ref()
+    }
+
+    /// The arguments of the function.
+    pub fn args(&self) -> &[ArgInfo] {
+        &self.args
+    }
+
+    /// The number of arguments the function takes.
+    pub fn arg_count(&self) -> usize {
+        self.args.len()
+    }
+
+    /// The return information of the function.
+    pub fn return_info(&self) -> &ReturnInfo {
+        &self.return_info
+    }
+}
+
+impl Default for FunctionInfo {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Type information for an argument of a [`DynamicFunction`].
+///
+/// [`DynamicFunction`]: crate::func::DynamicFunction
+#[derive(Debug, Clone)]
+pub struct ArgInfo {
+    index: usize,
+    name: Option<Cow<'static, str>>,
+    ownership: Ownership,
+    type_path: &'static str,
+}
+
+impl ArgInfo {
+    /// Create a new [`ArgInfo`] with the given argument index and type `T`.
+    ///
+    /// The name of the argument defaults to `None`.
+    pub fn new<T: TypePath + GetOwnership>(index: usize) -> Self {
+        Self {
+            index,
+            name: None,
+            ownership: T::ownership(),
+            type_path: T::type_path(),
+        }
+    }
+
+    /// Set the name of the argument.
+    ///
+    /// Reflected functions are not required to have named arguments,
+    /// so this is only used for display purposes.
+    pub fn with_name(mut self, name: impl Into<Cow<'static, str>>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// The index of the argument within its function.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// The name of the argument, if it was given one.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// The ownership of the argument.
+    pub fn ownership(&self) -> Ownership {
+        self.ownership
+    }
+
+    /// The [type path] of the argument.
+    ///
+    /// [type path]: TypePath::type_path
+    pub fn type_path(&self) -> &'static str {
+        self.type_path
+    }
+}
+
+/// Information about the return type of a [`DynamicFunction`].
+///
+/// [`DynamicFunction`]: crate::func::DynamicFunction
+#[derive(Debug, Clone)]
+pub struct ReturnInfo {
+    type_path: &'static str,
+    ownership: Ownership,
+}
+
+impl ReturnInfo {
+    /// Create a new [`ReturnInfo`] for the return type `T`.
+    pub fn new<T: TypePath + GetOwnership>() -> Self {
+        Self {
+            type_path: T::type_path(),
+            ownership: T::ownership(),
+        }
+    }
+
+    /// The [type path] of the return type.
+    ///
+    /// [type path]: TypePath::type_path
+    pub fn type_path(&self) -> &'static str {
+        self.type_path
+    }
+
+    /// The ownership of the return type.
+    pub fn ownership(&self) -> Ownership {
+        self.ownership
+    }
+}
@@ -0,0 +1,267 @@
+use std::any::type_name;
+
+use bevy_utils::all_tuples;
+
+use crate::func::args::{ArgList, FromArg};
+use crate::func::info::{FunctionInfo, ReturnInfo};
+use crate::func::return_type::{IntoReturn, Return};
+use crate::func::{DynamicFunction, FunctionError, GetOwnership};
+use crate::{Reflect, TypePath};
+
+/// A trait for types that can be converted into a [`DynamicFunction`].
+///
+/// This trait is automatically implemented for many standard Rust functions and closures
+/// whose arguments and return type implement the required traits.
+/// See the [module-level documentation] for the list of supported signatures.
+///
+/// The `Marker` type parameter is only used to distinguish between these blanket implementations
+/// and can be ignored.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::func::{ArgList, IntoFunction};
+/// fn add(a: i32, b: i32) -> i32 {
+///   a + b
+/// }
+///
+/// let func = add.into_function();
+/// let args = ArgList::new().push_owned(25_i32).push_owned(75_i32);
+/// let value = func.call(args).unwrap().unwrap_owned();
+/// assert_eq!(value.take::<i32>().unwrap(), 100);
+/// ```
+///
+/// [module-level documentation]: crate::func
+pub trait IntoFunction<'env, Marker> {
+    /// Converts [`Self`] into a [`DynamicFunction`].
+    fn into_function(self) -> DynamicFunction<'env>;
+}
+
+/// Returns the name of the function `F`, or `None` if it is a closure.
+fn function_name<F>() -> Option<&'static str> {
+    let name = type_name::<F>();
+    (!name.ends_with("{{closure}}")).then_some(name)
+}
+
+/// Creates the [`FunctionInfo`] of the function `F`, named after it unless it is a closure.
+fn function_info<F>() -> FunctionInfo {
+    match function_name::<F>() {
+        Some(name) => FunctionInfo::new().with_name(name),
+        None => FunctionInfo::new(),
+    }
+}
+
+/// Returns an error if `args` doesn't contain exactly `arg_count` arguments.
+fn check_arg_count(args: &ArgList, arg_count: usize) -> Result<(), FunctionError> {
+    if args.len() == arg_count {
+        Ok(())
+    } else {
+        Err(FunctionError::ArgCountMismatch {
+            expected: arg_count,
+            received: args.len(),
+        })
+    }
+}
+
+macro_rules! impl_into_function {
+    ($(($Arg:ident, $arg:ident)),*) => {
+        // === (...) -> ReturnType === //
+        // The return type of the marker is wrapped in a slice so that it can't overlap with the
+        // markers of the methods below, which return references.
+        impl<'env, $($Arg,)* ReturnType, Function> IntoFunction<'env, fn($($Arg),*) -> [ReturnType]> for Function
+        where
+            $($Arg: FromArg + GetOwnership + TypePath,)*
+            ReturnType: IntoReturn + GetOwnership + TypePath,
+            Function: Fn($($Arg),*) -> ReturnType + Send + Sync + 'env,
+            // This clause ensures that the function accepts arguments borrowed for any lifetime,
+            // as `Arg::Item` is the same type as `Arg` with its lifetime replaced.
+            Function: for<'a> Fn($($Arg::Item<'a>),*) -> ReturnType + Send + Sync + 'env,
+        {
+            #[allow(unused_mut)]
+            fn into_function(self) -> DynamicFunction<'env> {
+                let info = function_info::<Self>()
+                    $(.with_arg::<$Arg>())*
+                    .with_return_info(ReturnInfo::new::<ReturnType>());
+                let arg_count = info.arg_count();
+
+                DynamicFunction::new(
+                    move |mut args| {
+                        check_arg_count(&args, arg_count)?;
+                        $(let $arg = args.take::<$Arg>()?;)*
+                        Ok((self)($($arg,)*).into_return())
+                    },
+                    info,
+                )
+            }
+        }
+
+        // === (&self, ...) -> &ReturnType === //
+        impl<'env, Receiver, $($Arg,)* ReturnType, Function> IntoFunction<'env, fn(&Receiver, $($Arg),*) -> &ReturnType> for Function
+        where
+            Receiver: Reflect + TypePath,
+            $($Arg: FromArg + GetOwnership + TypePath,)*
+            ReturnType: Reflect + TypePath,
+            Function: for<'a> Fn(&'a Receiver, $($Arg),*) -> &'a ReturnType + Send + Sync + 'env,
+            Function: for<'a> Fn(&'a Receiver, $($Arg::Item<'a>),*) -> &'a ReturnType + Send + Sync + 'env,
+        {
+            fn into_function(self) -> DynamicFunction<'env> {
+                let info = function_info::<Self>()
+                    .with_arg::<&Receiver>()
+                    $(.with_arg::<$Arg>())*
+                    .with_return_info(ReturnInfo::new::<&ReturnType>());
+                let arg_count = info.arg_count();
+
+                DynamicFunction::new(
+                    move |mut args| {
+                        check_arg_count(&args, arg_count)?;
+                        let receiver = args.take_ref::<Receiver>()?;
+                        $(let $arg = args.take::<$Arg>()?;)*
+                        Ok(Return::Ref((self)(receiver, $($arg,)*)))
+                    },
+                    info,
+                )
+            }
+        }
+
+        // === (&mut self, ...) -> &mut ReturnType === //
+        impl<'env, Receiver, $($Arg,)* ReturnType, Function> IntoFunction<'env, fn(&mut Receiver, $($Arg),*) -> &mut ReturnType> for Function
+        where
+            Receiver: Reflect + TypePath,
+            $($Arg: FromArg + GetOwnership + TypePath,)*
+            ReturnType: Reflect + TypePath,
+            Function: for<'a> Fn(&'a mut Receiver, $($Arg),*) -> &'a mut ReturnType + Send + Sync + 'env,
+            Function: for<'a> Fn(&'a mut Receiver, $($Arg::Item<'a>),*) -> &'a mut ReturnType + Send + Sync + 'env,
+        {
+            fn into_function(self) -> DynamicFunction<'env> {
+                let info = function_info::<Self>()
+                    .with_arg::<&mut Receiver>()
+                    $(.with_arg::<$Arg>())*
+                    .with_return_info(ReturnInfo::new::<&mut ReturnType>());
+                let arg_count = info.arg_count();
+
+                DynamicFunction::new(
+                    move |mut args| {
+                        check_arg_count(&args, arg_count)?;
+                        let receiver = args.take_mut::<Receiver>()?;
+                        $(let $arg = args.take::<$Arg>()?;)*
+                        Ok(Return::Mut((self)(receiver, $($arg,)*)))
+                    },
+                    info,
+                )
+            }
+        }
+
+        // === (&mut self, ...) -> &ReturnType === //
+        impl<'env, Receiver, $($Arg,)* ReturnType, Function> IntoFunction<'env, fn(&mut Receiver, $($Arg),*) -> &ReturnType> for Function
+        where
+            Receiver: Reflect + TypePath,
+            $($Arg: FromArg + GetOwnership + TypePath,)*
+            ReturnType: Reflect + TypePath,
+            Function: for<'a> Fn(&'a mut Receiver, $($Arg),*) -> &'a ReturnType + Send + Sync + 'env,
+            Function: for<'a> Fn(&'a mut Receiver, $($Arg::Item<'a>),*) -> &'a ReturnType + Send + Sync + 'env,
+        {
+            fn into_function(self) -> DynamicFunction<'env> {
+                let info = function_info::<Self>()
+                    .with_arg::<&mut Receiver>()
+                    $(.with_arg::<$Arg>())*
+                    .with_return_info(ReturnInfo::new::<&ReturnType>());
+                let arg_count = info.arg_count();
+
+                DynamicFunction::new(
+                    move |mut args| {
+                        check_arg_count(&args, arg_count)?;
+                        let receiver = args.take_mut::<Receiver>()?;
+                        $(let $arg = args.take::<$Arg>()?;)*
+                        Ok(Return::Ref((self)(receiver, $($arg,)*)))
+                    },
+                    info,
+                )
+            }
+        }
+    };
+}
+
+all_tuples!(impl_into_function, 0, 15, Arg, arg);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::func::{ArgError, Ownership};
+
+    #[test]
+    fn should_create_dynamic_function_from_closure() {
+        let c = 23;
+        let func = (|a: i32, b: &i32| a + b + c).into_function();
+        assert!(func.name().is_none());
+
+        let args = ArgList::new().push_owned(25_i32).push_ref(&2_i32);
+        let value = func.call(args).unwrap().unwrap_owned();
+        assert_eq!(value.take::<i32>().unwrap(), 50);
+    }
+
+    #[test]
+    fn should_create_dynamic_function_from_function() {
+        fn add(a: i32, b: &mut i32) {
+            *b += a;
+        }
+
+        let func = add.into_function();
+        assert!(func.name().unwrap().ends_with("add"));
+
+        let info = func.info();
+        assert_eq!(info.arg_count(), 2);
+        assert_eq!(info.args()[0].type_path(), "i32");
+        assert_eq!(info.args()[0].ownership(), Ownership::Owned);
+        assert_eq!(info.args()[1].type_path(), "&mut i32");
+        assert_eq!(info.args()[1].ownership(), Ownership::Mut);
+        assert_eq!(info.return_info().type_path(), "()");
+
+        let mut total = 0_i32;
+        let args = ArgList::new().push_owned(25_i32).push_mut(&mut total);
+        assert!(func.call(args).unwrap().is_unit());
+        assert_eq!(total, 25);
+    }
+
+    #[test]
+    fn should_return_references_tied_to_receiver() {
+        fn first(list: &[i32; 3]) -> &i32 {
+            &list[0]
+        }
+
+        fn first_mut(list: &mut [i32; 3]) -> &mut i32 {
+            &mut list[0]
+        }
+
+        let mut list = [1, 2, 3];
+
+        let func = first_mut.into_function();
+        let args = ArgList::new().push_mut(&mut list);
+        *func
+            .call(args)
+            .unwrap()
+            .unwrap_mut()
+            .downcast_mut::<i32>()
+            .unwrap() = 5;
+
+        let func = first.into_function();
+        assert_eq!(func.info().return_info().ownership(), Ownership::Ref);
+        let args = ArgList::new().push_ref(&list);
+        let value = func.call(args).unwrap().unwrap_ref();
+        assert_eq!(value.downcast_ref::<i32>(), Some(&5));
+    }
+
+    #[test]
+    fn should_error_on_invalid_arg_type() {
+        let func = (|a: i32| a).into_function();
+        let args = ArgList::new().push_owned(25_u32);
+
+        assert_eq!(
+            func.call(args).unwrap_err(),
+            FunctionError::ArgError(ArgError::UnexpectedType {
+                index: 0,
+                expected: "i32".into(),
+                received: "u32".into(),
+            })
+        );
+    }
+}
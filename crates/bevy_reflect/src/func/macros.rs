@@ -0,0 +1,58 @@
+/// Implements [`FromArg`], [`GetOwnership`], and [`IntoReturn`] for the given type,
+/// which must implement [`FromReflect`] and [`TypePath`].
+///
+/// This is the manual counterpart of what `#[derive(Reflect)]` generates,
+/// used for the types that implement [`Reflect`] by hand.
+///
+/// The generic parameters of the type are given in brackets:
+///
+/// ```ignore
+/// impl_function_traits!([T] Vec<T>);
+/// impl_function_traits!([T, const N: usize] [T; N]);
+/// ```
+///
+/// [`FromArg`]: crate::func::FromArg
+/// [`GetOwnership`]: crate::func::GetOwnership
+/// [`IntoReturn`]: crate::func::IntoReturn
+/// [`FromReflect`]: crate::FromReflect
+/// [`TypePath`]: crate::TypePath
+/// [`Reflect`]: crate::Reflect
+macro_rules! impl_function_traits {
+    ([$($generics:tt)*] $ty:ty) => {
+        impl<$($generics)*> $crate::func::FromArg for $ty
+        where
+            Self: $crate::FromReflect + $crate::TypePath,
+        {
+            type Item<'from_arg> = Self;
+
+            fn from_arg(
+                arg: $crate::func::Arg<'_>,
+            ) -> Result<Self::Item<'_>, $crate::func::ArgError> {
+                arg.take_owned()
+            }
+        }
+
+        impl<$($generics)*> $crate::func::GetOwnership for $ty
+        where
+            Self: $crate::FromReflect + $crate::TypePath,
+        {
+            fn ownership() -> $crate::func::Ownership {
+                $crate::func::Ownership::Owned
+            }
+        }
+
+        impl<$($generics)*> $crate::func::IntoReturn for $ty
+        where
+            Self: $crate::FromReflect + $crate::TypePath,
+        {
+            fn into_return<'into_return>(self) -> $crate::func::Return<'into_return>
+            where
+                Self: 'into_return,
+            {
+                $crate::func::Return::Owned(Box::new(self))
+            }
+        }
+    };
+}
+
+pub(crate) use impl_function_traits;
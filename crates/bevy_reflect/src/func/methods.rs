@@ -0,0 +1,93 @@
+use std::borrow::Cow;
+
+use bevy_utils::HashMap;
+
+use crate::func::{ArgList, DynamicFunction, FunctionResult};
+
+/// Type data exposing the methods of a type as [`DynamicFunction`]s, callable by name.
+///
+/// This is usually registered using the `#[reflect(methods(...))]` attribute,
+/// where each method is named after its identifier.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{Reflect, TypeRegistry};
+/// # use bevy_reflect::func::{ArgList, ReflectMethods};
+/// #[derive(Reflect)]
+/// #[reflect(methods(double))]
+/// struct Counter(u32);
+///
+/// impl Counter {
+///     fn double(&mut self) {
+///         self.0 *= 2;
+///     }
+/// }
+///
+/// let mut registry = TypeRegistry::new();
+/// registry.register::<Counter>();
+///
+/// let mut counter = Counter(21);
+/// let methods = registry.get_type_data::<ReflectMethods>(std::any::TypeId::of::<Counter>()).unwrap();
+/// methods.call("double", ArgList::new().push_mut(&mut counter)).unwrap().unwrap();
+/// assert_eq!(counter.0, 42);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ReflectMethods {
+    methods: HashMap<Cow<'static, str>, DynamicFunction<'static>>,
+}
+
+impl ReflectMethods {
+    /// Creates a new [`ReflectMethods`] from the given methods.
+    ///
+    /// # Panics
+    ///
+    /// Panics if one of the methods doesn't have a [name].
+    ///
+    /// [name]: DynamicFunction::name
+    pub fn new(methods: impl IntoIterator<Item = DynamicFunction<'static>>) -> Self {
+        let methods = methods
+            .into_iter()
+            .map(|method| {
+                let name = method
+                    .name()
+                    .expect("methods registered as `ReflectMethods` must be named")
+                    .clone();
+                (name, method)
+            })
+            .collect();
+        Self { methods }
+    }
+
+    /// Returns the method with the given name, if any.
+    pub fn get(&self, name: &str) -> Option<&DynamicFunction<'static>> {
+        self.methods.get(name)
+    }
+
+    /// Calls the method with the given name and [args].
+    ///
+    /// The receiver (e.g. `&self`) must be passed as the first argument.
+    ///
+    /// Returns `None` if there is no method with the given name.
+    ///
+    /// [args]: ArgList
+    pub fn call<'a>(&self, name: &str, args: ArgList<'a>) -> Option<FunctionResult<'a>> {
+        let method = self.get(name)?;
+        Some(method.call(args))
+    }
+
+    /// Returns an iterator over the methods.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = &DynamicFunction<'static>> {
+        self.methods.values()
+    }
+
+    /// Returns the number of methods.
+    pub fn len(&self) -> usize {
+        self.methods.len()
+    }
+
+    /// Returns `true` if there are no methods.
+    pub fn is_empty(&self) -> bool {
+        self.methods.is_empty()
+    }
+}
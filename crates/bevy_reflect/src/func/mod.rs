@@ -0,0 +1,227 @@
+//! Reflection-based dynamic functions.
+//!
+//! This module provides a way to pass around and call functions dynamically
+//! using the [`DynamicFunction`] type.
+//!
+//! Many simple functions and closures can be automatically converted to [`DynamicFunction`]
+//! using the [`IntoFunction`] trait.
+//!
+//! Once the [`DynamicFunction`] is created, it can be called with a set of arguments provided
+//! via an [`ArgList`].
+//!
+//! This returns a [`FunctionResult`] containing the [`Return`] value,
+//! which can be used to extract a [`Reflect`] trait object.
+//!
+//! # Example
+//!
+//! ```
+//! # use bevy_reflect::Reflect;
+//! # use bevy_reflect::func::{ArgList, DynamicFunction, FunctionInfo, IntoFunction, Return};
+//! fn add(a: i32, b: i32) -> i32 {
+//!   a + b
+//! }
+//!
+//! let func: DynamicFunction = add.into_function();
+//! let args = ArgList::new()
+//!   // Pushing a known type with owned ownership
+//!   .push_owned(25_i32)
+//!   // Pushing a reflected type with owned ownership
+//!   .push_boxed(Box::new(75_i32) as Box<dyn Reflect>);
+//! let result = func.call(args).unwrap();
+//! let value = result.unwrap_owned();
+//! assert_eq!(value.take::<i32>().unwrap(), 100);
+//! ```
+//!
+//! # Functions vs Methods
+//!
+//! A method is a function that takes `self`, `&self` or `&mut self` as its first argument.
+//! Since Rust treats it as a regular function taking the receiver as its first argument,
+//! methods can be converted with [`IntoFunction`] as well.
+//!
+//! Methods can also be exposed on a reflected type using the `#[reflect(methods(...))]` attribute,
+//! which registers them as [`ReflectMethods`] type data so that they can be looked up by name
+//! through the [`TypeRegistry`].
+//!
+//! # Function Registration
+//!
+//! Functions can be stored by name in a [`FunctionRegistry`],
+//! which can then be used to look them up and call them dynamically,
+//! such as from a scripting language or a debug console.
+//!
+//! # Valid Signatures
+//!
+//! Many of the traits in this module have default blanket implementations over a specific set of function signatures.
+//!
+//! These signatures are:
+//! - `(...) -> R`
+//! - `for<'a> (&'a arg, ...) -> &'a R`
+//! - `for<'a> (&'a mut arg, ...) -> &'a R`
+//! - `for<'a> (&'a mut arg, ...) -> &'a mut R`
+//!
+//! Where `...` is 0 to 15 arguments (inclusive) of the form `T`, `&T`, or `&mut T`.
+//! The lifetime of any reference to the return type `R`, must be tied to a "receiver" argument
+//! (i.e. the first argument in the signature, normally `self`).
+//!
+//! Each of the argument types must implement [`FromArg`], [`GetOwnership`], and [`TypePath`],
+//! and the return type must implement [`IntoReturn`], [`GetOwnership`], and [`TypePath`].
+//! These traits are automatically implemented for types deriving [`Reflect`]
+//! when this feature is enabled.
+//!
+//! [`Reflect`]: crate::Reflect
+//! [`TypePath`]: crate::TypePath
+//! [`TypeRegistry`]: crate::TypeRegistry
+
+pub use args::*;
+pub use error::*;
+pub use function::*;
+pub use info::*;
+pub use into_function::*;
+pub use methods::*;
+pub use registry::*;
+pub use return_type::*;
+
+pub(crate) use macros::impl_function_traits;
+
+mod args;
+mod error;
+mod function;
+mod info;
+mod into_function;
+mod macros;
+mod methods;
+mod registry;
+mod return_type;
+
+use std::fmt::{Display, Formatter};
+
+/// The ownership of an argument or return value.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Ownership {
+    /// The value is borrowed immutably (e.g. `&T`).
+    Ref,
+    /// The value is borrowed mutably (e.g. `&mut T`).
+    Mut,
+    /// The value is owned (e.g. `T`).
+    Owned,
+}
+
+impl Display for Ownership {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Ref => write!(f, "reference"),
+            Self::Mut => write!(f, "mutable reference"),
+            Self::Owned => write!(f, "owned"),
+        }
+    }
+}
+
+/// A trait for getting the [`Ownership`] of a type.
+///
+/// This is used to describe the arguments and return value of a [`DynamicFunction`]
+/// in its [`FunctionInfo`].
+///
+/// This trait is automatically implemented for types deriving [`Reflect`](crate::Reflect)
+/// when the `functions` feature is enabled, and for references to any type.
+pub trait GetOwnership {
+    /// Returns the ownership of [`Self`].
+    fn ownership() -> Ownership;
+}
+
+impl<T: ?Sized> GetOwnership for &T {
+    fn ownership() -> Ownership {
+        Ownership::Ref
+    }
+}
+
+impl<T: ?Sized> GetOwnership for &mut T {
+    fn ownership() -> Ownership {
+        Ownership::Mut
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as bevy_reflect;
+    use crate::{Reflect, TypeRegistry};
+
+    #[derive(Reflect, Debug, PartialEq)]
+    #[reflect(methods(heal, name, name_mut))]
+    struct Player {
+        name: String,
+        health: u32,
+    }
+
+    impl Player {
+        fn heal(&mut self, amount: u32) -> u32 {
+            self.health += amount;
+            self.health
+        }
+
+        fn name(&self) -> &String {
+            &self.name
+        }
+
+        fn name_mut(&mut self) -> &mut String {
+            &mut self.name
+        }
+    }
+
+    #[test]
+    fn should_call_functions_with_reflected_types() {
+        fn rename(player: Player, name: String) -> Player {
+            Player { name, ..player }
+        }
+
+        let player = Player {
+            name: String::from("Alice"),
+            health: 10,
+        };
+        let args = ArgList::new()
+            .push_owned(player)
+            .push_owned(String::from("Bob"));
+        let result = rename.into_function().call(args).unwrap();
+
+        assert_eq!(
+            result.unwrap_owned().take::<Player>().unwrap(),
+            Player {
+                name: String::from("Bob"),
+                health: 10,
+            }
+        );
+    }
+
+    #[test]
+    fn should_call_registered_methods() {
+        let mut registry = TypeRegistry::new();
+        registry.register::<Player>();
+        let methods = registry.get_type_data::<ReflectMethods>(std::any::TypeId::of::<Player>());
+        let methods = methods.unwrap();
+        assert_eq!(methods.len(), 3);
+
+        let mut player = Player {
+            name: String::from("Alice"),
+            health: 10,
+        };
+
+        let args = ArgList::new().push_mut(&mut player).push_owned(5_u32);
+        let health = methods.call("heal", args).unwrap().unwrap();
+        assert_eq!(health.unwrap_owned().take::<u32>().unwrap(), 15);
+
+        let args = ArgList::new().push_mut(&mut player);
+        let name = methods.call("name_mut", args).unwrap().unwrap();
+        name.unwrap_mut()
+            .downcast_mut::<String>()
+            .unwrap()
+            .push_str(" Smith");
+
+        let args = ArgList::new().push_ref(&player);
+        let name = methods.call("name", args).unwrap().unwrap();
+        assert_eq!(
+            name.unwrap_ref().downcast_ref::<String>().unwrap(),
+            "Alice Smith"
+        );
+
+        assert!(methods.call("missing", ArgList::new()).is_none());
+    }
+}
@@ -0,0 +1,263 @@
+use std::borrow::Cow;
+use std::fmt::Debug;
+use std::sync::{Arc, PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+use bevy_utils::HashMap;
+
+use crate::func::{
+    ArgList, DynamicFunction, FunctionRegistrationError, FunctionResult, IntoFunction,
+};
+
+/// A registry of [reflected functions].
+///
+/// This is the function-equivalent to the [`TypeRegistry`].
+///
+/// Functions are registered by name, which defaults to the name of the function
+/// (see [`FunctionInfo::name`]).
+///
+/// [reflected functions]: crate::func
+/// [`TypeRegistry`]: crate::TypeRegistry
+/// [`FunctionInfo::name`]: crate::func::FunctionInfo::name
+#[derive(Default)]
+pub struct FunctionRegistry {
+    /// Maps function [names] to their respective [`DynamicFunction`].
+    ///
+    /// [names]: DynamicFunction::name
+    functions: HashMap<Cow<'static, str>, DynamicFunction<'static>>,
+}
+
+impl FunctionRegistry {
+    /// Attempts to register the given function.
+    ///
+    /// This function accepts both functions that satisfy [`IntoFunction`]
+    /// and direct [`DynamicFunction`] instances.
+    /// The given function will internally be stored as a [`DynamicFunction<'static>`]
+    /// and mapped according to its [name].
+    ///
+    /// Because the function must have a name,
+    /// anonymous functions (e.g. `|a: i32, b: i32| { a + b }`) must instead
+    /// be registered using [`register_with_name`] or converted to a [`DynamicFunction`]
+    /// and named using [`DynamicFunction::with_name`].
+    ///
+    /// If a registered function with the same name already exists,
+    /// it will not be registered again and an error will be returned.
+    /// To register the function anyway, overwriting any existing registration,
+    /// use [`overwrite_registration`] instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy_reflect::func::{FunctionRegistrationError, FunctionRegistry};
+    /// fn add(a: i32, b: i32) -> i32 {
+    ///     a + b
+    /// }
+    ///
+    /// # fn main() -> Result<(), FunctionRegistrationError> {
+    /// let mut registry = FunctionRegistry::default();
+    /// registry.register(add)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [name]: DynamicFunction::name
+    /// [`register_with_name`]: Self::register_with_name
+    /// [`overwrite_registration`]: Self::overwrite_registration
+    pub fn register<F, Marker>(
+        &mut self,
+        function: F,
+    ) -> Result<&mut Self, FunctionRegistrationError>
+    where
+        F: IntoFunction<'static, Marker> + 'static,
+    {
+        let function = function.into_function();
+        let name = function
+            .name()
+            .ok_or(FunctionRegistrationError::MissingName)?
+            .clone();
+        self.register_named(name, function)
+    }
+
+    /// Attempts to register the given function with the given name.
+    ///
+    /// This is useful for closures, which are unnamed,
+    /// or to register a function under a name other than its full [type name].
+    /// The name of the stored [`DynamicFunction`] is replaced with the given one.
+    ///
+    /// If a registered function with the same name already exists,
+    /// it will not be registered again and an error will be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy_reflect::func::{FunctionRegistrationError, FunctionRegistry};
+    /// # fn main() -> Result<(), FunctionRegistrationError> {
+    /// let mut registry = FunctionRegistry::default();
+    /// registry.register_with_name("my_crate::mul", |a: i32, b: i32| a * b)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [type name]: std::any::type_name
+    pub fn register_with_name<F, Marker>(
+        &mut self,
+        name: impl Into<Cow<'static, str>>,
+        function: F,
+    ) -> Result<&mut Self, FunctionRegistrationError>
+    where
+        F: IntoFunction<'static, Marker> + 'static,
+    {
+        let name = name.into();
+        let function = function.into_function().with_name(name.clone());
+        self.register_named(name, function)
+    }
+
+    fn register_named(
+        &mut self,
+        name: Cow<'static, str>,
+        function: DynamicFunction<'static>,
+    ) -> Result<&mut Self, FunctionRegistrationError> {
+        if self.functions.contains_key(&name) {
+            return Err(FunctionRegistrationError::DuplicateName(name));
+        }
+        self.functions.insert(name, function);
+        Ok(self)
+    }
+
+    /// Registers the given function, overwriting any existing registration with the same name.
+    ///
+    /// Returns the previous function with the same name, if any.
+    ///
+    /// Returns an error if the function is missing a name.
+    pub fn overwrite_registration<F, Marker>(
+        &mut self,
+        function: F,
+    ) -> Result<Option<DynamicFunction<'static>>, FunctionRegistrationError>
+    where
+        F: IntoFunction<'static, Marker> + 'static,
+    {
+        let function = function.into_function();
+        let name = function
+            .name()
+            .ok_or(FunctionRegistrationError::MissingName)?
+            .clone();
+        Ok(self.functions.insert(name, function))
+    }
+
+    /// Calls the function with the given [name] and [args].
+    ///
+    /// Returns `None` if no function with the given name is registered.
+    /// Otherwise, returns the result of the function call.
+    ///
+    /// [name]: DynamicFunction::name
+    /// [args]: ArgList
+    pub fn call<'a>(&self, name: &str, args: ArgList<'a>) -> Option<FunctionResult<'a>> {
+        let function = self.get(name)?;
+        Some(function.call(args))
+    }
+
+    /// Get a reference to a registered function by [name].
+    ///
+    /// [name]: DynamicFunction::name
+    pub fn get(&self, name: &str) -> Option<&DynamicFunction<'static>> {
+        self.functions.get(name)
+    }
+
+    /// Returns `true` if a function with the given [name] is registered.
+    ///
+    /// [name]: DynamicFunction::name
+    pub fn contains(&self, name: &str) -> bool {
+        self.functions.contains_key(name)
+    }
+
+    /// Returns an iterator over all registered functions.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = &DynamicFunction<'static>> {
+        self.functions.values()
+    }
+
+    /// Returns the number of registered functions.
+    pub fn len(&self) -> usize {
+        self.functions.len()
+    }
+
+    /// Returns `true` if no functions are registered.
+    pub fn is_empty(&self) -> bool {
+        self.functions.is_empty()
+    }
+}
+
+impl Debug for FunctionRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.functions.values()).finish()
+    }
+}
+
+/// A synchronized wrapper around a [`FunctionRegistry`].
+#[derive(Clone, Default, Debug)]
+pub struct FunctionRegistryArc {
+    pub internal: Arc<RwLock<FunctionRegistry>>,
+}
+
+impl FunctionRegistryArc {
+    /// Takes a read lock on the underlying [`FunctionRegistry`].
+    pub fn read(&self) -> RwLockReadGuard<'_, FunctionRegistry> {
+        self.internal.read().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Takes a write lock on the underlying [`FunctionRegistry`].
+    pub fn write(&self) -> RwLockWriteGuard<'_, FunctionRegistry> {
+        self.internal
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn foo() -> i32 {
+        123
+    }
+
+    #[test]
+    fn should_register_and_call_functions() {
+        let mut registry = FunctionRegistry::default();
+        registry
+            .register(foo)
+            .unwrap()
+            .register_with_name("mul", |a: i32, b: i32| a * b)
+            .unwrap();
+
+        assert_eq!(registry.len(), 2);
+        assert!(registry.contains(std::any::type_name_of_val(&foo)));
+
+        let args = ArgList::new().push_owned(3_i32).push_owned(4_i32);
+        let value = registry.call("mul", args).unwrap().unwrap();
+        assert_eq!(value.unwrap_owned().take::<i32>().unwrap(), 12);
+        assert_eq!(registry.get("mul").unwrap().name().unwrap(), "mul");
+
+        assert!(registry.call("missing", ArgList::new()).is_none());
+    }
+
+    #[test]
+    fn should_error_on_invalid_registrations() {
+        let mut registry = FunctionRegistry::default();
+        registry.register(foo).unwrap();
+
+        let name = std::any::type_name_of_val(&foo);
+        assert_eq!(
+            registry.register(foo).unwrap_err(),
+            FunctionRegistrationError::DuplicateName(Cow::Borrowed(name))
+        );
+        assert_eq!(
+            registry.register(|| 321_i32).unwrap_err(),
+            FunctionRegistrationError::MissingName
+        );
+
+        let previous = registry
+            .overwrite_registration(foo.into_function())
+            .unwrap();
+        assert!(previous.is_some());
+        assert_eq!(registry.len(), 1);
+    }
+}
@@ -0,0 +1,110 @@
+use crate::func::args::{Arg, ArgError, FromArg};
+use crate::func::{GetOwnership, Ownership};
+use crate::Reflect;
+
+/// The return type of a [`DynamicFunction`].
+///
+/// [`DynamicFunction`]: crate::func::DynamicFunction
+#[derive(Debug)]
+pub enum Return<'a> {
+    /// The function returns nothing (i.e. it returns `()`).
+    Unit,
+    /// The function returns an owned value.
+    Owned(Box<dyn Reflect>),
+    /// The function returns a reference to a value.
+    Ref(&'a dyn Reflect),
+    /// The function returns a mutable reference to a value.
+    Mut(&'a mut dyn Reflect),
+}
+
+impl<'a> Return<'a> {
+    /// Returns `true` if the return value is [`Self::Unit`].
+    pub fn is_unit(&self) -> bool {
+        matches!(self, Return::Unit)
+    }
+
+    /// Unwraps the return value as an owned value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the return value is not [`Self::Owned`].
+    pub fn unwrap_owned(self) -> Box<dyn Reflect> {
+        match self {
+            Return::Owned(value) => value,
+            _ => panic!("expected owned value"),
+        }
+    }
+
+    /// Unwraps the return value as a reference to a value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the return value is not [`Self::Ref`].
+    pub fn unwrap_ref(self) -> &'a dyn Reflect {
+        match self {
+            Return::Ref(value) => value,
+            _ => panic!("expected reference value"),
+        }
+    }
+
+    /// Unwraps the return value as a mutable reference to a value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the return value is not [`Self::Mut`].
+    pub fn unwrap_mut(self) -> &'a mut dyn Reflect {
+        match self {
+            Return::Mut(value) => value,
+            _ => panic!("expected mutable reference value"),
+        }
+    }
+}
+
+/// A trait for types that can be converted into a [`Return`] value.
+///
+/// This trait is automatically implemented for types deriving [`Reflect`]
+/// when the `functions` feature is enabled, and for references to any [`Reflect`] type.
+pub trait IntoReturn {
+    /// Converts [`Self`] into a [`Return`] value.
+    fn into_return<'into_return>(self) -> Return<'into_return>
+    where
+        Self: 'into_return;
+}
+
+impl<T: Reflect> IntoReturn for &T {
+    fn into_return<'into_return>(self) -> Return<'into_return>
+    where
+        Self: 'into_return,
+    {
+        Return::Ref(self)
+    }
+}
+
+impl<T: Reflect> IntoReturn for &mut T {
+    fn into_return<'into_return>(self) -> Return<'into_return>
+    where
+        Self: 'into_return,
+    {
+        Return::Mut(self)
+    }
+}
+
+impl IntoReturn for () {
+    fn into_return<'into_return>(self) -> Return<'into_return> {
+        Return::Unit
+    }
+}
+
+impl GetOwnership for () {
+    fn ownership() -> Ownership {
+        Ownership::Owned
+    }
+}
+
+impl FromArg for () {
+    type Item<'from_arg> = ();
+
+    fn from_arg(arg: Arg<'_>) -> Result<Self::Item<'_>, ArgError> {
+        arg.take_owned()
+    }
+}
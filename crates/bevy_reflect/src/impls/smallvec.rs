@@ -182,3 +182,6 @@ where
         registration
     }
 }
+
+#[cfg(feature = "functions")]
+crate::func::impl_function_traits!([T: smallvec::Array] SmallVec<T>);
@@ -403,6 +403,11 @@ impl_reflect_for_veclike!(
     VecDeque::<T>
 );
 
+#[cfg(feature = "functions")]
+crate::func::impl_function_traits!([T] ::alloc::vec::Vec<T>);
+#[cfg(feature = "functions")]
+crate::func::impl_function_traits!([T] ::alloc::collections::VecDeque<T>);
+
 macro_rules! impl_reflect_for_hashmap {
     ($ty:path) => {
         impl<K, V, S> Map for $ty
@@ -627,11 +632,15 @@ macro_rules! impl_reflect_for_hashmap {
 impl_reflect_for_hashmap!(::std::collections::HashMap<K, V, S>);
 impl_type_path!(::std::collections::hash_map::RandomState);
 impl_type_path!(::std::collections::HashMap<K, V, S>);
+#[cfg(feature = "functions")]
+crate::func::impl_function_traits!([K, V, S] ::std::collections::HashMap<K, V, S>);
 
 impl_reflect_for_hashmap!(bevy_utils::hashbrown::HashMap<K, V, S>);
 impl_type_path!(::bevy_utils::hashbrown::hash_map::DefaultHashBuilder);
 impl_type_path!(::bevy_utils::NoOpHash);
 impl_type_path!(::bevy_utils::hashbrown::HashMap<K, V, S>);
+#[cfg(feature = "functions")]
+crate::func::impl_function_traits!([K, V, S] ::bevy_utils::hashbrown::HashMap<K, V, S>);
 
 impl<K, V> Map for ::std::collections::BTreeMap<K, V>
 where
@@ -841,6 +850,8 @@ where
 }
 
 impl_type_path!(::std::collections::BTreeMap<K, V>);
+#[cfg(feature = "functions")]
+crate::func::impl_function_traits!([K, V] ::std::collections::BTreeMap<K, V>);
 
 impl<T: Reflect + TypePath + GetTypeRegistration, const N: usize> Array for [T; N] {
     #[inline]
@@ -967,6 +978,9 @@ impl<T: FromReflect + TypePath + GetTypeRegistration, const N: usize> FromReflec
     }
 }
 
+#[cfg(feature = "functions")]
+crate::func::impl_function_traits!([T, const N: usize] [T; N]);
+
 impl<T: Reflect + TypePath + GetTypeRegistration, const N: usize> Typed for [T; N] {
     fn type_info() -> &'static TypeInfo {
         static CELL: GenericTypeInfoCell = GenericTypeInfoCell::new();
@@ -1241,6 +1255,8 @@ impl<T: FromReflect + TypePath + GetTypeRegistration> Typed for Option<T> {
 }
 
 impl_type_path!(::core::option::Option<T>);
+#[cfg(feature = "functions")]
+crate::func::impl_function_traits!([T] Option<T>);
 
 impl<T: TypePath + ?Sized> TypePath for &'static T {
     fn type_path() -> &'static str {
@@ -1848,6 +1864,12 @@ impl Typed for Cow<'static, Path> {
 
 impl_type_path!(::std::path::Path);
 impl_type_path!(::alloc::borrow::Cow<'a: 'static, T: ToOwned + ?Sized>);
+#[cfg(feature = "functions")]
+crate::func::impl_function_traits!([] Cow<'static, str>);
+#[cfg(feature = "functions")]
+crate::func::impl_function_traits!([T: Clone] Cow<'static, [T]>);
+#[cfg(feature = "functions")]
+crate::func::impl_function_traits!([] Cow<'static, Path>);
 
 impl FromReflect for Cow<'static, Path> {
     fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
@@ -508,6 +508,8 @@ mod impls {
 }
 
 mod enums;
+#[cfg(feature = "functions")]
+pub mod func;
 pub mod serde;
 pub mod std_traits;
 pub mod utility;
@@ -631,6 +631,17 @@ impl_reflect_tuple! {0: A, 1: B, 2: C, 3: D, 4: E, 5: F, 6: G, 7: H, 8: I, 9: J}
 impl_reflect_tuple! {0: A, 1: B, 2: C, 3: D, 4: E, 5: F, 6: G, 7: H, 8: I, 9: J, 10: K}
 impl_reflect_tuple! {0: A, 1: B, 2: C, 3: D, 4: E, 5: F, 6: G, 7: H, 8: I, 9: J, 10: K, 11: L}
 
+#[cfg(feature = "functions")]
+const _: () = {
+    macro_rules! impl_function_traits_for_tuple {
+        ($($name:ident),*) => {
+            crate::func::impl_function_traits!([$($name),*] ($($name,)*));
+        };
+    }
+
+    all_tuples!(impl_function_traits_for_tuple, 1, 12, T);
+};
+
 macro_rules! impl_type_path_tuple {
     () => {
         impl TypePath for () {
@@ -0,0 +1,638 @@
+//! The built-in methods of the Bevy Remote Protocol.
+
+use bevy_ecs::{
+    component::ComponentId,
+    entity::Entity,
+    entity_disabling::DefaultQueryFilters,
+    query::QueryBuilder,
+    reflect::{AppTypeRegistry, ReflectComponent},
+    system::In,
+    world::{EntityRef, World},
+};
+use bevy_reflect::{
+    serde::{TypedReflectDeserializer, TypedReflectSerializer},
+    Reflect, TypeRegistration, TypeRegistry,
+};
+use bevy_utils::HashMap;
+use serde::{de::DeserializeOwned, de::DeserializeSeed, Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::{BrpError, BrpResult};
+
+/// The method path for a `bevy/get` request.
+pub const BRP_GET_METHOD: &str = "bevy/get";
+
+/// The method path for a `bevy/query` request.
+pub const BRP_QUERY_METHOD: &str = "bevy/query";
+
+/// The method path for a `bevy/spawn` request.
+pub const BRP_SPAWN_METHOD: &str = "bevy/spawn";
+
+/// The method path for a `bevy/insert` request.
+pub const BRP_INSERT_METHOD: &str = "bevy/insert";
+
+/// The method path for a `bevy/remove` request.
+pub const BRP_REMOVE_METHOD: &str = "bevy/remove";
+
+/// The method path for a `bevy/destroy` request.
+pub const BRP_DESTROY_METHOD: &str = "bevy/destroy";
+
+/// The method path for a `bevy/list` request.
+pub const BRP_LIST_METHOD: &str = "bevy/list";
+
+/// The parameters of a `bevy/get` request.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BrpGetParams {
+    /// The entity to get the components of.
+    pub entity: Entity,
+    /// The type paths of the components to get.
+    pub components: Vec<String>,
+}
+
+/// The parameters of a `bevy/query` request.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BrpQueryParams {
+    /// The components to return for each matching entity.
+    #[serde(default)]
+    pub data: BrpQuery,
+    /// Additional conditions the entities must fulfill.
+    #[serde(default)]
+    pub filter: BrpQueryFilter,
+}
+
+/// The components returned by a `bevy/query` request, identified by their type paths.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BrpQuery {
+    /// Components that must be present on the entities, and are returned.
+    #[serde(default)]
+    pub components: Vec<String>,
+    /// Components that are returned if present on the entities.
+    #[serde(default)]
+    pub option: Vec<String>,
+    /// Components whose presence on the entities is reported.
+    #[serde(default)]
+    pub has: Vec<String>,
+}
+
+/// The filters of a `bevy/query` request, identified by their type paths.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BrpQueryFilter {
+    /// Components that must be present on the entities.
+    #[serde(default)]
+    pub with: Vec<String>,
+    /// Components that must not be present on the entities.
+    #[serde(default)]
+    pub without: Vec<String>,
+    /// Whether to also match disabled entities, which are skipped by default.
+    #[serde(default)]
+    pub include_disabled: bool,
+}
+
+/// An entity matched by a `bevy/query` request.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BrpQueryRow {
+    /// The matched entity.
+    pub entity: Entity,
+    /// The values of the requested components of the entity, by type path.
+    pub components: Map<String, Value>,
+    /// Whether the entity has each of the components of [`BrpQuery::has`], by type path.
+    #[serde(default, skip_serializing_if = "Map::is_empty")]
+    pub has: Map<String, Value>,
+}
+
+/// The parameters of a `bevy/spawn` request.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BrpSpawnParams {
+    /// The components of the new entity, by type path.
+    pub components: HashMap<String, Value>,
+}
+
+/// The response to a `bevy/spawn` request.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BrpSpawnResponse {
+    /// The new entity.
+    pub entity: Entity,
+}
+
+/// The parameters of a `bevy/insert` request.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BrpInsertParams {
+    /// The entity to insert the components into.
+    pub entity: Entity,
+    /// The components to insert, by type path.
+    pub components: HashMap<String, Value>,
+}
+
+/// The parameters of a `bevy/remove` request.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BrpRemoveParams {
+    /// The entity to remove the components from.
+    pub entity: Entity,
+    /// The type paths of the components to remove.
+    pub components: Vec<String>,
+}
+
+/// The parameters of a `bevy/destroy` request.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BrpDestroyParams {
+    /// The entity to despawn.
+    pub entity: Entity,
+}
+
+/// The parameters of a `bevy/list` request.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BrpListParams {
+    /// The entity to list the components of, or `None` to list every reflected component type.
+    #[serde(default)]
+    pub entity: Option<Entity>,
+}
+
+/// Handles a `bevy/get` request.
+pub fn process_remote_get_request(In(params): In<Option<Value>>, world: &World) -> BrpResult {
+    let BrpGetParams { entity, components } = parse(params)?;
+    let type_registry = world.resource::<AppTypeRegistry>().read();
+    let entity_ref = get_entity(world, entity)?;
+
+    let mut response = Map::new();
+    for type_path in components {
+        let (_, reflect_component) = get_reflect_component(&type_registry, &type_path)?;
+        let value = serialize_component(entity_ref, reflect_component, &type_registry, &type_path)?
+            .ok_or_else(|| BrpError::component_error(&type_path, "Component not present"))?;
+        response.insert(type_path, value);
+    }
+
+    Ok(Value::Object(response))
+}
+
+/// Handles a `bevy/query` request.
+pub fn process_remote_query_request(In(params): In<Option<Value>>, world: &mut World) -> BrpResult {
+    let BrpQueryParams {
+        data: BrpQuery {
+            components,
+            option,
+            has,
+        },
+        filter:
+            BrpQueryFilter {
+                with,
+                without,
+                include_disabled,
+            },
+    } = parse_some_or_default(params)?;
+    let app_type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = app_type_registry.read();
+
+    let components = get_reflect_components(world, &type_registry, &components)?;
+    let option = get_reflect_components(world, &type_registry, &option)?;
+    let has = get_reflect_components(world, &type_registry, &has)?;
+    let with = get_reflect_components(world, &type_registry, &with)?;
+    let without = get_reflect_components(world, &type_registry, &without)?;
+
+    // A component that was never added to the world can't be present on any entity.
+    if components
+        .iter()
+        .chain(&with)
+        .any(|(_, id, _)| id.is_none())
+    {
+        return Ok(Value::Array(Vec::new()));
+    }
+
+    let disabling: Vec<ComponentId> = match world.get_resource::<DefaultQueryFilters>() {
+        Some(filters) if include_disabled => filters.disabling_ids().collect(),
+        _ => Vec::new(),
+    };
+
+    let mut query = QueryBuilder::<Entity>::new(world);
+    for (_, id, _) in components.iter().chain(&with) {
+        query.with_id(id.unwrap());
+    }
+    for (_, id, _) in &without {
+        if let Some(id) = id {
+            query.without_id(*id);
+        }
+    }
+    // Mentioning a disabling component opts out of the default filter on it.
+    for id in disabling {
+        query.optional(|query| {
+            query.ref_id(id);
+        });
+    }
+    let entities: Vec<Entity> = query.build().iter(world).collect();
+
+    let mut rows = Vec::with_capacity(entities.len());
+    for entity in entities {
+        let entity_ref = world.entity(entity);
+        let mut row = BrpQueryRow {
+            entity,
+            components: Map::new(),
+            has: Map::new(),
+        };
+        for (type_path, _, reflect_component) in components.iter().chain(&option) {
+            if let Some(value) =
+                serialize_component(entity_ref, reflect_component, &type_registry, type_path)?
+            {
+                row.components.insert(type_path.clone(), value);
+            }
+        }
+        for (type_path, _, reflect_component) in &has {
+            row.has.insert(
+                type_path.clone(),
+                Value::Bool(reflect_component.contains(entity_ref)),
+            );
+        }
+        rows.push(row);
+    }
+
+    serde_json::to_value(rows).map_err(BrpError::internal)
+}
+
+/// Handles a `bevy/spawn` request.
+pub fn process_remote_spawn_request(In(params): In<Option<Value>>, world: &mut World) -> BrpResult {
+    let BrpSpawnParams { components } = parse(params)?;
+    let app_type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = app_type_registry.read();
+
+    let components = deserialize_components(&type_registry, components)?;
+    let mut entity_world_mut = world.spawn_empty();
+    for (reflect_component, value) in components {
+        reflect_component.insert(&mut entity_world_mut, &*value, &type_registry);
+    }
+
+    let response = BrpSpawnResponse {
+        entity: entity_world_mut.id(),
+    };
+    serde_json::to_value(response).map_err(BrpError::internal)
+}
+
+/// Handles a `bevy/insert` request.
+pub fn process_remote_insert_request(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    let BrpInsertParams { entity, components } = parse(params)?;
+    let app_type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = app_type_registry.read();
+
+    let components = deserialize_components(&type_registry, components)?;
+    let mut entity_world_mut = world
+        .get_entity_mut(entity)
+        .ok_or_else(|| BrpError::entity_not_found(entity))?;
+    for (reflect_component, value) in components {
+        reflect_component.insert(&mut entity_world_mut, &*value, &type_registry);
+    }
+
+    Ok(Value::Null)
+}
+
+/// Handles a `bevy/remove` request.
+pub fn process_remote_remove_request(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    let BrpRemoveParams { entity, components } = parse(params)?;
+    let app_type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = app_type_registry.read();
+
+    let reflect_components = components
+        .iter()
+        .map(|type_path| get_reflect_component(&type_registry, type_path))
+        .collect::<Result<Vec<_>, _>>()?;
+    let mut entity_world_mut = world
+        .get_entity_mut(entity)
+        .ok_or_else(|| BrpError::entity_not_found(entity))?;
+    for (_, reflect_component) in reflect_components {
+        reflect_component.remove(&mut entity_world_mut);
+    }
+
+    Ok(Value::Null)
+}
+
+/// Handles a `bevy/destroy` request.
+pub fn process_remote_destroy_request(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    let BrpDestroyParams { entity } = parse(params)?;
+
+    if world.despawn(entity) {
+        Ok(Value::Null)
+    } else {
+        Err(BrpError::entity_not_found(entity))
+    }
+}
+
+/// Handles a `bevy/list` request.
+///
+/// Components that aren't registered with `#[reflect(Component)]` are not listed.
+pub fn process_remote_list_request(In(params): In<Option<Value>>, world: &World) -> BrpResult {
+    let BrpListParams { entity } = parse_some_or_default(params)?;
+    let type_registry = world.resource::<AppTypeRegistry>().read();
+
+    let type_paths: Vec<&str> = match entity {
+        Some(entity) => {
+            let entity_ref = get_entity(world, entity)?;
+            entity_ref
+                .archetype()
+                .components()
+                .filter_map(|id| world.components().get_info(id)?.type_id())
+                .filter_map(|type_id| type_registry.get(type_id))
+                .filter(|registration| registration.data::<ReflectComponent>().is_some())
+                .map(|registration| registration.type_info().type_path())
+                .collect()
+        }
+        None => type_registry
+            .iter()
+            .filter(|registration| registration.data::<ReflectComponent>().is_some())
+            .map(|registration| registration.type_info().type_path())
+            .collect(),
+    };
+
+    Ok(Value::from(type_paths))
+}
+
+/// Deserializes the parameters of a request, which must be present.
+fn parse<T: DeserializeOwned>(params: Option<Value>) -> Result<T, BrpError> {
+    let params = params.ok_or_else(|| BrpError::invalid_params("Params not provided"))?;
+    serde_json::from_value(params).map_err(|error| BrpError::invalid_params(error.to_string()))
+}
+
+/// Deserializes the parameters of a request, using the default parameters if there are none.
+fn parse_some_or_default<T: DeserializeOwned + Default>(
+    params: Option<Value>,
+) -> Result<T, BrpError> {
+    match params {
+        Some(params) => parse(Some(params)),
+        None => Ok(T::default()),
+    }
+}
+
+fn get_entity(world: &World, entity: Entity) -> Result<EntityRef<'_>, BrpError> {
+    world
+        .get_entity(entity)
+        .ok_or_else(|| BrpError::entity_not_found(entity))
+}
+
+/// Looks up the registration and [`ReflectComponent`] of the component with the given type path.
+fn get_reflect_component<'r>(
+    type_registry: &'r TypeRegistry,
+    type_path: &str,
+) -> Result<(&'r TypeRegistration, &'r ReflectComponent), BrpError> {
+    let registration = type_registry
+        .get_with_type_path(type_path)
+        .ok_or_else(|| BrpError::component_error(type_path, "Unknown type path"))?;
+    let reflect_component = registration
+        .data::<ReflectComponent>()
+        .ok_or_else(|| BrpError::component_error(type_path, "Type is not a reflected component"))?;
+    Ok((registration, reflect_component))
+}
+
+/// Looks up the [`ReflectComponent`] and [`ComponentId`] of each component, if it was added to the world.
+fn get_reflect_components<'r>(
+    world: &World,
+    type_registry: &'r TypeRegistry,
+    type_paths: &[String],
+) -> Result<Vec<(String, Option<ComponentId>, &'r ReflectComponent)>, BrpError> {
+    type_paths
+        .iter()
+        .map(|type_path| {
+            let (registration, reflect_component) =
+                get_reflect_component(type_registry, type_path)?;
+            let component_id = world.components().get_id(registration.type_id());
+            Ok((type_path.clone(), component_id, reflect_component))
+        })
+        .collect()
+}
+
+/// Serializes the component of `entity_ref`, returning `None` if it's not present.
+fn serialize_component(
+    entity_ref: EntityRef,
+    reflect_component: &ReflectComponent,
+    type_registry: &TypeRegistry,
+    type_path: &str,
+) -> Result<Option<Value>, BrpError> {
+    let Some(reflected) = reflect_component.reflect(entity_ref) else {
+        return Ok(None);
+    };
+    let serializer = TypedReflectSerializer::new(reflected, type_registry);
+    serde_json::to_value(serializer)
+        .map(Some)
+        .map_err(|error| BrpError::component_error(type_path, error))
+}
+
+/// Deserializes the components of a `bevy/spawn` or `bevy/insert` request.
+///
+/// Every component is deserialized before any of them is inserted, so that an invalid request
+/// doesn't leave the entity partially updated.
+fn deserialize_components(
+    type_registry: &TypeRegistry,
+    components: HashMap<String, Value>,
+) -> Result<Vec<(&ReflectComponent, Box<dyn Reflect>)>, BrpError> {
+    components
+        .into_iter()
+        .map(|(type_path, value)| {
+            let (registration, reflect_component) =
+                get_reflect_component(type_registry, &type_path)?;
+            let value = TypedReflectDeserializer::new(registration, type_registry)
+                .deserialize(value)
+                .map_err(|error| BrpError::component_error(&type_path, error))?;
+            Ok((reflect_component, value))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_app::App;
+    use bevy_ecs::{entity_disabling::Disabled, prelude::*};
+    use bevy_reflect::Reflect;
+    use serde_json::{json, Value};
+
+    use crate::{error_codes, BrpMessage, BrpResult, BrpSender, RemotePlugin};
+
+    #[derive(Component, Reflect, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Health(u32);
+
+    #[derive(Component, Reflect, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Name {
+        value: String,
+    }
+
+    fn setup() -> App {
+        let mut app = App::new();
+        app.register_type::<Health>()
+            .register_type::<Name>()
+            .add_plugins(RemotePlugin::default());
+        app
+    }
+
+    fn request(app: &mut App, method: &str, params: Value) -> BrpResult {
+        let (sender, receiver) = async_channel::bounded(1);
+        app.world
+            .resource::<BrpSender>()
+            .try_send(BrpMessage {
+                method: method.to_owned(),
+                params: Some(params),
+                sender,
+            })
+            .unwrap();
+        app.update();
+        receiver.try_recv().unwrap()
+    }
+
+    const HEALTH: &str = "bevy_remote::builtin_methods::tests::Health";
+    const NAME: &str = "bevy_remote::builtin_methods::tests::Name";
+
+    #[test]
+    fn spawn_get_insert_remove_destroy() {
+        let mut app = setup();
+
+        let response = request(
+            &mut app,
+            "bevy/spawn",
+            json!({ "components": { HEALTH: [10] } }),
+        )
+        .unwrap();
+        let entity: Entity = serde_json::from_value(response["entity"].clone()).unwrap();
+        assert_eq!(app.world.get::<Health>(entity), Some(&Health(10)));
+
+        let response = request(
+            &mut app,
+            "bevy/insert",
+            json!({ "entity": entity, "components": { HEALTH: [5], NAME: { "value": "orc" } } }),
+        );
+        assert_eq!(response, Ok(Value::Null));
+
+        let response = request(
+            &mut app,
+            "bevy/get",
+            json!({ "entity": entity, "components": [HEALTH, NAME] }),
+        );
+        assert_eq!(
+            response,
+            Ok(json!({ HEALTH: [5], NAME: { "value": "orc" } }))
+        );
+
+        let response = request(&mut app, "bevy/list", json!({ "entity": entity })).unwrap();
+        assert_eq!(response.as_array().unwrap().len(), 2);
+
+        request(
+            &mut app,
+            "bevy/remove",
+            json!({ "entity": entity, "components": [NAME] }),
+        )
+        .unwrap();
+        assert!(app.world.get::<Name>(entity).is_none());
+
+        request(&mut app, "bevy/destroy", json!({ "entity": entity })).unwrap();
+        assert!(app.world.get_entity(entity).is_none());
+
+        let response = request(&mut app, "bevy/destroy", json!({ "entity": entity }));
+        assert_eq!(response.unwrap_err().code, error_codes::ENTITY_NOT_FOUND);
+    }
+
+    #[test]
+    fn query() {
+        let mut app = setup();
+        let orc = app
+            .world
+            .spawn((
+                Health(5),
+                Name {
+                    value: "orc".to_owned(),
+                },
+            ))
+            .id();
+        let wall = app.world.spawn(Health(100)).id();
+        app.world.spawn_empty();
+
+        let response = request(
+            &mut app,
+            "bevy/query",
+            json!({ "data": { "components": [HEALTH], "has": [NAME] } }),
+        );
+        assert_eq!(
+            response,
+            Ok(json!([
+                { "entity": orc, "components": { HEALTH: [5] }, "has": { NAME: true } },
+                { "entity": wall, "components": { HEALTH: [100] }, "has": { NAME: false } },
+            ]))
+        );
+
+        let response = request(
+            &mut app,
+            "bevy/query",
+            json!({ "data": { "option": [NAME] }, "filter": { "with": [HEALTH] } }),
+        );
+        assert_eq!(
+            response,
+            Ok(json!([
+                { "entity": orc, "components": { NAME: { "value": "orc" } } },
+                { "entity": wall, "components": {} },
+            ]))
+        );
+
+        let response = request(
+            &mut app,
+            "bevy/query",
+            json!({ "filter": { "with": [HEALTH], "without": [NAME] } }),
+        );
+        assert_eq!(response, Ok(json!([{ "entity": wall, "components": {} }])));
+    }
+
+    #[test]
+    fn query_disabled() {
+        let mut app = setup();
+        let enabled = app.world.spawn(Health(1)).id();
+        let disabled = app.world.spawn((Health(2), Disabled)).id();
+
+        let response = request(
+            &mut app,
+            "bevy/query",
+            json!({ "filter": { "with": [HEALTH] } }),
+        );
+        assert_eq!(
+            response,
+            Ok(json!([{ "entity": enabled, "components": {} }]))
+        );
+
+        let response = request(
+            &mut app,
+            "bevy/query",
+            json!({ "filter": { "with": [HEALTH], "include_disabled": true } }),
+        );
+        assert_eq!(
+            response,
+            Ok(json!([
+                { "entity": enabled, "components": {} },
+                { "entity": disabled, "components": {} },
+            ]))
+        );
+    }
+
+    #[test]
+    fn invalid_requests() {
+        let mut app = setup();
+        let entity = app.world.spawn(Health(1)).id();
+
+        let response = request(&mut app, "bevy/get", json!({ "components": [HEALTH] }));
+        assert_eq!(response.unwrap_err().code, error_codes::INVALID_PARAMS);
+
+        let response = request(
+            &mut app,
+            "bevy/get",
+            json!({ "entity": entity, "components": ["not::a::Component"] }),
+        );
+        assert_eq!(response.unwrap_err().code, error_codes::COMPONENT_ERROR);
+
+        // Nothing is inserted if one of the components is invalid.
+        let response = request(
+            &mut app,
+            "bevy/insert",
+            json!({ "entity": entity, "components": { HEALTH: [2], NAME: 3 } }),
+        );
+        assert_eq!(response.unwrap_err().code, error_codes::COMPONENT_ERROR);
+        assert_eq!(app.world.get::<Health>(entity), Some(&Health(1)));
+    }
+}
@@ -0,0 +1,412 @@
+//! The HTTP transport of the Bevy Remote Protocol.
+//!
+//! Every request is sent as the body of an HTTP `POST` request, and its response is returned as
+//! the body of the HTTP response. By default, the server only listens on the loopback interface,
+//! on port [`DEFAULT_PORT`].
+//!
+//! Requests must have a `Content-Type: application/json` header and no `Origin` header. Browsers
+//! can't send such requests from other web pages without a CORS preflight, which the server never
+//! approves, so web pages open on the same machine can't control the app.
+//!
+//! Request bodies larger than [`MAX_REQUEST_BODY_SIZE`] are rejected. Notifications, which are
+//! requests without an `id`, are processed but answered with an empty `204 No Content` response.
+
+use std::{
+    error::Error,
+    net::{IpAddr, Ipv4Addr, TcpListener, TcpStream},
+    thread,
+};
+
+use anyhow::{anyhow, Result as AnyhowResult};
+use async_channel::Sender;
+use async_executor::LocalExecutor;
+use async_io::Async;
+use bevy_app::{App, Plugin, Startup};
+use bevy_ecs::system::{Res, Resource};
+use bevy_utils::tracing::{error, info};
+use http_body_util::{BodyExt, Full, LengthLimitError, Limited};
+use hyper::{
+    body::{Body, Bytes, Incoming},
+    header::{HeaderValue, CONTENT_TYPE, ORIGIN},
+    server::conn::http1,
+    service::service_fn,
+    Method, Request, Response, StatusCode,
+};
+use serde_json::Value;
+use smol_hyper::rt::{FuturesIo, SmolTimer};
+
+use crate::{error_codes, BrpError, BrpMessage, BrpRequest, BrpResponse, BrpResult, BrpSender};
+
+/// The default port the Bevy Remote Protocol server listens on.
+pub const DEFAULT_PORT: u16 = 15702;
+
+/// The default address the Bevy Remote Protocol server listens on: the loopback interface.
+pub const DEFAULT_ADDR: IpAddr = IpAddr::V4(Ipv4Addr::LOCALHOST);
+
+/// The maximum size of the body of a request, in bytes.
+pub const MAX_REQUEST_BODY_SIZE: usize = 1024 * 1024;
+
+/// Serves the Bevy Remote Protocol over HTTP.
+///
+/// This requires the [`RemotePlugin`](crate::RemotePlugin), which processes the requests.
+///
+/// Beware that any process able to reach the server gets full access to the [`World`](bevy_ecs::world::World).
+/// Only listen on an address other than the loopback interface on trusted networks.
+pub struct RemoteHttpPlugin {
+    /// The address the server listens on.
+    address: IpAddr,
+    /// The port the server listens on.
+    port: u16,
+}
+
+impl Default for RemoteHttpPlugin {
+    fn default() -> Self {
+        Self {
+            address: DEFAULT_ADDR,
+            port: DEFAULT_PORT,
+        }
+    }
+}
+
+impl RemoteHttpPlugin {
+    /// Sets the address the server listens on.
+    #[must_use]
+    pub fn with_address(mut self, address: impl Into<IpAddr>) -> Self {
+        self.address = address.into();
+        self
+    }
+
+    /// Sets the port the server listens on.
+    #[must_use]
+    pub fn with_port(mut self, port: u16) -> Self {
+        self.port = port;
+        self
+    }
+}
+
+impl Plugin for RemoteHttpPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(HostAddress(self.address))
+            .insert_resource(HostPort(self.port))
+            .add_systems(Startup, start_http_server);
+    }
+}
+
+/// The address the Bevy Remote Protocol server listens on.
+#[derive(Debug, Clone, Copy, Resource)]
+pub struct HostAddress(pub IpAddr);
+
+/// The port the Bevy Remote Protocol server listens on.
+#[derive(Debug, Clone, Copy, Resource)]
+pub struct HostPort(pub u16);
+
+/// Starts the server on a dedicated thread, so that it keeps running however the task pools
+/// are configured.
+fn start_http_server(
+    request_sender: Res<BrpSender>,
+    address: Res<HostAddress>,
+    port: Res<HostPort>,
+) {
+    let listener = match Async::<TcpListener>::bind((address.0, port.0)) {
+        Ok(listener) => listener,
+        Err(error) => {
+            error!(
+                "Failed to start the Bevy Remote Protocol server on {}:{}: {error}",
+                address.0, port.0
+            );
+            return;
+        }
+    };
+    info!(
+        "Bevy Remote Protocol server listening on {}:{}",
+        address.0, port.0
+    );
+
+    let request_sender = request_sender.clone();
+    let spawn_result = thread::Builder::new()
+        .name("bevy_remote_http".to_owned())
+        .spawn(move || {
+            let executor = LocalExecutor::new();
+            async_io::block_on(executor.run(listen(listener, request_sender, &executor)));
+        });
+    if let Err(error) = spawn_result {
+        error!("Failed to spawn the Bevy Remote Protocol server thread: {error}");
+    }
+}
+
+/// Accepts connections and handles each of them in its own task.
+async fn listen(
+    listener: Async<TcpListener>,
+    request_sender: Sender<BrpMessage>,
+    executor: &LocalExecutor<'_>,
+) {
+    loop {
+        let client = match listener.accept().await {
+            Ok((client, _)) => client,
+            Err(error) => {
+                error!("Failed to accept a Bevy Remote Protocol connection: {error}");
+                continue;
+            }
+        };
+
+        let request_sender = request_sender.clone();
+        executor
+            .spawn(async move {
+                // Errors only affect the client, which sees its connection closed.
+                let _ = handle_client(client, request_sender).await;
+            })
+            .detach();
+    }
+}
+
+async fn handle_client(
+    client: Async<TcpStream>,
+    request_sender: Sender<BrpMessage>,
+) -> AnyhowResult<()> {
+    http1::Builder::new()
+        .timer(SmolTimer::new())
+        .serve_connection(
+            FuturesIo::new(client),
+            service_fn(|request| process_request(request, &request_sender)),
+        )
+        .await?;
+
+    Ok(())
+}
+
+/// Forwards the request in the body of an HTTP request to the app, and returns its response.
+async fn process_request(
+    request: Request<Incoming>,
+    request_sender: &Sender<BrpMessage>,
+) -> AnyhowResult<Response<Full<Bytes>>> {
+    if let Err(status) = check_request(&request) {
+        let mut response = Response::new(Full::default());
+        *response.status_mut() = status;
+        return Ok(response);
+    }
+
+    let response = match read_body(request.into_body()).await? {
+        Some(body) => process_body(&body, request_sender).await?,
+        None => {
+            let error = BrpError {
+                code: error_codes::INVALID_REQUEST,
+                message: format!("The request body is larger than {MAX_REQUEST_BODY_SIZE} bytes"),
+                data: None,
+            };
+            Some(BrpResponse::new(None, Err(error)))
+        }
+    };
+
+    let Some(response) = response else {
+        let mut response = Response::new(Full::default());
+        *response.status_mut() = StatusCode::NO_CONTENT;
+        return Ok(response);
+    };
+    let mut response = Response::new(Full::new(Bytes::from(serde_json::to_vec(&response)?)));
+    response
+        .headers_mut()
+        .insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+    Ok(response)
+}
+
+/// Reads the body of an HTTP request, or returns `None` if it's larger than
+/// [`MAX_REQUEST_BODY_SIZE`].
+async fn read_body<B>(body: B) -> AnyhowResult<Option<Bytes>>
+where
+    B: Body,
+    B::Error: Into<Box<dyn Error + Send + Sync>>,
+{
+    match Limited::new(body, MAX_REQUEST_BODY_SIZE).collect().await {
+        Ok(body) => Ok(Some(body.to_bytes())),
+        Err(error) if error.is::<LengthLimitError>() => Ok(None),
+        Err(error) => Err(anyhow!(error)),
+    }
+}
+
+/// Runs the request in `body`, and returns its response, or `None` if it's a notification.
+async fn process_body(
+    body: &[u8],
+    request_sender: &Sender<BrpMessage>,
+) -> AnyhowResult<Option<BrpResponse>> {
+    let response = match parse_request(body) {
+        Ok(request) => {
+            let result =
+                process_single_request(request.method, request.params, request_sender).await?;
+            // JSON-RPC servers must not reply to notifications.
+            request.id.map(|id| BrpResponse::new(Some(id), result))
+        }
+        Err((id, error)) => Some(BrpResponse::new(id, Err(error))),
+    };
+    Ok(response)
+}
+
+/// Rejects HTTP requests that aren't JSON `POST` requests, or that were sent by a web page.
+fn check_request<B>(request: &Request<B>) -> Result<(), StatusCode> {
+    if request.method() != Method::POST {
+        return Err(StatusCode::METHOD_NOT_ALLOWED);
+    }
+    if request.headers().contains_key(ORIGIN) {
+        return Err(StatusCode::FORBIDDEN);
+    }
+    let is_json = request
+        .headers()
+        .get(CONTENT_TYPE)
+        .and_then(|content_type| content_type.to_str().ok())
+        .and_then(|content_type| content_type.split(';').next())
+        .is_some_and(|mime| mime.trim().eq_ignore_ascii_case("application/json"));
+    if !is_json {
+        return Err(StatusCode::UNSUPPORTED_MEDIA_TYPE);
+    }
+    Ok(())
+}
+
+/// Parses a [`BrpRequest`], returning the id of the request along with the error if it's invalid.
+fn parse_request(body: &[u8]) -> Result<BrpRequest, (Option<Value>, BrpError)> {
+    let value: Value = serde_json::from_slice(body).map_err(|error| {
+        let error = BrpError {
+            code: error_codes::PARSE_ERROR,
+            message: error.to_string(),
+            data: None,
+        };
+        (None, error)
+    })?;
+    let id = value.get("id").cloned();
+
+    let invalid_request = |message: String| {
+        let error = BrpError {
+            code: error_codes::INVALID_REQUEST,
+            message,
+            data: None,
+        };
+        (id.clone(), error)
+    };
+    let request: BrpRequest =
+        serde_json::from_value(value).map_err(|error| invalid_request(error.to_string()))?;
+    if request.jsonrpc != "2.0" {
+        return Err(invalid_request(format!(
+            "Unsupported JSON-RPC version `{}`",
+            request.jsonrpc
+        )));
+    }
+
+    Ok(request)
+}
+
+/// Sends a request to the app and waits for its result.
+async fn process_single_request(
+    method: String,
+    params: Option<Value>,
+    request_sender: &Sender<BrpMessage>,
+) -> AnyhowResult<BrpResult> {
+    let (result_sender, result_receiver) = async_channel::bounded(1);
+    request_sender
+        .send(BrpMessage {
+            method,
+            params,
+            sender: result_sender,
+        })
+        .await?;
+    Ok(result_receiver.recv().await?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_requests() {
+        let request = |method: Method, headers: &[(&str, &str)]| {
+            let mut builder = Request::builder().method(method);
+            for (name, value) in headers {
+                builder = builder.header(*name, *value);
+            }
+            builder.body(()).unwrap()
+        };
+
+        let json = ("content-type", "application/json; charset=utf-8");
+        assert_eq!(check_request(&request(Method::POST, &[json])), Ok(()));
+        assert_eq!(
+            check_request(&request(Method::GET, &[json])),
+            Err(StatusCode::METHOD_NOT_ALLOWED)
+        );
+        // Web pages can send `text/plain` requests without a CORS preflight.
+        assert_eq!(
+            check_request(&request(Method::POST, &[("content-type", "text/plain")])),
+            Err(StatusCode::UNSUPPORTED_MEDIA_TYPE)
+        );
+        assert_eq!(
+            check_request(&request(Method::POST, &[])),
+            Err(StatusCode::UNSUPPORTED_MEDIA_TYPE)
+        );
+        assert_eq!(
+            check_request(&request(
+                Method::POST,
+                &[json, ("origin", "https://example.com")]
+            )),
+            Err(StatusCode::FORBIDDEN)
+        );
+    }
+
+    #[test]
+    fn parse_requests() {
+        let request =
+            parse_request(br#"{"jsonrpc": "2.0", "id": 3, "method": "bevy/list"}"#).unwrap();
+        assert_eq!(request.method, "bevy/list");
+        assert_eq!(request.id, Some(Value::from(3)));
+        assert_eq!(request.params, None);
+
+        let (id, error) = parse_request(b"{").unwrap_err();
+        assert_eq!(id, None);
+        assert_eq!(error.code, error_codes::PARSE_ERROR);
+
+        let (id, error) =
+            parse_request(br#"{"jsonrpc": "1.0", "id": 4, "method": "bevy/list"}"#).unwrap_err();
+        assert_eq!(id, Some(Value::from(4)));
+        assert_eq!(error.code, error_codes::INVALID_REQUEST);
+
+        let (_, error) = parse_request(br#"{"jsonrpc": "2.0", "id": 5}"#).unwrap_err();
+        assert_eq!(error.code, error_codes::INVALID_REQUEST);
+    }
+
+    #[test]
+    fn read_bodies() {
+        let body = Full::new(Bytes::from(vec![b' '; MAX_REQUEST_BODY_SIZE]));
+        let body = async_io::block_on(read_body(body)).unwrap();
+        assert_eq!(body.map(|body| body.len()), Some(MAX_REQUEST_BODY_SIZE));
+
+        let body = Full::new(Bytes::from(vec![b' '; MAX_REQUEST_BODY_SIZE + 1]));
+        assert_eq!(async_io::block_on(read_body(body)).unwrap(), None);
+    }
+
+    #[test]
+    fn notifications_are_not_answered() {
+        let (request_sender, request_receiver) = async_channel::bounded::<BrpMessage>(1);
+        let (method_sender, method_receiver) = async_channel::unbounded();
+        let app = thread::spawn(move || {
+            while let Ok(message) = request_receiver.recv_blocking() {
+                method_sender.send_blocking(message.method).unwrap();
+                message.sender.send_blocking(Ok(Value::Null)).unwrap();
+            }
+        });
+
+        let response = async_io::block_on(process_body(
+            br#"{"jsonrpc": "2.0", "method": "bevy/list"}"#,
+            &request_sender,
+        ))
+        .unwrap();
+        assert!(response.is_none());
+        // The notification still ran.
+        assert_eq!(method_receiver.recv_blocking().unwrap(), "bevy/list");
+
+        let response = async_io::block_on(process_body(
+            br#"{"jsonrpc": "2.0", "id": 1, "method": "bevy/list"}"#,
+            &request_sender,
+        ))
+        .unwrap()
+        .unwrap();
+        assert_eq!(response.id, Some(Value::from(1)));
+
+        drop(request_sender);
+        app.join().unwrap();
+    }
+}
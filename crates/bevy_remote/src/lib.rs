@@ -0,0 +1,462 @@
+//! An implementation of the Bevy Remote Protocol (BRP), which allows external processes to
+//! inspect and edit a running Bevy app.
+//!
+//! The protocol is based on [JSON-RPC 2.0]: clients send requests with a `method` name and
+//! optional `params`, and the app answers each of them with either a `result` or an `error`.
+//! Requests are processed once per frame, in the [`Last`] schedule, with full access to the
+//! [`World`]. Components are read and written through reflection, so only component types
+//! registered in the [`AppTypeRegistry`](bevy_ecs::reflect::AppTypeRegistry) with
+//! `#[reflect(Component)]` can be accessed, and they are identified by their full type path
+//! (e.g. `bevy_transform::components::transform::Transform`).
+//!
+//! The [`RemotePlugin`] sets up the methods and the request processing, while the
+//! [`RemoteHttpPlugin`](http::RemoteHttpPlugin) exposes them over HTTP on a local socket.
+//! Both are needed for a client to reach the app:
+//!
+//! ```no_run
+//! use bevy_app::prelude::*;
+//! use bevy_remote::{http::RemoteHttpPlugin, RemotePlugin};
+//!
+//! App::new()
+//!     .add_plugins(RemotePlugin::default())
+//!     .add_plugins(RemoteHttpPlugin::default())
+//!     .run();
+//! ```
+//!
+//! A request is sent as the body of an HTTP `POST` request with a `Content-Type: application/json` header:
+//!
+//! ```json
+//! {
+//!     "jsonrpc": "2.0",
+//!     "id": 0,
+//!     "method": "bevy/get",
+//!     "params": {
+//!         "entity": 4294967298,
+//!         "components": ["bevy_transform::components::transform::Transform"]
+//!     }
+//! }
+//! ```
+//!
+//! and the response echoes its `id`:
+//!
+//! ```json
+//! {
+//!     "jsonrpc": "2.0",
+//!     "id": 0,
+//!     "result": {
+//!         "bevy_transform::components::transform::Transform": {
+//!             "translation": [0.0, 0.5, 0.0],
+//!             "rotation": [0.0, 0.0, 0.0, 1.0],
+//!             "scale": [1.0, 1.0, 1.0]
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! Entities are represented by their [`Entity::to_bits`](bevy_ecs::entity::Entity::to_bits) value.
+//!
+//! ## Built-in methods
+//!
+//! - `bevy/get`: Returns the values of some components of an entity.
+//!   - `params`: `{ "entity": <entity>, "components": [<type path>, ...] }`
+//!   - `result`: a map from each type path to the serialized component.
+//! - `bevy/query`: Returns the entities matching a query, with the values of some of their components.
+//!   - `params` (all fields are optional):
+//!     `{ "data": { "components": [...], "option": [...], "has": [...] }, "filter": { "with": [...], "without": [...], "include_disabled": <bool> } }`
+//!     - `components` must all be present on the entity, and are returned.
+//!     - `option` are returned if present.
+//!     - `has` are reported as `true` or `false` in the `has` map of each result.
+//!     - `with` and `without` filter the entities without returning anything.
+//!     - Disabled entities are skipped unless `include_disabled` is `true`.
+//!   - `result`: `[{ "entity": <entity>, "components": { ... }, "has": { ... } }, ...]`.
+//!     A query without any `params` lists every entity of the world that isn't disabled.
+//! - `bevy/spawn`: Spawns a new entity with the given components.
+//!   - `params`: `{ "components": { <type path>: <value>, ... } }`
+//!   - `result`: `{ "entity": <entity> }`
+//! - `bevy/insert`: Inserts components into an entity, replacing any existing value.
+//!   - `params`: `{ "entity": <entity>, "components": { <type path>: <value>, ... } }`
+//!   - `result`: `null`
+//! - `bevy/remove`: Removes components from an entity.
+//!   - `params`: `{ "entity": <entity>, "components": [<type path>, ...] }`
+//!   - `result`: `null`
+//! - `bevy/destroy`: Despawns an entity.
+//!   - `params`: `{ "entity": <entity> }`
+//!   - `result`: `null`
+//! - `bevy/list`: Lists the type paths of the components of an entity, or of every reflected
+//!   component type if no entity is given.
+//!   - `params` (optional): `{ "entity": <entity> }`
+//!   - `result`: `[<type path>, ...]`
+//!
+//! ## Custom methods
+//!
+//! Additional methods are added with [`RemotePlugin::with_method`]. A method is a system taking
+//! the request `params` as [`In<Option<Value>>`](bevy_ecs::system::In) and returning a [`BrpResult`]:
+//!
+//! ```
+//! # use bevy_ecs::prelude::*;
+//! use bevy_remote::{BrpResult, RemotePlugin};
+//! use serde_json::Value;
+//!
+//! #[derive(Resource)]
+//! struct Score(u32);
+//!
+//! fn get_score(In(_): In<Option<Value>>, score: Res<Score>) -> BrpResult {
+//!     Ok(Value::from(score.0))
+//! }
+//!
+//! let remote_plugin = RemotePlugin::default().with_method("game/score", get_score);
+//! ```
+//!
+//! [JSON-RPC 2.0]: https://www.jsonrpc.org/specification
+
+#![cfg_attr(docsrs, feature(doc_auto_cfg))]
+
+use std::sync::Mutex;
+
+use async_channel::{Receiver, Sender};
+use bevy_app::prelude::*;
+use bevy_derive::{Deref, DerefMut};
+use bevy_ecs::{
+    system::{BoxedSystem, IntoSystem, Resource, SystemId},
+    world::World,
+};
+use bevy_utils::HashMap;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+pub mod builtin_methods;
+pub mod http;
+
+/// Sets up the processing of Bevy Remote Protocol requests and registers the methods that
+/// answer them.
+///
+/// Requests are read from the [`BrpSender`] channel, so a transport such as the
+/// [`RemoteHttpPlugin`](http::RemoteHttpPlugin) is needed for clients to reach the app.
+pub struct RemotePlugin {
+    /// The methods to register, drained when the plugin is built.
+    methods: Mutex<Vec<(String, BoxedSystem<Option<Value>, BrpResult>)>>,
+}
+
+impl RemotePlugin {
+    /// Creates a [`RemotePlugin`] without any methods, not even the built-in ones.
+    pub fn empty() -> Self {
+        Self {
+            methods: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Adds a method named `name`, handled by the given system.
+    ///
+    /// The system receives the `params` of the request and returns the `result` or `error` of the
+    /// response. A method registered with the name of an existing one replaces it.
+    pub fn with_method<M>(
+        self,
+        name: impl Into<String>,
+        handler: impl IntoSystem<Option<Value>, BrpResult, M>,
+    ) -> Self {
+        self.methods
+            .lock()
+            .unwrap()
+            .push((name.into(), Box::new(IntoSystem::into_system(handler))));
+        self
+    }
+}
+
+impl Default for RemotePlugin {
+    fn default() -> Self {
+        Self::empty()
+            .with_method(
+                builtin_methods::BRP_GET_METHOD,
+                builtin_methods::process_remote_get_request,
+            )
+            .with_method(
+                builtin_methods::BRP_QUERY_METHOD,
+                builtin_methods::process_remote_query_request,
+            )
+            .with_method(
+                builtin_methods::BRP_SPAWN_METHOD,
+                builtin_methods::process_remote_spawn_request,
+            )
+            .with_method(
+                builtin_methods::BRP_INSERT_METHOD,
+                builtin_methods::process_remote_insert_request,
+            )
+            .with_method(
+                builtin_methods::BRP_REMOVE_METHOD,
+                builtin_methods::process_remote_remove_request,
+            )
+            .with_method(
+                builtin_methods::BRP_DESTROY_METHOD,
+                builtin_methods::process_remote_destroy_request,
+            )
+            .with_method(
+                builtin_methods::BRP_LIST_METHOD,
+                builtin_methods::process_remote_list_request,
+            )
+    }
+}
+
+impl Plugin for RemotePlugin {
+    fn build(&self, app: &mut App) {
+        let mut remote_methods = RemoteMethods::default();
+        for (name, system) in self.methods.lock().unwrap().drain(..) {
+            remote_methods.insert(name, app.world.register_boxed_system(system));
+        }
+
+        let (request_sender, request_receiver) = async_channel::unbounded();
+
+        app.insert_resource(remote_methods)
+            .insert_resource(BrpSender(request_sender))
+            .insert_resource(BrpReceiver(request_receiver))
+            .add_systems(Last, process_remote_requests);
+    }
+}
+
+/// The system handling a Bevy Remote Protocol method.
+pub type RemoteMethod = SystemId<Option<Value>, BrpResult>;
+
+/// The methods available to Bevy Remote Protocol clients, by name.
+#[derive(Debug, Default, Resource, Deref, DerefMut)]
+pub struct RemoteMethods(HashMap<String, RemoteMethod>);
+
+/// The sending end of the channel of Bevy Remote Protocol requests, used by transports to forward
+/// the requests they receive to the app.
+#[derive(Debug, Resource, Deref, DerefMut)]
+pub struct BrpSender(Sender<BrpMessage>);
+
+/// The receiving end of the channel of Bevy Remote Protocol requests, drained every frame by the
+/// [`RemotePlugin`].
+#[derive(Debug, Resource, Deref, DerefMut)]
+pub struct BrpReceiver(Receiver<BrpMessage>);
+
+/// A Bevy Remote Protocol request forwarded by a transport to the app.
+#[derive(Debug, Clone)]
+pub struct BrpMessage {
+    /// The name of the method to run.
+    pub method: String,
+    /// The parameters of the request, passed to the method.
+    pub params: Option<Value>,
+    /// The channel the result of the method is sent to.
+    pub sender: Sender<BrpResult>,
+}
+
+/// A JSON-RPC request, as sent by Bevy Remote Protocol clients.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BrpRequest {
+    /// The JSON-RPC version, which must be `"2.0"`.
+    pub jsonrpc: String,
+    /// The name of the method to run.
+    pub method: String,
+    /// An arbitrary identifier echoed in the response, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<Value>,
+    /// The parameters of the method, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub params: Option<Value>,
+}
+
+/// A JSON-RPC response, sent back to Bevy Remote Protocol clients.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BrpResponse {
+    /// The JSON-RPC version, always `"2.0"`.
+    pub jsonrpc: String,
+    /// The identifier of the request this response answers.
+    pub id: Option<Value>,
+    /// The outcome of the request.
+    #[serde(flatten)]
+    pub payload: BrpPayload,
+}
+
+impl BrpResponse {
+    /// Creates a response to the request with the given `id`.
+    pub fn new(id: Option<Value>, result: BrpResult) -> Self {
+        Self {
+            jsonrpc: "2.0".to_owned(),
+            id,
+            payload: result.into(),
+        }
+    }
+}
+
+/// The outcome of a Bevy Remote Protocol request, serialized as either a `result` or an `error`
+/// field of the [`BrpResponse`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BrpPayload {
+    /// The request succeeded.
+    Result(Value),
+    /// The request failed.
+    Error(BrpError),
+}
+
+impl From<BrpResult> for BrpPayload {
+    fn from(value: BrpResult) -> Self {
+        match value {
+            Ok(result) => BrpPayload::Result(result),
+            Err(error) => BrpPayload::Error(error),
+        }
+    }
+}
+
+/// The result of a Bevy Remote Protocol method.
+pub type BrpResult = Result<Value, BrpError>;
+
+/// An error returned by a Bevy Remote Protocol method.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BrpError {
+    /// The kind of error, usually one of the [`error_codes`].
+    pub code: i16,
+    /// A short description of the error.
+    pub message: String,
+    /// Additional information about the error, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<Value>,
+}
+
+impl BrpError {
+    /// An [`error_codes::INVALID_PARAMS`] error with the given message.
+    pub fn invalid_params(message: impl Into<String>) -> Self {
+        Self {
+            code: error_codes::INVALID_PARAMS,
+            message: message.into(),
+            data: None,
+        }
+    }
+
+    /// An [`error_codes::INTERNAL_ERROR`] error with the given message.
+    pub fn internal(message: impl std::fmt::Display) -> Self {
+        Self {
+            code: error_codes::INTERNAL_ERROR,
+            message: message.to_string(),
+            data: None,
+        }
+    }
+
+    /// An [`error_codes::ENTITY_NOT_FOUND`] error for the given entity.
+    pub fn entity_not_found(entity: bevy_ecs::entity::Entity) -> Self {
+        Self {
+            code: error_codes::ENTITY_NOT_FOUND,
+            message: format!("Entity {entity:?} does not exist"),
+            data: None,
+        }
+    }
+
+    /// An [`error_codes::COMPONENT_ERROR`] error for the component with the given type path.
+    pub fn component_error(type_path: &str, message: impl std::fmt::Display) -> Self {
+        Self {
+            code: error_codes::COMPONENT_ERROR,
+            message: format!("`{type_path}`: {message}"),
+            data: None,
+        }
+    }
+}
+
+/// The error codes of [`BrpError`]s.
+///
+/// Codes from `-32768` to `-32000` are reserved by the JSON-RPC specification, and Bevy uses
+/// codes from `-23400` to `-23499`.
+pub mod error_codes {
+    /// The request body is not valid JSON.
+    pub const PARSE_ERROR: i16 = -32700;
+    /// The request is not a valid JSON-RPC request.
+    pub const INVALID_REQUEST: i16 = -32600;
+    /// The method doesn't exist.
+    pub const METHOD_NOT_FOUND: i16 = -32601;
+    /// The method parameters are invalid.
+    pub const INVALID_PARAMS: i16 = -32602;
+    /// The method failed to run.
+    pub const INTERNAL_ERROR: i16 = -32603;
+
+    /// The requested entity doesn't exist.
+    pub const ENTITY_NOT_FOUND: i16 = -23401;
+    /// A component could not be found, serialized, deserialized or applied.
+    pub const COMPONENT_ERROR: i16 = -23402;
+}
+
+/// Runs the method of every pending Bevy Remote Protocol request and sends its result back.
+pub fn process_remote_requests(world: &mut World) {
+    if !world.contains_resource::<BrpReceiver>() {
+        return;
+    }
+
+    while let Ok(message) = world.resource::<BrpReceiver>().try_recv() {
+        let Some(&handler) = world.resource::<RemoteMethods>().get(&message.method) else {
+            let _ = message.sender.try_send(Err(BrpError {
+                code: error_codes::METHOD_NOT_FOUND,
+                message: format!("Method `{}` not found", message.method),
+                data: None,
+            }));
+            continue;
+        };
+
+        let result = world
+            .run_system_with_input(handler, message.params)
+            .unwrap_or_else(|error| {
+                Err(BrpError::internal(format!(
+                    "Failed to run method `{}`: {error:?}",
+                    message.method
+                )))
+            });
+        // The client may have disconnected in the meantime.
+        let _ = message.sender.try_send(result);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_ecs::system::{In, Res};
+
+    #[derive(Resource)]
+    struct Score(u32);
+
+    fn get_score(In(_): In<Option<Value>>, score: Res<Score>) -> BrpResult {
+        Ok(Value::from(score.0))
+    }
+
+    fn request(app: &mut App, method: &str, params: Option<Value>) -> BrpResult {
+        let (sender, receiver) = async_channel::bounded(1);
+        app.world
+            .resource::<BrpSender>()
+            .try_send(BrpMessage {
+                method: method.to_owned(),
+                params,
+                sender,
+            })
+            .unwrap();
+        app.update();
+        receiver.try_recv().unwrap()
+    }
+
+    #[test]
+    fn custom_and_missing_methods() {
+        let mut app = App::new();
+        app.insert_resource(Score(7))
+            .add_plugins(RemotePlugin::empty().with_method("game/score", get_score));
+
+        assert_eq!(request(&mut app, "game/score", None), Ok(Value::from(7)));
+        assert_eq!(
+            request(&mut app, "bevy/get", None).unwrap_err().code,
+            error_codes::METHOD_NOT_FOUND
+        );
+    }
+
+    #[test]
+    fn response_serialization() {
+        let response = BrpResponse::new(Some(Value::from(1)), Ok(Value::from("ok")));
+        assert_eq!(
+            serde_json::to_value(response).unwrap(),
+            serde_json::json!({ "jsonrpc": "2.0", "id": 1, "result": "ok" })
+        );
+
+        let response = BrpResponse::new(None, Err(BrpError::invalid_params("missing entity")));
+        assert_eq!(
+            serde_json::to_value(response).unwrap(),
+            serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": null,
+                "error": { "code": error_codes::INVALID_PARAMS, "message": "missing entity" }
+            })
+        );
+    }
+}
@@ -193,7 +193,7 @@ fn update_rendering(app_world: &mut World, _sub_app: &mut App) {
                 render_channels.send_blocking(render_app);
             } else {
                 // Renderer thread panicked
-                world.send_event(AppExit);
+                world.send_event(AppExit::error());
             }
         });
     });
@@ -176,6 +176,14 @@ impl DynamicScene {
     pub fn serialize_ron(&self, registry: &TypeRegistryArc) -> Result<String, ron::Error> {
         serialize_ron(SceneSerializer::new(self, registry))
     }
+
+    /// Serialize this dynamic scene into the compact binary scene format.
+    ///
+    /// The output can be loaded from `.scn.bin` files by the [`BinarySceneLoader`](crate::BinarySceneLoader).
+    #[cfg(feature = "serialize")]
+    pub fn serialize_binary(&self, registry: &TypeRegistryArc) -> Result<Vec<u8>, postcard::Error> {
+        postcard::to_allocvec(&SceneSerializer::new(self, registry))
+    }
 }
 
 /// Serialize a given Rust data structure into rust object notation (ron).
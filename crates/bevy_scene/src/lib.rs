@@ -11,6 +11,8 @@ mod dynamic_scene_builder;
 mod scene;
 mod scene_filter;
 mod scene_loader;
+#[cfg(feature = "serialize")]
+mod scene_saver;
 mod scene_spawner;
 
 #[cfg(feature = "serialize")]
@@ -26,6 +28,8 @@ pub use dynamic_scene_builder::*;
 pub use scene::*;
 pub use scene_filter::*;
 pub use scene_loader::*;
+#[cfg(feature = "serialize")]
+pub use scene_saver::*;
 pub use scene_spawner::*;
 
 #[allow(missing_docs)]
@@ -38,7 +42,11 @@ pub mod prelude {
 }
 
 use bevy_app::prelude::*;
+#[cfg(feature = "serialize")]
+use bevy_asset::processor::LoadAndSave;
 use bevy_asset::AssetApp;
+#[cfg(feature = "serialize")]
+use bevy_ecs::world::FromWorld;
 
 /// Plugin that provides scene functionality to an [`App`].
 #[derive(Default)]
@@ -50,9 +58,21 @@ impl Plugin for ScenePlugin {
         app.init_asset::<DynamicScene>()
             .init_asset::<Scene>()
             .init_asset_loader::<SceneLoader>()
+            .init_asset_loader::<BinarySceneLoader>()
             .add_event::<SceneInstanceReady>()
             .init_resource::<SceneSpawner>()
             .add_systems(SpawnScene, (scene_spawner, scene_spawner_system).chain());
+
+        // The processor is cloned (it's backed by an `Arc`), as the saver needs the world to be created.
+        if let Some(processor) = app
+            .world
+            .get_resource::<bevy_asset::processor::AssetProcessor>()
+            .cloned()
+        {
+            let saver = BinarySceneSaver::from_world(&mut app.world);
+            processor
+                .register_processor::<LoadAndSave<SceneLoader, BinarySceneSaver>>(saver.into());
+        }
     }
 }
 
@@ -35,6 +35,10 @@ pub enum SceneLoaderError {
     /// A [RON Error](ron::error::SpannedError)
     #[error("Could not parse RON: {0}")]
     RonSpannedError(#[from] ron::error::SpannedError),
+    /// A [postcard Error](postcard::Error), produced when parsing binary scenes
+    #[cfg(feature = "serialize")]
+    #[error("Could not parse binary scene: {0}")]
+    Postcard(#[from] postcard::Error),
 }
 
 #[cfg(feature = "serialize")]
@@ -64,3 +68,51 @@ impl AssetLoader for SceneLoader {
         &["scn", "scn.ron"]
     }
 }
+
+/// [`AssetLoader`] for loading Bevy scene files serialized in the compact binary scene format
+/// as [`DynamicScene`].
+///
+/// Binary scenes are much smaller and faster to load than RON scenes, but are not human-readable.
+/// They can be created with [`DynamicScene::serialize_binary`], or from RON scenes
+/// by the asset processor using the [`BinarySceneSaver`](crate::BinarySceneSaver).
+///
+/// The binary scene format is not self-describing: a scene must be loaded with the same
+/// reflected types (and field order) it was saved with.
+#[derive(Debug)]
+pub struct BinarySceneLoader {
+    type_registry: TypeRegistryArc,
+}
+
+impl FromWorld for BinarySceneLoader {
+    fn from_world(world: &mut World) -> Self {
+        let type_registry = world.resource::<AppTypeRegistry>();
+        BinarySceneLoader {
+            type_registry: type_registry.0.clone(),
+        }
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl AssetLoader for BinarySceneLoader {
+    type Asset = DynamicScene;
+    type Settings = ();
+    type Error = SceneLoaderError;
+
+    async fn load<'a>(
+        &'a self,
+        reader: &'a mut Reader<'_>,
+        _settings: &'a (),
+        _load_context: &'a mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let scene_deserializer = SceneDeserializer {
+            type_registry: &self.type_registry.read(),
+        };
+        Ok(scene_deserializer.deserialize(&mut postcard::Deserializer::from_bytes(&bytes))?)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["scn.bin"]
+    }
+}
@@ -0,0 +1,60 @@
+use crate::serde::SceneSerializer;
+use crate::{BinarySceneLoader, DynamicScene};
+use bevy_asset::{
+    io::Writer,
+    saver::{AssetSaver, SavedAsset},
+    AsyncWriteExt,
+};
+use bevy_ecs::reflect::AppTypeRegistry;
+use bevy_ecs::world::{FromWorld, World};
+use bevy_reflect::TypeRegistryArc;
+use thiserror::Error;
+
+/// [`AssetSaver`] for saving [`DynamicScene`]s in the compact binary scene format,
+/// loaded back from `.scn.bin` files by the [`BinarySceneLoader`].
+///
+/// When the asset processor is enabled, the [`ScenePlugin`](crate::ScenePlugin) registers a
+/// `LoadAndSave<SceneLoader, BinarySceneSaver>` processor, which converts RON scenes to binary scenes.
+#[derive(Debug)]
+pub struct BinarySceneSaver {
+    type_registry: TypeRegistryArc,
+}
+
+impl FromWorld for BinarySceneSaver {
+    fn from_world(world: &mut World) -> Self {
+        let type_registry = world.resource::<AppTypeRegistry>();
+        BinarySceneSaver {
+            type_registry: type_registry.0.clone(),
+        }
+    }
+}
+
+/// Possible errors that can be produced by [`BinarySceneSaver`]
+#[non_exhaustive]
+#[derive(Debug, Error)]
+pub enum SceneSaverError {
+    /// An [IO Error](std::io::Error)
+    #[error("Error while trying to write the scene file: {0}")]
+    Io(#[from] std::io::Error),
+    /// A [postcard Error](postcard::Error)
+    #[error("Could not serialize the binary scene: {0}")]
+    Postcard(#[from] postcard::Error),
+}
+
+impl AssetSaver for BinarySceneSaver {
+    type Asset = DynamicScene;
+    type Settings = ();
+    type OutputLoader = BinarySceneLoader;
+    type Error = SceneSaverError;
+
+    async fn save<'a>(
+        &'a self,
+        writer: &'a mut Writer,
+        scene: SavedAsset<'a, Self::Asset>,
+        _settings: &'a Self::Settings,
+    ) -> Result<(), Self::Error> {
+        let bytes = postcard::to_allocvec(&SceneSerializer::new(&scene, &self.type_registry))?;
+        writer.write_all(&bytes).await?;
+        Ok(())
+    }
+}
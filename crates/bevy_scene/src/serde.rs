@@ -867,6 +867,56 @@ mod tests {
         assert_scene_eq(&scene, &deserialized_scene);
     }
 
+    #[test]
+    fn should_roundtrip_binary_scene_like_ron() {
+        let mut world = create_world();
+
+        let a = world.spawn(Foo(123)).id();
+        let b = world
+            .spawn((
+                Bar(345),
+                MyComponent {
+                    foo: [1, 2, 3],
+                    bar: (1.3, 3.7),
+                    baz: MyEnum::Struct { value: 42 },
+                },
+            ))
+            .id();
+        let c = world.spawn((MyEntityRef(a), Baz(789))).id();
+
+        world.insert_resource(MyResource { foo: 123 });
+
+        let registry = &world.resource::<AppTypeRegistry>().0;
+        let scene = DynamicSceneBuilder::from_world(&world)
+            .extract_entities([a, b, c].into_iter())
+            .extract_resources()
+            .build();
+
+        let ron = scene.serialize_ron(registry).unwrap();
+        let binary = scene.serialize_binary(registry).unwrap();
+        assert!(binary.len() < ron.len());
+
+        let scene_deserializer = SceneDeserializer {
+            type_registry: &registry.read(),
+        };
+        let mut ron_deserializer = ron::de::Deserializer::from_str(&ron).unwrap();
+        let ron_scene = scene_deserializer
+            .deserialize(&mut ron_deserializer)
+            .unwrap();
+
+        let scene_deserializer = SceneDeserializer {
+            type_registry: &registry.read(),
+        };
+        let binary_scene = scene_deserializer
+            .deserialize(&mut postcard::Deserializer::from_bytes(&binary))
+            .unwrap();
+
+        assert_eq!(3, binary_scene.entities.len());
+        assert_eq!(1, binary_scene.resources.len());
+        assert_scene_eq(&ron_scene, &binary_scene);
+        assert_eq!(ron, binary_scene.serialize_ron(registry).unwrap());
+    }
+
     /// A crude equality checker for [`DynamicScene`], used solely for testing purposes.
     fn assert_scene_eq(expected: &DynamicScene, received: &DynamicScene) {
         assert_eq!(
@@ -0,0 +1,330 @@
+use bevy_app::{App, MainScheduleOrder, Plugin, PreUpdate, Startup};
+use bevy_ecs::{
+    event::Events,
+    schedule::{IntoSystemConfigs, ScheduleLabel},
+    world::FromWorld,
+};
+use bevy_utils::tracing::warn;
+
+use crate::state::{
+    setup_state_transitions_in_world, ComputedStates, ExitSchedules, FreelyMutableState, NextState,
+    State, StateTransition, StateTransitionEvent, StateTransitionSteps, States, SubStates,
+};
+use crate::state_scoped::clear_state_scoped_entities;
+
+/// State installation methods for [`App`].
+pub trait AppExtStates {
+    /// Initializes a [`State`] with standard starting values.
+    ///
+    /// If the [`State`] already exists, nothing happens.
+    ///
+    /// Adds [`State<S>`] and [`NextState<S>`] resources, and enables use of the [`OnEnter`](crate::state::OnEnter),
+    /// [`OnTransition`](crate::state::OnTransition) and [`OnExit`](crate::state::OnExit) schedules.
+    /// These schedules are triggered before [`Update`](bevy_app::Update) and at startup.
+    ///
+    /// If you would like to control how other systems run based on the current state, you can
+    /// emulate this behavior using the [`in_state`](crate::condition::in_state) [`Condition`](bevy_ecs::schedule::Condition).
+    ///
+    /// Note that you can also apply state transitions at other points in the schedule
+    /// by triggering the [`StateTransition`] schedule manually.
+    fn init_state<S: FreelyMutableState + FromWorld>(&mut self) -> &mut Self;
+
+    /// Inserts a specific [`State`] to the current [`App`] and overrides any [`State`] previously
+    /// added of the same type.
+    ///
+    /// Adds [`State<S>`] and [`NextState<S>`] resources, and enables use of the [`OnEnter`](crate::state::OnEnter),
+    /// [`OnTransition`](crate::state::OnTransition) and [`OnExit`](crate::state::OnExit) schedules.
+    /// These schedules are triggered before [`Update`](bevy_app::Update) and at startup.
+    ///
+    /// If you would like to control how other systems run based on the current state, you can
+    /// emulate this behavior using the [`in_state`](crate::condition::in_state) [`Condition`](bevy_ecs::schedule::Condition).
+    ///
+    /// Note that you can also apply state transitions at other points in the schedule
+    /// by triggering the [`StateTransition`] schedule manually.
+    fn insert_state<S: FreelyMutableState>(&mut self, state: S) -> &mut Self;
+
+    /// Sets up a type implementing [`ComputedStates`].
+    ///
+    /// This method is idempotent: it has no effect when called again using the same generic type.
+    ///
+    /// The [`State<S>`] resource of a computed state only exists while [`ComputedStates::compute`]
+    /// returns `Some`, and is updated in the [`StateTransition`] schedule after its source states.
+    fn add_computed_state<S: ComputedStates>(&mut self) -> &mut Self;
+
+    /// Sets up a type implementing [`SubStates`].
+    ///
+    /// This method is idempotent: it has no effect when called again using the same generic type.
+    ///
+    /// The [`State<S>`] resource of a sub-state only exists while its source states allow it,
+    /// and can be changed through [`NextState<S>`] in the meantime.
+    fn add_sub_state<S: SubStates>(&mut self) -> &mut Self;
+
+    /// Enable state-scoped entity clearing for state `S`.
+    ///
+    /// Entities with a [`StateScoped<S>`](crate::state_scoped::StateScoped) component are despawned
+    /// when the state they are scoped to is exited, right after its [`OnExit`](crate::state::OnExit) schedule runs.
+    fn enable_state_scoped_entities<S: States>(&mut self) -> &mut Self;
+}
+
+/// Separate function to only warn once for all state installation methods.
+fn warn_if_no_states_plugin_installed(app: &App) {
+    if !app.is_plugin_added::<StatesPlugin>() {
+        warn!("States were added to the app, but `StatesPlugin` is not installed.");
+    }
+}
+
+impl AppExtStates for App {
+    fn init_state<S: FreelyMutableState + FromWorld>(&mut self) -> &mut Self {
+        warn_if_no_states_plugin_installed(self);
+        if !self.world.contains_resource::<State<S>>() {
+            setup_state_transitions_in_world(&mut self.world, Some(Startup.intern()));
+            self.init_resource::<State<S>>()
+                .init_resource::<NextState<S>>()
+                .add_event::<StateTransitionEvent<S>>();
+            let schedule = self.get_schedule_mut(StateTransition).unwrap();
+            S::register_state(schedule);
+            let state = self.world.resource::<State<S>>().get().clone();
+            self.world.send_event(StateTransitionEvent {
+                exited: None,
+                entered: Some(state),
+            });
+        }
+
+        self
+    }
+
+    fn insert_state<S: FreelyMutableState>(&mut self, state: S) -> &mut Self {
+        warn_if_no_states_plugin_installed(self);
+        if !self.world.contains_resource::<State<S>>() {
+            setup_state_transitions_in_world(&mut self.world, Some(Startup.intern()));
+            self.insert_resource::<State<S>>(State::new(state.clone()))
+                .init_resource::<NextState<S>>()
+                .add_event::<StateTransitionEvent<S>>();
+
+            let schedule = self.get_schedule_mut(StateTransition).unwrap();
+            S::register_state(schedule);
+            self.world.send_event(StateTransitionEvent {
+                exited: None,
+                entered: Some(state),
+            });
+        } else {
+            let mut state_resource = self.world.resource_mut::<State<S>>();
+            *state_resource = State::new(state);
+        }
+
+        self
+    }
+
+    fn add_computed_state<S: ComputedStates>(&mut self) -> &mut Self {
+        warn_if_no_states_plugin_installed(self);
+        if !self
+            .world
+            .contains_resource::<Events<StateTransitionEvent<S>>>()
+        {
+            setup_state_transitions_in_world(&mut self.world, Some(Startup.intern()));
+            self.add_event::<StateTransitionEvent<S>>();
+            let schedule = self.get_schedule_mut(StateTransition).unwrap();
+            S::register_computed_state_systems(schedule);
+        }
+
+        self
+    }
+
+    fn add_sub_state<S: SubStates>(&mut self) -> &mut Self {
+        warn_if_no_states_plugin_installed(self);
+        if !self
+            .world
+            .contains_resource::<Events<StateTransitionEvent<S>>>()
+        {
+            setup_state_transitions_in_world(&mut self.world, Some(Startup.intern()));
+            self.init_resource::<NextState<S>>();
+            self.add_event::<StateTransitionEvent<S>>();
+            let schedule = self.get_schedule_mut(StateTransition).unwrap();
+            S::register_sub_state_systems(schedule);
+        }
+
+        self
+    }
+
+    fn enable_state_scoped_entities<S: States>(&mut self) -> &mut Self {
+        if !self
+            .world
+            .contains_resource::<Events<StateTransitionEvent<S>>>()
+        {
+            let name = std::any::type_name::<S>();
+            warn!("State scoped entities are enabled for state `{}`, but the state isn't installed in the app!", name);
+        }
+        // We work with [`StateTransition`] in set [`StateTransitionSteps::ExitSchedules`] as opposed to [`OnExit`],
+        // because [`OnExit`] only runs for one specific variant of the state.
+        self.add_systems(
+            StateTransition,
+            clear_state_scoped_entities::<S>
+                .in_set(StateTransitionSteps::ExitSchedules)
+                .after(ExitSchedules::<S>::default()),
+        )
+    }
+}
+
+/// Registers the [`StateTransition`] schedule in the [`MainScheduleOrder`] to enable state processing.
+#[derive(Default)]
+pub struct StatesPlugin;
+
+impl Plugin for StatesPlugin {
+    fn build(&self, app: &mut App) {
+        let mut schedule = app.world.resource_mut::<MainScheduleOrder>();
+        schedule.insert_after(PreUpdate, StateTransition);
+        setup_state_transitions_in_world(&mut app.world, Some(Startup.intern()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_app::App;
+    use bevy_ecs::{
+        entity_disabling::Disabled,
+        system::{Commands, Query},
+    };
+
+    use crate as bevy_state;
+    use crate::prelude::*;
+
+    #[derive(States, PartialEq, Eq, Debug, Default, Hash, Clone)]
+    enum AppState {
+        #[default]
+        MainMenu,
+        Playing {
+            paused: bool,
+        },
+    }
+
+    fn bar(mut commands: Commands) {
+        commands.spawn_empty();
+    }
+
+    fn foo(mut commands: Commands) {
+        commands.spawn_empty();
+    }
+
+    #[test]
+    fn add_systems_should_create_schedule_if_it_does_not_exist() {
+        let mut app = App::new();
+        app.add_plugins(StatesPlugin)
+            .init_state::<AppState>()
+            .add_systems(OnEnter(AppState::MainMenu), (foo, bar));
+
+        app.world.run_schedule(OnEnter(AppState::MainMenu));
+        assert_eq!(app.world.entities().len(), 2);
+    }
+
+    #[test]
+    fn add_systems_should_create_schedule_if_it_does_not_exist2() {
+        let mut app = App::new();
+        app.add_plugins(StatesPlugin)
+            .add_systems(OnEnter(AppState::MainMenu), (foo, bar))
+            .init_state::<AppState>();
+
+        app.world.run_schedule(OnEnter(AppState::MainMenu));
+        assert_eq!(app.world.entities().len(), 2);
+    }
+
+    #[derive(PartialEq, Eq, Debug, Hash, Clone)]
+    struct InGame;
+
+    impl ComputedStates for InGame {
+        type SourceStates = AppState;
+
+        fn compute(sources: AppState) -> Option<Self> {
+            matches!(sources, AppState::Playing { .. }).then_some(InGame)
+        }
+    }
+
+    #[derive(SubStates, PartialEq, Eq, Debug, Default, Hash, Clone)]
+    #[source(AppState = AppState::Playing { paused: false })]
+    enum Turn {
+        #[default]
+        Player,
+        Enemy,
+    }
+
+    #[test]
+    fn computed_and_sub_states_follow_their_source() {
+        let mut app = App::new();
+        app.add_plugins(StatesPlugin)
+            .init_state::<AppState>()
+            .add_computed_state::<InGame>()
+            .add_sub_state::<Turn>()
+            .add_systems(OnEnter(InGame), foo);
+
+        app.update();
+        assert!(!app.world.contains_resource::<State<InGame>>());
+        assert!(!app.world.contains_resource::<State<Turn>>());
+
+        app.world
+            .resource_mut::<NextState<AppState>>()
+            .set(AppState::Playing { paused: false });
+        app.update();
+        assert_eq!(*app.world.resource::<State<InGame>>().get(), InGame);
+        assert_eq!(*app.world.resource::<State<Turn>>().get(), Turn::Player);
+        assert_eq!(app.world.entities().len(), 1);
+
+        app.world.resource_mut::<NextState<Turn>>().set(Turn::Enemy);
+        app.update();
+        assert_eq!(*app.world.resource::<State<Turn>>().get(), Turn::Enemy);
+
+        // Pausing keeps the computed state, so `OnEnter(InGame)` doesn't run again.
+        app.world
+            .resource_mut::<NextState<AppState>>()
+            .set(AppState::Playing { paused: true });
+        app.update();
+        assert!(app.world.contains_resource::<State<InGame>>());
+        assert!(!app.world.contains_resource::<State<Turn>>());
+        assert_eq!(app.world.entities().len(), 1);
+    }
+
+    #[test]
+    fn state_scoped_entities_are_despawned_after_exit() {
+        let mut app = App::new();
+        app.add_plugins(StatesPlugin)
+            .init_state::<AppState>()
+            .enable_state_scoped_entities::<AppState>();
+
+        let menu = app.world.spawn(StateScoped(AppState::MainMenu)).id();
+        let unscoped = app.world.spawn_empty().id();
+
+        // Exit systems can still see the entities scoped to the state being exited.
+        app.add_systems(
+            OnExit(AppState::MainMenu),
+            |query: Query<&StateScoped<AppState>>| assert_eq!(query.iter().len(), 1),
+        );
+
+        app.update();
+        assert!(app.world.get_entity(menu).is_some());
+
+        app.world
+            .resource_mut::<NextState<AppState>>()
+            .set(AppState::Playing { paused: false });
+        app.update();
+        assert!(app.world.get_entity(menu).is_none());
+        assert!(app.world.get_entity(unscoped).is_some());
+    }
+
+    #[test]
+    fn disabled_state_scoped_entities_are_despawned_after_exit() {
+        let mut app = App::new();
+        app.add_plugins(StatesPlugin)
+            .init_state::<AppState>()
+            .enable_state_scoped_entities::<AppState>();
+
+        let menu = app
+            .world
+            .spawn((StateScoped(AppState::MainMenu), Disabled))
+            .id();
+        app.update();
+
+        app.world
+            .resource_mut::<NextState<AppState>>()
+            .set(AppState::Playing { paused: false });
+        app.update();
+        assert!(app.world.get_entity(menu).is_none());
+    }
+}
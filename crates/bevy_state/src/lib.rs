@@ -0,0 +1,47 @@
+//! In Bevy, states are app-wide interdependent, finite state machines that are generally used to model the large scale structure of your program: whether a game is paused, if the player is in combat, if assets are loaded and so on.
+//!
+//! This crate provides 3 distinct types of state, all of which implement the [`States`](state::States) trait:
+//!
+//! - Standard [`States`](state::States) can only be changed by manually setting the [`NextState<S>`](state::NextState) resource.
+//!   These states are the baseline on which the other state types are built, and can be used on
+//!   their own for many simple patterns. See the [state example](https://github.com/bevyengine/bevy/blob/latest/examples/ecs/state.rs)
+//!   for a simple use case.
+//! - [`SubStates`](state::SubStates) are children of other states - they can be changed manually using [`NextState<S>`](state::NextState),
+//!   but are removed from the [`World`](bevy_ecs::prelude::World) if the source states aren't in the right state.
+//! - [`ComputedStates`](state::ComputedStates) are fully derived from other states - they provide a [`compute`](state::ComputedStates::compute) method
+//!   that takes in the source states and returns their derived value.
+//!
+//! The [`StatesPlugin`](app::StatesPlugin) runs state transitions in the [`StateTransition`](state::StateTransition) schedule,
+//! and the [`AppExtStates`](app::AppExtStates) trait registers states with an [`App`](bevy_app::App).
+//!
+//! Entities can be tied to the lifetime of a state with the [`StateScoped`](state_scoped::StateScoped) component:
+//! they are despawned when the state they are scoped to is exited.
+#![cfg_attr(docsrs, feature(doc_auto_cfg))]
+
+#[cfg(feature = "bevy_app")]
+/// Provides [`App`](bevy_app::App) with state installation methods
+pub mod app;
+/// Provides definitions for the runtime conditions that interact with the state system
+pub mod condition;
+/// Provides definitions for the basic traits required by the state system
+pub mod state;
+
+/// Provides [`StateScoped`](crate::state_scoped::StateScoped) and
+/// [`clear_state_scoped_entities`](crate::state_scoped::clear_state_scoped_entities) for managing lifetime of entities.
+pub mod state_scoped;
+
+/// Most commonly used re-exported types.
+pub mod prelude {
+    #[cfg(feature = "bevy_app")]
+    #[doc(hidden)]
+    pub use crate::app::{AppExtStates, StatesPlugin};
+    #[doc(hidden)]
+    pub use crate::condition::*;
+    #[doc(hidden)]
+    pub use crate::state::{
+        ComputedStates, NextState, OnEnter, OnExit, OnTransition, State, StateSet, StateTransition,
+        StateTransitionEvent, States, SubStates,
+    };
+    #[doc(hidden)]
+    pub use crate::state_scoped::StateScoped;
+}
@@ -0,0 +1,307 @@
+//! In Bevy, states are app-wide interdependent, finite state machines that are generally used to model the large scale structure of your program: whether a game is paused, if the player is in combat, if assets are loaded and so on.
+//!
+//! This module provides 3 distinct types of state, all of which implement the [`States`] trait:
+//!
+//! - Standard [`States`] can only be changed by manually setting the [`NextState<S>`] resource.
+//!   These states are the baseline on which the other state types are built, and can be used on
+//!   their own for many simple patterns. See the [state example](https://github.com/bevyengine/bevy/blob/latest/examples/ecs/state.rs)
+//!   for a simple use case.
+//! - [`SubStates`] are children of other states - they can be changed manually using [`NextState<S>`],
+//!   but are removed from the [`World`](bevy_ecs::world::World) if the source states aren't in the right state.
+//! - [`ComputedStates`] are fully derived from other states - they provide a [`compute`](ComputedStates::compute) method
+//!   that takes in the source states and returns their derived value. They are particularly useful for situations
+//!   where a simplified view of the source states is necessary - such as having an `InAMenu` computed state, derived
+//!   from a source state that defines multiple distinct menus.
+//!
+//! Most of the utilities around state involve running systems during transitions between states, or
+//! determining whether to run certain systems, though they can be used more directly as well. This
+//! makes it easier to transition between menus, add loading screens, pause games, and the more.
+//!
+//! Specifically, Bevy provides the following utilities:
+//!
+//! - 3 Transition Schedules - [`OnEnter<S>`], [`OnExit<S>`] and [`OnTransition<S>`] - which are used
+//!   to trigger systems specifically during matching transitions.
+//! - A [`StateTransitionEvent<S>`] that gets fired when a given state changes.
+//! - The [`in_state<S>`](crate::condition::in_state) and [`state_changed<S>`](crate::condition::state_changed) run conditions - which are used
+//!   to determine whether a system should run based on the current state.
+//!
+//! All transitions happen in the [`StateTransition`] schedule, in the order described by
+//! [`StateTransitionSteps`]: a state is always exited after the states that depend on it,
+//! and entered before them.
+
+mod computed_states;
+mod freely_mutable_state;
+mod resources;
+mod state_set;
+mod states;
+mod sub_states;
+mod transitions;
+
+pub use computed_states::*;
+pub use freely_mutable_state::*;
+pub use resources::*;
+pub use state_set::*;
+pub use states::*;
+pub use sub_states::*;
+pub use transitions::*;
+
+pub use bevy_state_macros::States;
+
+#[cfg(test)]
+mod tests {
+    use bevy_ecs::event::Events;
+    use bevy_ecs::prelude::*;
+    use bevy_ecs::schedule::{InternedScheduleLabel, ScheduleLabel};
+
+    use crate as bevy_state;
+
+    use super::*;
+
+    #[derive(States, PartialEq, Eq, Debug, Default, Hash, Clone)]
+    enum SimpleState {
+        #[default]
+        A,
+        B(bool),
+    }
+
+    #[derive(PartialEq, Eq, Debug, Hash, Clone)]
+    enum TestComputedState {
+        BisTrue,
+        BisFalse,
+    }
+
+    impl ComputedStates for TestComputedState {
+        type SourceStates = Option<SimpleState>;
+
+        fn compute(sources: Option<SimpleState>) -> Option<Self> {
+            sources.and_then(|source| match source {
+                SimpleState::A => None,
+                SimpleState::B(value) => Some(if value { Self::BisTrue } else { Self::BisFalse }),
+            })
+        }
+    }
+
+    fn setup_world() -> World {
+        setup_world_with_startup(None)
+    }
+
+    fn setup_world_with_startup(startup_label: Option<InternedScheduleLabel>) -> World {
+        let mut world = World::new();
+        world.init_resource::<Events<StateTransitionEvent<SimpleState>>>();
+        world.init_resource::<State<SimpleState>>();
+        world.init_resource::<NextState<SimpleState>>();
+        setup_state_transitions_in_world(&mut world, startup_label);
+        world
+    }
+
+    #[test]
+    fn computed_state_with_a_single_source_is_correctly_derived() {
+        let mut world = setup_world();
+        world.init_resource::<Events<StateTransitionEvent<TestComputedState>>>();
+        let mut schedules = world.resource_mut::<Schedules>();
+        let transition = schedules.get_mut(StateTransition).unwrap();
+        SimpleState::register_state(transition);
+        TestComputedState::register_computed_state_systems(transition);
+
+        world.run_schedule(StateTransition);
+        assert_eq!(world.resource::<State<SimpleState>>().0, SimpleState::A);
+        assert!(!world.contains_resource::<State<TestComputedState>>());
+
+        world.insert_resource(NextState(Some(SimpleState::B(true))));
+        world.run_schedule(StateTransition);
+        assert_eq!(
+            world.resource::<State<SimpleState>>().0,
+            SimpleState::B(true)
+        );
+        assert_eq!(
+            world.resource::<State<TestComputedState>>().0,
+            TestComputedState::BisTrue
+        );
+
+        world.insert_resource(NextState(Some(SimpleState::B(false))));
+        world.run_schedule(StateTransition);
+        assert_eq!(
+            world.resource::<State<TestComputedState>>().0,
+            TestComputedState::BisFalse
+        );
+
+        world.insert_resource(NextState(Some(SimpleState::A)));
+        world.run_schedule(StateTransition);
+        assert_eq!(world.resource::<State<SimpleState>>().0, SimpleState::A);
+        assert!(!world.contains_resource::<State<TestComputedState>>());
+    }
+
+    #[derive(SubStates, PartialEq, Eq, Debug, Default, Hash, Clone)]
+    #[source(SimpleState = SimpleState::B(true))]
+    enum SubState {
+        #[default]
+        One,
+        Two,
+    }
+
+    #[test]
+    fn sub_state_exists_only_when_allowed_but_can_be_modified_freely() {
+        let mut world = setup_world();
+        world.init_resource::<Events<StateTransitionEvent<SubState>>>();
+        world.init_resource::<NextState<SubState>>();
+        let mut schedules = world.resource_mut::<Schedules>();
+        let transition = schedules.get_mut(StateTransition).unwrap();
+        SimpleState::register_state(transition);
+        SubState::register_sub_state_systems(transition);
+
+        world.run_schedule(StateTransition);
+        assert!(!world.contains_resource::<State<SubState>>());
+
+        // Setting a sub state that doesn't exist is a no-op.
+        world.insert_resource(NextState(Some(SubState::Two)));
+        world.run_schedule(StateTransition);
+        assert!(!world.contains_resource::<State<SubState>>());
+
+        world.insert_resource(NextState(Some(SimpleState::B(true))));
+        world.run_schedule(StateTransition);
+        assert_eq!(world.resource::<State<SubState>>().0, SubState::One);
+
+        world.insert_resource(NextState(Some(SubState::Two)));
+        world.run_schedule(StateTransition);
+        assert_eq!(world.resource::<State<SubState>>().0, SubState::Two);
+
+        // Leaving the allowed source value removes the sub state, and re-entering resets it.
+        world.insert_resource(NextState(Some(SimpleState::B(false))));
+        world.run_schedule(StateTransition);
+        assert!(!world.contains_resource::<State<SubState>>());
+
+        world.insert_resource(NextState(Some(SimpleState::B(true))));
+        world.run_schedule(StateTransition);
+        assert_eq!(world.resource::<State<SubState>>().0, SubState::One);
+    }
+
+    #[derive(States, PartialEq, Eq, Debug, Default, Hash, Clone)]
+    enum SimpleState2 {
+        #[default]
+        A1,
+        B2,
+    }
+
+    #[derive(PartialEq, Eq, Debug, Hash, Clone)]
+    enum ComplexComputedState {
+        InAAndA1,
+        InTrueBAndB2,
+    }
+
+    impl ComputedStates for ComplexComputedState {
+        type SourceStates = (Option<SimpleState>, Option<SimpleState2>);
+
+        fn compute(sources: (Option<SimpleState>, Option<SimpleState2>)) -> Option<Self> {
+            match sources {
+                (Some(SimpleState::A), Some(SimpleState2::A1)) => Some(Self::InAAndA1),
+                (Some(SimpleState::B(true)), Some(SimpleState2::B2)) => Some(Self::InTrueBAndB2),
+                _ => None,
+            }
+        }
+    }
+
+    #[test]
+    fn complex_computed_state_gets_derived_correctly() {
+        let mut world = setup_world();
+        world.init_resource::<Events<StateTransitionEvent<SimpleState2>>>();
+        world.init_resource::<Events<StateTransitionEvent<ComplexComputedState>>>();
+        world.init_resource::<State<SimpleState2>>();
+        world.init_resource::<NextState<SimpleState2>>();
+        let mut schedules = world.resource_mut::<Schedules>();
+        let transition = schedules.get_mut(StateTransition).unwrap();
+        SimpleState::register_state(transition);
+        SimpleState2::register_state(transition);
+        ComplexComputedState::register_computed_state_systems(transition);
+
+        // The computed state is only derived once one of its sources transitions.
+        world.run_schedule(StateTransition);
+        assert!(!world.contains_resource::<State<ComplexComputedState>>());
+
+        world.insert_resource(NextState(Some(SimpleState::B(true))));
+        world.run_schedule(StateTransition);
+        assert!(!world.contains_resource::<State<ComplexComputedState>>());
+
+        world.insert_resource(NextState(Some(SimpleState2::B2)));
+        world.run_schedule(StateTransition);
+        assert_eq!(
+            world.resource::<State<ComplexComputedState>>().0,
+            ComplexComputedState::InTrueBAndB2
+        );
+
+        world.insert_resource(NextState(Some(SimpleState::A)));
+        world.insert_resource(NextState(Some(SimpleState2::A1)));
+        world.run_schedule(StateTransition);
+        assert_eq!(
+            world.resource::<State<ComplexComputedState>>().0,
+            ComplexComputedState::InAAndA1
+        );
+    }
+
+    #[derive(Resource, Default, Debug)]
+    struct TransitionOrder(Vec<&'static str>);
+
+    #[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+    struct Setup;
+
+    fn record(name: &'static str) -> impl Fn(ResMut<TransitionOrder>) {
+        move |mut order: ResMut<TransitionOrder>| order.0.push(name)
+    }
+
+    #[test]
+    fn transition_schedules_run_in_dependency_order() {
+        let mut world = setup_world_with_startup(Some(Setup.intern()));
+        world.init_resource::<TransitionOrder>();
+        world.init_resource::<Events<StateTransitionEvent<TestComputedState>>>();
+        world.init_resource::<Events<StateTransitionEvent<SubState>>>();
+        world.init_resource::<NextState<SubState>>();
+
+        let mut schedules = world.resource_mut::<Schedules>();
+        let transition = schedules.get_mut(StateTransition).unwrap();
+        SimpleState::register_state(transition);
+        TestComputedState::register_computed_state_systems(transition);
+        SubState::register_sub_state_systems(transition);
+
+        for (label, name) in [
+            (OnExit(SimpleState::A).intern(), "exit simple"),
+            (OnEnter(SimpleState::B(true)).intern(), "enter simple"),
+            (
+                OnEnter(TestComputedState::BisTrue).intern(),
+                "enter computed",
+            ),
+            (OnEnter(SubState::One).intern(), "enter sub"),
+            (OnExit(SimpleState::B(true)).intern(), "exit simple"),
+            (OnEnter(SimpleState::A).intern(), "enter simple"),
+            (OnExit(TestComputedState::BisTrue).intern(), "exit computed"),
+            (OnExit(SubState::One).intern(), "exit sub"),
+        ] {
+            let mut schedule = Schedule::new(label);
+            schedule.add_systems(record(name));
+            schedules.insert(schedule);
+        }
+
+        world.send_event(StateTransitionEvent {
+            exited: None,
+            entered: Some(SimpleState::A),
+        });
+        world.run_schedule(Setup);
+        assert_eq!(world.resource::<TransitionOrder>().0, vec!["enter simple"]);
+        world.resource_mut::<TransitionOrder>().0.clear();
+
+        // Dependent states are entered after their source, in no particular order among themselves.
+        world.insert_resource(NextState(Some(SimpleState::B(true))));
+        world.run_schedule(StateTransition);
+        let order = &world.resource::<TransitionOrder>().0;
+        assert_eq!(order[..2], ["exit simple", "enter simple"]);
+        assert!(order[2..].contains(&"enter computed"));
+        assert!(order[2..].contains(&"enter sub"));
+        world.resource_mut::<TransitionOrder>().0.clear();
+
+        // ...and exited before it.
+        world.insert_resource(NextState(Some(SimpleState::A)));
+        world.run_schedule(StateTransition);
+        let order = &world.resource::<TransitionOrder>().0;
+        assert_eq!(order[2..], ["exit simple", "enter simple"]);
+        assert!(order[..2].contains(&"exit computed"));
+        assert!(order[..2].contains(&"exit sub"));
+    }
+}
@@ -0,0 +1,143 @@
+use std::ops::Deref;
+
+use bevy_ecs::change_detection::DetectChangesMut;
+#[cfg(feature = "bevy_reflect")]
+use bevy_ecs::reflect::ReflectResource;
+use bevy_ecs::system::{ResMut, Resource};
+use bevy_ecs::world::{FromWorld, World};
+#[cfg(feature = "bevy_reflect")]
+use bevy_reflect::std_traits::ReflectDefault;
+
+use super::{freely_mutable_state::FreelyMutableState, states::States};
+
+/// A finite-state machine whose transitions have associated schedules
+/// ([`OnEnter(state)`](super::OnEnter) and [`OnExit(state)`](super::OnExit)).
+///
+/// The current state value can be accessed through this resource. To *change* the state,
+/// queue a transition in the [`NextState<S>`] resource, and it will be applied during the
+/// [`StateTransition`](super::StateTransition) schedule.
+///
+/// The starting state is defined via the [`Default`] implementation for `S`.
+///
+/// ```
+/// use bevy_ecs::prelude::*;
+/// use bevy_state::prelude::*;
+///
+/// #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default, States)]
+/// enum GameState {
+///     #[default]
+///     MainMenu,
+///     SettingsMenu,
+///     InGame,
+/// }
+///
+/// fn game_logic(game_state: Res<State<GameState>>) {
+///     match game_state.get() {
+///         GameState::InGame => {
+///             // Run game logic here...
+///         },
+///         _ => {},
+///     }
+/// }
+/// ```
+#[derive(Resource, Debug)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(bevy_reflect::Reflect),
+    reflect(Resource)
+)]
+pub struct State<S: States>(pub(crate) S);
+
+impl<S: States> State<S> {
+    /// Creates a new state with a specific value.
+    ///
+    /// To change the state use [`NextState<S>`] rather than using this to modify the `State<S>`.
+    pub fn new(state: S) -> Self {
+        Self(state)
+    }
+
+    /// Get the current state.
+    pub fn get(&self) -> &S {
+        &self.0
+    }
+}
+
+impl<S: States + FromWorld> FromWorld for State<S> {
+    fn from_world(world: &mut World) -> Self {
+        Self(S::from_world(world))
+    }
+}
+
+impl<S: States> PartialEq<S> for State<S> {
+    fn eq(&self, other: &S) -> bool {
+        self.get() == other
+    }
+}
+
+impl<S: States> Deref for State<S> {
+    type Target = S;
+
+    fn deref(&self) -> &Self::Target {
+        self.get()
+    }
+}
+
+/// The next state of [`State<S>`].
+///
+/// To queue a transition, just set the contained value to `Some(next_state)`.
+/// Note that these transitions can be overridden by other systems:
+/// only the actual value of this resource at the time of the [`StateTransition`](super::StateTransition) schedule matters.
+///
+/// ```
+/// use bevy_ecs::prelude::*;
+/// use bevy_state::prelude::*;
+///
+/// #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default, States)]
+/// enum GameState {
+///     #[default]
+///     MainMenu,
+///     SettingsMenu,
+///     InGame,
+/// }
+///
+/// fn start_game(mut next_game_state: ResMut<NextState<GameState>>) {
+///     next_game_state.set(GameState::InGame);
+/// }
+/// ```
+#[derive(Resource, Debug)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(bevy_reflect::Reflect),
+    reflect(Resource, Default)
+)]
+pub struct NextState<S: FreelyMutableState>(pub Option<S>);
+
+impl<S: FreelyMutableState> Default for NextState<S> {
+    fn default() -> Self {
+        Self(None)
+    }
+}
+
+impl<S: FreelyMutableState> NextState<S> {
+    /// Tentatively set a planned state transition to `Some(state)`.
+    pub fn set(&mut self, state: S) {
+        self.0 = Some(state);
+    }
+
+    /// Remove any planned changes to [`State<S>`]
+    pub fn reset(&mut self) {
+        self.0 = None;
+    }
+}
+
+/// Takes the queued state out of [`NextState<S>`], only marking it as changed if it wasn't empty.
+pub(crate) fn take_next_state<S: FreelyMutableState>(
+    next_state: Option<ResMut<NextState<S>>>,
+) -> Option<S> {
+    let mut next_state = next_state?;
+    let taken = next_state.bypass_change_detection().0.take();
+    if taken.is_some() {
+        next_state.set_changed();
+    }
+    taken
+}
@@ -0,0 +1,110 @@
+use bevy_ecs::schedule::Schedule;
+
+use super::{freely_mutable_state::FreelyMutableState, state_set::StateSet, states::States};
+pub use bevy_state_macros::SubStates;
+
+/// A sub-state is a state that exists only when the source state meet certain conditions,
+/// but unlike [`ComputedStates`](crate::state::ComputedStates) - while they exist they can be manually modified.
+///
+/// The [`StateSet`] is passed into the `should_exist` method whenever one of them changes, and the
+/// result is used to determine the existence of [`State<Self>`](crate::state::State).
+///
+/// If the result is [`None`], the [`State<Self>`](crate::state::State) resource will be removed from the world,
+/// otherwise if the [`State<Self>`](crate::state::State) resource doesn't exist
+/// it will be created with the [`Some`] value.
+///
+/// Sub-states are registered with `App::add_sub_state::<S>()` and can be changed through
+/// [`NextState<S>`](crate::state::NextState) while they exist.
+///
+/// Most use cases are covered by the derive macro, which creates the state as its [`Default`]
+/// value whenever the source state matches the given pattern:
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_state::prelude::*;
+///
+/// #[derive(States, Clone, PartialEq, Eq, Hash, Debug, Default)]
+/// enum AppState {
+///     #[default]
+///     Menu,
+///     InGame
+/// }
+///
+///
+/// #[derive(SubStates, Clone, PartialEq, Eq, Hash, Debug, Default)]
+/// // This macro means that `GamePhase` will only exist when we're in the `InGame` state.
+/// #[source(AppState = AppState::InGame)]
+/// enum GamePhase {
+///     #[default]
+///     Setup,
+///     Battle,
+///     Conclusion
+/// }
+/// ```
+///
+/// The same can be written by hand, which allows more complex conditions:
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_state::prelude::*;
+/// # use bevy_state::state::{FreelyMutableState, StateSet};
+///
+/// #[derive(States, Clone, PartialEq, Eq, Hash, Debug, Default)]
+/// enum AppState {
+///     #[default]
+///     Menu,
+///     InGame { paused: bool }
+/// }
+///
+/// #[derive(Clone, PartialEq, Eq, Hash, Debug, Default)]
+/// enum GamePhase {
+///     #[default]
+///     Setup,
+///     Battle,
+///     Conclusion
+/// }
+///
+/// impl SubStates for GamePhase {
+///     /// We set the source state to be the state, or set of states,
+///     /// we want to depend on. Any of them can be wrapped in an Option.
+///     type SourceStates = Option<AppState>;
+///
+///     /// We then define the `should_exist` function, which takes in the source states
+///     /// and returns the value the sub-state should take when it is created,
+///     /// or `None` if it should not exist.
+///     fn should_exist(sources: Option<AppState>) -> Option<Self> {
+///         match sources {
+///             Some(AppState::InGame { .. }) => Some(GamePhase::Setup),
+///             _ => None
+///         }
+///     }
+/// }
+///
+/// impl States for GamePhase {
+///     const DEPENDENCY_DEPTH: usize = <GamePhase as SubStates>::SourceStates::SET_DEPENDENCY_DEPTH + 1;
+/// }
+///
+/// impl FreelyMutableState for GamePhase {}
+/// ```
+pub trait SubStates: States + FreelyMutableState {
+    /// The set of states from which the [`Self`] is derived.
+    ///
+    /// This can either be a single type that implements [`States`], or a tuple
+    /// containing multiple types that implement [`States`], or any combination of
+    /// types implementing [`States`] and Options of types implementing [`States`]
+    type SourceStates: StateSet;
+
+    /// This function gets called whenever one of the [`SourceStates`](Self::SourceStates) changes.
+    /// The result is used to determine the existence of [`State<Self>`](crate::state::State).
+    ///
+    /// If the result is [`None`], the [`State<Self>`](crate::state::State) resource will be removed from the world,
+    /// otherwise if the [`State<Self>`](crate::state::State) resource doesn't exist it will be created with the [`Some`] value.
+    fn should_exist(sources: Self::SourceStates) -> Option<Self>;
+
+    /// This function sets up systems that compute the state whenever one of the [`SourceStates`](Self::SourceStates)
+    /// change. It is called by `App::add_sub_state`, but can be called manually if `App` is not
+    /// used.
+    fn register_sub_state_systems(schedule: &mut Schedule) {
+        Self::SourceStates::register_sub_state_systems_in_schedule::<Self>(schedule);
+    }
+}
@@ -0,0 +1,247 @@
+use std::{marker::PhantomData, mem};
+
+use bevy_ecs::event::{Event, EventReader, EventWriter};
+use bevy_ecs::schedule::{
+    InternedScheduleLabel, IntoSystemSetConfigs, Schedule, ScheduleLabel, Schedules, SystemSet,
+};
+use bevy_ecs::system::{Commands, In, ResMut};
+use bevy_ecs::world::World;
+
+use super::{resources::State, states::States};
+
+/// The label of a [`Schedule`](bevy_ecs::schedule::Schedule) that **only** runs whenever [`State<S>`]
+/// enters this state.
+#[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct OnEnter<S: States>(pub S);
+
+/// The label of a [`Schedule`](bevy_ecs::schedule::Schedule) that **only** runs whenever [`State<S>`]
+/// exits this state.
+#[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct OnExit<S: States>(pub S);
+
+/// The label of a [`Schedule`](bevy_ecs::schedule::Schedule) that **only** runs whenever [`State<S>`]
+/// exits the `from` state, AND enters the `to` state.
+///
+/// Systems added to this schedule are always ran *after* [`OnExit`], and *before* [`OnEnter`].
+#[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct OnTransition<S: States> {
+    /// The state being exited.
+    pub from: S,
+    /// The state being entered.
+    pub to: S,
+}
+
+/// Runs [state transitions](States).
+#[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct StateTransition;
+
+/// Event sent when any state transition of `S` happens.
+///
+/// If you know exactly what state you want to respond to ahead of time, consider [`OnEnter`], [`OnTransition`], or [`OnExit`]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Event)]
+pub struct StateTransitionEvent<S: States> {
+    /// the state we were in before, or `None` if the state didn't exist
+    pub exited: Option<S>,
+    /// the state we're in now, or `None` if the state was removed
+    pub entered: Option<S>,
+}
+
+/// The steps of the [`StateTransition`] schedule, which run in order.
+///
+/// Systems of a state are placed in these sets according to its position in the dependency graph,
+/// so that a state's dependents are updated after it, exited before it and entered after it.
+#[derive(SystemSet, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum StateTransitionSteps {
+    /// States apply their transitions from [`NextState`](super::NextState)
+    /// and compute functions based on their parent states.
+    DependentTransitions,
+    /// Exit schedules are executed in leaf to root order.
+    ExitSchedules,
+    /// Transition schedules are executed in arbitrary order.
+    TransitionSchedules,
+    /// Enter schedules are executed in root to leaf order.
+    EnterSchedules,
+}
+
+/// System set that applies the transition of state `S`.
+#[derive(SystemSet, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ApplyStateTransition<S: States>(PhantomData<S>);
+
+impl<S: States> Default for ApplyStateTransition<S> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+/// System set that runs the exit schedule(s) of state `S`.
+#[derive(SystemSet, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ExitSchedules<S: States>(PhantomData<S>);
+
+impl<S: States> Default for ExitSchedules<S> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+/// System set that runs the transition schedule(s) of state `S`.
+#[derive(SystemSet, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct TransitionSchedules<S: States>(PhantomData<S>);
+
+impl<S: States> Default for TransitionSchedules<S> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+/// System set that runs the enter schedule(s) of state `S`.
+#[derive(SystemSet, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct EnterSchedules<S: States>(PhantomData<S>);
+
+impl<S: States> Default for EnterSchedules<S> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+/// Creates the [`StateTransition`] schedule with its [`StateTransitionSteps`], if it doesn't exist yet.
+///
+/// When `startup_label` is provided, the schedule is also run once from it, so the
+/// [`OnEnter`] schedules of initial states run before the first regular [`StateTransition`].
+pub fn setup_state_transitions_in_world(
+    world: &mut World,
+    startup_label: Option<InternedScheduleLabel>,
+) {
+    let mut schedules = world.get_resource_or_insert_with(Schedules::default);
+    if schedules.contains(StateTransition) {
+        return;
+    }
+    let mut schedule = Schedule::new(StateTransition);
+    schedule.configure_sets(
+        (
+            StateTransitionSteps::DependentTransitions,
+            StateTransitionSteps::ExitSchedules,
+            StateTransitionSteps::TransitionSchedules,
+            StateTransitionSteps::EnterSchedules,
+        )
+            .chain(),
+    );
+    schedules.insert(schedule);
+
+    if let Some(startup) = startup_label {
+        if !schedules.contains(startup) {
+            schedules.insert(Schedule::new(startup));
+        }
+        if let Some(schedule) = schedules.get_mut(startup) {
+            schedule.add_systems(|world: &mut World| {
+                let _ = world.try_run_schedule(StateTransition);
+            });
+        }
+    }
+}
+
+/// Applies the new value of state `S`, or removes it when `new_state` is `None`,
+/// and sends the matching [`StateTransitionEvent`].
+///
+/// The exit, transition and enter schedules are run later, by the systems registered in the
+/// [`StateTransitionSteps::ExitSchedules`], [`StateTransitionSteps::TransitionSchedules`]
+/// and [`StateTransitionSteps::EnterSchedules`] sets.
+pub(crate) fn internal_apply_state_transition<S: States>(
+    mut event: EventWriter<StateTransitionEvent<S>>,
+    mut commands: Commands,
+    current_state: Option<ResMut<State<S>>>,
+    new_state: Option<S>,
+) {
+    match new_state {
+        Some(entered) => {
+            match current_state {
+                // If the [`State<S>`] resource exists, and the state is not the one we are
+                // entering - we need to set the new value and send a transition event.
+                Some(mut state_resource) => {
+                    if *state_resource != entered {
+                        let exited = mem::replace(&mut state_resource.0, entered.clone());
+
+                        event.send(StateTransitionEvent {
+                            exited: Some(exited),
+                            entered: Some(entered),
+                        });
+                    }
+                }
+                None => {
+                    // If the [`State<S>`] resource does not exist, we create it and send a transition event.
+                    commands.insert_resource(State(entered.clone()));
+
+                    event.send(StateTransitionEvent {
+                        exited: None,
+                        entered: Some(entered),
+                    });
+                }
+            };
+        }
+        None => {
+            // We first remove the [`State<S>`] resource, and if one existed we send a transition event.
+            if let Some(resource) = current_state {
+                commands.remove_resource::<State<S>>();
+
+                event.send(StateTransitionEvent {
+                    exited: Some(resource.get().clone()),
+                    entered: None,
+                });
+            }
+        }
+    }
+}
+
+/// Returns the latest state transition event of type `S`, if any are available.
+pub fn last_transition<S: States>(
+    mut reader: EventReader<StateTransitionEvent<S>>,
+) -> Option<StateTransitionEvent<S>> {
+    reader.read().last().cloned()
+}
+
+pub(crate) fn run_enter<S: States>(
+    In(transition): In<Option<StateTransitionEvent<S>>>,
+    world: &mut World,
+) {
+    let Some(transition) = transition else {
+        return;
+    };
+    let Some(entered) = transition.entered else {
+        return;
+    };
+
+    let _ = world.try_run_schedule(OnEnter(entered));
+}
+
+pub(crate) fn run_exit<S: States>(
+    In(transition): In<Option<StateTransitionEvent<S>>>,
+    world: &mut World,
+) {
+    let Some(transition) = transition else {
+        return;
+    };
+    let Some(exited) = transition.exited else {
+        return;
+    };
+
+    let _ = world.try_run_schedule(OnExit(exited));
+}
+
+pub(crate) fn run_transition<S: States>(
+    In(transition): In<Option<StateTransitionEvent<S>>>,
+    world: &mut World,
+) {
+    let Some(transition) = transition else {
+        return;
+    };
+    let Some(exited) = transition.exited else {
+        return;
+    };
+    let Some(entered) = transition.entered else {
+        return;
+    };
+
+    let _ = world.try_run_schedule(OnTransition {
+        from: exited,
+        to: entered,
+    });
+}
@@ -0,0 +1,91 @@
+#[cfg(feature = "bevy_reflect")]
+use bevy_ecs::reflect::ReflectComponent;
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    entity_disabling::Disabled,
+    event::EventReader,
+    query::Has,
+    system::{Commands, Query},
+};
+use bevy_hierarchy::DespawnRecursiveExt;
+#[cfg(feature = "bevy_reflect")]
+use bevy_reflect::prelude::*;
+
+use crate::state::{StateTransitionEvent, States};
+
+/// Entities marked with this component will be removed
+/// when the world's state of the matching type no longer matches the supplied value.
+///
+/// To enable this feature remember to configure your application
+/// with [`enable_state_scoped_entities`](crate::app::AppExtStates::enable_state_scoped_entities) on your state(s) of choice.
+///
+/// The despawn is recursive, so the children of a scoped entity are removed along with it.
+/// [`Disabled`] entities are despawned as well.
+///
+/// ```
+/// use bevy_state::prelude::*;
+/// use bevy_ecs::prelude::*;
+///
+/// #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default, States)]
+/// enum GameState {
+///     #[default]
+///     MainMenu,
+///     SettingsMenu,
+///     InGame,
+/// }
+///
+/// # #[derive(Component)]
+/// # struct Player;
+///
+/// fn spawn_player(mut commands: Commands) {
+///     commands.spawn((
+///         StateScoped(GameState::InGame),
+///         Player
+///     ));
+/// }
+///
+/// # #[cfg(feature = "bevy_app")]
+/// # {
+/// # let mut app = bevy_app::App::new();
+/// # app.add_plugins(StatesPlugin);
+/// app.init_state::<GameState>();
+/// app.enable_state_scoped_entities::<GameState>();
+/// app.add_systems(OnEnter(GameState::InGame), spawn_player);
+/// # }
+/// ```
+#[derive(Component, Clone)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Component))]
+pub struct StateScoped<S: States>(pub S);
+
+/// Removes entities marked with [`StateScoped<S>`]
+/// when their state no longer matches the world state.
+///
+/// This system runs in the [`StateTransition`](crate::state::StateTransition) schedule,
+/// after the [`OnExit`](crate::state::OnExit) schedule of the exited state,
+/// so that exit systems can still access the scoped entities,
+/// and before any [`OnTransition`](crate::state::OnTransition) or [`OnEnter`](crate::state::OnEnter) schedules.
+pub fn clear_state_scoped_entities<S: States>(
+    mut commands: Commands,
+    mut transitions: EventReader<StateTransitionEvent<S>>,
+    // `Has<Disabled>` opts out of the default query filters, so disabled entities are cleaned up too.
+    query: Query<(Entity, &StateScoped<S>, Has<Disabled>)>,
+) {
+    // We use the latest event, because state machine internals generate at most 1
+    // transition event (per type) each frame. No event means no change happened
+    // and we skip iterating all entities.
+    let Some(transition) = transitions.read().last() else {
+        return;
+    };
+    if transition.entered == transition.exited {
+        return;
+    }
+    let Some(exited) = &transition.exited else {
+        return;
+    };
+    for (entity, binding, _) in &query {
+        if binding.0 == *exited {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
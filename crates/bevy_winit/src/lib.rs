@@ -189,6 +189,8 @@ struct WinitAppRunnerState {
     last_update: Instant,
     /// Number of "forced" updates to trigger on application start
     startup_forced_updates: u32,
+    /// The exit requested by the app, once the event loop has been asked to stop.
+    app_exit: Option<AppExit>,
 }
 
 impl WinitAppRunnerState {
@@ -229,6 +231,7 @@ impl Default for WinitAppRunnerState {
             last_update: Instant::now(),
             // 3 seems to be enough, 5 is a safe margin
             startup_forced_updates: 5,
+            app_exit: None,
         }
     }
 }
@@ -257,7 +260,10 @@ type UserEvent = RequestRedraw;
 ///
 /// Overriding the app's [runner](bevy_app::App::runner) while using `WinitPlugin` will bypass the
 /// `EventLoop`.
-pub fn winit_runner(mut app: App) {
+///
+/// Returns the [`AppExit`] that stopped the event loop, or an error if the event loop stopped
+/// on its own.
+pub fn winit_runner(mut app: App) -> AppExit {
     if app.plugins_state() == PluginsState::Ready {
         app.finish();
         app.cleanup();
@@ -274,7 +280,6 @@ pub fn winit_runner(mut app: App) {
     let mut runner_state = WinitAppRunnerState::default();
 
     // prepare structures to access data in the world
-    let mut app_exit_event_reader = ManualEventReader::<AppExit>::default();
     let mut redraw_event_reader = ManualEventReader::<RequestRedraw>::default();
 
     let mut focused_windows_state: SystemState<(Res<WinitSettings>, Query<&Window>)> =
@@ -291,11 +296,11 @@ pub fn winit_runner(mut app: App) {
         SystemState::<CreateWindowParams<Added<Window>>>::from_world(&mut app.world);
     let mut winit_events = Vec::default();
     // set up the event loop
+    let runner_state_ref = &mut runner_state;
     let event_handler = move |event, event_loop: &EventLoopWindowTarget<UserEvent>| {
         handle_winit_event(
             &mut app,
-            &mut app_exit_event_reader,
-            &mut runner_state,
+            runner_state_ref,
             &mut create_window,
             &mut event_writer_system_state,
             &mut focused_windows_state,
@@ -311,12 +316,17 @@ pub fn winit_runner(mut app: App) {
     if let Err(err) = event_loop.run(event_handler) {
         error!("winit event loop returned an error: {err}");
     }
+
+    // The event loop is only expected to stop after the app requested it through an `AppExit`.
+    runner_state.app_exit.unwrap_or_else(|| {
+        error!("winit event loop stopped without an `AppExit` being sent");
+        AppExit::error()
+    })
 }
 
 #[allow(clippy::too_many_arguments /* TODO: probs can reduce # of args */)]
 fn handle_winit_event(
     app: &mut App,
-    app_exit_event_reader: &mut ManualEventReader<AppExit>,
     runner_state: &mut WinitAppRunnerState,
     create_window: &mut SystemState<CreateWindowParams<Added<Window>>>,
     event_writer_system_state: &mut SystemState<(
@@ -344,11 +354,10 @@ fn handle_winit_event(
         }
         runner_state.redraw_requested = true;
 
-        if let Some(app_exit_events) = app.world.get_resource::<Events<AppExit>>() {
-            if app_exit_event_reader.read(app_exit_events).last().is_some() {
-                event_loop.exit();
-                return;
-            }
+        if let Some(app_exit) = app.should_exit() {
+            runner_state.app_exit = Some(app_exit);
+            event_loop.exit();
+            return;
         }
     }
 
@@ -402,7 +411,6 @@ fn handle_winit_event(
                         focused_windows_state,
                         event_loop,
                         create_window,
-                        app_exit_event_reader,
                         redraw_event_reader,
                         winit_events,
                     );
@@ -629,7 +637,6 @@ fn handle_winit_event(
                         focused_windows_state,
                         event_loop,
                         create_window,
-                        app_exit_event_reader,
                         redraw_event_reader,
                         winit_events,
                     );
@@ -729,7 +736,6 @@ fn run_app_update_if_should(
     focused_windows_state: &mut SystemState<(Res<WinitSettings>, Query<&Window>)>,
     event_loop: &EventLoopWindowTarget<UserEvent>,
     create_window: &mut SystemState<CreateWindowParams<Added<Window>>>,
-    app_exit_event_reader: &mut ManualEventReader<AppExit>,
     redraw_event_reader: &mut ManualEventReader<RequestRedraw>,
     winit_events: &mut Vec<WinitEvent>,
 ) {
@@ -787,10 +793,9 @@ fn run_app_update_if_should(
             }
         }
 
-        if let Some(app_exit_events) = app.world.get_resource::<Events<AppExit>>() {
-            if app_exit_event_reader.read(app_exit_events).last().is_some() {
-                event_loop.exit();
-            }
+        if let Some(app_exit) = app.should_exit() {
+            runner_state.app_exit = Some(app_exit);
+            event_loop.exit();
         }
     }
 
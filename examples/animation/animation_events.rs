@@ -0,0 +1,125 @@
+//! Triggers events from an animation clip when playback crosses their time.
+
+use bevy::animation::{AnimationTarget, AnimationTargetId, ReflectAnimationEvent};
+use bevy::prelude::*;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .register_type::<MessageEvent>()
+        .add_systems(Startup, setup)
+        .add_systems(Update, fade_message)
+        .run();
+}
+
+/// An event attached to the animation clip.
+///
+/// Animation events are stored through reflection, so that clips with events
+/// can be serialized. Their type must be registered with `ReflectAnimationEvent`.
+#[derive(Event, Reflect, Clone)]
+#[reflect(AnimationEvent)]
+struct MessageEvent {
+    text: String,
+    color: Color,
+}
+
+#[derive(Component)]
+struct MessageText;
+
+fn setup(
+    mut commands: Commands,
+    mut animations: ResMut<Assets<AnimationClip>>,
+    mut graphs: ResMut<Assets<AnimationGraph>>,
+) {
+    commands.spawn(Camera2dBundle::default());
+
+    commands.spawn((
+        TextBundle::from_section(
+            "",
+            TextStyle {
+                font_size: 60.0,
+                ..default()
+            },
+        )
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            top: Val::Px(12.0),
+            left: Val::Px(12.0),
+            ..default()
+        }),
+        MessageText,
+    ));
+
+    // The sprite bounces from one side of the screen to the other.
+    let name = Name::new("sprite");
+    let target_id = AnimationTargetId::from_name(&name);
+    let mut animation = AnimationClip::default();
+    animation.add_curve_to_target(
+        target_id,
+        VariableCurve {
+            keyframe_timestamps: vec![0.0, 1.0, 2.0],
+            keyframes: Keyframes::Translation(vec![
+                Vec3::new(-300.0, 0.0, 0.0),
+                Vec3::new(300.0, 0.0, 0.0),
+                Vec3::new(-300.0, 0.0, 0.0),
+            ]),
+            interpolation: Interpolation::Linear,
+        },
+    );
+
+    // Events are triggered on the `AnimationPlayer` entity each time playback
+    // crosses their time, even if a frame takes longer than usual.
+    animation.add_event(
+        1.0,
+        MessageEvent {
+            text: "Bounce right!".to_string(),
+            color: Color::srgb(1.0, 0.5, 0.5),
+        },
+    );
+    animation.add_event(
+        2.0,
+        MessageEvent {
+            text: "Bounce left!".to_string(),
+            color: Color::srgb(0.5, 0.5, 1.0),
+        },
+    );
+
+    let (graph, animation_index) = AnimationGraph::from_clip(animations.add(animation));
+    let mut player = AnimationPlayer::default();
+    player.play(animation_index).repeat();
+
+    let player_entity = commands
+        .spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    custom_size: Some(Vec2::splat(100.0)),
+                    ..default()
+                },
+                ..default()
+            },
+            name,
+            graphs.add(graph),
+            player,
+        ))
+        .observe(show_message)
+        .id();
+
+    commands.entity(player_entity).insert(AnimationTarget {
+        id: target_id,
+        player: player_entity,
+    });
+}
+
+fn show_message(trigger: Trigger<MessageEvent>, mut text: Query<&mut Text, With<MessageText>>) {
+    let event = trigger.event();
+    let mut text = text.single_mut();
+    text.sections[0].value.clone_from(&event.text);
+    text.sections[0].style.color = event.color;
+}
+
+fn fade_message(time: Res<Time>, mut text: Query<&mut Text, With<MessageText>>) {
+    let mut text = text.single_mut();
+    let color = &mut text.sections[0].style.color;
+    let alpha = color.alpha();
+    color.set_alpha((alpha - time.delta_seconds()).max(0.0));
+}
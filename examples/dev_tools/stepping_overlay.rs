@@ -0,0 +1,84 @@
+//! Showcase the stepping overlay, which steps through systems one at a time.
+
+use bevy::{
+    dev_tools::stepping::{SteppingControl, SteppingPlugin},
+    prelude::*,
+};
+
+fn main() {
+    App::new()
+        .add_plugins((
+            DefaultPlugins,
+            SteppingPlugin::default()
+                .add_schedule(Update)
+                .add_schedule(FixedUpdate)
+                .at(Val::Percent(35.0), Val::Percent(30.0)),
+        ))
+        .add_systems(Startup, setup)
+        .add_systems(Update, (spin, pulse, enable_on_start))
+        .add_systems(FixedUpdate, drift)
+        .run();
+}
+
+#[derive(Component)]
+struct Shape;
+
+fn setup(mut commands: Commands) {
+    // We need to spawn camera to see overlay
+    commands.spawn(Camera2dBundle::default());
+    commands.spawn((
+        Shape,
+        SpriteBundle {
+            sprite: Sprite {
+                color: Color::srgb(0.3, 0.6, 0.9),
+                custom_size: Some(Vec2::splat(100.0)),
+                ..default()
+            },
+            ..default()
+        },
+    ));
+    commands.spawn(
+        TextBundle::from_section(
+            "Press ` to toggle stepping, S to step, Space to continue.\n\
+             Use the arrow keys to select a system, B to toggle a breakpoint \
+             and X to skip it.",
+            TextStyle {
+                font_size: 20.0,
+                ..default()
+            },
+        )
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(12.0),
+            left: Val::Px(12.0),
+            ..default()
+        }),
+    );
+}
+
+// Stepping can also be driven without a keyboard, by sending `SteppingControl` events.
+fn enable_on_start(mut controls: EventWriter<SteppingControl>, mut done: Local<bool>) {
+    if !*done {
+        controls.send(SteppingControl::Enable);
+        *done = true;
+    }
+}
+
+fn spin(time: Res<Time>, mut shapes: Query<&mut Transform, With<Shape>>) {
+    for mut transform in &mut shapes {
+        transform.rotate_z(time.delta_seconds());
+    }
+}
+
+fn pulse(time: Res<Time>, mut shapes: Query<&mut Sprite, With<Shape>>) {
+    for mut sprite in &mut shapes {
+        let t = time.elapsed_seconds().sin() * 0.5 + 0.5;
+        sprite.color = Color::srgb(0.3, 0.6 * t + 0.2, 0.9);
+    }
+}
+
+fn drift(time: Res<Time>, mut shapes: Query<&mut Transform, With<Shape>>) {
+    for mut transform in &mut shapes {
+        transform.translation.x = (time.elapsed_seconds() * 0.5).sin() * 200.0;
+    }
+}
@@ -0,0 +1,67 @@
+//! Shows how systems and commands can return errors instead of panicking,
+//! and how to choose what happens to those errors.
+
+use bevy::ecs::error::{self, Result};
+use bevy::log::LogPlugin;
+use bevy::prelude::*;
+
+fn main() {
+    App::new()
+        .add_plugins(LogPlugin::default())
+        // By default, errors returned by systems and commands cause a panic.
+        // Here we log them as warnings instead.
+        .set_error_handler(error::warn)
+        .insert_resource(Config {
+            speed: "fast".to_string(),
+        })
+        .add_systems(Startup, spawn_player)
+        .add_systems(Update, (read_speed, heal_player, despawn_player).chain())
+        .run();
+}
+
+#[derive(Resource)]
+struct Config {
+    speed: String,
+}
+
+#[derive(Component)]
+struct Player;
+
+#[derive(Component)]
+struct Health(u32);
+
+fn spawn_player(mut commands: Commands) {
+    commands.spawn((Player, Health(10)));
+}
+
+// Systems returning a `Result` can use `?` to return early. The error is then passed to the
+// error handler, along with the name of the system.
+fn read_speed(config: Res<Config>) -> Result {
+    let speed: f32 = config.speed.parse()?;
+    info!("The speed is {speed}");
+    Ok(())
+}
+
+fn heal_player(mut commands: Commands, players: Query<Entity, With<Player>>) -> Result {
+    let player = players.get_single()?;
+
+    // Commands can fail too, for example if the entity is despawned before they are applied.
+    commands
+        .entity(player)
+        .queue(|mut entity: EntityWorldMut| -> Result {
+            let mut health = entity
+                .get_mut::<Health>()
+                .ok_or("the player has no health")?;
+            health.0 += 1;
+            info!("The player has {} health", health.0);
+            Ok(())
+        });
+    Ok(())
+}
+
+fn despawn_player(mut commands: Commands, players: Query<Entity, With<Player>>) {
+    for player in &players {
+        // `try_*` commands silently do nothing if the entity no longer exists.
+        commands.entity(player).try_despawn();
+    }
+}
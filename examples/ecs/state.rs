@@ -170,7 +170,7 @@ fn log_transitions(mut transitions: EventReader<StateTransitionEvent<AppState>>)
     for transition in transitions.read() {
         info!(
             "transition: {:?} => {:?}",
-            transition.before, transition.after
+            transition.exited, transition.entered
         );
     }
 }
@@ -0,0 +1,105 @@
+//! This example demonstrates how functions can be called dynamically using reflection.
+//!
+//! Function reflection is useful for calling regular Rust functions in a dynamic context,
+//! where the types of arguments, return values, and even the function itself aren't known at compile time.
+//!
+//! This can be used for things like adding scripting support to your application,
+//! processing deserialized reflection data, or even just storing type-erased versions of your functions.
+
+use bevy::log::LogPlugin;
+use bevy::prelude::*;
+use bevy::reflect::func::{ArgList, DynamicFunction, IntoFunction, ReflectMethods, Return};
+use std::any::TypeId;
+
+fn main() {
+    App::new()
+        .add_plugins(LogPlugin::default())
+        .register_type::<Player>()
+        // Registered functions are stored by name in the `AppFunctionRegistry` resource.
+        // By default, functions are named after their full type name (e.g. `function_reflection::add`).
+        .register_function(add)
+        // Closures don't have a name, so one must be provided.
+        .register_function_with_name("multiply", |a: i32, b: i32| a * b)
+        .add_systems(Startup, (call_functions, call_registered_functions))
+        .run();
+}
+
+/// Deriving `Reflect` also implements the traits needed to pass `Player` to reflected functions.
+///
+/// The `methods` attribute exposes the given methods through the `ReflectMethods` type data.
+#[derive(Reflect, Debug)]
+#[reflect(methods(heal, name))]
+struct Player {
+    name: String,
+    health: u32,
+}
+
+impl Player {
+    fn heal(&mut self, amount: u32) {
+        self.health += amount;
+    }
+
+    fn name(&self) -> &String {
+        &self.name
+    }
+}
+
+fn add(left: i32, right: i32) -> i32 {
+    left + right
+}
+
+fn call_functions(type_registry: Res<AppTypeRegistry>) {
+    // Most functions and closures can be converted into a `DynamicFunction` using `IntoFunction`.
+    let function: DynamicFunction = add.into_function();
+
+    // Arguments are passed in order through an `ArgList`.
+    // Each argument can be passed by value, by reference, or by mutable reference.
+    let args = ArgList::new().push_owned(2_i32).push_owned(3_i32);
+
+    // The result of the call is a `Return` value, which can hold an owned value or a reference.
+    let value: Box<dyn Reflect> = function.call(args).unwrap().unwrap_owned();
+    info!("add(2, 3) = {:?}", value.take::<i32>().unwrap());
+
+    // Calling a function with the wrong arguments returns an error instead of panicking.
+    let args = ArgList::new().push_owned(2_u32).push_owned(3_u32);
+    let error = function.call(args).unwrap_err();
+    info!("add(2_u32, 3_u32) failed: {error}");
+
+    // Methods exposed with `#[reflect(methods(...))]` can be looked up by name.
+    let type_registry = type_registry.read();
+    let methods = type_registry
+        .get_type_data::<ReflectMethods>(TypeId::of::<Player>())
+        .unwrap();
+
+    let mut player = Player {
+        name: String::from("Alice"),
+        health: 10,
+    };
+
+    // The receiver is always the first argument.
+    let args = ArgList::new().push_mut(&mut player).push_owned(5_u32);
+    let result = methods.call("heal", args).unwrap().unwrap();
+    assert!(result.is_unit());
+
+    let args = ArgList::new().push_ref(&player);
+    let Return::Ref(name) = methods.call("name", args).unwrap().unwrap() else {
+        unreachable!("`Player::name` returns a reference");
+    };
+    info!("{name:?} now has {} health", player.health);
+}
+
+fn call_registered_functions(function_registry: Res<AppFunctionRegistry>) {
+    let function_registry = function_registry.read();
+
+    for function in function_registry.iter() {
+        info!("Registered function: {function:?}");
+    }
+
+    let args = ArgList::new().push_owned(4_i32).push_owned(5_i32);
+    let value = function_registry
+        .call("multiply", args)
+        .unwrap()
+        .unwrap()
+        .unwrap_owned();
+    info!("multiply(4, 5) = {:?}", value.take::<i32>().unwrap());
+}
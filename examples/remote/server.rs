@@ -0,0 +1,63 @@
+//! A headless Bevy app that can be inspected and edited from another process through the
+//! Bevy Remote Protocol.
+//!
+//! While it runs, send requests to it with any HTTP client, for example `curl`:
+//!
+//! ```sh
+//! curl -X POST 127.0.0.1:15702 -H 'Content-Type: application/json' -d '{
+//!     "jsonrpc": "2.0",
+//!     "id": 1,
+//!     "method": "bevy/query",
+//!     "params": { "data": { "components": ["server::Player"] } }
+//! }'
+//! ```
+
+use bevy::{
+    app::ScheduleRunnerPlugin,
+    log::LogPlugin,
+    prelude::*,
+    remote::{http::RemoteHttpPlugin, RemotePlugin},
+    utils::Duration,
+};
+
+fn main() {
+    App::new()
+        .add_plugins((
+            MinimalPlugins.set(ScheduleRunnerPlugin::run_loop(Duration::from_secs_f64(
+                1.0 / 60.0,
+            ))),
+            LogPlugin::default(),
+        ))
+        .add_plugins((RemotePlugin::default(), RemoteHttpPlugin::default()))
+        // Only reflected components can be accessed remotely.
+        .register_type::<Player>()
+        .add_systems(Startup, setup)
+        .add_systems(Update, regenerate)
+        .run();
+}
+
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+struct Player {
+    name: String,
+    health: u32,
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Player {
+        name: "Ferris".to_string(),
+        health: 50,
+    });
+}
+
+fn regenerate(mut players: Query<&mut Player>, time: Res<Time>, mut timer: Local<Timer>) {
+    if timer.duration().is_zero() {
+        *timer = Timer::from_seconds(1.0, TimerMode::Repeating);
+    }
+    if !timer.tick(time.delta()).just_finished() {
+        return;
+    }
+    for mut player in &mut players {
+        player.health = (player.health + 1).min(100);
+    }
+}
@@ -21,6 +21,8 @@ crates=(
     bevy_core
     bevy_diagnostic
     bevy_hierarchy
+    bevy_state/macros
+    bevy_state
     bevy_transform
     bevy_window
     bevy_encase_derive
@@ -42,6 +44,7 @@ crates=(
     bevy_ui
     bevy_winit
     bevy_dev_tools
+    bevy_remote
     bevy_internal
     bevy_dylib
     bevy_color